@@ -1,4 +1,5 @@
 use fuels::{
+    accounts::impersonated_account::ImpersonatedAccount,
     core::codec::{calldata, encode_fn_selector, DecoderConfig, EncoderConfig},
     prelude::*,
     tx::ContractParameters,
@@ -36,6 +37,92 @@ async fn test_multiple_args() -> Result<()> {
     Ok(())
 }
 
+#[fuels::test(
+    Abigen(Contract(name = "TestContract", project = "e2e/sway/contracts/contract_test")),
+    Deploy(
+        name = "contract_instance",
+        contract = "TestContract",
+        wallet = "wallet"
+    )
+)]
+async fn test_attribute_injects_wallets_and_instances(
+    wallet: WalletUnlocked,
+    contract_instance: TestContract<WalletUnlocked>,
+    other_wallet: WalletUnlocked,
+) -> Result<()> {
+    let response = contract_instance.methods().get(5, 6).call().await?;
+    assert_eq!(response.value, 11);
+
+    assert_ne!(wallet.address(), other_wallet.address());
+    assert!(!other_wallet.get_balances().await?.is_empty());
+
+    Ok(())
+}
+
+#[fuels::test]
+async fn test_attribute_funds_every_argument(wallet: WalletUnlocked) -> Result<()> {
+    assert!(wallet.get_asset_balance(&AssetId::zeroed()).await? > 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contracts_are_built_on_demand() -> Result<()> {
+    let artifacts = build_sway_project("sway/contracts/contract_test")?;
+    assert_eq!(
+        build_sway_project("sway/contracts/contract_test")?,
+        artifacts
+    );
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    // The storage slots are loaded from the file next to the binary.
+    Contract::load_from(&artifacts.bin, LoadConfiguration::default())?
+        .deploy(&wallet, TxPolicies::default())
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contracts_can_be_included_in_genesis() -> Result<()> {
+    abigen!(Contract(
+        name = "TestContract",
+        abi = "e2e/sway/contracts/contract_test/out/release/contract_test-abi.json"
+    ));
+
+    let contract = Contract::load_from(
+        "sway/contracts/contract_test/out/release/contract_test.bin",
+        LoadConfiguration::default(),
+    )?;
+    let contract_id = contract.contract_id();
+
+    let mut wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(
+        wallet.address(),
+        AssetId::zeroed(),
+        DEFAULT_NUM_COINS,
+        DEFAULT_COIN_AMOUNT,
+    );
+    let state_config = StateConfig {
+        coins: coins.into_iter().map(Into::into).collect(),
+        contracts: vec![setup_genesis_contract(
+            contract.binary().to_vec(),
+            contract.salt(),
+            contract.storage_slots(),
+        )],
+        ..StateConfig::local_testnet()
+    };
+    let provider = setup_test_provider_from_state(state_config, None, None).await?;
+    wallet.set_provider(provider);
+
+    let contract_instance = TestContract::new(contract_id, wallet);
+    let response = contract_instance.methods().get(5, 6).call().await?;
+
+    assert_eq!(response.value, 11);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_contract_calling_contract() -> Result<()> {
     // Tests a contract call that calls another contract (FooCaller calls FooContract underneath)
@@ -1891,6 +1978,36 @@ async fn simulations_can_be_made_without_coins() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn simulations_can_be_made_as_an_impersonated_account() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "MyContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "MyContract",
+            wallet = "wallet"
+        )
+    );
+    let contract_id = contract_instance.contract_id();
+    let provider = wallet.provider().cloned();
+
+    let impersonated = ImpersonatedAccount::new(wallet.address().clone(), provider);
+
+    let response = MyContract::new(contract_id, impersonated)
+        .methods()
+        .get(5, 6)
+        .simulate(Execution::StateReadOnly)
+        .await?;
+
+    assert_eq!(response.value, 11);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn simulations_can_be_made_without_coins_multicall() -> Result<()> {
     setup_program_test!(
@@ -2105,3 +2222,102 @@ async fn max_fee_estimation_respects_tolerance() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn can_decode_contract_calls_of_raw_transactions() -> Result<()> {
+    use fuels::{
+        programs::calls::TransactionDecoder,
+        types::{transaction::TransactionType, Token},
+    };
+
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+    let contract_id = contract_instance.contract_id().clone();
+
+    let tx = contract_instance
+        .methods()
+        .initialize_counter(42)
+        .call_params(CallParameters::default().with_gas_forwarded(1_000_000))?
+        .build_tx()
+        .await?;
+    let bytes = TransactionType::Script(tx).encode();
+
+    let json_abi =
+        std::fs::read_to_string("sway/contracts/contract_test/out/release/contract_test-abi.json")?;
+    let decoder = TransactionDecoder::default().with_abi(contract_id.clone(), &json_abi)?;
+    let decoded = decoder.decode(&bytes)?;
+
+    let [call] = decoded.calls.as_slice() else {
+        panic!("should decode a single call");
+    };
+    assert_eq!(call.contract_id, contract_id);
+    assert_eq!(call.function_name, "initialize_counter");
+    assert_eq!(call.args, Some(vec![Token::U64(42)]));
+    assert_eq!(call.gas_forwarded, Some(1_000_000));
+
+    let calls = TransactionDecoder::default().decode(&bytes)?.calls;
+    assert_eq!(calls[0].function_name, "initialize_counter");
+    assert_eq!(calls[0].args, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn asset_events_are_decoded_from_receipts() -> Result<()> {
+    use fuels::types::{AssetEvent, AssetMinted, AssetTransferred, Bytes32};
+
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TokenContract",
+            project = "e2e/sway/contracts/token_ops"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TokenContract",
+            wallet = "wallet"
+        ),
+    );
+    let contract_id: ContractId = contract_instance.contract_id().into();
+    let asset_id = contract_instance.contract_id().asset_id(&Bits256::zeroed());
+    let contract_methods = contract_instance.methods();
+
+    let response = contract_methods.mint_coins(100).call().await?;
+    assert_eq!(
+        response.asset_events(),
+        [AssetEvent::Minted(AssetMinted {
+            contract_id,
+            sub_id: Bytes32::zeroed(),
+            asset_id,
+            amount: 100,
+        })]
+    );
+
+    let recipient = Identity::Address(wallet.address().into());
+    let response = contract_methods
+        .transfer(40, asset_id, recipient)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+    assert_eq!(
+        response.asset_events(),
+        [AssetEvent::Transferred(AssetTransferred {
+            from: contract_id,
+            to: recipient,
+            asset_id,
+            amount: 40,
+        })]
+    );
+
+    Ok(())
+}
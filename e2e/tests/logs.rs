@@ -479,6 +479,61 @@ async fn test_require_log() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_log_and_revert_assertions() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(
+            Contract(
+                name = "LogContract",
+                project = "e2e/sway/logs/contract_logs"
+            ),
+            Contract(
+                name = "RequireContract",
+                project = "e2e/sway/contracts/require"
+            ),
+        ),
+        Deploy(
+            name = "log_contract",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+        Deploy(
+            name = "require_contract",
+            contract = "RequireContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let response = log_contract
+        .methods()
+        .produce_logs_custom_types()
+        .call()
+        .await?;
+    assert_emitted!(
+        response,
+        TestStruct {
+            field_1: true,
+            field_3: 64,
+            ..
+        }
+    );
+    assert_emitted!(response, TestEnum, TestEnum::VariantTwo);
+    assert_receipt!(response.receipts, Receipt::LogData { .. });
+
+    let result = require_contract.methods().require_primitive().call().await;
+    assert_reverted_with!(result, 42u64);
+
+    let result = require_contract
+        .methods()
+        .require_with_additional_logs()
+        .call()
+        .await;
+    assert_reverted_with!(result, 64u64);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_multi_call_require_log_single_contract() -> Result<()> {
     setup_program_test!(
@@ -1636,3 +1691,182 @@ async fn script_heap_log() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn contract_events_can_be_streamed() -> Result<()> {
+    use futures::StreamExt;
+
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "e2e/sway/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let from_block = wallet.try_provider()?.latest_block_height().await? + 1;
+    let response = contract_instance
+        .methods()
+        .produce_logs_custom_types()
+        .call()
+        .await?;
+
+    let events = contract_instance
+        .events::<TestStruct>()
+        .from_block(from_block)
+        .stream()
+        .await?;
+    futures::pin_mut!(events);
+    let (event, meta) = events.next().await.expect("stream is not over")?;
+
+    assert_eq!(event.field_3, 64);
+    assert_eq!(meta.tx_id, response.tx_id.expect("is set"));
+    assert!(matches!(
+        response.receipts[meta.log_index],
+        Receipt::LogData { .. }
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn logs_can_be_decoded_as_json() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "e2e/sway/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let response = contract_instance
+        .methods()
+        .produce_logs_values()
+        .call()
+        .await?;
+
+    let logs = contract_instance
+        .log_decoder()
+        .decode_logs_json(&response.receipts, response.tx_id)?;
+
+    let values: Vec<_> = logs.iter().map(|log| log["value"].clone()).collect();
+    assert_eq!(values, [64, 32, 16, 8]);
+    assert_eq!(logs[0]["type"], "u64");
+    assert_eq!(logs[0]["receipt_index"], 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn log_formatters_can_be_registered_at_runtime() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "e2e/sway/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let mut log_decoder = contract_instance.log_decoder();
+    log_decoder.register_type_formatter(|value: u64| format!("{value} units"));
+    let contract_instance = contract_instance.with_log_decoder(log_decoder);
+
+    let response = contract_instance
+        .methods()
+        .produce_logs_values()
+        .call()
+        .await?;
+
+    let logs = response.decode_logs();
+    assert_eq!(logs.filter_succeeded()[0], "64 units");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contract_events_can_be_indexed() -> Result<()> {
+    use fuels::programs::indexer::{EventIndexer, EventQuery};
+
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "e2e/sway/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let provider = wallet.try_provider()?.clone();
+    let mut indexer = EventIndexer::in_memory(provider.clone())?
+        .watch(&contract_instance)
+        .starting_at(provider.latest_block_height().await? + 1);
+
+    contract_instance
+        .methods()
+        .produce_logs_values()
+        .call()
+        .await?;
+
+    assert_eq!(indexer.sync().await?, 4);
+    assert_eq!(indexer.sync().await?, 0);
+
+    let events = indexer.query(
+        &EventQuery::default()
+            .with_contract_id(contract_instance.contract_id())
+            .with_event_type("u64"),
+    )?;
+    let values: Vec<_> = events.iter().map(|event| event.value.clone()).collect();
+    assert_eq!(values, [64, 32, 16, 8]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn logs_of_several_types_can_be_decoded_together() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "e2e/sway/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let response = contract_instance
+        .methods()
+        .produce_logs_custom_types()
+        .call()
+        .await?;
+
+    let logs = response.decode_logs_of_types::<(TestEnum, TestStruct)>()?;
+
+    assert_eq!(logs.len(), 2);
+    assert_eq!(logs[0].get::<TestStruct>().map(|log| log.field_3), Some(64));
+    assert_eq!(logs[1].get::<TestEnum>(), Some(&TestEnum::VariantTwo));
+    assert!(logs[0].receipt_index < logs[1].receipt_index);
+
+    Ok(())
+}
@@ -142,6 +142,33 @@ async fn transfer_coins_and_messages_to_predicate() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn predicates_can_be_funded_from_genesis() -> Result<()> {
+    let predicate =
+        Predicate::load_from("sway/predicates/basic_predicate/out/release/basic_predicate.bin")?;
+    let asset_id = AssetId::zeroed();
+
+    let funds = [(
+        predicate.address().clone(),
+        WalletConfig {
+            assets: vec![AssetConfig {
+                id: asset_id,
+                num_coins: 2,
+                coin_amount: 10,
+            }],
+            messages: vec![BridgedMessageConfig {
+                amount: 5,
+                data: vec![],
+            }],
+        },
+    )];
+    let provider = setup_test_provider_with_funds(&funds, None, None).await?;
+
+    assert_address_balance(predicate.address(), &provider, asset_id, 25).await;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn spend_predicate_coins_messages_basic() -> Result<()> {
     abigen!(Predicate(
@@ -424,6 +451,17 @@ async fn predicate_transfer_to_base_layer() -> Result<()> {
     assert_eq!(proof.amount, amount);
     assert_eq!(proof.recipient, base_layer_address);
 
+    let relay = predicate
+        .try_provider()?
+        .get_withdrawal_relay(&tx_id, &msg_nonce, CommitBlock::Height(2))
+        .await?
+        .expect("failed to retrieve withdrawal relay");
+
+    assert_eq!(relay.message.amount, amount);
+    assert_eq!(relay.message.recipient, base_layer_address.hash());
+    assert_eq!(relay.root_block_header.height, 2);
+    assert_eq!(relay.calldata()[..4], WithdrawalRelay::selector());
+
     Ok(())
 }
 
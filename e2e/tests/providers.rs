@@ -12,12 +12,14 @@ use fuels::{
         block::Block,
         coin_type::CoinType,
         errors::transaction::Reason,
+        input::Input,
         message::Message,
         transaction_builders::{BuildableTransaction, ScriptTransactionBuilder},
         tx_status::TxStatus,
-        Bits256,
+        Bits256, Bytes32, Nonce,
     },
 };
+use futures::{StreamExt, TryStreamExt};
 
 #[tokio::test]
 async fn test_provider_launch_and_connect() -> Result<()> {
@@ -96,6 +98,495 @@ async fn test_network_error() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn provider_fails_over_to_healthy_node() -> Result<()> {
+    let primary = FuelService::start(
+        NodeConfig::default(),
+        ChainConfig::default(),
+        StateConfig::default(),
+    )
+    .await?;
+    let backup = FuelService::start(
+        NodeConfig::default(),
+        ChainConfig::default(),
+        StateConfig::default(),
+    )
+    .await?;
+
+    let provider = Provider::connect_multi(
+        [
+            "127.0.0.1:1".to_string(),
+            primary.bound_address().to_string(),
+            backup.bound_address().to_string(),
+        ],
+        FailoverPolicy::PrimaryBackup,
+    )
+    .await?;
+    assert_eq!(provider.urls().count(), 3);
+
+    provider.produce_blocks(1, None).await?;
+    primary.stop().await.unwrap();
+
+    // The first node was never reachable and the second one went down.
+    assert_eq!(provider.latest_block_height().await?, 0);
+
+    backup.stop().await.unwrap();
+    assert!(matches!(
+        provider.chain_info().await,
+        Err(Error::Provider(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn provider_requests_go_through_middlewares() -> Result<()> {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use fuels::accounts::provider::middleware::{Middleware, Next, NodeRequest, NodeResponse};
+
+    /// Counts the requests for `chain_info` and fails every other one.
+    #[derive(Debug, Clone, Default)]
+    struct Chaos {
+        chain_info_requests: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl Middleware for Chaos {
+        async fn handle(
+            &self,
+            request: &mut NodeRequest<'_>,
+            next: Next<'_>,
+        ) -> std::io::Result<NodeResponse> {
+            if request.operation == "chain_info"
+                && self.chain_info_requests.fetch_add(1, Ordering::Relaxed) % 2 == 0
+            {
+                return Err(std::io::Error::other("chaos"));
+            }
+
+            next.run(request).await
+        }
+    }
+
+    let chaos = Chaos::default();
+    let provider = setup_test_provider(vec![], vec![], None, None)
+        .await?
+        .with_middleware(chaos.clone());
+
+    assert!(provider.chain_info().await.is_err());
+    provider.chain_info().await?;
+    assert_eq!(chaos.chain_info_requests.load(Ordering::Relaxed), 2);
+
+    // Retried requests go through the middlewares again.
+    let provider = provider.with_retry_config(RetryConfig::new(2, Backoff::default())?);
+    provider.chain_info().await?;
+    assert_eq!(chaos.chain_info_requests.load(Ordering::Relaxed), 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn middlewares_see_the_arguments_and_add_headers() -> Result<()> {
+    use std::sync::{Arc, Mutex};
+
+    use fuels::accounts::provider::middleware::{Middleware, Next, NodeRequest, NodeResponse};
+
+    /// Records the requests and adds an api key to them.
+    #[derive(Debug, Clone, Default)]
+    struct ApiKey {
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Middleware for ApiKey {
+        async fn handle(
+            &self,
+            request: &mut NodeRequest<'_>,
+            next: Next<'_>,
+        ) -> std::io::Result<NodeResponse> {
+            self.requests.lock().unwrap().push(request.cache_key());
+            request
+                .headers
+                .insert("x-api-key", "secret".parse().expect("valid header"));
+
+            next.run(request).await
+        }
+    }
+
+    let api_key = ApiKey::default();
+    let provider = setup_test_provider(vec![], vec![], None, None)
+        .await?
+        .with_middleware(api_key.clone());
+
+    #[derive(serde::Deserialize)]
+    struct Data {
+        chain: serde_json::Value,
+    }
+    let data: Data = provider
+        .raw_query("query { chain { name } }", serde_json::json!({}))
+        .await?;
+    assert!(data.chain.is_object());
+
+    // Requests sent by the `FuelClient` cannot carry the headers.
+    let error = provider
+        .produce_blocks(1, None)
+        .await
+        .expect_err("should fail");
+    assert!(error.to_string().contains("cannot add headers"));
+
+    let requests = api_key.requests.lock().unwrap();
+    assert!(requests[0].starts_with("raw_query") && requests[0].contains("chain { name }"));
+    assert_eq!(requests[1], "produce_blocks(1, None)");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn chaos_middleware_injects_faults() -> Result<()> {
+    set_test_seed(7);
+
+    let mut wallet = launch_provider_and_get_wallet().await?;
+    let chaos = ChaosMiddleware::new(ChaosConfig {
+        dropped_submissions: 1.0,
+        reorged_blocks: 1.0,
+        ..Default::default()
+    });
+    let provider = wallet
+        .try_provider()?
+        .clone()
+        .with_middleware(chaos.clone());
+    wallet.set_provider(provider.clone());
+
+    let balance = wallet.get_asset_balance(provider.base_asset_id()).await?;
+    let result = wallet
+        .transfer(
+            &Bech32Address::default(),
+            100,
+            *provider.base_asset_id(),
+            TxPolicies::default(),
+        )
+        .await;
+
+    assert!(result.is_err());
+    assert!(chaos.faults().contains(&Fault::DroppedSubmission));
+    assert_eq!(
+        wallet.get_asset_balance(provider.base_asset_id()).await?,
+        balance
+    );
+
+    let height = provider.latest_block_height().await?;
+    let first = provider
+        .block_by_height(height.into())
+        .await?
+        .expect("block exists");
+    let second = provider
+        .block_by_height(height.into())
+        .await?
+        .expect("block exists");
+
+    assert_ne!(first.id, second.id);
+    assert!(chaos.faults().contains(&Fault::ReorgedBlock { height }));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn provider_reports_metrics() -> Result<()> {
+    use std::sync::{Arc, Mutex};
+
+    let requests = Arc::new(Mutex::new(vec![]));
+    let submissions = Arc::new(Mutex::new(vec![]));
+
+    let mut wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?.clone().with_metrics({
+        let requests = requests.clone();
+        let submissions = submissions.clone();
+        move |event| match event {
+            MetricEvent::NodeRequest {
+                operation, error, ..
+            } => requests.lock().unwrap().push((*operation, error.is_some())),
+            MetricEvent::TransactionSubmission {
+                tx_id,
+                bytes,
+                error,
+                ..
+            } => submissions
+                .lock()
+                .unwrap()
+                .push((*tx_id, *bytes, error.is_some())),
+        }
+    });
+    wallet.set_provider(provider.clone());
+
+    provider.chain_info().await?;
+    assert_eq!(*requests.lock().unwrap(), [("chain_info", false)]);
+
+    let (tx_id, _) = wallet
+        .transfer(
+            &Bech32Address::default(),
+            100,
+            *provider.base_asset_id(),
+            TxPolicies::default(),
+        )
+        .await?;
+
+    let submissions = submissions.lock().unwrap();
+    assert_eq!(submissions.len(), 1);
+    let (submitted_id, bytes, failed) = submissions[0];
+    assert_eq!(submitted_id, tx_id);
+    assert!(bytes > 0);
+    assert!(!failed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn provider_caches_chain_info_when_sending() -> Result<()> {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use fuels::accounts::provider::middleware::{Middleware, Next, NodeRequest, NodeResponse};
+
+    #[derive(Debug, Clone, Default)]
+    struct ChainInfoCounter(Arc<AtomicUsize>);
+
+    #[async_trait::async_trait]
+    impl Middleware for ChainInfoCounter {
+        async fn handle(
+            &self,
+            request: &mut NodeRequest<'_>,
+            next: Next<'_>,
+        ) -> std::io::Result<NodeResponse> {
+            if request.operation == "chain_info" {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+
+            next.run(request).await
+        }
+    }
+
+    let mut wallets =
+        launch_custom_provider_and_get_wallets(WalletsConfig::default(), None, None).await?;
+    let mut wallet = wallets.pop().unwrap();
+    let counter = ChainInfoCounter::default();
+    let provider = wallet
+        .try_provider()?
+        .clone()
+        .with_middleware(counter.clone())
+        .with_chain_info_ttl(std::time::Duration::from_secs(60));
+    wallet.set_provider(provider.clone());
+
+    let receiver = WalletUnlocked::new_random(None);
+    for _ in 0..3 {
+        wallet
+            .transfer(
+                receiver.address(),
+                100,
+                AssetId::zeroed(),
+                TxPolicies::default(),
+            )
+            .await?;
+    }
+    assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+
+    provider.invalidate_chain_info();
+    wallet
+        .transfer(
+            receiver.address(),
+            100,
+            AssetId::zeroed(),
+            TxPolicies::default(),
+        )
+        .await?;
+    assert_eq!(counter.0.load(Ordering::Relaxed), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn provider_throttles_requests() -> Result<()> {
+    let provider = setup_test_provider(vec![], vec![], None, None)
+        .await?
+        .with_rate_limit(RateLimit::new(2, std::time::Duration::from_millis(500))?)
+        .with_endpoint_rate_limit(
+            RateLimit::per_second(10)?.with_jitter(std::time::Duration::from_millis(10)),
+        );
+
+    // The burst of two goes through, the three requests after it are queued.
+    let start = std::time::Instant::now();
+    for _ in 0..5 {
+        provider.latest_block_height().await?;
+    }
+    assert!(start.elapsed() >= std::time::Duration::from_millis(750));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn provider_tracks_blocks_for_reorgs() -> Result<()> {
+    let provider = setup_test_provider(vec![], vec![], None, None).await?;
+    assert!(provider.check_reorg().await.is_err());
+
+    let provider = provider.with_reorg_tracking(5);
+    provider.produce_blocks(3, None).await?;
+    let blocks = provider.blocks_stream(..).try_collect::<Vec<_>>().await?;
+    assert_eq!(blocks.len(), 4);
+
+    // The node never replaces its blocks.
+    assert!(provider.check_reorg().await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_send_raw_graphql_queries() -> Result<()> {
+    #[derive(serde::Deserialize)]
+    struct Data {
+        block: Option<BlockData>,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct BlockData {
+        height: String,
+    }
+
+    let provider = setup_test_provider(vec![], vec![], None, None).await?;
+    provider.produce_blocks(3, None).await?;
+
+    let data: Data = provider
+        .raw_query(
+            "query($height: U32) { block(height: $height) { height } }",
+            serde_json::json!({ "height": "2" }),
+        )
+        .await?;
+    assert_eq!(data.block.unwrap().height, "2");
+
+    let error = provider
+        .raw_query::<Data>("query { unknownField }", serde_json::json!({}))
+        .await
+        .expect_err("should fail");
+    assert!(matches!(error, Error::Provider(_)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_verify_blocks_and_transaction_inclusion() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?.clone();
+
+    let (tx_id, _) = wallet
+        .transfer(
+            &Bech32Address::default(),
+            100,
+            *provider.base_asset_id(),
+            TxPolicies::default(),
+        )
+        .await?;
+
+    let ConsensusConfig::PoA { signing_key } = ChainConfig::default().consensus;
+    let genesis = provider.block_by_height(0.into()).await?.unwrap();
+    let verifier = BlockVerifier::new(signing_key).with_genesis_id(genesis.id);
+    let verified_provider = provider.clone().with_block_verifier(verifier);
+
+    let block = verified_provider
+        .verify_transaction_inclusion(&tx_id)
+        .await?;
+    assert!(block.transactions.contains(&tx_id));
+
+    let height = verified_provider.latest_block_height().await?;
+    let tip = verified_provider.verify_header_chain(height).await?;
+    assert_eq!(tip.header.height, height);
+
+    let untrusted_provider = provider.with_block_verifier(BlockVerifier::new(Address::zeroed()));
+    let error = untrusted_provider
+        .block_by_height(block.header.height.into())
+        .await
+        .expect_err("block is not signed by the zero address");
+    assert!(matches!(error, Error::Provider(_)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn checks_node_compatibility_on_connect() -> Result<()> {
+    let provider = setup_test_provider(vec![], vec![], None, None).await?;
+
+    let compatibility = provider.node_compatibility();
+    assert_ne!(compatibility.level, CompatibilityLevel::Incompatible);
+    let schema_hash = compatibility
+        .schema_hash
+        .clone()
+        .expect("fuel-core allows introspection");
+
+    compatibility.ensure_compatible(Some(&schema_hash))?;
+    let error = compatibility
+        .ensure_compatible(Some("00"))
+        .expect_err("should fail");
+    assert!(matches!(error, Error::Provider(_)));
+
+    // the schema does not change between connections to the same node
+    let reconnected = Provider::connect(provider.url()).await?;
+    assert_eq!(
+        reconnected.node_compatibility().schema_hash,
+        Some(schema_hash)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_provider_replays_recorded_traffic() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let node = wallet.try_provider()?;
+    let fixture = tempfile::NamedTempFile::new()?;
+
+    let (balance, height) = {
+        let recorder = MockProvider::record(node.url(), fixture.path()).await?;
+        let provider = recorder.provider().await?;
+
+        (
+            provider
+                .get_asset_balance(wallet.address(), *provider.base_asset_id())
+                .await?,
+            provider.latest_block_height().await?,
+        )
+    };
+    // the node is not needed anymore
+    node.produce_blocks(2, None).await?;
+
+    let mock = MockProvider::replay(fixture.path()).await?;
+    let provider = mock.provider().await?;
+    assert_eq!(
+        provider
+            .get_asset_balance(wallet.address(), *provider.base_asset_id())
+            .await?,
+        balance
+    );
+    assert_eq!(provider.latest_block_height().await?, height);
+
+    mock.set_response("balance", serde_json::json!({ "amount": "42" }));
+    assert_eq!(
+        provider
+            .get_asset_balance(wallet.address(), *provider.base_asset_id())
+            .await?,
+        42
+    );
+
+    let error = provider
+        .get_messages(wallet.address())
+        .await
+        .expect_err("was not recorded");
+    assert!(error.to_string().contains("no recorded response"));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_input_message() -> Result<()> {
     let compare_messages =
@@ -219,71 +710,312 @@ async fn can_increase_block_height() -> Result<()> {
 }
 
 #[tokio::test]
-async fn can_set_custom_block_time() -> Result<()> {
-    // ANCHOR: use_produce_blocks_custom_time
-    let block_time = 20u32; // seconds
-    let config = NodeConfig {
-        // This is how you specify the time between blocks
-        block_production: Trigger::Interval {
-            block_time: std::time::Duration::from_secs(block_time.into()),
-        },
-        ..NodeConfig::default()
-    };
+async fn can_subscribe_to_blocks_and_tx_status() -> Result<()> {
     let wallets =
-        launch_custom_provider_and_get_wallets(WalletsConfig::default(), Some(config), None)
-            .await?;
+        launch_custom_provider_and_get_wallets(WalletsConfig::default(), None, None).await?;
     let wallet = &wallets[0];
     let provider = wallet.try_provider()?;
 
-    assert_eq!(provider.latest_block_height().await?, 0u32);
-    let origin_block_time = provider.latest_block_time().await?.unwrap();
-    let blocks_to_produce = 3;
+    let blocks = provider.subscribe_blocks().await?;
+    provider.produce_blocks(2, None).await?;
 
-    provider.produce_blocks(blocks_to_produce, None).await?;
-    assert_eq!(provider.latest_block_height().await?, blocks_to_produce);
-    let expected_latest_block_time = origin_block_time
-        .checked_add_signed(Duration::try_seconds((blocks_to_produce * block_time) as i64).unwrap())
-        .unwrap();
-    assert_eq!(
-        provider.latest_block_time().await?.unwrap(),
-        expected_latest_block_time
+    let heights = blocks
+        .take(2)
+        .map_ok(|block| block.header.height)
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(heights, [1, 2]);
+
+    let asset_id = AssetId::zeroed();
+    let amount = 100;
+    let inputs = wallet
+        .get_asset_inputs_for_amount(asset_id, amount, None)
+        .await?;
+    let outputs = wallet.get_asset_outputs_for_amount(
+        WalletUnlocked::new_random(None).address(),
+        asset_id,
+        amount,
     );
-    // ANCHOR_END: use_produce_blocks_custom_time
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    tb.add_signer(wallet.clone())?;
+    wallet.adjust_for_fee(&mut tb, amount).await?;
+    let tx = tb.build(provider).await?;
 
-    let req = PaginationRequest {
-        cursor: None,
-        results: 10,
-        direction: PageDirection::Forward,
-    };
-    let blocks: Vec<Block> = provider.get_blocks(req).await?.results;
+    let tx_id = provider.send_transaction(tx).await?;
+    let statuses = provider
+        .subscribe_tx_status(&tx_id)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    assert!(matches!(statuses.last(), Some(TxStatus::Success { .. })));
 
-    assert_eq!(blocks[1].header.time.unwrap().timestamp(), 20);
-    assert_eq!(blocks[2].header.time.unwrap().timestamp(), 40);
-    assert_eq!(blocks[3].header.time.unwrap().timestamp(), 60);
     Ok(())
 }
 
 #[tokio::test]
-async fn can_retrieve_latest_block_time() -> Result<()> {
-    let provider = setup_test_provider(vec![], vec![], None, None).await?;
-    let since_epoch = 1676039910;
-
-    let latest_timestamp = Utc.timestamp_opt(since_epoch, 0).unwrap();
-    provider.produce_blocks(1, Some(latest_timestamp)).await?;
+async fn resubmitting_a_transaction_resolves_to_it() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
 
-    assert_eq!(
-        provider.latest_block_time().await?.unwrap(),
-        latest_timestamp
+    let amount = 100;
+    let inputs = wallet
+        .get_asset_inputs_for_amount(*provider.base_asset_id(), amount, None)
+        .await?;
+    let outputs = wallet.get_asset_outputs_for_amount(
+        WalletUnlocked::new_random(None).address(),
+        *provider.base_asset_id(),
+        amount,
     );
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    tb.add_signer(wallet.clone())?;
+    wallet.adjust_for_fee(&mut tb, amount).await?;
+    let tx = tb.build(provider).await?;
+
+    let tx_id = provider.send_transaction(tx.clone()).await?;
+    assert_eq!(provider.send_transaction(tx.clone()).await?, tx_id);
+
+    provider
+        .await_transaction_commit::<ScriptTransaction>(tx_id)
+        .await?;
+    assert_eq!(provider.send_transaction(tx.clone()).await?, tx_id);
+    assert!(matches!(
+        provider.send_transaction_and_await_commit(tx).await?,
+        TxStatus::Success { .. }
+    ));
 
     Ok(())
 }
 
 #[tokio::test]
-async fn contract_deployment_respects_maturity() -> Result<()> {
-    abigen!(Contract(name="MyContract", abi="e2e/sway/contracts/transaction_block_height/out/release/transaction_block_height-abi.json"));
+async fn spendable_balance_settles_once_committed() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let asset_id = *provider.base_asset_id();
+    let receiver = WalletUnlocked::new_random(None);
 
-    let wallets =
+    let amount = 100;
+    let inputs = wallet
+        .get_asset_inputs_for_amount(asset_id, amount, None)
+        .await?;
+    let outputs = wallet.get_asset_outputs_for_amount(receiver.address(), asset_id, amount);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    tb.add_signer(wallet.clone())?;
+    wallet.adjust_for_fee(&mut tb, amount).await?;
+    let tx = tb.build(provider).await?;
+
+    let tx_id = provider.send_transaction(tx).await?;
+    provider
+        .await_transaction_commit::<ScriptTransaction>(tx_id)
+        .await?;
+
+    // The committed transfer is no longer counted as pending, so it isn't applied twice.
+    assert_eq!(
+        provider
+            .get_spendable_balance(receiver.address(), asset_id)
+            .await?,
+        amount
+    );
+    assert_eq!(
+        provider
+            .get_spendable_balance(wallet.address(), asset_id)
+            .await?,
+        provider
+            .get_asset_balance(wallet.address(), asset_id)
+            .await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_stream_paginated_results() -> Result<()> {
+    let wallet = WalletUnlocked::new_random(None);
+    let num_coins = 250;
+    let coins = setup_single_asset_coins(wallet.address(), AssetId::zeroed(), num_coins, 1);
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+
+    let streamed_coins = provider
+        .coins_stream(wallet.address(), AssetId::zeroed())
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(streamed_coins.len(), num_coins as usize);
+
+    provider.produce_blocks(250, None).await?;
+    let heights = provider
+        .blocks_stream(120..=130)
+        .map_ok(|block| block.header.height)
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(heights, (120..=130).collect::<Vec<_>>());
+
+    let all_blocks = provider.blocks_stream(..).try_collect::<Vec<_>>().await?;
+    assert_eq!(all_blocks.len(), 251);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_scan_historical_receipts() -> Result<()> {
+    let wallets =
+        launch_custom_provider_and_get_wallets(WalletsConfig::default(), None, None).await?;
+    let wallet = &wallets[0];
+    let provider = wallet.try_provider()?;
+
+    let receiver = WalletUnlocked::new_random(None);
+    let mut tx_ids = vec![];
+    for _ in 0..3 {
+        let (tx_id, _) = wallet
+            .transfer(
+                receiver.address(),
+                100,
+                AssetId::zeroed(),
+                TxPolicies::default(),
+            )
+            .await?;
+        tx_ids.push(tx_id);
+    }
+
+    let filter = ReceiptFilter::default()
+        .with_kind(ReceiptKind::ScriptResult)
+        .with_concurrency(2);
+    let scanned = provider
+        .scan_receipts(filter.clone())
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(
+        scanned
+            .iter()
+            .map(|scanned| scanned.tx_id)
+            .collect::<Vec<_>>(),
+        tx_ids
+    );
+
+    let resumed = provider
+        .scan_receipts(filter.resume_after(scanned[0].cursor))
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(resumed, scanned[1..]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_subscribe_to_receipts() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+
+    let filter = ReceiptFilter::default().with_kind(ReceiptKind::ScriptResult);
+    let mut receipts = Box::pin(provider.subscribe_receipts(filter).await?);
+
+    let (tx_id, _) = wallet
+        .transfer(
+            &Bech32Address::default(),
+            100,
+            AssetId::zeroed(),
+            TxPolicies::default(),
+        )
+        .await?;
+
+    let scanned = receipts.next().await.expect("stream should not end")?;
+    assert_eq!(scanned.tx_id, tx_id);
+    assert!(matches!(scanned.receipt, Receipt::ScriptResult { .. }));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_set_custom_block_time() -> Result<()> {
+    // ANCHOR: use_produce_blocks_custom_time
+    let block_time = 20u32; // seconds
+    let config = NodeConfig {
+        // This is how you specify the time between blocks
+        block_production: Trigger::Interval {
+            block_time: std::time::Duration::from_secs(block_time.into()),
+        },
+        ..NodeConfig::default()
+    };
+    let wallets =
+        launch_custom_provider_and_get_wallets(WalletsConfig::default(), Some(config), None)
+            .await?;
+    let wallet = &wallets[0];
+    let provider = wallet.try_provider()?;
+
+    assert_eq!(provider.latest_block_height().await?, 0u32);
+    let origin_block_time = provider.latest_block_time().await?.unwrap();
+    let blocks_to_produce = 3;
+
+    provider.produce_blocks(blocks_to_produce, None).await?;
+    assert_eq!(provider.latest_block_height().await?, blocks_to_produce);
+    let expected_latest_block_time = origin_block_time
+        .checked_add_signed(Duration::try_seconds((blocks_to_produce * block_time) as i64).unwrap())
+        .unwrap();
+    assert_eq!(
+        provider.latest_block_time().await?.unwrap(),
+        expected_latest_block_time
+    );
+    // ANCHOR_END: use_produce_blocks_custom_time
+
+    let req = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+    let blocks: Vec<Block> = provider.get_blocks(req).await?.results;
+
+    assert_eq!(blocks[1].header.time.unwrap().timestamp(), 20);
+    assert_eq!(blocks[2].header.time.unwrap().timestamp(), 40);
+    assert_eq!(blocks[3].header.time.unwrap().timestamp(), 60);
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_estimate_heights_from_durations() -> Result<()> {
+    let config = NodeConfig {
+        block_production: Trigger::Interval {
+            block_time: std::time::Duration::from_secs(20),
+        },
+        ..NodeConfig::default()
+    };
+    let provider = setup_test_provider(vec![], vec![], Some(config), None).await?;
+
+    assert!(provider.average_block_time().await.is_err());
+
+    provider.produce_blocks(3, None).await?;
+
+    assert_eq!(
+        provider.average_block_time().await?,
+        std::time::Duration::from_secs(20)
+    );
+    let height = provider
+        .estimate_height_in(std::time::Duration::from_secs(50))
+        .await?;
+    assert_eq!(height, 6);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_retrieve_latest_block_time() -> Result<()> {
+    let provider = setup_test_provider(vec![], vec![], None, None).await?;
+    let since_epoch = 1676039910;
+
+    let latest_timestamp = Utc.timestamp_opt(since_epoch, 0).unwrap();
+    provider.produce_blocks(1, Some(latest_timestamp)).await?;
+
+    assert_eq!(
+        provider.latest_block_time().await?.unwrap(),
+        latest_timestamp
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contract_deployment_respects_maturity() -> Result<()> {
+    abigen!(Contract(name="MyContract", abi="e2e/sway/contracts/transaction_block_height/out/release/transaction_block_height-abi.json"));
+
+    let wallets =
         launch_custom_provider_and_get_wallets(WalletsConfig::default(), None, None).await?;
     let wallet = &wallets[0];
     let provider = wallet.try_provider()?;
@@ -967,6 +1699,41 @@ async fn can_produce_blocks_with_trig_never() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn can_produce_blocks_at_an_interval() -> Result<()> {
+    let config = NodeConfig {
+        block_production: Trigger::Never,
+        ..NodeConfig::default()
+    };
+    let provider = setup_test_provider(vec![], vec![], Some(config), None).await?;
+    let start_time = Utc.timestamp_opt(1676039910, 0).unwrap();
+
+    let height = provider
+        .produce_blocks_with_interval(3, Some(start_time), std::time::Duration::from_secs(3600))
+        .await?;
+
+    assert_eq!(height, 3);
+    assert_eq!(
+        provider.latest_block_time().await?.unwrap(),
+        start_time + Duration::hours(2)
+    );
+
+    provider
+        .produce_blocks_with_interval(1, None, std::time::Duration::from_secs(60))
+        .await?;
+    assert_eq!(
+        provider.latest_block_time().await?.unwrap(),
+        start_time + Duration::hours(2) + Duration::minutes(1)
+    );
+
+    assert!(provider
+        .produce_blocks_with_interval(1, None, std::time::Duration::from_millis(500))
+        .await
+        .is_err());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn can_upload_executor_and_trigger_upgrade() -> Result<()> {
     let mut wallet = WalletUnlocked::new_random(None);
@@ -1163,3 +1930,607 @@ async fn tx_with_witness_data() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn can_track_and_claim_deposits() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let nonce = Nonce::from([1; 32]);
+
+    let coins = setup_single_asset_coins(wallet.address(), AssetId::zeroed(), 1, 1);
+    let deposit = setup_single_message(
+        &Bech32Address::default(),
+        wallet.address(),
+        DEFAULT_COIN_AMOUNT,
+        nonce,
+        vec![],
+    );
+    let provider = setup_test_provider(coins, vec![deposit], None, None).await?;
+    wallet.set_provider(provider.clone());
+
+    let message = provider
+        .await_deposit(wallet.address(), &nonce, std::time::Duration::from_secs(5))
+        .await?;
+    assert_eq!(message.amount, DEFAULT_COIN_AMOUNT);
+
+    let unknown_nonce = Nonce::from([2; 32]);
+    assert!(provider
+        .get_deposit(wallet.address(), &unknown_nonce)
+        .await?
+        .is_none());
+
+    wallet
+        .claim_deposit(&message, TxPolicies::default())
+        .await?;
+
+    assert!(provider
+        .get_deposit(wallet.address(), &nonce)
+        .await?
+        .is_none());
+    let coins = wallet.get_coins(AssetId::zeroed()).await?;
+    assert!(coins
+        .iter()
+        .any(|coin| coin.amount > 1 && coin.amount < DEFAULT_COIN_AMOUNT));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_get_gas_price_stats() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+
+    for tip in [1, 2, 3, 4] {
+        wallet
+            .transfer(
+                &Bech32Address::default(),
+                1,
+                *provider.base_asset_id(),
+                TxPolicies::default().with_tip(tip),
+            )
+            .await?;
+    }
+
+    let stats = provider.gas_price_stats(4).await?;
+
+    assert_eq!(stats.horizon, 4);
+    let tips = stats.tips.expect("recent blocks should have transactions");
+    assert_eq!(tips.transactions_count, 4);
+    assert_eq!((tips.min, tips.p50, tips.max), (1, 2, 4));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn block_walker_resumes_from_persisted_cursor() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+
+    wallet
+        .transfer(
+            &Bech32Address::default(),
+            1,
+            *provider.base_asset_id(),
+            TxPolicies::default(),
+        )
+        .await?;
+    provider.produce_blocks(2, None).await?;
+
+    let mut walker = BlockWalker::new(provider.clone(), 1).with_transactions();
+    let first = walker.next_block().await?.expect("block 1 should exist");
+    assert_eq!(first.block.header.height, 1);
+    // the transfer and the mint
+    assert_eq!(first.transactions.len(), 2);
+
+    let persisted = serde_json::to_string(&walker.cursor().expect("cursor should be set"))
+        .expect("cursor should serialize");
+    drop(walker);
+
+    let cursor: BlockCursor = serde_json::from_str(&persisted).expect("cursor should deserialize");
+    let mut walker = BlockWalker::resume(provider.clone(), cursor);
+    let heights = [
+        walker
+            .next_block()
+            .await?
+            .map(|walked| walked.block.header.height),
+        walker
+            .next_block()
+            .await?
+            .map(|walked| walked.block.header.height),
+        walker
+            .next_block()
+            .await?
+            .map(|walked| walked.block.header.height),
+    ];
+    assert_eq!(heights, [Some(2), Some(3), None]);
+
+    let unknown_cursor = BlockCursor {
+        height: 2,
+        block_id: Bytes32::zeroed(),
+    };
+    let error = BlockWalker::resume(provider.clone(), unknown_cursor)
+        .next_block()
+        .await
+        .expect_err("should detect the replaced cursor block");
+    assert!(error.to_string().contains("no longer part of the chain"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_fork_state_snapshot_locally() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let base_asset_id = *provider.base_asset_id();
+
+    let contract_id = Contract::load_from(
+        "sway/contracts/contract_test/out/release/contract_test.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await?;
+    wallet
+        .force_transfer_to_contract(&contract_id, 100, base_asset_id, TxPolicies::default())
+        .await?;
+
+    let snapshot = provider
+        .state_snapshot(&[wallet.address().clone()], &[contract_id.clone()])
+        .await?;
+    let fork = setup_test_provider_from_state(snapshot, None, None).await?;
+
+    assert_eq!(
+        fork.get_balances(wallet.address()).await?,
+        provider.get_balances(wallet.address()).await?
+    );
+    assert_eq!(
+        fork.get_contract_asset_balance(&contract_id, base_asset_id)
+            .await?,
+        100
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_dry_run_multiple_transactions() -> Result<()> {
+    let wallets =
+        launch_custom_provider_and_get_wallets(WalletsConfig::new(Some(2), None, None), None, None)
+            .await?;
+    let provider = wallets[0].try_provider()?;
+    let base_asset_id = *provider.base_asset_id();
+
+    let mut txs = vec![];
+    for wallet in &wallets {
+        let amount = 100;
+        let inputs = wallet
+            .get_asset_inputs_for_amount(base_asset_id, amount, None)
+            .await?;
+        let outputs =
+            wallet.get_asset_outputs_for_amount(&Bech32Address::default(), base_asset_id, amount);
+        let mut tb =
+            ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+        tb.add_signer(wallet.clone())?;
+        wallet.adjust_for_fee(&mut tb, amount).await?;
+
+        txs.push(tb.build(provider).await?);
+    }
+    let tx_ids: Vec<_> = txs.iter().map(|tx| tx.id(provider.chain_id())).collect();
+
+    let statuses = provider.dry_run_multiple(txs.into_iter().collect()).await?;
+
+    assert_eq!(
+        statuses.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+        tx_ids
+    );
+    assert!(statuses
+        .iter()
+        .all(|(_, status)| matches!(status, TxStatus::Success { .. })));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_get_balances_of_many_addresses() -> Result<()> {
+    let wallets =
+        launch_custom_provider_and_get_wallets(WalletsConfig::new(Some(3), None, None), None, None)
+            .await?;
+    let provider = wallets[0].try_provider()?;
+    let empty_address = WalletUnlocked::new_random(None).address().clone();
+
+    let mut addresses: Vec<_> = wallets
+        .iter()
+        .map(|wallet| wallet.address().clone())
+        .collect();
+    addresses.push(empty_address.clone());
+
+    let balances = provider.balances_many(&addresses).await?;
+
+    assert_eq!(balances.len(), addresses.len());
+    for wallet in &wallets {
+        assert_eq!(
+            balances[wallet.address()],
+            provider.get_balances(wallet.address()).await?
+        );
+    }
+    assert!(balances[&empty_address].is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn send_transaction_once_does_not_pay_twice() -> Result<()> {
+    use fuels::accounts::submission_journal::MemoryJournal;
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet
+        .try_provider()?
+        .clone()
+        .with_submission_journal(MemoryJournal::default());
+    let base_asset_id = *provider.base_asset_id();
+
+    let mut txs = vec![];
+    for amount in [100, 200] {
+        let inputs = wallet
+            .get_asset_inputs_for_amount(base_asset_id, amount, None)
+            .await?;
+        let outputs =
+            wallet.get_asset_outputs_for_amount(&Bech32Address::default(), base_asset_id, amount);
+        let mut tb =
+            ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+        tb.add_signer(wallet.clone())?;
+        wallet.adjust_for_fee(&mut tb, amount).await?;
+
+        txs.push(tb.build(&provider).await?);
+    }
+    let [first, retry]: [ScriptTransaction; 2] = txs.try_into().unwrap();
+
+    let tx_id = provider.send_transaction_once("payment-1", first).await?;
+    assert_eq!(provider.journaled_transaction("payment-1")?, Some(tx_id));
+
+    assert_eq!(
+        provider.send_transaction_once("payment-1", retry).await?,
+        tx_id
+    );
+    provider
+        .await_transaction_commit::<ScriptTransaction>(tx_id)
+        .await?;
+    assert_eq!(
+        provider.get_balances(&Bech32Address::default()).await?[&base_asset_id.to_string()],
+        100
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn requests_fail_after_their_timeout() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?.clone();
+
+    let impatient = provider
+        .clone()
+        .with_timeouts(Timeouts::default().with_query(std::time::Duration::from_nanos(1)));
+    let err = impatient
+        .latest_block_height()
+        .await
+        .expect_err("should time out");
+    assert!(matches!(err, Error::Provider(msg) if msg.starts_with("timed out")));
+
+    let (tx_id, _) = wallet
+        .transfer(
+            &Bech32Address::default(),
+            100,
+            *provider.base_asset_id(),
+            TxPolicies::default(),
+        )
+        .await?;
+    let err = provider
+        .await_transaction_commit_until::<ScriptTransaction>(
+            Bytes32::zeroed(),
+            std::time::Instant::now(),
+        )
+        .await
+        .expect_err("should time out");
+    assert!(matches!(err, Error::Provider(msg) if msg.starts_with("timed out")));
+
+    let status = provider
+        .await_transaction_commit_until::<ScriptTransaction>(
+            tx_id,
+            std::time::Instant::now() + std::time::Duration::from_secs(10),
+        )
+        .await?;
+    assert!(matches!(status, TxStatus::Success { .. }));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_build_transactions_offline() -> Result<()> {
+    use fuels::types::{ConsensusParametersFile, OfflineContext};
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let base_asset_id = *provider.base_asset_id();
+
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("consensus_parameters.json");
+    provider.consensus_parameters().save(&path)?;
+    let gas_price = provider.latest_gas_price().await?.gas_price;
+
+    let offline = OfflineContext::load(&path, gas_price)?;
+    let inputs = wallet
+        .get_asset_inputs_for_amount(base_asset_id, 1000, None)
+        .await?;
+    let outputs =
+        wallet.get_asset_outputs_for_amount(&Bech32Address::default(), base_asset_id, 100);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(
+        inputs,
+        outputs,
+        TxPolicies::default().with_script_gas_limit(0),
+    );
+    tb.add_signer(wallet.clone())?;
+    let tx = tb.build(&offline).await?;
+
+    let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+    assert!(matches!(tx_status, TxStatus::Success { .. }));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn wallet_without_provider_can_build_transfers() -> Result<()> {
+    use fuels::types::OfflineContext;
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let base_asset_id = *provider.base_asset_id();
+
+    let offline_wallet = WalletUnlocked::new_random(None);
+    wallet
+        .transfer(
+            offline_wallet.address(),
+            1000,
+            base_asset_id,
+            TxPolicies::default(),
+        )
+        .await?;
+    let inputs = provider
+        .get_coins(offline_wallet.address(), base_asset_id)
+        .await?
+        .into_iter()
+        .map(|coin| Input::resource_signed(CoinType::Coin(coin)))
+        .collect::<Vec<_>>();
+
+    let offline = OfflineContext::new(
+        provider.consensus_parameters().clone(),
+        provider.latest_gas_price().await?.gas_price,
+    );
+    let err = offline_wallet
+        .build_transfer(
+            &Bech32Address::default(),
+            1001,
+            base_asset_id,
+            inputs.clone(),
+            TxPolicies::default(),
+            &offline,
+        )
+        .await
+        .expect_err("inputs should not cover the transfer");
+    assert!(matches!(err, Error::Other(_)));
+
+    let tx = offline_wallet
+        .build_transfer(
+            &Bech32Address::default(),
+            100,
+            base_asset_id,
+            inputs,
+            TxPolicies::default(),
+            &offline,
+        )
+        .await?;
+
+    let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+    assert!(matches!(tx_status, TxStatus::Success { .. }));
+    assert_eq!(
+        provider
+            .get_asset_balance(&Bech32Address::default(), base_asset_id)
+            .await?,
+        100
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn privileged_account_can_upgrade_the_chain() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(
+        wallet.address(),
+        AssetId::zeroed(),
+        100,
+        DEFAULT_COIN_AMOUNT,
+    );
+
+    let mut chain_config = ChainConfig::local_testnet();
+    chain_config
+        .consensus_parameters
+        .set_privileged_address(wallet.address().into());
+    let mut consensus_parameters = chain_config.consensus_parameters.clone();
+
+    let provider = setup_test_provider(coins, vec![], None, Some(chain_config)).await?;
+    wallet.set_provider(provider.clone());
+
+    // This is downloaded over in `build.rs`
+    let executor = std::fs::read(Path::new(env!("OUT_DIR")).join("fuel-core-wasm-executor.wasm"))?;
+    let root = wallet
+        .upload_bytecode(&executor, 65536, TxPolicies::default())
+        .await?;
+    wallet
+        .upgrade_state_transition(root, TxPolicies::default())
+        .await?;
+
+    let tx_params = consensus_parameters.tx_params().with_max_inputs(100);
+    consensus_parameters.set_tx_params(tx_params);
+    wallet
+        .upgrade_consensus_parameters(&consensus_parameters, TxPolicies::default())
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transaction_cost_breaks_down_the_fee() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let base_asset_id = *provider.base_asset_id();
+    let tip = 10;
+
+    let inputs = wallet
+        .get_asset_inputs_for_amount(base_asset_id, 100, None)
+        .await?;
+    let outputs =
+        wallet.get_asset_outputs_for_amount(&Bech32Address::default(), base_asset_id, 100);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(
+        inputs,
+        outputs,
+        TxPolicies::default().with_tip(tip),
+    );
+    tb.add_signer(wallet.clone())?;
+    wallet.adjust_for_fee(&mut tb, 100).await?;
+    let tx = tb.build(provider).await?;
+
+    let cost = provider.estimate_transaction_cost(tx, None, None).await?;
+
+    assert_eq!(cost.tip, tip);
+    assert_eq!(cost.base_fee + cost.tip, cost.total_fee);
+    assert!(cost.predicate_gas_used.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_speed_up_pending_transactions() -> Result<()> {
+    let config = NodeConfig {
+        block_production: Trigger::Never,
+        ..NodeConfig::default()
+    };
+    let wallets =
+        launch_custom_provider_and_get_wallets(WalletsConfig::default(), Some(config), None)
+            .await?;
+    let wallet = &wallets[0];
+    let provider = wallet.try_provider()?;
+    let base_asset_id = *provider.base_asset_id();
+
+    let inputs = wallet
+        .get_asset_inputs_for_amount(base_asset_id, 100, None)
+        .await?;
+    let outputs =
+        wallet.get_asset_outputs_for_amount(&Bech32Address::default(), base_asset_id, 100);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    tb.add_signer(wallet.clone())?;
+    let tx = tb.build(provider).await?;
+    let tx_id = provider.send_transaction(tx.clone()).await?;
+
+    let err = wallet
+        .speed_up(tx.clone(), 0)
+        .await
+        .expect_err("the tip should have to grow");
+    assert!(matches!(err, Error::Other(_)));
+
+    let replacement_id = wallet.speed_up(tx, 10).await?;
+    assert_ne!(replacement_id, tx_id);
+    provider.produce_blocks(1, None).await?;
+
+    let status = provider.tx_status(&replacement_id).await?;
+    assert!(matches!(status, TxStatus::Success { .. }));
+    let status = provider.tx_status(&tx_id).await?;
+    assert!(!matches!(status, TxStatus::Success { .. }));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn can_cancel_pending_transactions() -> Result<()> {
+    let config = NodeConfig {
+        block_production: Trigger::Never,
+        ..NodeConfig::default()
+    };
+    let wallets =
+        launch_custom_provider_and_get_wallets(WalletsConfig::default(), Some(config), None)
+            .await?;
+    let wallet = &wallets[0];
+    let provider = wallet.try_provider()?;
+    let base_asset_id = *provider.base_asset_id();
+
+    let inputs = wallet
+        .get_asset_inputs_for_amount(base_asset_id, 100, None)
+        .await?;
+    let outputs =
+        wallet.get_asset_outputs_for_amount(&Bech32Address::default(), base_asset_id, 100);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    tb.add_signer(wallet.clone())?;
+    let tx = tb.build(provider).await?;
+    let tx_id = provider.send_transaction(tx.clone()).await?;
+
+    let err = wallets[1]
+        .cancel(&tx)
+        .await
+        .expect_err("the transaction spends no coin of the other wallet");
+    assert!(matches!(err, Error::Other(_)));
+
+    let cancellation_id = wallet.cancel(&tx).await?;
+    provider.produce_blocks(1, None).await?;
+
+    let status = provider.tx_status(&cancellation_id).await?;
+    assert!(matches!(status, TxStatus::Success { .. }));
+    let status = provider.tx_status(&tx_id).await?;
+    assert!(!matches!(status, TxStatus::Success { .. }));
+    assert_eq!(
+        provider
+            .get_asset_balance(&Bech32Address::default(), base_asset_id)
+            .await?,
+        0
+    );
+
+    Ok(())
+}
+
+#[cfg(not(feature = "fuel-core-lib"))]
+#[tokio::test]
+async fn cluster_validators_sync_the_blocks_of_the_producer() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(
+        wallet.address(),
+        AssetId::zeroed(),
+        DEFAULT_NUM_COINS,
+        DEFAULT_COIN_AMOUNT,
+    );
+    let cluster = launch_cluster(2, coins, vec![], None, None).await?;
+    wallet.set_provider(cluster.producer().clone());
+
+    let recipient = WalletUnlocked::new_random(None);
+    let (tx_id, _) = wallet
+        .transfer(
+            recipient.address(),
+            100,
+            AssetId::zeroed(),
+            TxPolicies::default(),
+        )
+        .await?;
+
+    let height = cluster.producer().latest_block_height().await?;
+    cluster
+        .await_height(height, std::time::Duration::from_secs(10))
+        .await?;
+
+    for validator in cluster.validators() {
+        assert!(validator.get_transaction_by_id(&tx_id).await?.is_some());
+        assert_eq!(
+            validator
+                .get_asset_balance(recipient.address(), AssetId::zeroed())
+                .await?,
+            100
+        );
+    }
+
+    Ok(())
+}
@@ -2,9 +2,10 @@ use std::iter::repeat;
 
 use fuel_tx::{input::coin::CoinSigned, Input};
 use fuels::{
+    accounts::wallet::DEFAULT_DERIVATION_PATH_PREFIX,
     prelude::*,
     tx::{TxPointer, UtxoId},
-    types::{output::Output, Bytes32},
+    types::{output::Output, Bytes32, Nonce},
 };
 
 #[tokio::test]
@@ -394,6 +395,39 @@ async fn transfer_more_than_owned() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn complete_balances_span_multiple_pages() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let asset_id = AssetId::from([1; 32usize]);
+
+    let mut coins = setup_single_asset_coins(wallet.address(), asset_id, 150, 10);
+    coins.extend(setup_single_asset_coins(
+        wallet.address(),
+        AssetId::zeroed(),
+        120,
+        1,
+    ));
+    let messages = vec![setup_single_message(
+        &Bech32Address::default(),
+        wallet.address(),
+        1000,
+        Nonce::default(),
+        vec![],
+    )];
+
+    let provider = setup_test_provider(coins, messages, None, None).await?;
+    wallet.set_provider(provider);
+
+    let balances = wallet.get_complete_balances(None).await?;
+    assert_eq!(balances[&asset_id], 1500);
+    assert_eq!(balances[&AssetId::zeroed()], 1120);
+
+    assert_eq!(wallet.get_messages().await?.len(), 1);
+    assert!(wallet.get_complete_balances(Some(200)).await.is_err());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn transfer_coins_of_non_base_asset() -> Result<()> {
     const AMOUNT: u64 = 10000;
@@ -433,6 +467,237 @@ async fn transfer_coins_of_non_base_asset() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn multi_transfer_of_mixed_assets() -> Result<()> {
+    let mut sender = WalletUnlocked::new_random(None);
+    let receivers = [
+        WalletUnlocked::new_random(None),
+        WalletUnlocked::new_random(None),
+    ];
+
+    let asset_id = AssetId::from([1; 32usize]);
+    let mut coins = setup_single_asset_coins(sender.address(), asset_id, 2, 1000);
+    coins.extend(setup_single_asset_coins(
+        sender.address(),
+        AssetId::zeroed(),
+        2,
+        1000,
+    ));
+
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    sender.set_provider(provider.clone());
+
+    sender
+        .multi_transfer(
+            &[
+                (receivers[0].address().clone(), 300, asset_id),
+                (receivers[1].address().clone(), 400, asset_id),
+                (receivers[1].address().clone(), 50, AssetId::zeroed()),
+            ],
+            TxPolicies::default(),
+        )
+        .await?;
+
+    assert_eq!(sender.get_asset_balance(&asset_id).await?, 1300);
+    assert_eq!(
+        provider
+            .get_asset_balance(receivers[0].address(), asset_id)
+            .await?,
+        300
+    );
+    assert_eq!(
+        provider
+            .get_asset_balance(receivers[1].address(), asset_id)
+            .await?,
+        400
+    );
+    assert_eq!(
+        provider
+            .get_asset_balance(receivers[1].address(), AssetId::zeroed())
+            .await?,
+        50
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn observers_audit_and_veto_transactions() -> Result<()> {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use fuels::{
+        accounts::observer::{ObservedAccount, TransactionObserver},
+        types::{
+            errors::{error, Error},
+            transaction::TransactionSummary,
+        },
+    };
+
+    #[derive(Debug, Default, Clone)]
+    struct AuditLog {
+        signed: Arc<Mutex<Vec<Bytes32>>>,
+        submitted: Arc<Mutex<Vec<Bytes32>>>,
+        max_outputs: usize,
+    }
+
+    #[async_trait]
+    impl TransactionObserver for AuditLog {
+        async fn on_sign(&self, summary: &TransactionSummary) -> Result<()> {
+            if summary.outputs.len() > self.max_outputs {
+                return Err(error!(Other, "too many outputs"));
+            }
+            self.signed.lock().unwrap().push(summary.id);
+
+            Ok(())
+        }
+
+        async fn on_submit(&self, summary: &TransactionSummary, error: Option<&Error>) {
+            assert!(error.is_none());
+            self.submitted.lock().unwrap().push(summary.id);
+        }
+    }
+
+    let mut wallet = WalletUnlocked::new_random(None);
+    let mut other_wallet = WalletUnlocked::new_random(None);
+    let mut coins = setup_single_asset_coins(wallet.address(), AssetId::zeroed(), 1, 1000);
+    coins.extend(setup_single_asset_coins(
+        other_wallet.address(),
+        AssetId::zeroed(),
+        1,
+        1000,
+    ));
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    wallet.set_provider(provider.clone());
+    other_wallet.set_provider(provider);
+
+    let audit_log = AuditLog {
+        max_outputs: 2,
+        ..Default::default()
+    };
+    let observed = ObservedAccount::new(wallet).with_observer(audit_log.clone());
+
+    let receiver = WalletUnlocked::new_random(None);
+    let (tx_id, _) = observed
+        .transfer(
+            receiver.address(),
+            100,
+            AssetId::zeroed(),
+            TxPolicies::default(),
+        )
+        .await?;
+
+    assert_eq!(*audit_log.signed.lock().unwrap(), [tx_id]);
+    assert_eq!(*audit_log.submitted.lock().unwrap(), [tx_id]);
+
+    // other accounts sharing the provider are not observed
+    other_wallet
+        .transfer(
+            receiver.address(),
+            100,
+            AssetId::zeroed(),
+            TxPolicies::default(),
+        )
+        .await?;
+    assert_eq!(audit_log.signed.lock().unwrap().len(), 1);
+
+    let vetoed = observed
+        .multi_transfer(
+            &[
+                (receiver.address().clone(), 100, AssetId::zeroed()),
+                (receiver.address().clone(), 100, AssetId::zeroed()),
+            ],
+            TxPolicies::default(),
+        )
+        .await
+        .expect_err("should be vetoed");
+    assert!(vetoed.to_string().contains("too many outputs"));
+    assert_eq!(audit_log.submitted.lock().unwrap().len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sign_transaction_offline() -> Result<()> {
+    use fuels::types::unsigned_transaction::{OfflineSigner, UnsignedTransaction};
+
+    // Never connected to a provider.
+    let cold_wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(cold_wallet.address(), AssetId::zeroed(), 1, 1000);
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+
+    let watch_only = Wallet::from_address(cold_wallet.address().clone(), Some(provider.clone()));
+    let receiver = WalletUnlocked::new_random(None);
+
+    let inputs = watch_only
+        .get_spendable_resources(AssetId::zeroed(), 100, None)
+        .await?
+        .into_iter()
+        .map(fuels::types::input::Input::resource_signed)
+        .collect();
+    let outputs = vec![
+        Output::coin(receiver.address().into(), 100, AssetId::zeroed()),
+        Output::change(watch_only.address().into(), 0, AssetId::zeroed()),
+    ];
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    tb.add_signer(OfflineSigner::new(watch_only.address().clone()))?;
+    let tx = tb.build(&provider).await?;
+
+    let exported = UnsignedTransaction::new(tx, provider.chain_id())?.to_json()?;
+
+    // On the air-gapped machine.
+    let mut unsigned = UnsignedTransaction::from_json(&exported)?;
+    unsigned.sign(&cold_wallet).await?;
+    let signed = unsigned.to_json()?;
+
+    let tx: ScriptTransaction = UnsignedTransaction::from_json(&signed)?.into_transaction()?;
+    provider.send_transaction_and_await_commit(tx).await?;
+
+    assert_eq!(
+        provider
+            .get_asset_balance(receiver.address(), AssetId::zeroed())
+            .await?,
+        100
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn watch_only_account_upgraded_to_signing() -> Result<()> {
+    use fuels::accounts::signers::SignerAccount;
+
+    let wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(wallet.address(), AssetId::zeroed(), 1, 1000);
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+
+    let mut account = SignerAccount::watch_only(wallet.address().clone(), Some(provider));
+    assert_eq!(account.get_asset_balance(&AssetId::zeroed()).await?, 1000);
+
+    let receiver = WalletUnlocked::new_random(None);
+    assert!(account
+        .transfer(
+            receiver.address(),
+            100,
+            AssetId::zeroed(),
+            TxPolicies::default()
+        )
+        .await
+        .is_err());
+
+    account.attach_signer(wallet)?;
+    account
+        .transfer(
+            receiver.address(),
+            100,
+            AssetId::zeroed(),
+            TxPolicies::default(),
+        )
+        .await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_transfer_with_multiple_signatures() -> Result<()> {
     let wallet_config = base_asset_wallet_config(5);
@@ -478,3 +743,309 @@ async fn test_transfer_with_multiple_signatures() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn discover_funded_accounts_from_mnemonic() -> Result<()> {
+    let phrase = "oblige salon price punch saddle immune slogan rare snap desert retire surprise";
+    let funded_indices = [0, 2, 5];
+
+    let coins = funded_indices
+        .iter()
+        .map(|index| {
+            let path = format!("{DEFAULT_DERIVATION_PATH_PREFIX}/{index}'/0/0");
+            let wallet = WalletUnlocked::new_from_mnemonic_phrase_with_path(phrase, None, &path)?;
+
+            Ok(setup_single_asset_coins(
+                wallet.address(),
+                AssetId::zeroed(),
+                1,
+                100,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .concat();
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+
+    let discovered = WalletUnlocked::discover_accounts(phrase, &provider, 3).await?;
+    assert_eq!(discovered.len(), 3);
+
+    let discovered = WalletUnlocked::discover_accounts(phrase, &provider, 2).await?;
+    assert_eq!(discovered.len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn consolidate_coins() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(wallet.address(), AssetId::zeroed(), 300, 1_000);
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    wallet.set_provider(provider);
+
+    let tx_ids = wallet
+        .consolidate_coins(AssetId::zeroed(), 3, TxPolicies::default())
+        .await?;
+
+    assert!(tx_ids.len() > 1);
+    assert!(wallet.get_coins(AssetId::zeroed()).await?.len() <= 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sweep_all_assets() -> Result<()> {
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new_multiple_assets(
+            2,
+            vec![
+                AssetConfig {
+                    id: AssetId::zeroed(),
+                    num_coins: 5,
+                    coin_amount: 1_000_000,
+                },
+                AssetConfig {
+                    id: AssetId::from([1u8; 32]),
+                    num_coins: 3,
+                    coin_amount: 100,
+                },
+            ],
+        ),
+        None,
+        None,
+    )
+    .await?;
+    let receiver = wallets.pop().unwrap();
+    let sender = wallets.pop().unwrap();
+
+    sender
+        .sweep(receiver.address(), None, TxPolicies::default())
+        .await?;
+
+    assert!(sender.get_balances().await?.is_empty());
+    assert_eq!(
+        receiver
+            .get_asset_balance(&AssetId::from([1u8; 32]))
+            .await?,
+        600
+    );
+    assert!(receiver.get_asset_balance(&AssetId::zeroed()).await? > 5_000_000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sponsor_pays_the_fee() -> Result<()> {
+    let asset_id = AssetId::from([1u8; 32]);
+    let mut sender = WalletUnlocked::new_random(None);
+    let mut sponsor = WalletUnlocked::new_random(None);
+    let receiver = WalletUnlocked::new_random(None);
+
+    let mut coins = setup_single_asset_coins(sender.address(), asset_id, 1, 100);
+    coins.extend(setup_single_asset_coins(
+        sponsor.address(),
+        AssetId::zeroed(),
+        1,
+        1_000_000,
+    ));
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    sender.set_provider(provider.clone());
+    sponsor.set_provider(provider.clone());
+
+    let inputs = sender
+        .get_asset_inputs_for_amount(asset_id, 100, None)
+        .await?;
+    let outputs = sender.get_asset_outputs_for_amount(receiver.address(), asset_id, 100);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    sender.add_witnesses(&mut tb)?;
+    sponsor.sponsor_fee(&mut tb).await?;
+
+    let tx = tb.build(&provider).await?;
+    provider
+        .send_transaction_and_await_commit(tx)
+        .await?
+        .check(None)?;
+
+    assert_eq!(
+        provider
+            .get_asset_balance(receiver.address(), asset_id)
+            .await?,
+        100
+    );
+    assert!(sender.get_balances().await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transactions_are_validated_locally() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let consensus_parameters = provider.consensus_parameters();
+    let base_asset_id = *provider.base_asset_id();
+
+    let inputs = wallet
+        .get_asset_inputs_for_amount(base_asset_id, 100, None)
+        .await?;
+    let outputs =
+        wallet.get_asset_outputs_for_amount(&Bech32Address::default(), base_asset_id, 100);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    tb.add_signer(wallet.clone())?;
+    let tx = tb.build(provider).await?;
+
+    assert!(tx.validate(consensus_parameters, 1).is_empty());
+
+    let mut restrictive_parameters = consensus_parameters.clone();
+    let tx_params = restrictive_parameters.tx_params().with_max_inputs(0);
+    restrictive_parameters.set_tx_params(tx_params);
+    // Changing the tip invalidates the signature of the only input.
+    let violations = tx
+        .with_tip(1)
+        .validate(&restrictive_parameters, 1_000_000_000);
+
+    assert!(violations.contains(&fuel_tx::ValidityError::TransactionInputsMax));
+    assert!(violations.contains(&fuel_tx::ValidityError::InputInvalidSignature { index: 0 }));
+    assert!(violations.iter().any(|violation| matches!(
+        violation,
+        fuel_tx::ValidityError::InsufficientFeeAmount { .. }
+    )));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transactions_can_be_summarized_before_signing() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let base_asset_id = *provider.base_asset_id();
+    let receiver = Bech32Address::default();
+
+    let inputs = wallet
+        .get_asset_inputs_for_amount(base_asset_id, 100, None)
+        .await?;
+    let input_amount: u64 = inputs.iter().map(|input| input.amount().unwrap()).sum();
+    let outputs = wallet.get_asset_outputs_for_amount(&receiver, base_asset_id, 100);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    tb.add_signer(wallet.clone())?;
+
+    let summary = tb.summary(provider).await?;
+
+    assert_eq!(summary.signers, vec![wallet.address().clone()]);
+    assert_eq!(summary.inputs_by_asset[&base_asset_id], input_amount);
+    assert_eq!(summary.outputs_by_asset[&base_asset_id], 100);
+    assert!(summary.max_fee.is_some());
+
+    let tx = tb.build(provider).await?;
+    let signed_summary = tx.summary(provider.consensus_parameters());
+
+    assert_eq!(signed_summary.size, summary.size);
+    assert_eq!(signed_summary.signers, summary.signers);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn payouts_are_split_across_transactions() -> Result<()> {
+    use fuels::{
+        accounts::payout::Payout,
+        tx::{ConsensusParameters, TxParameters},
+    };
+
+    let mut consensus_parameters = ConsensusParameters::default();
+    let tx_params = TxParameters::default()
+        .with_max_outputs(4)
+        .with_max_size(1_000_000);
+    consensus_parameters.set_tx_params(tx_params);
+    let chain_config = ChainConfig {
+        consensus_parameters,
+        ..ChainConfig::default()
+    };
+
+    let asset_id = AssetId::from([1u8; 32]);
+    let mut wallet = WalletUnlocked::new_random(None);
+    let mut coins = setup_single_asset_coins(wallet.address(), AssetId::zeroed(), 2, 1_000_000);
+    coins.extend(setup_single_asset_coins(
+        wallet.address(),
+        asset_id,
+        2,
+        1_000,
+    ));
+    let provider = setup_test_provider(coins, vec![], None, Some(chain_config)).await?;
+    wallet.set_provider(provider.clone());
+
+    let recipients: Vec<_> = (0..3)
+        .map(|_| WalletUnlocked::new_random(None).address().clone())
+        .collect();
+    let payout = Payout::new()
+        .with_payment(recipients[0].clone(), asset_id, 100)
+        .with_payment(recipients[1].clone(), asset_id, 200)
+        .with_payment(recipients[2].clone(), AssetId::zeroed(), 300);
+
+    let tx_ids = payout.send(&wallet).await?;
+
+    // Two payments, a change output for their asset and one for the fee fill a transaction.
+    assert_eq!(tx_ids.len(), 2);
+    for (recipient, asset_id, amount) in [
+        (&recipients[0], asset_id, 100),
+        (&recipients[1], asset_id, 200),
+        (&recipients[2], AssetId::zeroed(), 300),
+    ] {
+        assert_eq!(
+            provider.get_asset_balance(recipient, asset_id).await?,
+            amount
+        );
+    }
+    assert_eq!(wallet.get_asset_balance(&asset_id).await?, 1_700);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn canonical_order_gives_the_same_transaction_ids() -> Result<()> {
+    use fuels::types::{input::Input, transaction_builders::TxLayout};
+
+    async fn build_transfer_id(
+        wallet: &WalletUnlocked,
+        inputs: Vec<Input>,
+        outputs: Vec<Output>,
+    ) -> Result<Bytes32> {
+        let provider = wallet.try_provider()?;
+        let tx_policies = TxPolicies::default().with_max_fee(1_000);
+        let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies)
+            .with_layout(TxLayout::default().with_canonical_order());
+        tb.add_signer(wallet.clone())?;
+
+        Ok(tb.build(provider).await?.id(provider.chain_id()))
+    }
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let base_asset_id = *provider.base_asset_id();
+
+    let amount = DEFAULT_COIN_AMOUNT + 1;
+    let inputs = wallet
+        .get_asset_inputs_for_amount(base_asset_id, amount, None)
+        .await?;
+    assert!(inputs.len() > 1);
+    let mut outputs =
+        wallet.get_asset_outputs_for_amount(&Bech32Address::default(), base_asset_id, amount);
+
+    let tx_id = build_transfer_id(&wallet, inputs.clone(), outputs.clone()).await?;
+    let mut reordered_inputs = inputs.clone();
+    reordered_inputs.reverse();
+    outputs.reverse();
+
+    assert_eq!(
+        build_transfer_id(&wallet, reordered_inputs, outputs).await?,
+        tx_id
+    );
+
+    let missing_max_fee =
+        ScriptTransactionBuilder::prepare_transfer(inputs, vec![], TxPolicies::default())
+            .with_layout(TxLayout::default().with_canonical_order())
+            .with_build_strategy(ScriptBuildStrategy::NoSignatures)
+            .build(provider)
+            .await;
+    assert!(missing_max_fee.is_err());
+
+    Ok(())
+}
@@ -382,6 +382,14 @@ mod tests {
         // Verify the amount and recipient
         assert_eq!(proof.amount, amount);
         assert_eq!(proof.recipient, base_layer_address);
+
+        // Assemble the call relaying the withdrawal on the L1 message portal
+        let relay = wallet
+            .try_provider()?
+            .get_withdrawal_relay(&tx_id, &msg_id, CommitBlock::Height(2))
+            .await?
+            .expect("failed to retrieve withdrawal relay");
+        let _calldata = relay.calldata();
         // ANCHOR_END: wallet_withdraw_to_base
 
         Ok(())
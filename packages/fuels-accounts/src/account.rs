@@ -1,20 +1,29 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
 
 use async_trait::async_trait;
 use fuel_core_client::client::pagination::{PaginatedResult, PaginationRequest};
-use fuel_tx::{Output, Receipt, TxId, TxPointer, UtxoId};
-use fuel_types::{AssetId, Bytes32, ContractId, Nonce};
-use fuels_core::types::{
-    bech32::{Bech32Address, Bech32ContractId},
-    coin::Coin,
-    coin_type::CoinType,
-    coin_type_id::CoinTypeId,
-    errors::Result,
-    input::Input,
-    message::Message,
-    transaction::{Transaction, TxPolicies},
-    transaction_builders::{BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder},
-    transaction_response::TransactionResponse,
+use fuel_tx::{ConsensusParameters, Output, Receipt, TxId, TxPointer, UtxoId};
+use fuel_types::{Address, AssetId, Bytes32, ContractId, Nonce};
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::{Bech32Address, Bech32ContractId},
+        coin::Coin,
+        coin_type::CoinType,
+        coin_type_id::CoinTypeId,
+        errors::{error, Result},
+        input::Input,
+        message::Message,
+        transaction::{ScriptTransaction, Transaction, TxPolicies},
+        transaction_builders::{
+            BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder,
+            UpgradeTransactionBuilder, UploadTransactionBuilder,
+        },
+        transaction_response::TransactionResponse,
+        tx_status::TxStatus,
+        unsigned_transaction::UnsignedTransaction,
+        DryRunner,
+    },
 };
 
 use crate::{
@@ -25,6 +34,10 @@ use crate::{
     provider::{Provider, ResourceFilter},
 };
 
+/// Number of inputs [`Account::consolidate_coins`] keeps free in every transaction for the
+/// inputs covering the fee.
+const CONSOLIDATION_FEE_INPUTS: usize = 8;
+
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait ViewOnlyAccount: std::fmt::Debug + Send + Sync + Clone {
     fn address(&self) -> &Bech32Address;
@@ -58,6 +71,14 @@ pub trait ViewOnlyAccount: std::fmt::Debug + Send + Sync + Clone {
             .await
     }
 
+    /// Get the balance of `asset_id` once the transactions sent through the provider are
+    /// committed. See [`Provider::get_spendable_balance`].
+    async fn get_spendable_balance(&self, asset_id: &AssetId) -> Result<u64> {
+        self.try_provider()?
+            .get_spendable_balance(self.address(), *asset_id)
+            .await
+    }
+
     /// Gets all unspent messages owned by the account.
     async fn get_messages(&self) -> Result<Vec<Message>> {
         Ok(self.try_provider()?.get_messages(self.address()).await?)
@@ -70,6 +91,17 @@ pub trait ViewOnlyAccount: std::fmt::Debug + Send + Sync + Clone {
         self.try_provider()?.get_balances(self.address()).await
     }
 
+    /// Get the balances of all assets, computed from every coin and spendable message of the
+    /// account. See [`Provider::get_complete_balances`].
+    async fn get_complete_balances(
+        &self,
+        max_resources: Option<usize>,
+    ) -> Result<HashMap<AssetId, u64>> {
+        self.try_provider()?
+            .get_complete_balances(self.address(), max_resources)
+            .await
+    }
+
     /// Get some spendable resources (coins and messages) of asset `asset_id` owned by the account
     /// that add up at least to amount `amount`. The returned coins (UTXOs) are actual coins that
     /// can be spent. The number of UXTOs is optimized to prevent dust accumulation.
@@ -156,11 +188,65 @@ pub trait Account: ViewOnlyAccount {
         Ok(())
     }
 
+    /// Pays the fee of a transaction built by another account. Base asset inputs owned by this
+    /// account, and its witness, are added to `tb` to cover the whole fee while the remaining
+    /// inputs, outputs and witnesses stay untouched. The base asset change is returned to this
+    /// account, so `tb` must not already contain a base asset change output owned by someone else.
+    async fn sponsor_fee<Tb: TransactionBuilder + Sync>(&self, tb: &mut Tb) -> Result<()> {
+        let provider = self.try_provider()?;
+        let base_asset_id = provider.base_asset_id();
+
+        let foreign_base_change = tb.outputs().iter().any(|output| {
+            matches!(output, Output::Change { to, asset_id, .. }
+                if asset_id == base_asset_id && *to != Address::from(self.address()))
+        });
+        if foreign_base_change {
+            return Err(error!(
+                Other,
+                "cannot sponsor a transaction sending its base asset change to another owner"
+            ));
+        }
+
+        self.add_witnesses(tb)?;
+
+        let (base_assets, _) = available_base_assets_and_amount(tb, base_asset_id);
+        // The sponsor alone covers the fee, whatever base asset the other inputs hold.
+        let fee_amount = calculate_missing_base_amount(tb, 0, 0, provider).await?;
+
+        if fee_amount > 0 {
+            let fee_inputs = self
+                .get_asset_inputs_for_amount(*base_asset_id, fee_amount, Some(base_assets))
+                .await?;
+
+            adjust_inputs_outputs(tb, fee_inputs, self.address(), base_asset_id);
+        }
+
+        Ok(())
+    }
+
     // Add signatures to the builder if the underlying account is a wallet
     fn add_witnesses<Tb: TransactionBuilder>(&self, _tb: &mut Tb) -> Result<()> {
         Ok(())
     }
 
+    /// Sends a transaction of this account and waits for its commit, see
+    /// [`Provider::send_transaction_and_await_commit`]. The transactions sent by the methods of
+    /// the account, and by the contract calls and deployments it pays for, go through here.
+    async fn send_transaction_and_await_commit<T: Transaction + Send + Sync>(
+        &self,
+        tx: T,
+    ) -> Result<TxStatus> {
+        self.try_provider()?
+            .send_transaction_and_await_commit(tx)
+            .await
+    }
+
+    /// Sends a transaction of this account without waiting for its commit, see
+    /// [`Provider::send_transaction`].
+    async fn send_transaction<T: Transaction + Send>(&self, tx: T) -> Result<TxId> {
+        self.try_provider()?.send_transaction(tx).await
+    }
+
     /// Transfer funds from this account to another `Address`.
     /// Fails if amount for asset ID is larger than address's spendable coins.
     /// Returns the transaction ID that was sent and the list of receipts.
@@ -194,7 +280,115 @@ pub trait Account: ViewOnlyAccount {
         let tx = tx_builder.build(provider).await?;
         let tx_id = tx.id(provider.chain_id());
 
-        let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+        let tx_status = self.send_transaction_and_await_commit(tx).await?;
+
+        let receipts = tx_status.take_receipts_checked(None)?;
+
+        Ok((tx_id, receipts))
+    }
+
+    /// Builds and signs a transfer spending `inputs` without querying a node, e.g. with an
+    /// [`OfflineContext`](fuels_core::types::OfflineContext) as `dry_runner`. The fee is computed
+    /// from the consensus parameters and gas price of `dry_runner` and must be covered by the base
+    /// asset of `inputs`, whose change is returned to the account.
+    async fn build_transfer<D: DryRunner>(
+        &self,
+        to: &Bech32Address,
+        amount: u64,
+        asset_id: AssetId,
+        inputs: Vec<Input>,
+        tx_policies: TxPolicies,
+        dry_runner: &D,
+    ) -> Result<ScriptTransaction> {
+        let base_asset_id = *dry_runner.consensus_parameters().base_asset_id();
+        let mut outputs = self.get_asset_outputs_for_amount(to, asset_id, amount);
+        if asset_id != base_asset_id {
+            outputs.push(Output::change(self.address().into(), 0, base_asset_id));
+        }
+
+        let mut tx_builder =
+            ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
+        self.add_witnesses(&mut tx_builder)?;
+
+        let used_base_amount = if asset_id == base_asset_id { amount } else { 0 };
+        let (_, base_amount) = available_base_assets_and_amount(&tx_builder, &base_asset_id);
+        let missing_base_amount =
+            calculate_missing_base_amount(&tx_builder, base_amount, used_base_amount, dry_runner)
+                .await?;
+        if missing_base_amount > 0 {
+            return Err(error!(
+                Other,
+                "the inputs lack {missing_base_amount} of the base asset to cover the transfer and its fee"
+            ));
+        }
+
+        tx_builder.build(dry_runner).await
+    }
+
+    /// Transfers every `(to, amount, asset_id)` of `transfers` in a single transaction.
+    /// Assets may be mixed, a change output is added for each of them.
+    /// Returns the transaction ID that was sent and the list of receipts.
+    async fn multi_transfer(
+        &self,
+        transfers: &[(Bech32Address, u64, AssetId)],
+        tx_policies: TxPolicies,
+    ) -> Result<(TxId, Vec<Receipt>)> {
+        if transfers.is_empty() {
+            return Err(error!(
+                Other,
+                "`multi_transfer` requires at least one transfer"
+            ));
+        }
+
+        let provider = self.try_provider()?;
+
+        let mut totals: Vec<(AssetId, u64)> = vec![];
+        for (_, amount, asset_id) in transfers {
+            match totals.iter_mut().find(|(id, _)| id == asset_id) {
+                Some((_, total)) => {
+                    *total = total
+                        .checked_add(*amount)
+                        .ok_or_else(|| error!(Other, "transfer amounts overflow"))?
+                }
+                None => totals.push((*asset_id, *amount)),
+            }
+        }
+
+        let mut inputs = vec![];
+        for (asset_id, total) in &totals {
+            inputs.extend(
+                self.get_asset_inputs_for_amount(*asset_id, *total, None)
+                    .await?,
+            );
+        }
+
+        let outputs = transfers
+            .iter()
+            .map(|(to, amount, asset_id)| Output::coin(to.into(), *amount, *asset_id))
+            .chain(
+                totals
+                    .iter()
+                    .map(|(asset_id, _)| Output::change(self.address().into(), 0, *asset_id)),
+            )
+            .collect();
+
+        let mut tx_builder =
+            ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
+
+        self.add_witnesses(&mut tx_builder)?;
+
+        let used_base_amount = totals
+            .iter()
+            .find(|(asset_id, _)| asset_id == provider.base_asset_id())
+            .map(|(_, total)| *total)
+            .unwrap_or_default();
+        self.adjust_for_fee(&mut tx_builder, used_base_amount)
+            .await?;
+
+        let tx = tx_builder.build(provider).await?;
+        let tx_id = tx.id(provider.chain_id());
+
+        let tx_status = self.send_transaction_and_await_commit(tx).await?;
 
         let receipts = tx_status.take_receipts_checked(None)?;
 
@@ -256,7 +450,7 @@ pub trait Account: ViewOnlyAccount {
         let tx = tb.build(provider).await?;
 
         let tx_id = tx.id(provider.chain_id());
-        let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+        let tx_status = self.send_transaction_and_await_commit(tx).await?;
 
         let receipts = tx_status.take_receipts_checked(None)?;
 
@@ -292,7 +486,7 @@ pub trait Account: ViewOnlyAccount {
         let tx = tb.build(provider).await?;
 
         let tx_id = tx.id(provider.chain_id());
-        let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+        let tx_status = self.send_transaction_and_await_commit(tx).await?;
 
         let receipts = tx_status.take_receipts_checked(None)?;
 
@@ -301,6 +495,369 @@ pub trait Account: ViewOnlyAccount {
 
         Ok((tx_id, nonce, receipts))
     }
+
+    /// Claims a message sent to this account from L1, e.g. by a bridge deposit, by spending it
+    /// into a coin owned by this account. The fee is paid from the message.
+    /// Messages carrying data can only be spent by the script or predicate they are meant for.
+    /// Returns the transaction ID and the list of receipts.
+    async fn claim_deposit(
+        &self,
+        message: &Message,
+        tx_policies: TxPolicies,
+    ) -> Result<(TxId, Vec<Receipt>)> {
+        if message.recipient != *self.address() {
+            return Err(error!(
+                Other,
+                "deposit `{}` is sent to `{}`, not to `{}`",
+                message.nonce,
+                message.recipient,
+                self.address()
+            ));
+        }
+        if message.is_retryable() {
+            return Err(error!(
+                Other,
+                "deposit `{}` carries data and cannot be claimed as a coin", message.nonce
+            ));
+        }
+
+        let provider = self.try_provider()?;
+        let base_asset_id = *provider.base_asset_id();
+
+        // Excluding every other resource forces the selection of exactly the message.
+        let mut excluded: Vec<_> = self
+            .get_coins(base_asset_id)
+            .await?
+            .into_iter()
+            .map(|coin| CoinTypeId::UtxoId(coin.utxo_id))
+            .collect();
+        excluded.extend(
+            self.get_messages()
+                .await?
+                .into_iter()
+                .filter(|other| other.nonce != message.nonce)
+                .map(|other| CoinTypeId::Nonce(other.nonce)),
+        );
+
+        let inputs = self
+            .get_asset_inputs_for_amount(base_asset_id, message.amount, Some(excluded))
+            .await?;
+        let outputs = vec![Output::change(self.address().into(), 0, base_asset_id)];
+
+        let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
+        self.add_witnesses(&mut tb)?;
+
+        let tx = tb.build(provider).await?;
+        let tx_id = tx.id(provider.chain_id());
+
+        let tx_status = self.send_transaction_and_await_commit(tx).await?;
+        let receipts = tx_status.take_receipts_checked(None)?;
+
+        Ok((tx_id, receipts))
+    }
+
+    /// Sends the whole balance of every asset, or only of `asset_ids` if given, to `to`.
+    /// When the base asset is swept it is sent through a change output so that `to` receives
+    /// exactly what is left after paying the fee.
+    /// Returns the transaction ID that was sent and the list of receipts.
+    async fn sweep(
+        &self,
+        to: &Bech32Address,
+        asset_ids: Option<Vec<AssetId>>,
+        tx_policies: TxPolicies,
+    ) -> Result<(TxId, Vec<Receipt>)> {
+        let provider = self.try_provider()?;
+        let base_asset_id = *provider.base_asset_id();
+
+        let mut balances = self
+            .get_balances()
+            .await?
+            .into_iter()
+            .map(|(asset_id, amount)| Ok((AssetId::from_str(&asset_id)?, amount)))
+            .collect::<Result<Vec<_>>>()?;
+        balances.retain(|(asset_id, amount)| {
+            *amount > 0
+                && asset_ids
+                    .as_ref()
+                    .map_or(true, |selected| selected.contains(asset_id))
+        });
+        if balances.is_empty() {
+            return Err(error!(
+                Other,
+                "no balance to sweep from `{}`",
+                self.address()
+            ));
+        }
+
+        let mut inputs = vec![];
+        let mut outputs = vec![];
+        for (asset_id, amount) in &balances {
+            inputs.extend(
+                self.get_asset_inputs_for_amount(*asset_id, *amount, None)
+                    .await?,
+            );
+
+            let output = if *asset_id == base_asset_id {
+                Output::change(to.into(), 0, base_asset_id)
+            } else {
+                Output::coin(to.into(), *amount, *asset_id)
+            };
+            outputs.push(output);
+        }
+
+        let max_inputs = provider.consensus_parameters().tx_params().max_inputs() as usize;
+        if inputs.len() > max_inputs {
+            return Err(error!(
+                Other,
+                "sweeping requires `{}` inputs but at most `{max_inputs}` are allowed, consider consolidating coins first",
+                inputs.len()
+            ));
+        }
+
+        let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
+        self.add_witnesses(&mut tb)?;
+
+        let sweeps_base_asset = balances
+            .iter()
+            .any(|(asset_id, _)| *asset_id == base_asset_id);
+        if !sweeps_base_asset {
+            self.adjust_for_fee(&mut tb, 0).await?;
+        }
+
+        let tx = tb.build(provider).await?;
+        let tx_id = tx.id(provider.chain_id());
+
+        let tx_status = self.send_transaction_and_await_commit(tx).await?;
+        let receipts = tx_status.take_receipts_checked(None)?;
+
+        Ok((tx_id, receipts))
+    }
+
+    /// Merges the coins of `asset_id` owned by this account, smallest first, until at most
+    /// `target_count` remain. Each transaction spends at most the chain's `max_inputs` coins,
+    /// so consolidating a large number of coins takes several transactions.
+    /// Returns the IDs of the sent transactions.
+    async fn consolidate_coins(
+        &self,
+        asset_id: AssetId,
+        target_count: usize,
+        tx_policies: TxPolicies,
+    ) -> Result<Vec<TxId>> {
+        if target_count == 0 {
+            return Err(error!(Other, "`target_count` must be at least 1"));
+        }
+
+        let provider = self.try_provider()?;
+        let max_inputs = provider.consensus_parameters().tx_params().max_inputs() as usize;
+        // Leave room for the base asset inputs paying the fee.
+        let batch_size = max_inputs.saturating_sub(CONSOLIDATION_FEE_INPUTS).max(2);
+
+        let mut tx_ids = vec![];
+        loop {
+            let mut coins = self.get_coins(asset_id).await?;
+            if coins.len() <= target_count {
+                break;
+            }
+            coins.sort_by_key(|coin| coin.amount);
+
+            let batch_len = batch_size.min(coins.len() - target_count + 1);
+            let amount = coins[..batch_len].iter().map(|coin| coin.amount).sum();
+
+            // Excluding every other resource forces the selection of exactly the batch.
+            let mut excluded: Vec<_> = coins[batch_len..]
+                .iter()
+                .map(|coin| CoinTypeId::UtxoId(coin.utxo_id))
+                .collect();
+            if asset_id == *provider.base_asset_id() {
+                excluded.extend(
+                    self.get_messages()
+                        .await?
+                        .into_iter()
+                        .map(|message| CoinTypeId::Nonce(message.nonce)),
+                );
+            }
+
+            let inputs = self
+                .get_asset_inputs_for_amount(asset_id, amount, Some(excluded))
+                .await?;
+            let outputs = vec![Output::change(self.address().into(), 0, asset_id)];
+
+            let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
+            self.add_witnesses(&mut tb)?;
+            self.adjust_for_fee(&mut tb, 0).await?;
+
+            let tx = tb.build(provider).await?;
+            let tx_id = tx.id(provider.chain_id());
+            self.send_transaction_and_await_commit(tx)
+                .await?
+                .check(None)?;
+
+            tx_ids.push(tx_id);
+        }
+
+        Ok(tx_ids)
+    }
+
+    /// Resubmits the pending transaction `tx` paying `tip`, which must be higher than its tip, so
+    /// that the node replaces it. The inputs and outputs stay the same, the max fee grows by as
+    /// much as the tip and the transaction is signed again by this account, so `tx` must not need
+    /// the signature of any other account.
+    /// Returns the ID of the replacement transaction.
+    async fn speed_up<T: Transaction + Send>(&self, tx: T, tip: u64) -> Result<TxId>
+    where
+        Self: Signer,
+    {
+        let provider = self.try_provider()?;
+
+        let current_tip = tx.tip().unwrap_or_default();
+        if tip <= current_tip {
+            return Err(error!(
+                Other,
+                "the new tip {tip} must be higher than the current tip {current_tip}"
+            ));
+        }
+
+        let mut tx = tx.with_tip(tip);
+        tx.precompute(&provider.chain_id())?;
+
+        let mut unsigned = UnsignedTransaction::new(tx, provider.chain_id())?;
+        if unsigned
+            .missing_signers()
+            .contains(ViewOnlyAccount::address(self))
+        {
+            unsigned.sign(self).await?;
+        }
+
+        self.send_transaction(unsigned.into_transaction::<T>()?)
+            .await
+    }
+
+    /// Invalidates the pending transaction `tx` by spending one of its coins owned by this
+    /// account back to it, with a higher tip so that the node replaces `tx`. Base asset coins are
+    /// preferred, as the fee of the cancellation is then paid by the coin itself.
+    /// Returns the ID of the cancelling transaction.
+    async fn cancel<T: Transaction + Sync>(&self, tx: &T) -> Result<TxId> {
+        let provider = self.try_provider()?;
+        let base_asset_id = *provider.base_asset_id();
+        let owner = Address::from(self.address());
+
+        let coins = tx.inputs().iter().filter_map(|input| {
+            let utxo_id = *input.utxo_id()?;
+            let asset_id = *input.asset_id(&base_asset_id)?;
+
+            (input.is_coin_signed() && input.input_owner() == Some(&owner)).then(|| Coin {
+                amount: input.amount().unwrap_or_default(),
+                asset_id,
+                utxo_id,
+                owner: self.address().clone(),
+                ..Default::default()
+            })
+        });
+        let (base_coins, other_coins): (Vec<_>, Vec<_>) =
+            coins.partition(|coin| coin.asset_id == base_asset_id);
+        let coin = base_coins
+            .into_iter()
+            .max_by_key(|coin| coin.amount)
+            .or_else(|| other_coins.into_iter().next())
+            .ok_or_else(|| {
+                error!(
+                    Other,
+                    "the transaction spends no coin owned by `{}`",
+                    self.address()
+                )
+            })?;
+
+        let outputs = vec![Output::change(owner, 0, coin.asset_id)];
+        let inputs = vec![Input::resource_signed(CoinType::Coin(coin))];
+        let tx_policies = TxPolicies::default().with_tip(tx.tip().unwrap_or_default() + 1);
+
+        let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
+        self.add_witnesses(&mut tb)?;
+        self.adjust_for_fee(&mut tb, 0).await?;
+
+        let cancellation = tb.build(provider).await?;
+
+        self.send_transaction(cancellation).await
+    }
+
+    /// Uploads `bytecode`, e.g. a state transition function, in subsections of at most
+    /// `subsection_size` bytes, one transaction per subsection. Only the privileged address of
+    /// the chain may upload. Returns the root of the bytecode, to be given to
+    /// [`upgrade_state_transition`](Account::upgrade_state_transition).
+    async fn upload_bytecode(
+        &self,
+        bytecode: &[u8],
+        subsection_size: usize,
+        tx_policies: TxPolicies,
+    ) -> Result<Bytes32> {
+        let provider = self.try_provider()?;
+
+        let builders = UploadTransactionBuilder::prepare_bytecode_upload(
+            bytecode,
+            subsection_size,
+            tx_policies,
+        )?;
+        let root = builders
+            .first()
+            .map(|builder| builder.root)
+            .ok_or_else(|| error!(Other, "cannot upload empty bytecode"))?;
+
+        for mut tb in builders {
+            self.add_witnesses(&mut tb)?;
+            self.adjust_for_fee(&mut tb, 0).await?;
+
+            let tx = tb.build(provider).await?;
+            self.send_transaction_and_await_commit(tx)
+                .await?
+                .check(None)?;
+        }
+
+        Ok(root)
+    }
+
+    /// Upgrades the state transition function of the chain to the bytecode uploaded with
+    /// [`upload_bytecode`](Account::upload_bytecode) under `root`. Only the privileged address
+    /// of the chain may upgrade it. Returns the transaction ID.
+    async fn upgrade_state_transition(
+        &self,
+        root: Bytes32,
+        tx_policies: TxPolicies,
+    ) -> Result<TxId> {
+        let tb = UpgradeTransactionBuilder::prepare_state_transition_upgrade(root, tx_policies);
+
+        send_upgrade(self, tb).await
+    }
+
+    /// Replaces the consensus parameters of the chain by `consensus_parameters`. Only the
+    /// privileged address of the chain may upgrade them. Returns the transaction ID.
+    async fn upgrade_consensus_parameters(
+        &self,
+        consensus_parameters: &ConsensusParameters,
+        tx_policies: TxPolicies,
+    ) -> Result<TxId> {
+        let tb = UpgradeTransactionBuilder::prepare_consensus_parameters_upgrade(
+            consensus_parameters,
+            tx_policies,
+        );
+
+        send_upgrade(self, tb).await
+    }
+}
+
+async fn send_upgrade(account: &impl Account, mut tb: UpgradeTransactionBuilder) -> Result<TxId> {
+    let provider = account.try_provider()?;
+
+    account.add_witnesses(&mut tb)?;
+    account.adjust_for_fee(&mut tb, 0).await?;
+
+    let tx = tb.build(provider).await?;
+    let tx_id = tx.id(provider.chain_id());
+    account
+        .send_transaction_and_await_commit(tx)
+        .await?
+        .check(None)?;
+
+    Ok(tx_id)
 }
 
 #[cfg(test)]
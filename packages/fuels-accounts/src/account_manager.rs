@@ -0,0 +1,200 @@
+use std::collections::{BTreeMap, HashMap};
+
+use fuel_tx::TxId;
+use fuels_core::types::{
+    errors::{error, Result},
+    transaction::TxPolicies,
+    AssetId,
+};
+use zeroize::Zeroizing;
+
+use crate::{
+    derivation::DerivationTemplate, provider::Provider, wallet::WalletUnlocked, Account,
+    ViewOnlyAccount,
+};
+
+/// Derives, caches and labels many [`WalletUnlocked`]s from a single mnemonic phrase.
+///
+/// Accounts live at the default Fuel path `m/44'/1179993420'/{index}'/0/0`, unless another
+/// scheme is set with [`with_derivation_template`](Self::with_derivation_template), and are only
+/// derived the first time they are requested.
+#[derive(Debug, Clone)]
+pub struct AccountManager {
+    phrase: Zeroizing<String>,
+    provider: Option<Provider>,
+    accounts: BTreeMap<u32, WalletUnlocked>,
+    labels: HashMap<String, u32>,
+    template: DerivationTemplate,
+}
+
+impl AccountManager {
+    /// Fails if `phrase` is not a valid mnemonic phrase.
+    pub fn new(phrase: &str, provider: Option<Provider>) -> Result<Self> {
+        let mut manager = Self {
+            phrase: Zeroizing::new(phrase.to_string()),
+            provider,
+            accounts: BTreeMap::new(),
+            labels: HashMap::new(),
+            template: DerivationTemplate::default(),
+        };
+        manager.account(0)?;
+
+        Ok(manager)
+    }
+
+    /// Derives accounts with `template` from now on. Accounts derived so far are dropped, labels
+    /// keep pointing to the same indices.
+    pub fn with_derivation_template(mut self, template: DerivationTemplate) -> Self {
+        self.template = template;
+        self.accounts.clear();
+
+        self
+    }
+
+    /// Returns the account at `index`, deriving it if needed.
+    pub fn account(&mut self, index: u32) -> Result<&WalletUnlocked> {
+        if !self.accounts.contains_key(&index) {
+            let wallet = WalletUnlocked::new_from_mnemonic_phrase_with_template(
+                &self.phrase,
+                self.provider.clone(),
+                &self.template,
+                index,
+            )?;
+            self.accounts.insert(index, wallet);
+        }
+
+        Ok(&self.accounts[&index])
+    }
+
+    /// Returns the accounts at `indices`, deriving those that are missing.
+    pub fn accounts(
+        &mut self,
+        indices: impl IntoIterator<Item = u32>,
+    ) -> Result<Vec<WalletUnlocked>> {
+        indices
+            .into_iter()
+            .map(|index| self.account(index).cloned())
+            .collect()
+    }
+
+    /// Names the account at `index`, deriving it if needed. A label already in use is moved to
+    /// the new account.
+    pub fn set_label(&mut self, index: u32, label: impl Into<String>) -> Result<&WalletUnlocked> {
+        self.account(index)?;
+        self.labels.insert(label.into(), index);
+
+        Ok(&self.accounts[&index])
+    }
+
+    pub fn by_label(&self, label: &str) -> Option<&WalletUnlocked> {
+        self.labels
+            .get(label)
+            .and_then(|index| self.accounts.get(index))
+    }
+
+    pub fn label_of(&self, index: u32) -> Option<&str> {
+        self.labels
+            .iter()
+            .find_map(|(label, i)| (*i == index).then_some(label.as_str()))
+    }
+
+    /// All accounts derived so far, ordered by index.
+    pub fn derived(&self) -> impl Iterator<Item = (u32, &WalletUnlocked)> {
+        self.accounts.iter().map(|(index, wallet)| (*index, wallet))
+    }
+
+    pub fn provider(&self) -> Option<&Provider> {
+        self.provider.as_ref()
+    }
+
+    /// Sets the provider of the manager and of every account derived so far.
+    pub fn set_provider(&mut self, provider: Provider) {
+        for wallet in self.accounts.values_mut() {
+            wallet.set_provider(provider.clone());
+        }
+        self.provider = Some(provider);
+    }
+
+    /// Queries the `asset_id` balance of every derived account.
+    pub async fn get_asset_balances(&self, asset_id: &AssetId) -> Result<BTreeMap<u32, u64>> {
+        let mut balances = BTreeMap::new();
+        for (index, wallet) in &self.accounts {
+            balances.insert(*index, wallet.get_asset_balance(asset_id).await?);
+        }
+
+        Ok(balances)
+    }
+
+    /// Sends `amount` of `asset_id` from `funder` to every derived account.
+    pub async fn fund_all(
+        &self,
+        funder: &impl Account,
+        amount: u64,
+        asset_id: AssetId,
+        tx_policies: TxPolicies,
+    ) -> Result<Vec<TxId>> {
+        if self.accounts.is_empty() {
+            return Err(error!(Other, "no accounts have been derived yet"));
+        }
+
+        let mut tx_ids = Vec::with_capacity(self.accounts.len());
+        for wallet in self.accounts.values() {
+            let (tx_id, _) = funder
+                .transfer(wallet.address(), amount, asset_id, tx_policies)
+                .await?;
+            tx_ids.push(tx_id);
+        }
+
+        Ok(tx_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::derivation::DerivationPath;
+
+    const PHRASE: &str =
+        "oblige salon price punch saddle immune slogan rare snap desert retire surprise";
+
+    #[test]
+    fn derives_lazily_and_labels_accounts() -> Result<()> {
+        let mut manager = AccountManager::new(PHRASE, None)?;
+        assert_eq!(manager.derived().count(), 1);
+
+        let path = DerivationPath::fuel_account(7)?.to_string();
+        let expected = WalletUnlocked::new_from_mnemonic_phrase_with_path(PHRASE, None, &path)?;
+
+        let address = manager.set_label(7, "market-maker")?.address().clone();
+        assert_eq!(&address, expected.address());
+        assert_eq!(
+            manager.by_label("market-maker").unwrap().address(),
+            &address
+        );
+        assert_eq!(manager.label_of(7), Some("market-maker"));
+
+        assert!(manager.set_label(1 << 31, "out-of-range").is_err());
+        assert!(manager.by_label("out-of-range").is_none());
+        assert_eq!(manager.label_of(1 << 31), None);
+
+        manager.accounts(0..3)?;
+        let indices: Vec<_> = manager.derived().map(|(index, _)| index).collect();
+        assert_eq!(indices, [0, 1, 2, 7]);
+
+        assert!(AccountManager::new("not a mnemonic", None).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn derives_with_custom_template() -> Result<()> {
+        let mut manager = AccountManager::new(PHRASE, None)?
+            .with_derivation_template("m/44'/60'/0'/0/{index}".parse()?);
+
+        let expected =
+            WalletUnlocked::new_from_mnemonic_phrase_with_path(PHRASE, None, "m/44'/60'/0'/0/1")?;
+        assert_eq!(manager.account(1)?.address(), expected.address());
+
+        Ok(())
+    }
+}
@@ -8,11 +8,10 @@ use fuels_core::types::{
     errors::{error, Error, Result},
     input::Input,
     transaction_builders::TransactionBuilder,
+    DryRunner,
 };
 use itertools::{Either, Itertools};
 
-use crate::provider::Provider;
-
 pub fn extract_message_nonce(receipts: &[Receipt]) -> Option<Nonce> {
     receipts.iter().find_map(|m| m.nonce()).copied()
 }
@@ -21,7 +20,7 @@ pub async fn calculate_missing_base_amount(
     tb: &impl TransactionBuilder,
     available_base_amount: u64,
     reserved_base_amount: u64,
-    provider: &Provider,
+    provider: impl DryRunner,
 ) -> Result<u64> {
     let max_fee = tb.estimate_max_fee(provider).await?;
 
@@ -56,7 +55,8 @@ pub fn available_base_assets_and_amount(
                         sum += amount;
                         Some(resource.id())
                     }
-                    CoinType::Message(message) => {
+                    // Retryable messages cannot pay the fee.
+                    CoinType::Message(message) if !message.is_retryable() => {
                         sum += message.amount;
                         Some(resource.id())
                     }
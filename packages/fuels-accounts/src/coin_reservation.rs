@@ -0,0 +1,85 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use fuels_core::types::coin_type_id::CoinTypeId;
+
+/// Resources handed out by the provider that have not been spent or released yet.
+///
+/// Every reservation expires after `ttl` so that resources selected for transactions which are
+/// never sent become available again.
+#[derive(Debug)]
+pub(crate) struct CoinReservations {
+    ttl: Duration,
+    reserved: HashMap<CoinTypeId, Instant>,
+}
+
+impl CoinReservations {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            reserved: HashMap::default(),
+        }
+    }
+
+    pub fn active(&mut self) -> Vec<CoinTypeId> {
+        self.remove_expired();
+
+        self.reserved.keys().cloned().collect()
+    }
+
+    /// Reserves all of `ids` or none of them if any is already reserved.
+    pub fn try_reserve(&mut self, ids: &[CoinTypeId]) -> bool {
+        self.remove_expired();
+
+        if ids.iter().any(|id| self.reserved.contains_key(id)) {
+            return false;
+        }
+
+        let expires_at = Instant::now() + self.ttl;
+        self.reserved
+            .extend(ids.iter().map(|id| (id.clone(), expires_at)));
+
+        true
+    }
+
+    pub fn release<'a>(&mut self, ids: impl IntoIterator<Item = &'a CoinTypeId>) {
+        for id in ids {
+            self.reserved.remove(id);
+        }
+    }
+
+    fn remove_expired(&mut self) {
+        let now = Instant::now();
+        self.reserved.retain(|_, expires_at| *expires_at > now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::UtxoId;
+    use fuel_types::{Bytes32, Nonce};
+
+    use super::*;
+
+    #[test]
+    fn reservations_are_exclusive_until_released_or_expired() {
+        let nonce = CoinTypeId::Nonce(Nonce::new([2u8; 32]));
+        let utxo_id = CoinTypeId::UtxoId(UtxoId::new(Bytes32::from([1u8; 32]), 0));
+        let both = [nonce, utxo_id.clone()];
+
+        let mut reservations = CoinReservations::new(Duration::from_secs(60));
+        assert!(reservations.try_reserve(&both[1..]));
+        assert!(!reservations.try_reserve(&both));
+        assert_eq!(reservations.active(), both[1..]);
+
+        reservations.release([&utxo_id]);
+        assert!(reservations.try_reserve(&both));
+
+        let mut reservations = CoinReservations::new(Duration::ZERO);
+        assert!(reservations.try_reserve(&both[..1]));
+        assert!(reservations.try_reserve(&both[..1]));
+        assert!(reservations.active().is_empty());
+    }
+}
@@ -0,0 +1,274 @@
+//! Client-side strategies for choosing which resources fund a transaction.
+//!
+//! By default the node picks the resources through its `coinsToSpend` query. Setting a strategy
+//! through [`Provider::with_coin_selection`](crate::provider::Provider::with_coin_selection)
+//! makes the provider fetch all spendable resources of the asset and choose among them locally.
+
+use std::fmt::Debug;
+
+use fuels_core::types::{
+    coin_type::CoinType,
+    errors::{error, Error, Result},
+};
+
+/// Chooses resources from `candidates` whose amounts add up to at least `target`, using at
+/// most `max_inputs` of them.
+pub trait CoinSelection: Debug + Send + Sync {
+    fn select(
+        &self,
+        candidates: Vec<CoinType>,
+        target: u64,
+        max_inputs: usize,
+    ) -> Result<Vec<CoinType>>;
+}
+
+/// Spends the biggest resources first, minimizing the number of inputs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LargestFirst;
+
+impl CoinSelection for LargestFirst {
+    fn select(
+        &self,
+        mut candidates: Vec<CoinType>,
+        target: u64,
+        max_inputs: usize,
+    ) -> Result<Vec<CoinType>> {
+        candidates.sort_by_key(|resource| std::cmp::Reverse(resource.amount()));
+
+        take_until_covered(candidates, target, max_inputs)
+    }
+}
+
+/// Spends the smallest resources first, consolidating dust as a side effect.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SmallestFirst;
+
+impl CoinSelection for SmallestFirst {
+    fn select(
+        &self,
+        mut candidates: Vec<CoinType>,
+        target: u64,
+        max_inputs: usize,
+    ) -> Result<Vec<CoinType>> {
+        candidates.sort_by_key(CoinType::amount);
+
+        // Dropping the smallest resources is the only way to stay under `max_inputs`.
+        let total = candidates.len();
+        let mut selected = vec![];
+        let mut sum = 0u64;
+        for resource in candidates {
+            sum = sum.saturating_add(resource.amount());
+            selected.push(resource);
+
+            if selected.len() > max_inputs {
+                sum -= selected.remove(0).amount();
+            }
+            if sum >= target {
+                return Ok(selected);
+            }
+        }
+
+        Err(insufficient_resources(target, total, max_inputs))
+    }
+}
+
+/// Searches for the combination of resources producing the smallest change, falling back to
+/// [`LargestFirst`] when no combination is found within `max_tries` steps.
+#[derive(Debug, Clone, Copy)]
+pub struct BranchAndBound {
+    pub max_tries: usize,
+}
+
+impl Default for BranchAndBound {
+    fn default() -> Self {
+        Self { max_tries: 100_000 }
+    }
+}
+
+impl CoinSelection for BranchAndBound {
+    fn select(
+        &self,
+        mut candidates: Vec<CoinType>,
+        target: u64,
+        max_inputs: usize,
+    ) -> Result<Vec<CoinType>> {
+        candidates.sort_by_key(|resource| std::cmp::Reverse(resource.amount()));
+        let amounts: Vec<u64> = candidates.iter().map(CoinType::amount).collect();
+
+        let mut search = Search {
+            amounts: &amounts,
+            remaining: suffix_sums(&amounts),
+            target,
+            max_inputs,
+            tries_left: self.max_tries,
+            current: vec![],
+            best: None,
+        };
+        search.explore(0, 0);
+
+        match search.best {
+            Some((_, indices)) => {
+                let mut candidates: Vec<_> = candidates.into_iter().map(Some).collect();
+                Ok(indices
+                    .into_iter()
+                    .filter_map(|index| candidates[index].take())
+                    .collect())
+            }
+            None => LargestFirst.select(candidates, target, max_inputs),
+        }
+    }
+}
+
+struct Search<'a> {
+    amounts: &'a [u64],
+    /// `remaining[i]` is the sum of `amounts[i..]`.
+    remaining: Vec<u64>,
+    target: u64,
+    max_inputs: usize,
+    tries_left: usize,
+    current: Vec<usize>,
+    /// The smallest change found so far together with the indices producing it.
+    best: Option<(u64, Vec<usize>)>,
+}
+
+impl Search<'_> {
+    fn explore(&mut self, index: usize, sum: u64) {
+        if self.tries_left == 0 || self.best.as_ref().is_some_and(|(change, _)| *change == 0) {
+            return;
+        }
+        self.tries_left -= 1;
+
+        if sum >= self.target {
+            let change = sum - self.target;
+            let is_better = self.best.as_ref().map_or(true, |(best_change, best)| {
+                change < *best_change || (change == *best_change && self.current.len() < best.len())
+            });
+            if is_better {
+                self.best = Some((change, self.current.clone()));
+            }
+            return;
+        }
+
+        let too_short = sum.saturating_add(self.remaining[index]) < self.target;
+        let too_many_inputs = self.current.len() >= self.max_inputs;
+        if index == self.amounts.len() || too_short || too_many_inputs {
+            return;
+        }
+
+        let with_current = sum.saturating_add(self.amounts[index]);
+        let overshoots_best = self
+            .best
+            .as_ref()
+            .is_some_and(|(change, _)| with_current > self.target.saturating_add(*change));
+        if !overshoots_best {
+            self.current.push(index);
+            self.explore(index + 1, with_current);
+            self.current.pop();
+        }
+
+        self.explore(index + 1, sum);
+    }
+}
+
+fn suffix_sums(amounts: &[u64]) -> Vec<u64> {
+    let mut sums = vec![0u64; amounts.len() + 1];
+    for (index, amount) in amounts.iter().enumerate().rev() {
+        sums[index] = sums[index + 1].saturating_add(*amount);
+    }
+
+    sums
+}
+
+fn take_until_covered(
+    candidates: Vec<CoinType>,
+    target: u64,
+    max_inputs: usize,
+) -> Result<Vec<CoinType>> {
+    let total = candidates.len();
+    let mut selected = vec![];
+    let mut sum = 0u64;
+    for resource in candidates.into_iter().take(max_inputs) {
+        sum = sum.saturating_add(resource.amount());
+        selected.push(resource);
+
+        if sum >= target {
+            return Ok(selected);
+        }
+    }
+
+    Err(insufficient_resources(target, total, max_inputs))
+}
+
+fn insufficient_resources(target: u64, available: usize, max_inputs: usize) -> Error {
+    error!(
+        Other,
+        "not enough resources to cover `{target}` with at most `{max_inputs}` of the `{available}` available"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{AssetId, UtxoId};
+    use fuel_types::Bytes32;
+    use fuels_core::types::coin::Coin;
+
+    use super::*;
+
+    fn coins(amounts: &[u64]) -> Vec<CoinType> {
+        amounts
+            .iter()
+            .enumerate()
+            .map(|(index, amount)| {
+                CoinType::Coin(Coin {
+                    amount: *amount,
+                    asset_id: AssetId::zeroed(),
+                    utxo_id: UtxoId::new(Bytes32::zeroed(), index as u16),
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+
+    fn amounts(selected: Result<Vec<CoinType>>) -> Vec<u64> {
+        let mut amounts: Vec<_> = selected
+            .expect("should select")
+            .iter()
+            .map(CoinType::amount)
+            .collect();
+        amounts.sort();
+
+        amounts
+    }
+
+    #[test]
+    fn strategies_select_expected_resources() {
+        let candidates = coins(&[1, 2, 5, 10, 20]);
+
+        assert_eq!(
+            amounts(LargestFirst.select(candidates.clone(), 22, 10)),
+            [10, 20]
+        );
+        assert_eq!(
+            amounts(SmallestFirst.select(candidates.clone(), 7, 10)),
+            [1, 2, 5]
+        );
+        assert_eq!(
+            amounts(SmallestFirst.select(candidates.clone(), 7, 2)),
+            [2, 5]
+        );
+        assert_eq!(
+            amounts(BranchAndBound::default().select(candidates.clone(), 22, 10)),
+            [2, 20]
+        );
+        assert_eq!(
+            amounts(BranchAndBound::default().select(candidates.clone(), 20, 3)),
+            [20]
+        );
+        assert!(BranchAndBound::default()
+            .select(candidates.clone(), 22, 1)
+            .is_err());
+
+        assert!(LargestFirst.select(candidates.clone(), 100, 10).is_err());
+        assert!(LargestFirst.select(candidates, 31, 2).is_err());
+    }
+}
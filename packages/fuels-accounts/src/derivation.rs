@@ -0,0 +1,220 @@
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use fuels_core::types::errors::{error, Error, Result};
+
+pub const DEFAULT_DERIVATION_PATH_PREFIX: &str = "m/44'/1179993420'";
+
+/// The SLIP-44 coin type registered for Fuel.
+pub const FUEL_COIN_TYPE: u32 = 1179993420;
+
+const INDEX_PLACEHOLDER: &str = "{index}";
+
+const HARDENED_BIT: u32 = 1 << 31;
+
+/// A BIP-32 derivation path such as `m/44'/1179993420'/0'/0/0`.
+///
+/// Each index is stored in its raw form, i.e. hardened indices have the most significant bit set.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DerivationPath {
+    indices: Vec<u32>,
+}
+
+impl DerivationPath {
+    pub fn new(indices: Vec<u32>) -> Self {
+        Self { indices }
+    }
+
+    /// The default Fuel path for the account at `index`: `m/44'/1179993420'/{index}'/0/0`.
+    pub fn fuel_account(index: u32) -> Result<Self> {
+        format!("{DEFAULT_DERIVATION_PATH_PREFIX}/{index}'/0/0").parse()
+    }
+
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    pub fn is_hardened(index: u32) -> bool {
+        index & HARDENED_BIT != 0
+    }
+}
+
+impl Default for DerivationPath {
+    fn default() -> Self {
+        Self::fuel_account(0).expect("default derivation path should be valid")
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    fn from_str(path: &str) -> Result<Self> {
+        let mut segments = path.split('/');
+
+        if segments.next() != Some("m") {
+            return Err(error!(
+                Other,
+                "derivation path `{path}` must start with `m`"
+            ));
+        }
+
+        let indices = segments
+            .map(|segment| {
+                let (number, hardened) = match segment.strip_suffix(['\'', 'h']) {
+                    Some(number) => (number, true),
+                    None => (segment, false),
+                };
+
+                let index = number
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|index| *index < HARDENED_BIT);
+
+                match index {
+                    Some(index) if hardened => Ok(index | HARDENED_BIT),
+                    Some(index) => Ok(index),
+                    None => Err(error!(
+                        Other,
+                        "invalid segment `{segment}` in derivation path `{path}`"
+                    )),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { indices })
+    }
+}
+
+impl Display for DerivationPath {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "m")?;
+        for index in &self.indices {
+            if Self::is_hardened(*index) {
+                write!(f, "/{}'", index & !HARDENED_BIT)?;
+            } else {
+                write!(f, "/{index}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A derivation path scheme with an `{index}` placeholder, such as `m/44'/60'/0'/0/{index}`,
+/// used to derive consecutive accounts the way other wallets do.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DerivationTemplate {
+    template: String,
+}
+
+impl DerivationTemplate {
+    /// Fails if `template` does not contain `{index}` exactly once or is not a valid path once
+    /// it is substituted.
+    pub fn new(template: impl Into<String>) -> Result<Self> {
+        let template = Self {
+            template: template.into(),
+        };
+
+        if template.template.matches(INDEX_PLACEHOLDER).count() != 1 {
+            return Err(error!(
+                Other,
+                "derivation template `{}` must contain `{INDEX_PLACEHOLDER}` exactly once",
+                template.template
+            ));
+        }
+        template.path(0)?;
+
+        Ok(template)
+    }
+
+    /// The BIP-44 scheme `m/44'/{coin_type}'/{index}'/0/0`, deriving one account per index.
+    pub fn bip44(coin_type: u32) -> Self {
+        Self {
+            template: format!("m/44'/{coin_type}'/{INDEX_PLACEHOLDER}'/0/0"),
+        }
+    }
+
+    pub fn path(&self, index: u32) -> Result<DerivationPath> {
+        self.template
+            .replace(INDEX_PLACEHOLDER, &index.to_string())
+            .parse()
+    }
+}
+
+/// The default Fuel scheme, `m/44'/1179993420'/{index}'/0/0`.
+impl Default for DerivationTemplate {
+    fn default() -> Self {
+        Self::bip44(FUEL_COIN_TYPE)
+    }
+}
+
+impl FromStr for DerivationTemplate {
+    type Err = Error;
+
+    fn from_str(template: &str) -> Result<Self> {
+        Self::new(template)
+    }
+}
+
+impl Display for DerivationTemplate {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.template)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_displays_paths() -> Result<()> {
+        let path: DerivationPath = "m/44'/1179993420'/3'/0/7".parse()?;
+
+        assert_eq!(
+            path.indices(),
+            &[
+                44 | HARDENED_BIT,
+                1179993420 | HARDENED_BIT,
+                3 | HARDENED_BIT,
+                0,
+                7
+            ]
+        );
+        assert_eq!(path.to_string(), "m/44'/1179993420'/3'/0/7");
+        assert_eq!(
+            DerivationPath::fuel_account(3)?.indices()[..3],
+            path.indices()[..3]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn substitutes_template_index() -> Result<()> {
+        assert_eq!(
+            DerivationTemplate::default().path(3)?,
+            DerivationPath::fuel_account(3)?
+        );
+
+        let template: DerivationTemplate = "m/44'/60'/0'/0/{index}".parse()?;
+        assert_eq!(template.path(5)?.to_string(), "m/44'/60'/0'/0/5");
+
+        for template in ["m/44'/60'/0'/0/0", "m/{index}/{index}", "m/x/{index}"] {
+            assert!(
+                template.parse::<DerivationTemplate>().is_err(),
+                "{template}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_malformed_paths() {
+        for path in ["44'/0", "m/x", "m/2147483648", "m//0"] {
+            assert!(path.parse::<DerivationPath>().is_err(), "{path}");
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use fuels_core::types::{
+    bech32::Bech32Address,
+    errors::{error, Result},
+};
+use serde::Deserialize;
+use serde_json::json;
+use tokio::time::Instant;
+
+use crate::provider::Provider;
+
+pub const TESTNET_FAUCET_URL: &str = "https://faucet-testnet.fuel.network";
+
+/// A client for a Fuel faucet, used to fund accounts on public test networks.
+#[derive(Clone)]
+pub struct Faucet {
+    client: reqwest::Client,
+    url: String,
+    captcha: Option<String>,
+    poll_interval: Duration,
+}
+
+impl Default for Faucet {
+    fn default() -> Self {
+        Self::new(TESTNET_FAUCET_URL)
+    }
+}
+
+impl Faucet {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            client: Default::default(),
+            url: url.into(),
+            captcha: None,
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+
+    /// Sends `captcha` along with every request, for faucets that require one.
+    pub fn with_captcha(mut self, captcha: impl Into<String>) -> Self {
+        self.captcha = Some(captcha.into());
+        self
+    }
+
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// How often [`fund`](Self::fund) checks whether the funds arrived.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Asks the faucet to send base asset coins to `address`, returning the amount it dispensed.
+    ///
+    /// The coins are sent in a transaction of their own: use [`fund`](Self::fund) to wait until
+    /// they can be spent.
+    pub async fn request_funds(&self, address: &Bech32Address) -> Result<u64> {
+        let response = self
+            .client
+            .post(self.url("dispense"))
+            .json(&json!({
+                "address": address.hash().to_string(),
+                "captcha": self.captcha.as_deref().unwrap_or_default(),
+            }))
+            .send()
+            .await
+            .map_err(|e| error!(Other, "faucet request for `{address}` failed: {e}"))?;
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        let dispensed: DispenseResponse = serde_json::from_str(&body).map_err(|_| {
+            error!(
+                Other,
+                "faucet request for `{address}` failed with status `{status}`: {body}"
+            )
+        })?;
+
+        match dispensed {
+            DispenseResponse::Dispensed { tokens } if status.is_success() => Ok(tokens),
+            DispenseResponse::Error { error } => {
+                Err(error!(Other, "faucet refused to fund `{address}`: {error}"))
+            }
+            _ => Err(error!(
+                Other,
+                "faucet request for `{address}` failed with status `{status}`: {body}"
+            )),
+        }
+    }
+
+    /// Requests funds for `address` and waits until its base asset balance grows, failing after
+    /// `timeout`. Returns the new balance.
+    pub async fn fund(
+        &self,
+        provider: &Provider,
+        address: &Bech32Address,
+        timeout: Duration,
+    ) -> Result<u64> {
+        let base_asset_id = *provider.base_asset_id();
+        let initial_balance = provider.get_asset_balance(address, base_asset_id).await?;
+
+        self.request_funds(address).await?;
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            let balance = provider.get_asset_balance(address, base_asset_id).await?;
+            if balance > initial_balance {
+                return Ok(balance);
+            }
+
+            if Instant::now() + self.poll_interval > deadline {
+                return Err(error!(
+                    Other,
+                    "funds from the faucet did not reach `{address}` within {timeout:?}"
+                ));
+            }
+
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    fn url(&self, endpoint: &str) -> String {
+        format!("{}/{endpoint}", self.url.trim_end_matches('/'))
+    }
+}
+
+impl std::fmt::Debug for Faucet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Faucet")
+            .field("url", &self.url)
+            .field("poll_interval", &self.poll_interval)
+            .finish()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DispenseResponse {
+    Error { error: String },
+    Dispensed { tokens: u64 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_faucet_responses() -> Result<()> {
+        let dispensed = serde_json::from_str(r#"{"status":"Success","tokens":500000000}"#)?;
+        assert!(matches!(
+            dispensed,
+            DispenseResponse::Dispensed { tokens: 500000000 }
+        ));
+
+        let refused = serde_json::from_str(r#"{"error":"Account has been rate limited"}"#)?;
+        assert!(matches!(refused, DispenseResponse::Error { .. }));
+
+        Ok(())
+    }
+}
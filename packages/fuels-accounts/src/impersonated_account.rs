@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use fuels_core::types::{
+    bech32::Bech32Address, coin_type_id::CoinTypeId, errors::Result, input::Input,
+    transaction_builders::TransactionBuilder, unsigned_transaction::OfflineSigner, AssetId,
+};
+
+use crate::{accounts_utils::try_provider_error, provider::Provider, Account, ViewOnlyAccount};
+
+/// An account that only knows its address and produces empty signatures, see [`OfflineSigner`].
+///
+/// It spends the real resources of the address, so it can be used to simulate calls "as" any
+/// address with `Execution::StateReadOnly`, where witnesses are not validated. Transactions it
+/// builds will be rejected if sent to the network.
+#[derive(Debug, Clone)]
+pub struct ImpersonatedAccount {
+    address: Bech32Address,
+    provider: Option<Provider>,
+}
+
+impl ImpersonatedAccount {
+    pub fn new(address: Bech32Address, provider: Option<Provider>) -> Self {
+        Self { address, provider }
+    }
+
+    pub fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+
+    pub fn provider(&self) -> Option<&Provider> {
+        self.provider.as_ref()
+    }
+
+    pub fn set_provider(&mut self, provider: Provider) {
+        self.provider = Some(provider);
+    }
+}
+
+impl ViewOnlyAccount for ImpersonatedAccount {
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.provider.as_ref().ok_or_else(try_provider_error)
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Account for ImpersonatedAccount {
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: Option<Vec<CoinTypeId>>,
+    ) -> Result<Vec<Input>> {
+        Ok(self
+            .get_spendable_resources(asset_id, amount, excluded_coins)
+            .await?
+            .into_iter()
+            .map(Input::resource_signed)
+            .collect::<Vec<Input>>())
+    }
+
+    fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        tb.add_signer(OfflineSigner::new(self.address.clone()))?;
+
+        Ok(())
+    }
+}
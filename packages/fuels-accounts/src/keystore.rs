@@ -0,0 +1,365 @@
+use std::path::Path;
+
+use aes::Aes128;
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use fuel_crypto::SecretKey;
+use fuels_core::types::errors::{error, Result};
+use hmac::Hmac;
+use rand::{CryptoRng, Rng};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+type Aes128Ctr = ctr::Ctr128BE<Aes128>;
+
+const CIPHER: &str = "aes-128-ctr";
+const DKLEN: u8 = 32;
+/// The scrypt parameters of new keystores, the same as `geth` and `eth-keystore`.
+const SCRYPT_LOG_N: u8 = 13;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+/// Bounds on the key derivation parameters of imported keystores, so that a crafted file cannot
+/// make decryption use gigabytes of memory or run for hours. They are well above the parameters
+/// used by `geth` and `eth-keystore`.
+const MAX_SCRYPT_N: u32 = 1 << 20;
+const MAX_SCRYPT_R: u32 = 16;
+const MAX_SCRYPT_P: u32 = 16;
+const MAX_PBKDF2_ROUNDS: u32 = 10_000_000;
+
+/// A password-encrypted secret key following the Web3 Secret Storage definition (version 3).
+///
+/// This is the layout written by [`WalletUnlocked::encrypt`](crate::wallet::WalletUnlocked::encrypt)
+/// and used by most Ethereum tooling: the key is encrypted with `aes-128-ctr` using a key derived
+/// by `scrypt` (or `pbkdf2` when importing) from the password.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    pub id: String,
+    pub version: u8,
+    pub crypto: KeystoreCrypto,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeystoreCrypto {
+    pub cipher: String,
+    pub cipherparams: CipherParams,
+    pub ciphertext: String,
+    pub kdf: String,
+    pub kdfparams: KdfParams,
+    pub mac: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CipherParams {
+    pub iv: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KdfParams {
+    Scrypt {
+        dklen: u8,
+        n: u32,
+        p: u32,
+        r: u32,
+        salt: String,
+    },
+    Pbkdf2 {
+        c: u32,
+        dklen: u8,
+        prf: String,
+        salt: String,
+    },
+}
+
+impl Keystore {
+    /// Encrypts `secret_key` with a key derived from `password`.
+    pub fn encrypt<R: Rng + CryptoRng>(
+        rng: &mut R,
+        secret_key: &SecretKey,
+        password: impl AsRef<[u8]>,
+    ) -> Result<Self> {
+        let mut id = [0u8; 16];
+        rng.fill_bytes(&mut id);
+
+        Self::encrypt_with_id(rng, secret_key, password, format_uuid_v4(id))
+    }
+
+    fn encrypt_with_id<R: Rng + CryptoRng>(
+        rng: &mut R,
+        secret_key: &SecretKey,
+        password: impl AsRef<[u8]>,
+        id: String,
+    ) -> Result<Self> {
+        let mut salt = [0u8; 32];
+        rng.fill_bytes(&mut salt);
+        let mut iv = [0u8; 16];
+        rng.fill_bytes(&mut iv);
+
+        let kdfparams = KdfParams::Scrypt {
+            dklen: DKLEN,
+            n: 1 << SCRYPT_LOG_N,
+            p: SCRYPT_P,
+            r: SCRYPT_R,
+            salt: hex::encode(salt),
+        };
+        let derived_key = kdfparams.derive_key(password.as_ref())?;
+
+        let mut ciphertext = secret_key.to_vec();
+        Aes128Ctr::new(derived_key[..16].into(), &iv.into()).apply_keystream(&mut ciphertext);
+        let mac = compute_mac(&derived_key, &ciphertext);
+
+        Ok(Self {
+            id,
+            version: 3,
+            crypto: KeystoreCrypto {
+                cipher: CIPHER.to_string(),
+                cipherparams: CipherParams {
+                    iv: hex::encode(iv),
+                },
+                ciphertext: hex::encode(ciphertext),
+                kdf: kdfparams.name().to_string(),
+                kdfparams,
+                mac: hex::encode(mac),
+            },
+        })
+    }
+
+    /// Decrypts the secret key. Fails if `password` is wrong.
+    pub fn decrypt(&self, password: impl AsRef<[u8]>) -> Result<SecretKey> {
+        let crypto = &self.crypto;
+        if crypto.cipher != CIPHER {
+            return Err(error!(
+                Other,
+                "unsupported keystore cipher `{}`, expected `{CIPHER}`", crypto.cipher
+            ));
+        }
+        if crypto.kdf != crypto.kdfparams.name() {
+            return Err(error!(
+                Other,
+                "keystore kdf `{}` does not match its `{}` parameters",
+                crypto.kdf,
+                crypto.kdfparams.name()
+            ));
+        }
+
+        let derived_key = crypto.kdfparams.derive_key(password.as_ref())?;
+        let ciphertext = hex::decode(&crypto.ciphertext)?;
+
+        let mac = compute_mac(&derived_key, &ciphertext);
+        if !bool::from(mac.as_slice().ct_eq(&hex::decode(&crypto.mac)?)) {
+            return Err(error!(
+                Other,
+                "keystore MAC mismatch, the password is probably wrong"
+            ));
+        }
+
+        let iv = <[u8; 16]>::try_from(hex::decode(&crypto.cipherparams.iv)?.as_slice())?;
+        let mut plaintext = Zeroizing::new(ciphertext);
+        Aes128Ctr::new(derived_key[..16].into(), &iv.into()).apply_keystream(&mut plaintext);
+
+        Ok(SecretKey::try_from(plaintext.as_slice())?)
+    }
+
+    /// Re-encrypts the secret key under `new_password` with a fresh salt and IV, keeping the
+    /// keystore id.
+    pub fn rotate_password<R: Rng + CryptoRng>(
+        &self,
+        rng: &mut R,
+        old_password: impl AsRef<[u8]>,
+        new_password: impl AsRef<[u8]>,
+    ) -> Result<Self> {
+        let secret_key = self.decrypt(old_password)?;
+
+        Self::encrypt_with_id(rng, &secret_key, new_password, self.id.clone())
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        Ok(std::fs::write(path, self.to_json()?)?)
+    }
+}
+
+impl KdfParams {
+    fn name(&self) -> &'static str {
+        match self {
+            KdfParams::Scrypt { .. } => "scrypt",
+            KdfParams::Pbkdf2 { .. } => "pbkdf2",
+        }
+    }
+
+    fn derive_key(&self, password: &[u8]) -> Result<Zeroizing<Vec<u8>>> {
+        let (dklen, salt) = match self {
+            KdfParams::Scrypt { dklen, salt, .. } | KdfParams::Pbkdf2 { dklen, salt, .. } => {
+                (*dklen, hex::decode(salt)?)
+            }
+        };
+
+        if dklen < DKLEN {
+            return Err(error!(Other, "keystore `dklen` must be at least {DKLEN}"));
+        }
+
+        let mut key = Zeroizing::new(vec![0u8; dklen as usize]);
+        match self {
+            KdfParams::Scrypt { n, p, r, .. } => {
+                if !n.is_power_of_two() {
+                    return Err(error!(Other, "scrypt parameter `n` must be a power of two"));
+                }
+                if *n > MAX_SCRYPT_N || *r > MAX_SCRYPT_R || *p > MAX_SCRYPT_P {
+                    return Err(error!(
+                        Other,
+                        "scrypt parameters exceed the supported bounds: n <= {MAX_SCRYPT_N}, r <= {MAX_SCRYPT_R}, p <= {MAX_SCRYPT_P}"
+                    ));
+                }
+
+                let params = scrypt::Params::new(n.trailing_zeros() as u8, *r, *p)
+                    .map_err(|e| error!(Other, "invalid scrypt parameters: {e}"))?;
+                scrypt::scrypt(password, &salt, &params, &mut key)
+                    .map_err(|e| error!(Other, "scrypt failed: {e}"))?;
+            }
+            KdfParams::Pbkdf2 { c, prf, .. } => {
+                if prf != "hmac-sha256" {
+                    return Err(error!(Other, "unsupported pbkdf2 prf `{prf}`"));
+                }
+                if !(1..=MAX_PBKDF2_ROUNDS).contains(c) {
+                    return Err(error!(
+                        Other,
+                        "pbkdf2 rounds must be between 1 and {MAX_PBKDF2_ROUNDS}"
+                    ));
+                }
+
+                pbkdf2::pbkdf2::<Hmac<Sha256>>(password, &salt, *c, &mut key);
+            }
+        }
+
+        Ok(key)
+    }
+}
+
+fn compute_mac(derived_key: &[u8], ciphertext: &[u8]) -> [u8; 32] {
+    Keccak256::new()
+        .chain_update(&derived_key[16..32])
+        .chain_update(ciphertext)
+        .finalize()
+        .into()
+}
+
+fn format_uuid_v4(mut bytes: [u8; 16]) -> String {
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn encrypt_decrypt_and_rotate() -> Result<()> {
+        let mut rng = StdRng::seed_from_u64(42);
+        let secret_key = SecretKey::random(&mut rng);
+
+        let keystore =
+            Keystore::from_json(&Keystore::encrypt(&mut rng, &secret_key, "old")?.to_json()?)?;
+        assert_eq!(keystore.decrypt("old")?, secret_key);
+        assert!(keystore.decrypt("wrong").is_err());
+
+        let rotated = keystore.rotate_password(&mut rng, "old", "new")?;
+        assert_eq!(rotated.id, keystore.id);
+        assert_eq!(rotated.decrypt("new")?, secret_key);
+        assert!(rotated.decrypt("old").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_unbounded_kdf_parameters() -> Result<()> {
+        let mut rng = StdRng::seed_from_u64(42);
+        let keystore = Keystore::encrypt(&mut rng, &SecretKey::random(&mut rng), "password")?;
+
+        let with_kdfparams = |kdfparams| {
+            let mut keystore = keystore.clone();
+            keystore.crypto.kdfparams = kdfparams;
+            keystore
+        };
+        let salt = hex::encode([0; 32]);
+
+        let scrypt = with_kdfparams(KdfParams::Scrypt {
+            dklen: DKLEN,
+            n: 1 << 30,
+            p: 1,
+            r: 8,
+            salt: salt.clone(),
+        });
+        assert!(scrypt.decrypt("password").is_err());
+
+        let pbkdf2 = with_kdfparams(KdfParams::Pbkdf2 {
+            c: u32::MAX,
+            dklen: DKLEN,
+            prf: "hmac-sha256".to_string(),
+            salt,
+        });
+        assert!(pbkdf2.decrypt("password").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_kdf_parameters_of_another_kdf() -> Result<()> {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut keystore = Keystore::encrypt(&mut rng, &SecretKey::random(&mut rng), "password")?;
+
+        keystore.crypto.kdf = "pbkdf2".to_string();
+
+        let err = keystore
+            .decrypt("password")
+            .expect_err("scrypt parameters should not be used for pbkdf2");
+        assert!(err.to_string().contains("does not match"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn compatible_with_eth_keystore() -> Result<()> {
+        let dir = tempdir()?;
+        let mut rng = StdRng::seed_from_u64(42);
+        let secret_key = SecretKey::random(&mut rng);
+
+        let id = eth_keystore::encrypt_key(&dir, &mut rng, *secret_key, "password", None)
+            .map_err(|e| error!(Other, "{e}"))?;
+        let keystore = Keystore::load(dir.path().join(&id))?;
+        assert_eq!(keystore.decrypt("password")?, secret_key);
+
+        let path = dir.path().join("exported");
+        Keystore::encrypt(&mut rng, &secret_key, "password")?.save(&path)?;
+        let decrypted =
+            eth_keystore::decrypt_key(&path, "password").map_err(|e| error!(Other, "{e}"))?;
+        assert_eq!(decrypted, secret_key.to_vec());
+
+        Ok(())
+    }
+}
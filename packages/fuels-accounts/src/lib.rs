@@ -1,10 +1,32 @@
 #[cfg(feature = "std")]
 mod account;
 #[cfg(feature = "std")]
+pub mod account_manager;
+#[cfg(feature = "std")]
 mod accounts_utils;
 #[cfg(feature = "std")]
+mod coin_reservation;
+#[cfg(feature = "std")]
+pub mod coin_selection;
+#[cfg(feature = "std")]
+pub mod impersonated_account;
+#[cfg(feature = "keystore")]
+pub mod keystore;
+#[cfg(feature = "std")]
+pub mod observer;
+#[cfg(feature = "std")]
+pub mod payout;
+#[cfg(feature = "std")]
+mod pending_transactions;
+#[cfg(feature = "std")]
 pub mod provider;
 #[cfg(feature = "std")]
+pub mod submission_journal;
+#[cfg(feature = "std")]
+pub mod time_locked_wallet;
+#[cfg(feature = "std")]
+pub mod vault;
+#[cfg(feature = "std")]
 pub mod wallet;
 
 #[cfg(feature = "std")]
@@ -13,4 +35,14 @@ pub use account::*;
 #[cfg(feature = "coin-cache")]
 mod coin_cache;
 
+#[cfg(feature = "faucet")]
+pub mod faucet;
+
+#[cfg(feature = "wasm-provider")]
+pub mod wasm_provider;
+
+pub mod derivation;
+pub mod personal_sign;
 pub mod predicate;
+pub mod signers;
+pub mod typed_data;
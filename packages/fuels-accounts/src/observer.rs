@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use fuel_crypto::{Message, Signature};
+use fuel_tx::TxId;
+pub use fuels_core::traits::TransactionObserver;
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::Bech32Address,
+        coin_type_id::CoinTypeId,
+        errors::{Error, Result},
+        input::Input,
+        transaction::{Transaction, TransactionSummary},
+        transaction_builders::TransactionBuilder,
+        tx_status::TxStatus,
+        AssetId,
+    },
+};
+
+use crate::{provider::Provider, Account, ViewOnlyAccount};
+
+/// An account whose transactions are reported to [`TransactionObserver`]s: before its signers
+/// sign them and once they are submitted. Works with any account, and only observes the
+/// transactions of this account, not those of other accounts sharing its provider.
+///
+/// Contract calls and deployments paid by the account are observed as well. Transactions signed
+/// again by [`Account::speed_up`] are only reported once submitted.
+#[derive(Debug, Clone)]
+pub struct ObservedAccount<A> {
+    account: A,
+    observers: Vec<Arc<dyn TransactionObserver>>,
+}
+
+impl<A: Account> ObservedAccount<A> {
+    pub fn new(account: A) -> Self {
+        Self {
+            account,
+            observers: vec![],
+        }
+    }
+
+    /// Notifies `observer` of every transaction of the account, after the observers added before.
+    pub fn with_observer(mut self, observer: impl TransactionObserver + 'static) -> Self {
+        self.observers.push(Arc::new(observer));
+
+        self
+    }
+
+    pub fn account(&self) -> &A {
+        &self.account
+    }
+
+    pub fn into_account(self) -> A {
+        self.account
+    }
+
+    fn summarize(&self, tx: &impl Transaction) -> Result<TransactionSummary> {
+        let consensus_parameters = self.try_provider()?.consensus_parameters();
+
+        Ok(TransactionSummary::new(tx, consensus_parameters))
+    }
+
+    async fn notify_submitted(&self, summary: &TransactionSummary, error: Option<&Error>) {
+        for observer in &self.observers {
+            observer.on_submit(summary, error).await;
+        }
+    }
+}
+
+impl<A: Account> ViewOnlyAccount for ObservedAccount<A> {
+    fn address(&self) -> &Bech32Address {
+        self.account.address()
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.account.try_provider()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<A: Account> Account for ObservedAccount<A> {
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: Option<Vec<CoinTypeId>>,
+    ) -> Result<Vec<Input>> {
+        self.account
+            .get_asset_inputs_for_amount(asset_id, amount, excluded_coins)
+            .await
+    }
+
+    fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        self.account.add_witnesses(tb)?;
+        for observer in &self.observers {
+            tb.add_observer(Arc::clone(observer));
+        }
+
+        Ok(())
+    }
+
+    async fn send_transaction_and_await_commit<T: Transaction + Send + Sync>(
+        &self,
+        tx: T,
+    ) -> Result<TxStatus> {
+        let summary = self.summarize(&tx)?;
+
+        let result = self.account.send_transaction_and_await_commit(tx).await;
+        self.notify_submitted(&summary, result.as_ref().err()).await;
+
+        result
+    }
+
+    async fn send_transaction<T: Transaction + Send>(&self, tx: T) -> Result<TxId> {
+        let summary = self.summarize(&tx)?;
+
+        let result = self.account.send_transaction(tx).await;
+        self.notify_submitted(&summary, result.as_ref().err()).await;
+
+        result
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<A: Account + Signer> Signer for ObservedAccount<A> {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        self.account.sign(message).await
+    }
+
+    fn address(&self) -> &Bech32Address {
+        Signer::address(&self.account)
+    }
+}
@@ -0,0 +1,256 @@
+use std::{collections::VecDeque, iter};
+
+use fuel_tx::{Output, TxId};
+use fuels_core::types::{
+    bech32::Bech32Address,
+    errors::{error, Result},
+    transaction::{Transaction, TxPolicies},
+    transaction_builders::{BuildableTransaction, ScriptTransactionBuilder},
+    AssetId,
+};
+use itertools::Itertools;
+
+use crate::Account;
+
+/// Number of inputs kept free in every payout transaction for the inputs covering the fee.
+const PAYOUT_FEE_INPUTS: usize = 8;
+
+/// A payment of a [`Payout`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Payment {
+    pub recipient: Bech32Address,
+    pub asset_id: AssetId,
+    pub amount: u64,
+}
+
+/// Pays many recipients, in any assets, in as few transactions as the chain's limits allow.
+///
+/// Each transaction pays as many recipients as fit in its outputs, along with a single change
+/// output per asset. When the coins needed exceed the inputs a transaction can have, its
+/// payments are split across more transactions. The transactions are sent one after the
+/// other, each once the previous one is committed, so that its change can be spent.
+#[derive(Debug, Clone, Default)]
+pub struct Payout {
+    payments: Vec<Payment>,
+    tx_policies: TxPolicies,
+}
+
+impl Payout {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_payment(
+        mut self,
+        recipient: impl Into<Bech32Address>,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Self {
+        self.payments.push(Payment {
+            recipient: recipient.into(),
+            asset_id,
+            amount,
+        });
+        self
+    }
+
+    pub fn with_payments(mut self, payments: impl IntoIterator<Item = Payment>) -> Self {
+        self.payments.extend(payments);
+        self
+    }
+
+    /// The policies of every transaction of the payout.
+    pub fn with_tx_policies(mut self, tx_policies: TxPolicies) -> Self {
+        self.tx_policies = tx_policies;
+        self
+    }
+
+    pub fn payments(&self) -> &[Payment] {
+        &self.payments
+    }
+
+    /// Sends the payments from `account`. Returns the IDs of the committed transactions, in the
+    /// order they were sent. If a transaction fails, the payments of the previous ones are
+    /// already made.
+    pub async fn send(&self, account: &impl Account) -> Result<Vec<TxId>> {
+        if self.payments.is_empty() {
+            return Err(error!(Other, "a payout requires at least one payment"));
+        }
+
+        let provider = account.try_provider()?;
+        let tx_params = provider.consensus_parameters().tx_params();
+        let max_inputs = tx_params.max_inputs() as usize;
+        let max_outputs = tx_params.max_outputs() as usize;
+
+        let mut batches = batch_by_outputs(&self.payments, max_outputs, *provider.base_asset_id())?;
+        let mut tx_ids = vec![];
+        while let Some(batch) = batches.pop_front() {
+            let totals = totals_per_asset(&batch)?;
+
+            let mut inputs = vec![];
+            for (asset_id, total) in &totals {
+                inputs.extend(
+                    account
+                        .get_asset_inputs_for_amount(*asset_id, *total, None)
+                        .await?,
+                );
+            }
+
+            if inputs.len() + PAYOUT_FEE_INPUTS > max_inputs {
+                if batch.len() == 1 {
+                    return Err(error!(
+                        Other,
+                        "paying {} of `{}` requires more than the `{max_inputs}` inputs allowed, consider consolidating coins first",
+                        batch[0].amount,
+                        batch[0].asset_id
+                    ));
+                }
+
+                let (first, second) = batch.split_at(batch.len() / 2);
+                batches.push_front(second.to_vec());
+                batches.push_front(first.to_vec());
+                continue;
+            }
+
+            let outputs =
+                batch
+                    .iter()
+                    .map(|payment| {
+                        Output::coin(
+                            (&payment.recipient).into(),
+                            payment.amount,
+                            payment.asset_id,
+                        )
+                    })
+                    .chain(totals.iter().map(|(asset_id, _)| {
+                        Output::change(account.address().into(), 0, *asset_id)
+                    }))
+                    .collect();
+
+            let mut tb =
+                ScriptTransactionBuilder::prepare_transfer(inputs, outputs, self.tx_policies);
+            account.add_witnesses(&mut tb)?;
+
+            let used_base_amount = totals
+                .iter()
+                .find(|(asset_id, _)| asset_id == provider.base_asset_id())
+                .map(|(_, total)| *total)
+                .unwrap_or_default();
+            account.adjust_for_fee(&mut tb, used_base_amount).await?;
+
+            let tx = tb.build(provider).await?;
+            let tx_id = tx.id(provider.chain_id());
+            account
+                .send_transaction_and_await_commit(tx)
+                .await?
+                .check(None)?;
+
+            tx_ids.push(tx_id);
+        }
+
+        Ok(tx_ids)
+    }
+}
+
+fn totals_per_asset(payments: &[Payment]) -> Result<Vec<(AssetId, u64)>> {
+    let mut totals: Vec<(AssetId, u64)> = vec![];
+    for payment in payments {
+        match totals.iter_mut().find(|(id, _)| *id == payment.asset_id) {
+            Some((_, total)) => {
+                *total = total
+                    .checked_add(payment.amount)
+                    .ok_or_else(|| error!(Other, "payment amounts overflow"))?
+            }
+            None => totals.push((payment.asset_id, payment.amount)),
+        }
+    }
+
+    Ok(totals)
+}
+
+/// Splits `payments` into batches whose coin outputs, change outputs per asset and base asset
+/// change output for the fee fit in `max_outputs`.
+fn batch_by_outputs(
+    payments: &[Payment],
+    max_outputs: usize,
+    base_asset_id: AssetId,
+) -> Result<VecDeque<Vec<Payment>>> {
+    let outputs_needed = |batch: &[Payment]| {
+        let assets = batch
+            .iter()
+            .map(|payment| payment.asset_id)
+            .chain(iter::once(base_asset_id))
+            .unique()
+            .count();
+
+        batch.len() + assets
+    };
+
+    let mut batches = VecDeque::new();
+    let mut batch: Vec<Payment> = vec![];
+    for payment in payments {
+        batch.push(payment.clone());
+        if outputs_needed(&batch) > max_outputs {
+            let payment = batch.pop().expect("just pushed");
+            if batch.is_empty() {
+                return Err(error!(
+                    Other,
+                    "a payment requires more than the `{max_outputs}` outputs allowed"
+                ));
+            }
+
+            batches.push_back(std::mem::take(&mut batch));
+            batch.push(payment);
+        }
+    }
+    batches.push_back(batch);
+
+    Ok(batches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payment(asset: u8, amount: u64) -> Payment {
+        Payment {
+            recipient: Bech32Address::default(),
+            asset_id: AssetId::from([asset; 32]),
+            amount,
+        }
+    }
+
+    #[test]
+    fn payments_are_batched_by_outputs() -> Result<()> {
+        let base_asset_id = AssetId::zeroed();
+        let payments = vec![payment(1, 1), payment(1, 2), payment(2, 3), payment(0, 4)];
+
+        // Each batch needs its coin outputs, a change output per asset and one for the fee.
+        let batches = batch_by_outputs(&payments, 4, base_asset_id)?;
+
+        assert_eq!(
+            batches,
+            VecDeque::from([
+                vec![payment(1, 1), payment(1, 2)],
+                vec![payment(2, 3), payment(0, 4)],
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn totals_are_per_asset() -> Result<()> {
+        let payments = vec![payment(1, 1), payment(2, 3), payment(1, 2)];
+
+        let totals = totals_per_asset(&payments)?;
+
+        assert_eq!(
+            totals,
+            vec![(AssetId::from([1; 32]), 3), (AssetId::from([2; 32]), 3)]
+        );
+        assert!(totals_per_asset(&[payment(1, u64::MAX), payment(1, 1)]).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use fuel_tx::{Input, Output, TxId};
+use fuel_types::{Address, AssetId};
+use fuels_core::types::tx_status::TxStatus;
+
+/// How a transaction is expected to change the balances of the addresses it touches.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct BalanceEffects {
+    spent: HashMap<(Address, AssetId), u64>,
+    received: HashMap<(Address, AssetId), u64>,
+}
+
+impl BalanceEffects {
+    /// Change outputs are expected to receive what is left of their asset after the coin outputs
+    /// and, for the base asset, after `max_fee`.
+    pub fn new(
+        inputs: &[Input],
+        outputs: &[Output],
+        max_fee: u64,
+        base_asset_id: &AssetId,
+    ) -> Self {
+        let mut effects = Self::default();
+        let mut available: HashMap<AssetId, u64> = HashMap::new();
+
+        for input in inputs {
+            let (Some(owner), Some(asset_id), Some(amount)) = (
+                input.input_owner().or_else(|| input.recipient()),
+                input.asset_id(base_asset_id),
+                input.amount(),
+            ) else {
+                continue;
+            };
+
+            add(&mut effects.spent, (*owner, *asset_id), amount);
+            *available.entry(*asset_id).or_default() += amount;
+        }
+
+        if let Some(available) = available.get_mut(base_asset_id) {
+            *available = available.saturating_sub(max_fee);
+        }
+
+        for output in outputs {
+            if let Output::Coin {
+                to,
+                amount,
+                asset_id,
+            } = output
+            {
+                add(&mut effects.received, (*to, *asset_id), *amount);
+                if let Some(available) = available.get_mut(asset_id) {
+                    *available = available.saturating_sub(*amount);
+                }
+            }
+        }
+
+        for output in outputs {
+            if let Output::Change { to, asset_id, .. } = output {
+                let change = available.remove(asset_id).unwrap_or_default();
+                add(&mut effects.received, (*to, *asset_id), change);
+            }
+        }
+
+        effects
+    }
+}
+
+fn add(amounts: &mut HashMap<(Address, AssetId), u64>, key: (Address, AssetId), amount: u64) {
+    let total = amounts.entry(key).or_default();
+    *total = total.saturating_add(amount);
+}
+
+/// Transactions sent through the provider that were not committed yet.
+#[derive(Debug, Default)]
+pub(crate) struct PendingTransactions {
+    txs: HashMap<TxId, BalanceEffects>,
+}
+
+impl PendingTransactions {
+    pub fn insert(&mut self, tx_id: TxId, effects: BalanceEffects) {
+        self.txs.insert(tx_id, effects);
+    }
+
+    /// Forgets `tx_id` once `status` is final, its effects being part of the committed balances.
+    pub fn update(&mut self, tx_id: &TxId, status: &TxStatus) {
+        if !matches!(status, TxStatus::Submitted) {
+            self.txs.remove(tx_id);
+        }
+    }
+
+    pub fn tx_ids(&self) -> Vec<TxId> {
+        self.txs.keys().copied().collect()
+    }
+
+    /// Applies the effects of every pending transaction to the committed `balance`.
+    pub fn apply(&self, owner: &Address, asset_id: &AssetId, balance: u64) -> u64 {
+        let key = (*owner, *asset_id);
+
+        self.txs.values().fold(balance, |balance, effects| {
+            let spent = effects.spent.get(&key).copied().unwrap_or_default();
+            let received = effects.received.get(&key).copied().unwrap_or_default();
+
+            balance.saturating_sub(spent).saturating_add(received)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{TxPointer, UtxoId};
+
+    use super::*;
+
+    #[test]
+    fn pending_transfer_moves_balance_and_returns_change() {
+        let base_asset_id = AssetId::zeroed();
+        let sender = Address::from([1; 32]);
+        let receiver = Address::from([2; 32]);
+
+        let coin = |amount| {
+            Input::coin_signed(
+                UtxoId::default(),
+                sender,
+                amount,
+                base_asset_id,
+                TxPointer::default(),
+                0,
+            )
+        };
+        let inputs = [coin(600), coin(400)];
+        let outputs = [
+            Output::coin(receiver, 300, base_asset_id),
+            Output::change(sender, 0, base_asset_id),
+        ];
+
+        let mut pending = PendingTransactions::default();
+        pending.insert(
+            TxId::zeroed(),
+            BalanceEffects::new(&inputs, &outputs, 10, &base_asset_id),
+        );
+
+        assert_eq!(
+            pending.apply(&sender, &base_asset_id, 1500),
+            1500 - 300 - 10
+        );
+        assert_eq!(pending.apply(&receiver, &base_asset_id, 0), 300);
+
+        pending.update(&TxId::zeroed(), &TxStatus::Submitted);
+        assert_eq!(
+            pending.apply(&sender, &base_asset_id, 1500),
+            1500 - 300 - 10
+        );
+
+        pending.update(&TxId::zeroed(), &TxStatus::Success { receipts: vec![] });
+        assert_eq!(pending.apply(&sender, &base_asset_id, 1500), 1500);
+    }
+}
@@ -0,0 +1,82 @@
+//! Signing of arbitrary off-chain messages, e.g. for "sign in with Fuel" flows.
+//!
+//! Messages are prefixed with [`PERSONAL_MESSAGE_PREFIX`] and their length before being hashed,
+//! so a personal signature can never be replayed as a transaction signature.
+
+use fuel_crypto::{Message, Signature};
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::Bech32Address,
+        errors::{error, Result},
+    },
+};
+
+pub const PERSONAL_MESSAGE_PREFIX: &str = "\x19Fuel Signed Message:\n";
+
+/// Hashes `message` as `sha256(PERSONAL_MESSAGE_PREFIX ++ len(message) ++ message)`, the length
+/// being written in decimal.
+pub fn personal_message(message: impl AsRef<[u8]>) -> Message {
+    let message = message.as_ref();
+    let payload = [
+        PERSONAL_MESSAGE_PREFIX.as_bytes(),
+        message.len().to_string().as_bytes(),
+        message,
+    ]
+    .concat();
+
+    Message::new(payload)
+}
+
+pub async fn sign_personal(
+    signer: &(impl Signer + ?Sized),
+    message: impl AsRef<[u8]>,
+) -> Result<Signature> {
+    signer.sign(personal_message(message)).await
+}
+
+/// Checks that `signature` is a personal signature of `message` made by `address`.
+pub fn verify_personal(
+    address: &Bech32Address,
+    message: impl AsRef<[u8]>,
+    signature: &Signature,
+) -> Result<()> {
+    let signer = signature.recover(&personal_message(message))?.hash();
+
+    if *signer != *address.hash() {
+        return Err(error!(
+            Other,
+            "personal signature was not made by `{address}`"
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use fuel_crypto::{PublicKey, SecretKey};
+    use fuels_core::types::bech32::FUEL_BECH32_HRP;
+
+    use super::*;
+
+    #[test]
+    fn personal_signatures_are_domain_separated() -> Result<()> {
+        let secret_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?;
+        let address = Bech32Address::new(FUEL_BECH32_HRP, PublicKey::from(&secret_key).hash());
+
+        let signature = Signature::sign(&secret_key, &personal_message("sign in"));
+        verify_personal(&address, "sign in", &signature)?;
+
+        assert!(verify_personal(&address, "sign out", &signature).is_err());
+
+        let raw_signature = Signature::sign(&secret_key, &Message::new("sign in"));
+        assert!(verify_personal(&address, "sign in", &raw_signature).is_err());
+
+        Ok(())
+    }
+}
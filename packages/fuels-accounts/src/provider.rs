@@ -1,20 +1,49 @@
-use std::{collections::HashMap, fmt::Debug, net::SocketAddr};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    net::SocketAddr,
+    ops::{Bound, RangeBounds},
+    pin::pin,
+    time::{Duration, Instant},
+};
 
-mod retry_util;
+mod batch_balances;
+mod block_verification;
+mod block_walker;
+mod da_compression;
+mod failover;
+mod gas_price_stats;
+#[cfg(feature = "metrics")]
+mod metrics;
+pub mod middleware;
+mod rate_limit;
+mod receipt_scan;
+mod reorg;
+pub(crate) mod retry_util;
 mod retryable_client;
 mod supported_fuel_core_version;
 mod supported_versions;
+mod timeouts;
+mod withdrawal_relay;
 
-#[cfg(feature = "coin-cache")]
 use std::sync::Arc;
 
+pub use block_verification::BlockVerifier;
+pub use block_walker::{BlockCursor, BlockWalker, WalkedBlock};
 use chrono::{DateTime, Utc};
+pub use da_compression::{DaCompressedBlock, DaDecompressor};
+use da_compression::{DaCompressedBlockData, DA_COMPRESSED_BLOCK_QUERY};
+pub use failover::FailoverPolicy;
+use fuel_core_chain_config::{
+    CoinConfig, ContractBalanceConfig, ContractConfig, MessageConfig, StateConfig,
+};
 use fuel_core_client::client::{
     pagination::{PageDirection, PaginatedResult, PaginationRequest},
     types::{
         balance::Balance,
         contract::ContractBalance,
         gas_price::{EstimateGasPrice, LatestGasPrice},
+        Block as ClientBlock,
     },
 };
 use fuel_core_types::{
@@ -22,11 +51,11 @@ use fuel_core_types::{
     services::executor::TransactionExecutionResult,
 };
 use fuel_tx::{
-    AssetId, ConsensusParameters, Receipt, Transaction as FuelTransaction, TxId, UtxoId,
+    AssetId, ConsensusParameters, Receipt, Transaction as FuelTransaction, TxId, UniqueIdentifier,
+    UtxoId,
 };
 use fuel_types::{Address, BlockHeight, Bytes32, ChainId, Nonce};
-#[cfg(feature = "coin-cache")]
-use fuels_core::types::coin_type_id::CoinTypeId;
+pub use fuels_core::types::ReceiptKind;
 use fuels_core::{
     constants::{DEFAULT_GAS_ESTIMATION_BLOCK_HORIZON, DEFAULT_GAS_ESTIMATION_TOLERANCE},
     types::{
@@ -35,25 +64,64 @@ use fuels_core::{
         chain_info::ChainInfo,
         coin::Coin,
         coin_type::CoinType,
-        errors::Result,
+        coin_type_id::CoinTypeId,
+        errors::{error, Error, Result},
         message::Message,
         message_proof::MessageProof,
         node_info::NodeInfo,
-        transaction::{Transaction, Transactions},
+        transaction::{Transaction, TransactionSummary, TransactionType, Transactions},
         transaction_response::TransactionResponse,
         tx_status::TxStatus,
-        DryRun, DryRunner,
+        DryRun, DryRunner, ReceiptIteratorExt, ReceiptMatcher,
     },
 };
+use futures::{stream, Stream, StreamExt, TryStreamExt};
+pub use gas_price_stats::{GasPriceStats, TipPercentiles};
+#[cfg(feature = "metrics")]
+pub use metrics::MetricEvent;
+use middleware::Middleware;
+pub use rate_limit::RateLimit;
+pub use receipt_scan::{ReceiptFilter, ScanCursor, ScannedReceipt};
+use reorg::HeaderChain;
+pub use reorg::Reorg;
 pub use retry_util::{Backoff, RetryConfig};
+use serde::{de::DeserializeOwned, Serialize};
 pub use supported_fuel_core_version::SUPPORTED_FUEL_CORE_VERSION;
+pub use supported_versions::{CompatibilityLevel, NodeCompatibility};
 use tai64::Tai64;
+pub use timeouts::{OperationClass, Timeouts};
 #[cfg(feature = "coin-cache")]
 use tokio::sync::Mutex;
+pub use withdrawal_relay::{
+    CommitBlock, RelayedBlockHeader, RelayedBlockHeaderLite, RelayedMerkleProof, RelayedMessage,
+    WithdrawalRelay, RELAY_MESSAGE_SIGNATURE,
+};
 
 #[cfg(feature = "coin-cache")]
 use crate::coin_cache::CoinsCache;
-use crate::provider::retryable_client::RetryableClient;
+use crate::{
+    coin_reservation::CoinReservations,
+    coin_selection::CoinSelection,
+    pending_transactions::{BalanceEffects, PendingTransactions},
+    provider::retryable_client::RetryableClient,
+    submission_journal::SubmissionJournal,
+};
+
+const MAX_RESERVATION_ATTEMPTS: usize = 5;
+const PAGE_SIZE: i32 = 100;
+/// Number of latest blocks over which [`Provider::average_block_time`] is measured.
+const BLOCK_TIME_SAMPLE: u32 = 100;
+/// Parts of the errors with which the node rejects a transaction it already has, either in its
+/// pool or committed, whose inputs are then spent.
+const DUPLICATE_SUBMISSION_ERRORS: [&str; 5] = [
+    "Hash is already known",
+    "UTXO is spent",
+    "UTXO does not exist",
+    "UTXO input does not exist",
+    "does not match any received message",
+];
+/// How often [`Provider::subscribe_blocks`] asks the node for the next block.
+pub const BLOCK_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 #[derive(Debug, Clone, PartialEq)]
 // ANCHOR: transaction_cost
@@ -62,6 +130,12 @@ pub struct TransactionCost {
     pub gas_used: u64,
     pub metered_bytes_size: u64,
     pub total_fee: u64,
+    /// The tip paid to the block producer, included in `total_fee`.
+    pub tip: u64,
+    /// The part of `total_fee` paying for the gas of the transaction, without the tip.
+    pub base_fee: u64,
+    /// The gas used by every predicate of the transaction, in the order of its inputs.
+    pub predicate_gas_used: Vec<u64>,
 }
 // ANCHOR_END: transaction_cost
 
@@ -86,7 +160,7 @@ impl ResourceQueries {
     }
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 // ANCHOR: resource_filter
 pub struct ResourceFilter {
     pub from: Bech32Address,
@@ -112,6 +186,80 @@ impl ResourceFilter {
     }
 }
 
+fn extend_filter_with_excluded<'a>(
+    filter: &mut ResourceFilter,
+    excluded: impl IntoIterator<Item = &'a CoinTypeId>,
+) {
+    for id in excluded {
+        match id {
+            CoinTypeId::UtxoId(utxo_id) if !filter.excluded_utxos.contains(utxo_id) => {
+                filter.excluded_utxos.push(*utxo_id)
+            }
+            CoinTypeId::Nonce(nonce) if !filter.excluded_message_nonces.contains(nonce) => {
+                filter.excluded_message_nonces.push(*nonce)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Streams the results of every page of [`PAGE_SIZE`] results, requesting the next page once
+/// the previous one was consumed.
+fn paginate<T, E, F, Fut>(fetch: F) -> impl Stream<Item = Result<T>>
+where
+    F: FnMut(PaginationRequest<String>) -> Fut,
+    Fut: std::future::Future<Output = std::result::Result<PaginatedResult<T, String>, E>>,
+    Error: From<E>,
+{
+    paginate_from(None, fetch)
+}
+
+/// Like [`paginate`], starting after `cursor`.
+fn paginate_from<T, E, F, Fut>(cursor: Option<String>, fetch: F) -> impl Stream<Item = Result<T>>
+where
+    F: FnMut(PaginationRequest<String>) -> Fut,
+    Fut: std::future::Future<Output = std::result::Result<PaginatedResult<T, String>, E>>,
+    Error: From<E>,
+{
+    stream::try_unfold((fetch, Some(cursor)), |(mut fetch, cursor)| async move {
+        let Some(cursor) = cursor else {
+            return Ok(None);
+        };
+
+        let page = fetch(PaginationRequest {
+            cursor,
+            results: PAGE_SIZE,
+            direction: PageDirection::Forward,
+        })
+        .await?;
+        let next_cursor = page.has_next_page.then_some(page.cursor);
+
+        Ok(Some((page.results, (fetch, next_cursor))))
+    })
+    .map_ok(|results| stream::iter(results.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+/// Collects every page of results. Fails if more than `max_results` results exist.
+async fn collect_pages<T, E, F, Fut>(max_results: Option<usize>, fetch: F) -> Result<Vec<T>>
+where
+    F: FnMut(PaginationRequest<String>) -> Fut,
+    Fut: std::future::Future<Output = std::result::Result<PaginatedResult<T, String>, E>>,
+    Error: From<E>,
+{
+    let mut results = pin!(paginate(fetch));
+    let mut collected = vec![];
+
+    while let Some(result) = results.try_next().await? {
+        collected.push(result);
+        if let Some(max) = max_results.filter(|max| collected.len() > *max) {
+            return Err(error!(Other, "more than {max} results available"));
+        }
+    }
+
+    Ok(collected)
+}
+
 /// Encapsulates common client operations in the SDK.
 /// Note that you may also use `client`, which is an instance
 /// of `FuelClient`, directly, which provides a broader API.
@@ -121,6 +269,14 @@ pub struct Provider {
     consensus_parameters: ConsensusParameters,
     #[cfg(feature = "coin-cache")]
     cache: Arc<Mutex<CoinsCache>>,
+    coin_selection: Option<Arc<dyn CoinSelection>>,
+    reservations: Option<Arc<std::sync::Mutex<CoinReservations>>>,
+    pending_transactions: Arc<std::sync::Mutex<PendingTransactions>>,
+    chain_info_ttl: Duration,
+    cached_chain_info: Arc<std::sync::Mutex<Option<(Instant, ChainInfo)>>>,
+    block_verifier: Option<BlockVerifier>,
+    header_chain: Option<Arc<std::sync::Mutex<HeaderChain>>>,
+    submission_journal: Option<Arc<dyn SubmissionJournal>>,
 }
 
 impl Provider {
@@ -134,8 +290,30 @@ impl Provider {
     }
 
     /// Connects to an existing node at the given address.
+    ///
+    /// Connecting to a node with an unsupported version succeeds, but request errors are then
+    /// prefixed with a warning. Use [`node_compatibility`](Self::node_compatibility) to reject such
+    /// nodes upfront.
     pub async fn connect(url: impl AsRef<str>) -> Result<Provider> {
         let client = RetryableClient::connect(&url, Default::default()).await?;
+
+        Self::from_client(client).await
+    }
+
+    /// Connects to several nodes of the same network, spreading requests over them according to
+    /// `policy` and failing over to another node whenever one fails.
+    ///
+    /// Only one of the nodes has to be reachable, the others are retried later.
+    pub async fn connect_multi(
+        urls: impl IntoIterator<Item = impl AsRef<str>>,
+        policy: FailoverPolicy,
+    ) -> Result<Provider> {
+        let client = RetryableClient::connect_multi(urls, policy, Default::default()).await?;
+
+        Self::from_client(client).await
+    }
+
+    async fn from_client(client: RetryableClient) -> Result<Provider> {
         let consensus_parameters = client.chain_info().await?.consensus_parameters;
 
         Ok(Self {
@@ -143,24 +321,63 @@ impl Provider {
             consensus_parameters,
             #[cfg(feature = "coin-cache")]
             cache: Default::default(),
+            coin_selection: None,
+            reservations: None,
+            pending_transactions: Default::default(),
+            chain_info_ttl: Duration::ZERO,
+            cached_chain_info: Default::default(),
+            block_verifier: None,
+            header_chain: None,
+            submission_journal: None,
         })
     }
 
+    /// The URL of the node, or of the first node given to [`connect_multi`](Self::connect_multi).
     pub fn url(&self) -> &str {
         self.client.url()
     }
 
+    pub fn urls(&self) -> impl Iterator<Item = &str> {
+        self.client.urls()
+    }
+
+    /// The version and GraphQL schema hash of the node, as checked when connecting.
+    pub fn node_compatibility(&self) -> &NodeCompatibility {
+        self.client.compatibility()
+    }
+
     /// Sends a transaction to the underlying Provider's client.
     pub async fn send_transaction_and_await_commit<T: Transaction>(
         &self,
         tx: T,
     ) -> Result<TxStatus> {
+        let used_coins = tx.used_coins(self.base_asset_id());
+        let result = self.submit_and_await_commit(tx).await;
+        self.release_coins(used_coins.values().flatten());
+
+        result
+    }
+
+    async fn submit_and_await_commit<T: Transaction>(&self, tx: T) -> Result<TxStatus> {
         let tx = self.prepare_transaction_for_sending(tx).await?;
-        let tx_status = self
-            .client
-            .submit_and_await_commit(&tx.clone().into())
-            .await?
-            .into();
+        let summary = TransactionSummary::new(&tx, self.consensus_parameters());
+
+        let submission = async {
+            match self
+                .client
+                .submit_and_await_commit(&tx.clone().into())
+                .await
+            {
+                Ok(status) => Ok(status.into()),
+                Err(e) => {
+                    self.known_submission(summary.id, e.into()).await?;
+                    self.await_transaction_commit::<T>(summary.id).await
+                }
+            }
+        };
+        let tx_status: TxStatus = self
+            .instrument_submission(&summary, tx.metered_bytes_size(), submission)
+            .await?;
 
         #[cfg(feature = "coin-cache")]
         if matches!(
@@ -179,7 +396,7 @@ impl Provider {
     async fn prepare_transaction_for_sending<T: Transaction>(&self, mut tx: T) -> Result<T> {
         tx.precompute(&self.chain_id())?;
 
-        let chain_info = self.chain_info().await?;
+        let chain_info = self.cached_chain_info().await?;
         let Header {
             height: latest_block_height,
             state_transition_bytecode_version: latest_chain_executor_version,
@@ -199,13 +416,207 @@ impl Provider {
         Ok(tx)
     }
 
+    /// Submits `tx` to the node, returning its id.
+    ///
+    /// Submitting a transaction the node already has, e.g. when retrying a submission whose
+    /// response was lost, returns the id of that transaction rather than an error.
     pub async fn send_transaction<T: Transaction>(&self, tx: T) -> Result<TxId> {
-        let tx = self.prepare_transaction_for_sending(tx).await?;
-        self.submit(tx).await
+        let used_coins = tx.used_coins(self.base_asset_id());
+        let result = async {
+            let tx = self.prepare_transaction_for_sending(tx).await?;
+            let effects = BalanceEffects::new(
+                tx.inputs(),
+                tx.outputs(),
+                tx.max_fee().unwrap_or_default(),
+                self.base_asset_id(),
+            );
+
+            let summary = TransactionSummary::new(&tx, self.consensus_parameters());
+
+            let bytes = tx.metered_bytes_size();
+            let submission = async {
+                match self.submit(tx).await {
+                    Err(e) => self
+                        .known_submission(summary.id, e)
+                        .await
+                        .map(|_| summary.id),
+                    result => result,
+                }
+            };
+            let tx_id = self
+                .instrument_submission(&summary, bytes, submission)
+                .await?;
+
+            self.pending_transactions
+                .lock()
+                .expect("not poisoned")
+                .insert(tx_id, effects);
+
+            Ok(tx_id)
+        }
+        .await;
+
+        if result.is_err() {
+            self.release_coins(used_coins.values().flatten());
+        }
+
+        result
+    }
+
+    /// Submits `tx` unless a transaction recorded for the idempotency `key` in the submission
+    /// journal is known to the node, in which case the id of that transaction is returned instead.
+    ///
+    /// The id of `tx` is recorded for `key` before `tx` is submitted, so that a process crashing
+    /// in between finds it when retrying. Requires
+    /// [`with_submission_journal`](Self::with_submission_journal).
+    pub async fn send_transaction_once<T: Transaction>(&self, key: &str, tx: T) -> Result<TxId> {
+        let journal = self
+            .submission_journal
+            .as_ref()
+            .ok_or_else(|| error!(Other, "no submission journal is set on the provider"))?;
+
+        if let Some(tx_id) = journal.get(key)? {
+            let known = self
+                .get_transaction_by_id(&tx_id)
+                .await?
+                .is_some_and(|response| !matches!(response.status, TxStatus::SqueezedOut { .. }));
+            if known {
+                return Ok(tx_id);
+            }
+        }
+
+        journal.record(key, tx.id(self.chain_id()))?;
+
+        self.send_transaction(tx).await
+    }
+
+    /// The transaction recorded for the idempotency `key` by
+    /// [`send_transaction_once`](Self::send_transaction_once), if any.
+    pub fn journaled_transaction(&self, key: &str) -> Result<Option<TxId>> {
+        self.submission_journal
+            .as_ref()
+            .ok_or_else(|| error!(Other, "no submission journal is set on the provider"))?
+            .get(key)
+    }
+
+    /// Resolves `error`, with which the node rejected the transaction `tx_id`, to the status of
+    /// that transaction if the node already has it, e.g. because a previous submission succeeded
+    /// but its response was lost. Other errors are returned as they are.
+    async fn known_submission(&self, tx_id: TxId, error: Error) -> Result<TxStatus> {
+        let message = error.to_string();
+        if !DUPLICATE_SUBMISSION_ERRORS
+            .iter()
+            .any(|duplicate| message.contains(duplicate))
+        {
+            return Err(error);
+        }
+
+        match self
+            .client
+            .transaction_status(&tx_id)
+            .await
+            .map(TxStatus::from)
+        {
+            Ok(TxStatus::SqueezedOut { .. }) | Err(_) => Err(error),
+            Ok(status) => Ok(status),
+        }
     }
 
+    /// Runs `submission`, reporting it to tracing and the metrics callback.
+    #[cfg_attr(
+        not(any(feature = "tracing", feature = "metrics")),
+        allow(unused_variables)
+    )]
+    async fn instrument_submission<R>(
+        &self,
+        summary: &TransactionSummary,
+        bytes: usize,
+        submission: impl std::future::Future<Output = Result<R>>,
+    ) -> Result<R> {
+        #[cfg(feature = "metrics")]
+        let started = Instant::now();
+
+        #[cfg(feature = "tracing")]
+        let submission = tracing::Instrument::instrument(
+            submission,
+            tracing::info_span!("submit_transaction", tx_id = %summary.id, bytes),
+        );
+        let result = submission.await;
+
+        #[cfg(feature = "tracing")]
+        if let Err(e) = &result {
+            tracing::warn!(tx_id = %summary.id, error = %e, "transaction submission failed");
+        }
+
+        #[cfg(feature = "metrics")]
+        self.client
+            .record_metric(&MetricEvent::TransactionSubmission {
+                tx_id: summary.id,
+                bytes,
+                latency: started.elapsed(),
+                error: result.as_ref().err(),
+            });
+
+        result
+    }
+
+    /// Waits until the transaction `id` is committed, failing with a "timed out"
+    /// [`Error::Provider`] after the await commit timeout of the provider, if it has one.
     pub async fn await_transaction_commit<T: Transaction>(&self, id: TxId) -> Result<TxStatus> {
-        Ok(self.client.await_transaction_commit(&id).await?.into())
+        match self.client.timeouts().get(OperationClass::AwaitCommit) {
+            Some(timeout) => {
+                self.await_transaction_commit_until::<T>(id, Instant::now() + timeout)
+                    .await
+            }
+            None => self.wait_for_commit(id).await,
+        }
+    }
+
+    /// Like [`await_transaction_commit`](Self::await_transaction_commit), failing with a "timed
+    /// out" [`Error::Provider`] if the transaction is not committed by `deadline`.
+    pub async fn await_transaction_commit_until<T: Transaction>(
+        &self,
+        id: TxId,
+        deadline: Instant,
+    ) -> Result<TxStatus> {
+        tokio::time::timeout_at(
+            tokio::time::Instant::from_std(deadline),
+            self.wait_for_commit(id),
+        )
+        .await
+        .map_err(|_| {
+            error!(
+                Provider,
+                "timed out: transaction `{id}` was not committed before the deadline"
+            )
+        })?
+    }
+
+    async fn wait_for_commit(&self, id: TxId) -> Result<TxStatus> {
+        let mut statuses = pin!(self.subscribe_tx_status(&id).await?);
+        while let Some(status) = statuses.next().await {
+            let status = status?;
+            if !matches!(status, TxStatus::Submitted) {
+                self.update_pending_transaction(&id, &status);
+                return Ok(status);
+            }
+        }
+
+        Err(error!(
+            Other,
+            "status updates of transaction `{id}` ended before it was committed"
+        ))
+    }
+
+    /// Streams every status change of the transaction `tx_id`, starting with its current status.
+    /// The stream ends once the transaction reaches a final status.
+    pub async fn subscribe_tx_status<'a>(
+        &'a self,
+        tx_id: &'a TxId,
+    ) -> Result<impl Stream<Item = Result<TxStatus>> + 'a> {
+        let statuses = self.client.subscribe_transaction_status(tx_id).await?;
+
+        Ok(statuses.map(|status| Ok(status?.into())))
     }
 
     async fn validate_transaction<T: Transaction>(&self, tx: T) -> Result<()> {
@@ -234,11 +645,56 @@ impl Provider {
     }
 
     pub async fn tx_status(&self, tx_id: &TxId) -> Result<TxStatus> {
-        Ok(self.client.transaction_status(tx_id).await?.into())
+        let status = self.client.transaction_status(tx_id).await?.into();
+        self.update_pending_transaction(tx_id, &status);
+
+        Ok(status)
+    }
+
+    fn update_pending_transaction(&self, tx_id: &TxId, status: &TxStatus) {
+        self.pending_transactions
+            .lock()
+            .expect("not poisoned")
+            .update(tx_id, status);
     }
 
     pub async fn chain_info(&self) -> Result<ChainInfo> {
-        Ok(self.client.chain_info().await?.into())
+        let chain_info: ChainInfo = self.client.chain_info().await?.into();
+
+        if !self.chain_info_ttl.is_zero() {
+            *self.cached_chain_info.lock().expect("poisoned lock") =
+                Some((Instant::now(), chain_info.clone()));
+        }
+
+        Ok(chain_info)
+    }
+
+    /// The chain info used when building and sending transactions, fetched at most once every
+    /// [`with_chain_info_ttl`](Self::with_chain_info_ttl).
+    async fn cached_chain_info(&self) -> Result<ChainInfo> {
+        if let Some((fetched_at, chain_info)) =
+            &*self.cached_chain_info.lock().expect("poisoned lock")
+        {
+            if fetched_at.elapsed() < self.chain_info_ttl {
+                return Ok(chain_info.clone());
+            }
+        }
+
+        self.chain_info().await
+    }
+
+    /// Forgets the chain info cached because of [`with_chain_info_ttl`](Self::with_chain_info_ttl).
+    pub fn invalidate_chain_info(&self) {
+        *self.cached_chain_info.lock().expect("poisoned lock") = None;
+    }
+
+    /// Fetches the consensus parameters, including the gas costs and the base asset id, again.
+    /// They are otherwise only fetched when connecting, e.g. they are stale after a chain upgrade.
+    pub async fn refresh_consensus_parameters(&mut self) -> Result<()> {
+        self.invalidate_chain_info();
+        self.consensus_parameters = self.chain_info().await?.consensus_parameters;
+
+        Ok(())
     }
 
     pub fn consensus_parameters(&self) -> &ConsensusParameters {
@@ -265,6 +721,48 @@ impl Provider {
         Ok(self.client.estimate_gas_price(block_horizon).await?)
     }
 
+    /// Gets the latest gas price, the gas price estimated for inclusion within `horizon` blocks
+    /// and the percentiles of the tips paid by the transactions of the last `horizon` blocks.
+    ///
+    /// Every transaction of the considered blocks is requested from the node, so large horizons
+    /// are slow.
+    pub async fn gas_price_stats(&self, horizon: u32) -> Result<GasPriceStats> {
+        let horizon = horizon.max(1);
+        let latest = self.latest_gas_price().await?;
+        let estimated = self.estimate_gas_price(horizon).await?;
+
+        let block_height = latest.block_height;
+        let first_height = block_height.saturating_sub(horizon - 1);
+        let tx_ids = self
+            .blocks_stream(first_height..=block_height)
+            .map_ok(|block| block.transactions)
+            .try_concat()
+            .await?;
+
+        let mut tips = vec![];
+        for tx_id in tx_ids {
+            let Some(response) = self.get_transaction_by_id(&tx_id).await? else {
+                continue;
+            };
+            let tip = match response.transaction {
+                TransactionType::Script(tx) => tx.tip(),
+                TransactionType::Create(tx) => tx.tip(),
+                TransactionType::Upload(tx) => tx.tip(),
+                TransactionType::Upgrade(tx) => tx.tip(),
+                TransactionType::Mint(_) => continue,
+            };
+            tips.push(tip.unwrap_or_default());
+        }
+
+        Ok(GasPriceStats {
+            latest_gas_price: latest.gas_price,
+            block_height,
+            estimated_gas_price: estimated.gas_price,
+            horizon: block_height - first_height + 1,
+            tips: TipPercentiles::new(tips),
+        })
+    }
+
     pub async fn dry_run(&self, tx: impl Transaction) -> Result<TxStatus> {
         let [tx_status] = self
             .client
@@ -279,6 +777,9 @@ impl Provider {
         Ok(tx_status)
     }
 
+    /// Dry-runs `transactions` in a single request, returning the status of each of them in
+    /// order. They are executed one after the other, so a transaction can spend the outputs of
+    /// the ones before it, e.g. to simulate a bundle of dependent transactions.
     pub async fn dry_run_multiple(
         &self,
         transactions: Transactions,
@@ -315,6 +816,8 @@ impl Provider {
         Ok(tx_status)
     }
 
+    /// Like [`dry_run_multiple`](Self::dry_run_multiple), optionally skipping the validation
+    /// of the spent UTXOs and executing with `gas_price`.
     pub async fn dry_run_opt_multiple(
         &self,
         transactions: Transactions,
@@ -332,35 +835,56 @@ impl Provider {
 
     /// Gets all unspent coins owned by address `from`, with asset ID `asset_id`.
     pub async fn get_coins(&self, from: &Bech32Address, asset_id: AssetId) -> Result<Vec<Coin>> {
-        let mut coins: Vec<Coin> = vec![];
+        self.coins_stream(from, asset_id).try_collect().await
+    }
 
-        let mut cursor = None;
+    /// Streams the unspent coins owned by address `from`, with asset ID `asset_id`, requesting
+    /// them from the node page by page.
+    pub fn coins_stream(
+        &self,
+        from: &Bech32Address,
+        asset_id: AssetId,
+    ) -> impl Stream<Item = Result<Coin>> + '_ {
+        let owner = from.into();
 
-        loop {
-            let res = self
-                .client
-                .coins(
-                    &from.into(),
-                    Some(&asset_id),
-                    PaginationRequest {
-                        cursor: cursor.clone(),
-                        results: 100,
-                        direction: PageDirection::Forward,
-                    },
-                )
-                .await?;
+        paginate(move |pagination| async move {
+            self.client.coins(&owner, Some(&asset_id), pagination).await
+        })
+        .map_ok(Into::into)
+    }
+
+    async fn request_coins_to_spend(&self, mut filter: ResourceFilter) -> Result<Vec<CoinType>> {
+        let Some(reservations) = &self.reservations else {
+            return self.select_coins(filter).await;
+        };
+
+        for _ in 0..MAX_RESERVATION_ATTEMPTS {
+            let reserved = reservations.lock().expect("not poisoned").active();
+            extend_filter_with_excluded(&mut filter, &reserved);
 
-            if res.results.is_empty() {
-                break;
+            let resources = self.select_coins(filter.clone()).await?;
+            let ids: Vec<_> = resources.iter().map(CoinType::id).collect();
+
+            // Another task may have reserved some of the resources while we were selecting.
+            if reservations.lock().expect("not poisoned").try_reserve(&ids) {
+                return Ok(resources);
             }
-            coins.extend(res.results.into_iter().map(Into::into));
-            cursor = res.cursor;
         }
 
-        Ok(coins)
+        Err(error!(
+            Other,
+            "could not reserve resources for `{}` after {MAX_RESERVATION_ATTEMPTS} attempts",
+            filter.from
+        ))
     }
 
-    async fn request_coins_to_spend(&self, filter: ResourceFilter) -> Result<Vec<CoinType>> {
+    async fn select_coins(&self, filter: ResourceFilter) -> Result<Vec<CoinType>> {
+        if let Some(coin_selection) = &self.coin_selection {
+            return self
+                .select_coins_locally(coin_selection.as_ref(), filter)
+                .await;
+        }
+
         let queries = filter.resource_queries();
 
         let res = self
@@ -379,6 +903,41 @@ impl Provider {
         Ok(res)
     }
 
+    async fn select_coins_locally(
+        &self,
+        coin_selection: &dyn CoinSelection,
+        filter: ResourceFilter,
+    ) -> Result<Vec<CoinType>> {
+        let base_asset_id = *self.base_asset_id();
+        let asset_id = filter.asset_id.unwrap_or(base_asset_id);
+
+        let coins = self
+            .get_coins(&filter.from, asset_id)
+            .await?
+            .into_iter()
+            .filter(|coin| !filter.excluded_utxos.contains(&coin.utxo_id))
+            .map(CoinType::Coin);
+
+        let messages = if asset_id == base_asset_id {
+            self.get_messages(&filter.from)
+                .await?
+                .into_iter()
+                .filter(|message| {
+                    message.data.is_empty()
+                        && !filter.excluded_message_nonces.contains(&message.nonce)
+                })
+                .map(CoinType::Message)
+                .collect()
+        } else {
+            vec![]
+        };
+
+        let candidates = coins.chain(messages).collect();
+        let max_inputs = self.consensus_parameters.tx_params().max_inputs() as usize;
+
+        coin_selection.select(candidates, filter.amount, max_inputs)
+    }
+
     /// Get some spendable coins of asset `asset_id` for address `from` that add up at least to
     /// amount `amount`. The returned coins (UTXOs) are actual coins that can be spent. The number
     /// of coins (UXTOs) is optimized to prevent dust accumulation.
@@ -407,28 +966,7 @@ impl Provider {
         let asset_id = filter.asset_id.unwrap_or(*self.base_asset_id());
         let used_coins = cache.get_active(&(filter.from.clone(), asset_id));
 
-        let excluded_utxos = used_coins
-            .iter()
-            .filter_map(|coin_id| match coin_id {
-                CoinTypeId::UtxoId(utxo_id) => Some(utxo_id),
-                _ => None,
-            })
-            .cloned()
-            .collect::<Vec<_>>();
-
-        let excluded_message_nonces = used_coins
-            .iter()
-            .filter_map(|coin_id| match coin_id {
-                CoinTypeId::Nonce(nonce) => Some(nonce),
-                _ => None,
-            })
-            .cloned()
-            .collect::<Vec<_>>();
-
-        filter.excluded_utxos.extend(excluded_utxos);
-        filter
-            .excluded_message_nonces
-            .extend(excluded_message_nonces);
+        extend_filter_with_excluded(filter, &used_coins);
     }
 
     /// Get the balance of all spendable coins `asset_id` for address `address`. This is different
@@ -445,6 +983,34 @@ impl Provider {
             .await?)
     }
 
+    /// Get the balance of `asset_id` for `address` as it will be once the transactions sent with
+    /// [`send_transaction`](Self::send_transaction) are committed: resources they spend are
+    /// subtracted and the outputs, including the expected change, they pay to `address` are added.
+    pub async fn get_spendable_balance(
+        &self,
+        address: &Bech32Address,
+        asset_id: AssetId,
+    ) -> Result<u64> {
+        let pending_tx_ids = self
+            .pending_transactions
+            .lock()
+            .expect("not poisoned")
+            .tx_ids();
+        for tx_id in pending_tx_ids {
+            // Final statuses are evicted by `tx_status`, while a transaction whose status could
+            // not be fetched is still counted as pending.
+            let _ = self.tx_status(&tx_id).await;
+        }
+
+        let balance = self.get_asset_balance(address, asset_id).await?;
+
+        Ok(self
+            .pending_transactions
+            .lock()
+            .expect("not poisoned")
+            .apply(&address.into(), &asset_id, balance))
+    }
+
     /// Get the balance of all spendable coins `asset_id` for contract with id `contract_id`.
     pub async fn get_contract_asset_balance(
         &self,
@@ -461,18 +1027,9 @@ impl Provider {
     /// getting the coins because we are only returning the numbers (the sum of UTXOs coins amount
     /// for each asset id) and not the UTXOs coins themselves
     pub async fn get_balances(&self, address: &Bech32Address) -> Result<HashMap<String, u64>> {
-        // We don't paginate results because there are likely at most ~100 different assets in one
-        // wallet
-        let pagination = PaginationRequest {
-            cursor: None,
-            results: 9999,
-            direction: PageDirection::Forward,
-        };
-        let balances_vec = self
-            .client
-            .balances(&address.into(), pagination)
-            .await?
-            .results;
+        let owner = address.into();
+        let balances_vec =
+            collect_pages(None, |pagination| self.client.balances(&owner, pagination)).await?;
         let balances = balances_vec
             .into_iter()
             .map(
@@ -486,32 +1043,88 @@ impl Provider {
         Ok(balances)
     }
 
-    /// Get all balances of all assets for the contract with id `contract_id`.
-    pub async fn get_contract_balances(
+    /// Gets the balances of every address in `addresses`, like [`get_balances`](Self::get_balances),
+    /// querying the balances of many addresses in a single request.
+    pub async fn balances_many(
         &self,
-        contract_id: &Bech32ContractId,
-    ) -> Result<HashMap<AssetId, u64>> {
-        let mut pagination = PaginationRequest {
-            cursor: None,
-            results: 512,
-            direction: PageDirection::Forward,
-        };
+        addresses: &[Bech32Address],
+    ) -> Result<HashMap<Bech32Address, HashMap<String, u64>>> {
+        let mut balances = HashMap::new();
+        for batch in addresses.chunks(batch_balances::BALANCES_BATCH_SIZE) {
+            let owners: Vec<Address> = batch.iter().map(Into::into).collect();
+            let (query, variables) = batch_balances::balances_query(&owners, PAGE_SIZE);
+            let data = self.raw_query(&query, variables).await?;
+
+            for (address, owner_balances) in batch
+                .iter()
+                .zip(batch_balances::parse_balances(data, batch.len())?)
+            {
+                let owner_balances = match owner_balances {
+                    Some(owner_balances) => owner_balances,
+                    None => self.get_balances(address).await?,
+                };
+                balances.insert(address.clone(), owner_balances);
+            }
+        }
 
-        let mut balances_vec = vec![];
-        loop {
-            let mut paginated_result = self
-                .client
-                .contract_balances(&contract_id.into(), pagination.clone())
-                .await?;
+        Ok(balances)
+    }
 
-            pagination.cursor = paginated_result.cursor;
-            balances_vec.append(&mut paginated_result.results);
+    /// Sums every unspent coin and every spendable message owned by `address`, fetching as many
+    /// pages as needed. Messages count towards the base asset.
+    ///
+    /// If `max_resources` is given, fails instead of returning a partial total when `address`
+    /// owns more coins and messages than that.
+    pub async fn get_complete_balances(
+        &self,
+        address: &Bech32Address,
+        max_resources: Option<usize>,
+    ) -> Result<HashMap<AssetId, u64>> {
+        let owner = address.into();
+        let coins = collect_pages(max_resources, |pagination| {
+            self.client.coins(&owner, None, pagination)
+        })
+        .await?;
 
-            if !paginated_result.has_next_page {
-                break;
-            }
+        let remaining = max_resources.map(|max| max - coins.len());
+        let messages = collect_pages(remaining, |pagination| {
+            self.client.messages(Some(&owner), pagination)
+        })
+        .await?;
+
+        let base_asset_id = *self.base_asset_id();
+        let resources = coins
+            .into_iter()
+            .map(|coin| (coin.asset_id, coin.amount))
+            .chain(
+                messages
+                    .into_iter()
+                    .filter(|message| message.data.is_empty())
+                    .map(|message| (base_asset_id, message.amount)),
+            );
+
+        let mut balances = HashMap::new();
+        for (asset_id, amount) in resources {
+            let balance: &mut u64 = balances.entry(asset_id).or_default();
+            *balance = balance
+                .checked_add(amount)
+                .ok_or_else(|| error!(Other, "balance of asset `{asset_id}` overflows"))?;
         }
 
+        Ok(balances)
+    }
+
+    /// Get all balances of all assets for the contract with id `contract_id`.
+    pub async fn get_contract_balances(
+        &self,
+        contract_id: &Bech32ContractId,
+    ) -> Result<HashMap<AssetId, u64>> {
+        let contract_id = contract_id.into();
+        let balances_vec = collect_pages(None, |pagination| {
+            self.client.contract_balances(&contract_id, pagination)
+        })
+        .await?;
+
         let balances = balances_vec
             .into_iter()
             .map(
@@ -526,6 +1139,86 @@ impl Provider {
         Ok(balances)
     }
 
+    /// Exports the coins and messages owned by `owners` and the code and balances of `contracts`
+    /// as a [`StateConfig`], from which a local node can be booted to fork the network, e.g.
+    /// with `fuels-test-helpers`.
+    ///
+    /// The node does not expose the storage of contracts, so they are exported without it. Coins
+    /// are exported as created in the genesis block of the fork.
+    pub async fn state_snapshot(
+        &self,
+        owners: &[Bech32Address],
+        contracts: &[Bech32ContractId],
+    ) -> Result<StateConfig> {
+        let mut coins = vec![];
+        let mut messages = vec![];
+        for owner in owners {
+            let address = owner.into();
+            let owned_coins = collect_pages(None, |pagination| {
+                self.client.coins(&address, None, pagination)
+            })
+            .await?;
+            coins.extend(owned_coins.into_iter().map(|coin| {
+                CoinConfig::from(Coin {
+                    block_created: 0,
+                    ..coin.into()
+                })
+            }));
+
+            messages.extend(
+                self.get_messages(owner)
+                    .await?
+                    .into_iter()
+                    .map(MessageConfig::from),
+            );
+        }
+
+        let mut contract_configs = vec![];
+        for contract_id in contracts {
+            let id = contract_id.into();
+            let contract = self
+                .client
+                .contract(&id)
+                .await?
+                .ok_or_else(|| error!(Provider, "contract `{contract_id}` not found"))?;
+            let balances = self
+                .get_contract_balances(contract_id)
+                .await?
+                .into_iter()
+                .map(|(asset_id, amount)| ContractBalanceConfig { asset_id, amount })
+                .collect();
+
+            contract_configs.push(ContractConfig {
+                contract_id: id,
+                code: contract.bytecode,
+                balances,
+                ..Default::default()
+            });
+        }
+
+        Ok(StateConfig {
+            coins,
+            messages,
+            contracts: contract_configs,
+            ..Default::default()
+        })
+    }
+
+    /// Sends a GraphQL `query` to the node, for fields the SDK does not wrap yet. It goes through
+    /// the same failover, retries and middlewares as every other request.
+    ///
+    /// `T` is deserialized from the `data` of the response.
+    pub async fn raw_query<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: impl Serialize,
+    ) -> Result<T> {
+        let variables = serde_json::to_value(variables)?;
+        let data = self.client.raw_query(query, &variables).await?;
+
+        Ok(serde_json::from_value(data)?)
+    }
+
     pub async fn get_transaction_by_id(&self, tx_id: &TxId) -> Result<Option<TransactionResponse>> {
         Ok(self.client.transaction(tx_id).await?.map(Into::into))
     }
@@ -563,6 +1256,24 @@ impl Provider {
         })
     }
 
+    /// Streams every transaction of the chain, requesting them from the node page by page.
+    pub fn transactions_stream(&self) -> impl Stream<Item = Result<TransactionResponse>> + '_ {
+        paginate(|pagination| self.client.transactions(pagination)).map_ok(Into::into)
+    }
+
+    /// Streams the transactions of `owner`, requesting them from the node page by page.
+    pub fn transactions_by_owner_stream(
+        &self,
+        owner: &Bech32Address,
+    ) -> impl Stream<Item = Result<TransactionResponse>> + '_ {
+        let owner = owner.into();
+
+        paginate(move |pagination| async move {
+            self.client.transactions_by_owner(&owner, pagination).await
+        })
+        .map_ok(Into::into)
+    }
+
     pub async fn latest_block_height(&self) -> Result<u32> {
         Ok(self.chain_info().await?.latest_block.header.height)
     }
@@ -571,12 +1282,53 @@ impl Provider {
         Ok(self.chain_info().await?.latest_block.header.time)
     }
 
+    /// Measures the time between blocks over the latest [`BLOCK_TIME_SAMPLE`] blocks.
+    pub async fn average_block_time(&self) -> Result<Duration> {
+        let latest = self.chain_info().await?.latest_block.header;
+        let blocks = latest.height.min(BLOCK_TIME_SAMPLE);
+        if blocks == 0 {
+            return Err(error!(
+                Other,
+                "cannot measure the block time before the first block"
+            ));
+        }
+
+        let earlier = self
+            .block_by_height((latest.height - blocks).into())
+            .await?
+            .ok_or_else(|| error!(Provider, "block {} not found", latest.height - blocks))?
+            .header;
+        let (Some(latest_time), Some(earlier_time)) = (latest.time, earlier.time) else {
+            return Err(error!(Provider, "the node returned blocks without a time"));
+        };
+        let elapsed = (latest_time - earlier_time).to_std().unwrap_or_default();
+
+        Ok(elapsed / blocks)
+    }
+
+    /// Estimates the block height reached after `duration` from the average block time, e.g. to
+    /// set a maturity in wall-clock time with
+    /// [`TxPolicies::with_maturity`](fuels_core::types::transaction::TxPolicies::with_maturity).
+    pub async fn estimate_height_in(&self, duration: Duration) -> Result<u32> {
+        let block_time = self.average_block_time().await?;
+        let latest_height = self.latest_block_height().await?;
+        if block_time.is_zero() {
+            return Ok(latest_height);
+        }
+
+        let blocks = duration.as_nanos().div_ceil(block_time.as_nanos());
+        let blocks = u32::try_from(blocks).unwrap_or(u32::MAX);
+
+        Ok(latest_height.saturating_add(blocks))
+    }
+
     pub async fn produce_blocks(
         &self,
         blocks_to_produce: u32,
         start_time: Option<DateTime<Utc>>,
     ) -> Result<u32> {
         let start_time = start_time.map(|time| Tai64::from_unix(time.timestamp()).0);
+        self.invalidate_chain_info();
 
         Ok(self
             .client
@@ -585,12 +1337,321 @@ impl Provider {
             .into())
     }
 
+    /// Produces `blocks_to_produce` blocks `interval` apart, the first one at `start_time`, or
+    /// `interval` after the latest block. Block times have a precision of a second, so `interval`
+    /// is truncated to whole seconds and must be at least one.
+    ///
+    /// Meant for test nodes whose block production is `Trigger::Never`, which only produce blocks
+    /// when asked to, so that time-dependent logic can be tested against exact block times.
+    pub async fn produce_blocks_with_interval(
+        &self,
+        blocks_to_produce: u32,
+        start_time: Option<DateTime<Utc>>,
+        interval: Duration,
+    ) -> Result<u32> {
+        let interval = chrono::Duration::seconds(interval.as_secs() as i64);
+        if interval.is_zero() {
+            return Err(error!(
+                Other,
+                "the interval between blocks must be at least a second"
+            ));
+        }
+
+        let mut block_time = match start_time {
+            Some(start_time) => start_time,
+            None => {
+                let latest_time = self
+                    .latest_block_time()
+                    .await?
+                    .ok_or_else(|| error!(Other, "the latest block has no time"))?;
+
+                latest_time + interval
+            }
+        };
+
+        let mut height = self.latest_block_height().await?;
+        for _ in 0..blocks_to_produce {
+            height = self.produce_blocks(1, Some(block_time)).await?;
+            block_time += interval;
+        }
+
+        Ok(height)
+    }
+
+    /// Streams the blocks produced after this call, in order of height.
+    ///
+    /// The node has no block subscription, so it is polled for the next block every
+    /// [`BLOCK_POLL_INTERVAL`].
+    pub async fn subscribe_blocks(&self) -> Result<impl Stream<Item = Result<Block>>> {
+        let next_height = self.latest_block_height().await? + 1;
+
+        Ok(stream::try_unfold(
+            (self.clone(), next_height),
+            |(provider, height)| async move {
+                loop {
+                    if let Some(block) = provider.block_by_height(height.into()).await? {
+                        return Ok(Some((block, (provider, height + 1))));
+                    }
+
+                    tokio::time::sleep(BLOCK_POLL_INTERVAL).await;
+                }
+            },
+        ))
+    }
+
+    /// Compares the blocks tracked since [`with_reorg_tracking`](Self::with_reorg_tracking) with
+    /// the ones of the node, returning the seen blocks that were replaced, if any.
+    ///
+    /// If all the tracked blocks were replaced, the common ancestor reported is the height below
+    /// the oldest of them, as the node is not checked further back.
+    pub async fn check_reorg(&self) -> Result<Option<Reorg>> {
+        let chain = self
+            .header_chain
+            .as_ref()
+            .ok_or_else(|| error!(Provider, "reorg tracking is not enabled on the provider"))?;
+        let tracked = chain
+            .lock()
+            .expect("header chain lock poisoned")
+            .ids_from_head();
+
+        let mut replaced = vec![];
+        let mut common_ancestor = None;
+        for (height, id) in tracked {
+            match self.block_by_height(height.into()).await? {
+                Some(block) if block.id == id => {
+                    common_ancestor = Some(height);
+                    break;
+                }
+                _ => replaced.push((height, id)),
+            }
+        }
+
+        let Some(&(oldest_replaced, _)) = replaced.last() else {
+            return Ok(None);
+        };
+        let replaced = chain
+            .lock()
+            .expect("header chain lock poisoned")
+            .remove(&replaced);
+        if replaced.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Reorg {
+            common_ancestor: common_ancestor.unwrap_or(oldest_replaced.saturating_sub(1)),
+            replaced,
+        }))
+    }
+
+    /// Streams the reorgs found by [`check_reorg`](Self::check_reorg), which is called every
+    /// [`BLOCK_POLL_INTERVAL`]. The latest block is tracked on every poll, so that reorgs are
+    /// found even if no blocks are requested in the meantime.
+    pub fn subscribe_reorgs(&self) -> Result<impl Stream<Item = Result<Reorg>>> {
+        if self.header_chain.is_none() {
+            return Err(error!(
+                Provider,
+                "reorg tracking is not enabled on the provider"
+            ));
+        }
+
+        Ok(stream::try_unfold(self.clone(), |provider| async move {
+            loop {
+                if let Some(reorg) = provider.check_reorg().await? {
+                    return Ok(Some((reorg, provider)));
+                }
+
+                let latest_height = provider.latest_block_height().await?;
+                provider.block_by_height(latest_height.into()).await?;
+
+                tokio::time::sleep(BLOCK_POLL_INTERVAL).await;
+            }
+        }))
+    }
+
+    /// Streams the committed blocks with a height in `range`, requesting them from the node page
+    /// by page.
+    pub fn blocks_stream(
+        &self,
+        range: impl RangeBounds<u32>,
+    ) -> impl Stream<Item = Result<Block>> + '_ {
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = range.end_bound().cloned();
+        // The cursor of the blocks is the height of the last block returned.
+        let cursor = start.checked_sub(1).map(|height| height.to_string());
+
+        paginate_from(cursor, |pagination| self.client.blocks(pagination))
+            .and_then(|block| std::future::ready(self.verified_block(block)))
+            .try_skip_while(move |block| std::future::ready(Ok(block.header.height < start)))
+            .try_take_while(move |block| {
+                let height = block.header.height;
+                std::future::ready(Ok(match end {
+                    Bound::Included(end) => height <= end,
+                    Bound::Excluded(end) => height < end,
+                    Bound::Unbounded => true,
+                }))
+            })
+    }
+
+    /// Walks the blocks selected by `filter` and streams the receipts of their transactions that
+    /// match it, in chain order. Up to [`ReceiptFilter::with_concurrency`] transactions are
+    /// fetched at the same time.
+    ///
+    /// Every receipt comes with a [`ScanCursor`] which can be given to
+    /// [`ReceiptFilter::resume_after`] to continue an interrupted scan.
+    pub fn scan_receipts(
+        &self,
+        filter: ReceiptFilter,
+    ) -> impl Stream<Item = Result<ScannedReceipt>> + '_ {
+        let concurrency = filter.concurrency();
+
+        self.blocks_stream(filter.blocks())
+            .map_ok(|block| {
+                let block_height = block.header.height;
+                stream::iter(
+                    block
+                        .transactions
+                        .into_iter()
+                        .enumerate()
+                        .map(move |(tx_index, tx_id)| Ok((block_height, tx_index, tx_id))),
+                )
+            })
+            .try_flatten()
+            .map_ok(move |(block_height, tx_index, tx_id)| async move {
+                let receipts = match self
+                    .get_transaction_by_id(&tx_id)
+                    .await?
+                    .map(|tx| tx.status)
+                {
+                    Some(TxStatus::Success { receipts } | TxStatus::Revert { receipts, .. }) => {
+                        receipts
+                    }
+                    _ => vec![],
+                };
+
+                Ok::<_, Error>((block_height, tx_index, tx_id, receipts))
+            })
+            .try_buffered(concurrency)
+            .map_ok(move |(block_height, tx_index, tx_id, receipts)| {
+                let filter = filter.clone();
+                stream::iter(
+                    receipts
+                        .into_iter()
+                        .enumerate()
+                        .map(move |(receipt_index, receipt)| ScannedReceipt {
+                            tx_id,
+                            receipt,
+                            cursor: ScanCursor {
+                                block_height,
+                                tx_index,
+                                receipt_index,
+                            },
+                        })
+                        .filter(move |scanned| {
+                            filter.is_resumed_past(&scanned.cursor)
+                                && filter.matches(&scanned.receipt)
+                        })
+                        .map(Ok),
+                )
+            })
+            .try_flatten()
+    }
+
+    /// Streams the receipts matching `filter` of the blocks produced after this call, or from the
+    /// first block of `filter` or its resume cursor if it sets one, in chain order. If `filter`
+    /// has a last block, the stream ends once the block after it is produced.
+    ///
+    /// The node has no receipt subscription, so it is polled for the next block every
+    /// [`BLOCK_POLL_INTERVAL`].
+    pub async fn subscribe_receipts(
+        &self,
+        filter: ReceiptFilter,
+    ) -> Result<impl Stream<Item = Result<ScannedReceipt>>> {
+        let start_height = match filter.start_height() {
+            Some(start_height) => start_height,
+            None => self.latest_block_height().await? + 1,
+        };
+        let end_filter = filter.clone();
+
+        Ok(BlockWalker::new(self.clone(), start_height)
+            .with_transactions()
+            .with_concurrency(filter.concurrency())
+            .into_stream()
+            .try_take_while(move |walked| {
+                std::future::ready(Ok(!end_filter.is_past_end(walked.block.header.height)))
+            })
+            .map_ok(move |walked| {
+                let filter = filter.clone();
+                let block_height = walked.block.header.height;
+                stream::iter(
+                    walked
+                        .block
+                        .transactions
+                        .into_iter()
+                        .zip(walked.transactions)
+                        .enumerate()
+                        .flat_map(move |(tx_index, (tx_id, response))| {
+                            let receipts = match response.status {
+                                TxStatus::Success { receipts }
+                                | TxStatus::Revert { receipts, .. } => receipts,
+                                _ => vec![],
+                            };
+                            receipts
+                                .into_iter()
+                                .enumerate()
+                                .map(move |(receipt_index, receipt)| ScannedReceipt {
+                                    tx_id,
+                                    receipt,
+                                    cursor: ScanCursor {
+                                        block_height,
+                                        tx_index,
+                                        receipt_index,
+                                    },
+                                })
+                        })
+                        .filter(move |scanned| {
+                            filter.is_resumed_past(&scanned.cursor)
+                                && filter.matches(&scanned.receipt)
+                        })
+                        .map(Ok),
+                )
+            })
+            .try_flatten())
+    }
+
+    /// Gets the block at `height` in the compressed form the node posts to the DA layer, which is
+    /// cheaper to sync in bulk. Returns `None` if the node did not compress the block, and fails
+    /// if the node does not expose compressed blocks.
+    ///
+    /// Use [`DaCompressedBlock::transactions`] to decode its transactions.
+    pub async fn da_compressed_block(&self, height: u32) -> Result<Option<DaCompressedBlock>> {
+        let data: DaCompressedBlockData = self
+            .raw_query(
+                DA_COMPRESSED_BLOCK_QUERY,
+                serde_json::json!({ "height": height.to_string() }),
+            )
+            .await?;
+
+        data.into_block(height)
+    }
+
     pub async fn block(&self, block_id: &Bytes32) -> Result<Option<Block>> {
-        Ok(self.client.block(block_id).await?.map(Into::into))
+        self.client
+            .block(block_id)
+            .await?
+            .map(|block| self.verified_block(block))
+            .transpose()
     }
 
     pub async fn block_by_height(&self, height: BlockHeight) -> Result<Option<Block>> {
-        Ok(self.client.block_by_height(height).await?.map(Into::into))
+        self.client
+            .block_by_height(height)
+            .await?
+            .map(|block| self.verified_block(block))
+            .transpose()
     }
 
     // - Get block(s)
@@ -602,7 +1663,11 @@ impl Provider {
 
         Ok(PaginatedResult {
             cursor: pr.cursor,
-            results: pr.results.into_iter().map(Into::into).collect(),
+            results: pr
+                .results
+                .into_iter()
+                .map(|block| self.verified_block(block))
+                .collect::<Result<_>>()?,
             has_next_page: pr.has_next_page,
             has_previous_page: pr.has_previous_page,
         })
@@ -632,11 +1697,22 @@ impl Provider {
             .fee_checked_from_tx(&self.consensus_parameters, gas_price)
             .expect("Error calculating TransactionFee");
 
+        let total_fee = transaction_fee.max_fee();
+        let tip = tx.tip().unwrap_or_default();
+        let predicate_gas_used = tx
+            .inputs()
+            .iter()
+            .filter_map(|input| input.predicate_gas_used())
+            .collect();
+
         Ok(TransactionCost {
             gas_price,
             gas_used,
             metered_bytes_size: tx.metered_bytes_size() as u64,
-            total_fee: transaction_fee.max_fee(),
+            total_fee,
+            tip,
+            base_fee: total_fee.saturating_sub(tip),
+            predicate_gas_used,
         })
     }
 
@@ -665,20 +1741,62 @@ impl Provider {
     }
 
     pub async fn get_messages(&self, from: &Bech32Address) -> Result<Vec<Message>> {
-        let pagination = PaginationRequest {
-            cursor: None,
-            results: 100,
-            direction: PageDirection::Forward,
-        };
+        self.messages_stream(from).try_collect().await
+    }
 
-        Ok(self
-            .client
-            .messages(Some(&from.into()), pagination)
-            .await?
-            .results
-            .into_iter()
-            .map(Into::into)
-            .collect())
+    /// Streams the messages sent to `from`, requesting them from the node page by page.
+    pub fn messages_stream(
+        &self,
+        from: &Bech32Address,
+    ) -> impl Stream<Item = Result<Message>> + '_ {
+        let owner = from.into();
+
+        paginate(
+            move |pagination| async move { self.client.messages(Some(&owner), pagination).await },
+        )
+        .map_ok(Into::into)
+    }
+
+    /// Gets the message with `nonce` sent to `recipient` from L1, e.g. by a bridge deposit. It
+    /// is returned once the node relayed it from L1 and until it is spent.
+    pub async fn get_deposit(
+        &self,
+        recipient: &Bech32Address,
+        nonce: &Nonce,
+    ) -> Result<Option<Message>> {
+        let mut messages = pin!(self.messages_stream(recipient));
+        while let Some(message) = messages.try_next().await? {
+            if message.nonce == *nonce {
+                return Ok(Some(message));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Waits until the message with `nonce` sent to `recipient` from L1 can be spent on Fuel,
+    /// polling the node every [`BLOCK_POLL_INTERVAL`] and failing after `timeout`.
+    pub async fn await_deposit(
+        &self,
+        recipient: &Bech32Address,
+        nonce: &Nonce,
+        timeout: Duration,
+    ) -> Result<Message> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(message) = self.get_deposit(recipient, nonce).await? {
+                return Ok(message);
+            }
+
+            if Instant::now() + BLOCK_POLL_INTERVAL > deadline {
+                return Err(error!(
+                    Other,
+                    "deposit `{nonce}` to `{recipient}` was not relayed within {timeout:?}"
+                ));
+            }
+
+            tokio::time::sleep(BLOCK_POLL_INTERVAL).await;
+        }
     }
 
     pub async fn get_message_proof(
@@ -702,11 +1820,276 @@ impl Provider {
         Ok(proof)
     }
 
+    /// Gets the proof that the message with `nonce`, sent by the transaction `tx_id`, is part
+    /// of the history of `commit_block`.
+    pub async fn get_message_proof_at(
+        &self,
+        tx_id: &TxId,
+        nonce: &Nonce,
+        commit_block: CommitBlock,
+    ) -> Result<Option<MessageProof>> {
+        match commit_block {
+            CommitBlock::Id(id) => self.get_message_proof(tx_id, nonce, Some(&id), None).await,
+            CommitBlock::Height(height) => {
+                self.get_message_proof(tx_id, nonce, None, Some(height))
+                    .await
+            }
+        }
+    }
+
+    /// Gets the arguments relaying the message with `nonce`, sent by the transaction `tx_id`,
+    /// to the L1 message portal once `commit_block` is committed there.
+    pub async fn get_withdrawal_relay(
+        &self,
+        tx_id: &TxId,
+        nonce: &Nonce,
+        commit_block: CommitBlock,
+    ) -> Result<Option<WithdrawalRelay>> {
+        self.get_message_proof_at(tx_id, nonce, commit_block)
+            .await?
+            .as_ref()
+            .map(WithdrawalRelay::new)
+            .transpose()
+    }
+
     pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
         self.client.set_retry_config(retry_config);
 
         self
     }
+
+    /// Tracks the headers of the last `depth` blocks returned by the provider, so that
+    /// [`check_reorg`](Self::check_reorg) and [`subscribe_reorgs`](Self::subscribe_reorgs) can
+    /// report when the node replaces them. Reorgs deeper than `depth` are reported as replacing
+    /// all the tracked blocks.
+    pub fn with_reorg_tracking(mut self, depth: usize) -> Self {
+        self.header_chain = Some(Arc::new(std::sync::Mutex::new(HeaderChain::new(depth))));
+        self
+    }
+
+    /// Checks every block returned by the node with `verifier`, failing the requests returning
+    /// blocks that do not pass. Enables
+    /// [`verify_transaction_inclusion`](Self::verify_transaction_inclusion) and
+    /// [`verify_header_chain`](Self::verify_header_chain).
+    pub fn with_block_verifier(mut self, verifier: BlockVerifier) -> Self {
+        self.block_verifier = Some(verifier);
+        self
+    }
+
+    fn verified_block(&self, block: ClientBlock) -> Result<Block> {
+        if let Some(verifier) = &self.block_verifier {
+            verifier.verify(&block)?;
+        }
+
+        let block = Block::from(block);
+        if let Some(chain) = &self.header_chain {
+            chain
+                .lock()
+                .expect("header chain lock poisoned")
+                .record(&block.header);
+        }
+
+        Ok(block)
+    }
+
+    fn block_verifier(&self) -> Result<&BlockVerifier> {
+        self.block_verifier
+            .as_ref()
+            .ok_or_else(|| error!(Provider, "no block verifier set on the provider"))
+    }
+
+    /// Proves that the transaction `tx_id` is part of a block signed by the authority, returning
+    /// that block.
+    ///
+    /// Every transaction of the block is fetched to check them against the `transactions_root`
+    /// of its header. Requires a [`BlockVerifier`].
+    pub async fn verify_transaction_inclusion(&self, tx_id: &TxId) -> Result<Block> {
+        self.block_verifier()?;
+
+        let height = self
+            .get_transaction_by_id(tx_id)
+            .await?
+            .and_then(|response| response.block_height)
+            .ok_or_else(|| error!(Provider, "transaction `{tx_id}` is not included in a block"))?;
+        let block = self
+            .client
+            .block_by_height(height)
+            .await?
+            .ok_or_else(|| error!(Provider, "block {height} not found"))?;
+        let block = self.verified_block(block)?;
+
+        if !block.transactions.contains(tx_id) {
+            return Err(error!(
+                Provider,
+                "transaction `{tx_id}` is not part of block {height}"
+            ));
+        }
+
+        let chain_id = self.chain_id();
+        let transactions =
+            futures::future::try_join_all(block.transactions.iter().map(|id| async move {
+                let transaction = self
+                    .client
+                    .transaction(id)
+                    .await?
+                    .ok_or_else(|| error!(Provider, "transaction `{id}` not found"))?
+                    .transaction;
+
+                if transaction.id(&chain_id) != *id {
+                    return Err(error!(
+                        Provider,
+                        "node returned another transaction for `{id}`"
+                    ));
+                }
+
+                Ok(transaction)
+            }))
+            .await?;
+
+        let header = &block.header;
+        if usize::from(header.transactions_count) != transactions.len()
+            || block_verification::transactions_root(&transactions) != header.transactions_root
+        {
+            return Err(error!(
+                Provider,
+                "transactions of block {height} do not match its `transactions_root`"
+            ));
+        }
+
+        Ok(block)
+    }
+
+    /// Verifies every block from genesis up to `height`, and that each of them commits to the
+    /// ones before it through its `prev_root`. Returns the verified block at `height`.
+    ///
+    /// This walks the whole chain and is only meant to anchor a light client once, e.g. with
+    /// the genesis id given to [`BlockVerifier::with_genesis_id`]. Requires a [`BlockVerifier`].
+    pub async fn verify_header_chain(&self, height: u32) -> Result<Block> {
+        self.block_verifier()?;
+
+        let mut block_ids = vec![];
+        let mut blocks = pin!(self.blocks_stream(..=height));
+        let mut last = None::<Block>;
+        while let Some(block) = blocks.try_next().await? {
+            let header = &block.header;
+
+            if let Some(previous) = &last {
+                if header.height != previous.header.height + 1 {
+                    return Err(error!(
+                        Provider,
+                        "block {} follows block {}", header.height, previous.header.height
+                    ));
+                }
+
+                if header.prev_root != block_verification::merkle_root(&block_ids) {
+                    return Err(error!(
+                        Provider,
+                        "block {}: `prev_root` does not commit to the previous blocks",
+                        header.height
+                    ));
+                }
+            }
+
+            block_ids.push(block.id);
+            last = Some(block);
+        }
+
+        last.filter(|block| block.header.height == height)
+            .ok_or_else(|| error!(Provider, "block {height} not found"))
+    }
+
+    /// Reuses the chain info, and with it the latest block header, for up to `ttl` when building
+    /// and sending transactions instead of fetching it for every transaction. Transactions are
+    /// then checked against a block height that can be up to `ttl` old.
+    ///
+    /// Caching is disabled by default. Explicit calls to [`chain_info`](Self::chain_info) always
+    /// reach the node.
+    pub fn with_chain_info_ttl(mut self, ttl: Duration) -> Self {
+        self.chain_info_ttl = ttl;
+        self.invalidate_chain_info();
+
+        self
+    }
+
+    /// Reports the latency and errors of every request to a node, as well as the size of the
+    /// transactions sent, to `callback`.
+    #[cfg(feature = "metrics")]
+    pub fn with_metrics(
+        mut self,
+        callback: impl Fn(&MetricEvent<'_>) + Send + Sync + 'static,
+    ) -> Self {
+        self.client
+            .set_metrics(metrics::MetricsCallback::new(callback));
+
+        self
+    }
+
+    /// Fails the requests to the nodes taking longer than `timeouts` allow for their class with a
+    /// "timed out" [`Error::Provider`].
+    pub fn with_timeouts(mut self, timeouts: Timeouts) -> Self {
+        self.client.set_timeouts(timeouts);
+
+        self
+    }
+
+    /// Queues requests so that no more than `limit` are sent, to all the nodes of the provider
+    /// together. Retried and failed over requests count as new ones.
+    pub fn with_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.client.set_rate_limit(limit);
+
+        self
+    }
+
+    /// Queues requests so that no more than `limit` are sent to each node of the provider. Can
+    /// be combined with [`with_rate_limit`](Self::with_rate_limit).
+    pub fn with_endpoint_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.client.set_endpoint_rate_limit(limit);
+
+        self
+    }
+
+    /// Adds a layer around every request sent to a node. The first middleware added is the
+    /// outermost one. Requests retried or failed over go through the middlewares again.
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.client.add_middleware(Arc::new(middleware));
+
+        self
+    }
+
+    /// Reserves the resources returned by [`get_spendable_resources`](Self::get_spendable_resources)
+    /// so that concurrent transactions sharing this provider never select the same ones.
+    ///
+    /// Reservations are released once the transaction is rejected or committed, when
+    /// [`release_coins`](Self::release_coins) is called, or after `ttl` otherwise.
+    pub fn with_coin_reservation(mut self, ttl: Duration) -> Self {
+        self.reservations = Some(Arc::new(std::sync::Mutex::new(CoinReservations::new(ttl))));
+
+        self
+    }
+
+    /// Makes reserved resources available for selection again, e.g. after a transaction built
+    /// from them is dropped without being sent.
+    pub fn release_coins<'a>(&self, ids: impl IntoIterator<Item = &'a CoinTypeId>) {
+        if let Some(reservations) = &self.reservations {
+            reservations.lock().expect("not poisoned").release(ids);
+        }
+    }
+
+    /// Records the transactions sent with [`send_transaction_once`](Self::send_transaction_once)
+    /// in `journal`.
+    pub fn with_submission_journal(mut self, journal: impl SubmissionJournal + 'static) -> Self {
+        self.submission_journal = Some(Arc::new(journal));
+
+        self
+    }
+
+    /// Chooses the resources funding transactions with `coin_selection` instead of the node's
+    /// default strategy.
+    pub fn with_coin_selection(mut self, coin_selection: impl CoinSelection + 'static) -> Self {
+        self.coin_selection = Some(Arc::new(coin_selection));
+
+        self
+    }
 }
 
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
@@ -724,8 +2107,10 @@ impl DryRunner for Provider {
 
         let variable_outputs = receipts
             .iter()
-            .filter(
-                |receipt| matches!(receipt, Receipt::TransferOut { amount, .. } if *amount != 0),
+            .matching(
+                &ReceiptMatcher::default()
+                    .with_kind(ReceiptKind::TransferOut)
+                    .with_amount(1..),
             )
             .count();
 
@@ -759,7 +2144,7 @@ impl DryRunner for Provider {
         let latest_chain_executor_version = match latest_chain_executor_version {
             Some(exec_version) => exec_version,
             None => {
-                self.chain_info()
+                self.cached_chain_info()
                     .await?
                     .latest_block
                     .header
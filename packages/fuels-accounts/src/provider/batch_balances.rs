@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use fuel_types::Address;
+use fuels_core::types::errors::{error, Result};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// How many owners are queried in a single request, to stay below the query complexity limit
+/// of the node.
+pub(crate) const BALANCES_BATCH_SIZE: usize = 20;
+
+/// A query for the first page of balances of every owner, each aliased by its index.
+pub(crate) fn balances_query(owners: &[Address], page_size: i32) -> (String, Value) {
+    let parameters = (0..owners.len())
+        .map(|index| format!("$owner{index}: Address!"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let fields = (0..owners.len())
+        .map(|index| {
+            format!(
+                "owner{index}: balances(filter: {{ owner: $owner{index} }}, first: {page_size}) \
+                 {{ nodes {{ assetId amount }} pageInfo {{ hasNextPage }} }}"
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let variables = owners
+        .iter()
+        .enumerate()
+        .map(|(index, owner)| {
+            (
+                format!("owner{index}"),
+                Value::String(format!("{owner:#x}")),
+            )
+        })
+        .collect::<Map<_, _>>();
+
+    (
+        format!("query({parameters}) {{ {fields} }}"),
+        Value::Object(variables),
+    )
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BalanceConnection {
+    nodes: Vec<BalanceNode>,
+    page_info: PageInfo,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BalanceNode {
+    asset_id: String,
+    amount: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+}
+
+/// The balances of every owner queried by [`balances_query`], by asset id, or `None` for the
+/// owners with more balances than fit in a page, which have to be queried on their own.
+pub(crate) fn parse_balances(
+    data: Value,
+    owners_count: usize,
+) -> Result<Vec<Option<HashMap<String, u64>>>> {
+    let mut data: HashMap<String, BalanceConnection> = serde_json::from_value(data)?;
+
+    (0..owners_count)
+        .map(|index| {
+            let connection = data
+                .remove(&format!("owner{index}"))
+                .ok_or_else(|| error!(Provider, "missing balances of owner {index}"))?;
+            if connection.page_info.has_next_page {
+                return Ok(None);
+            }
+
+            connection
+                .nodes
+                .into_iter()
+                .map(|node| {
+                    let amount = node.amount.parse().map_err(|err| {
+                        error!(Provider, "invalid balance amount `{}`: {err}", node.amount)
+                    })?;
+                    let asset_id = node.asset_id.trim_start_matches("0x").to_string();
+
+                    Ok((asset_id, amount))
+                })
+                .collect::<Result<_>>()
+                .map(Some)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn query_aliases_every_owner() {
+        let owners = [Address::new([1; 32]), Address::new([2; 32])];
+
+        let (query, variables) = balances_query(&owners, 100);
+
+        assert!(query.starts_with("query($owner0: Address!, $owner1: Address!)"));
+        assert!(query.contains("owner1: balances(filter: { owner: $owner1 }, first: 100)"));
+        assert_eq!(variables["owner0"], json!(format!("0x{}", "01".repeat(32))));
+    }
+
+    #[test]
+    fn parses_balances_by_owner() -> Result<()> {
+        let asset_id = format!("0x{}", "aa".repeat(32));
+        let data = json!({
+            "owner0": {
+                "nodes": [{ "assetId": asset_id, "amount": "42" }],
+                "pageInfo": { "hasNextPage": false },
+            },
+            "owner1": {
+                "nodes": [],
+                "pageInfo": { "hasNextPage": true },
+            },
+        });
+
+        let balances = parse_balances(data, 2)?;
+
+        assert_eq!(
+            balances,
+            vec![Some(HashMap::from([("aa".repeat(32), 42)])), None]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+use fuel_core_client::client::types::{
+    block::{Consensus, Header as ClientHeader},
+    Block as ClientBlock,
+};
+use fuel_core_types::{
+    blockchain::{
+        header::{
+            ApplicationHeader, ConsensusHeader, GeneratedApplicationFields,
+            GeneratedConsensusFields,
+        },
+        primitives::BlockId,
+    },
+    fuel_merkle::binary::root_calculator::MerkleRootCalculator,
+};
+use fuel_tx::{Input, Transaction};
+use fuel_types::{canonical::Serialize, Address, Bytes32};
+use fuels_core::types::errors::{error, Result};
+
+/// Checks blocks returned by an untrusted node against the proof-of-authority key of the
+/// network, so that their headers, and the transactions they commit to, can be relied upon.
+///
+/// A block passes if its id is the hash of its header and it is signed by the authority. The
+/// genesis block is not signed, it only passes if its id was given to
+/// [`with_genesis_id`](Self::with_genesis_id).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockVerifier {
+    signer: Address,
+    genesis_id: Option<Bytes32>,
+}
+
+impl BlockVerifier {
+    /// Trusts the blocks signed by the key with address `signer`, i.e. the `signing_key` of the
+    /// PoA consensus in the chain config of the network.
+    pub fn new(signer: impl Into<Address>) -> Self {
+        Self {
+            signer: signer.into(),
+            genesis_id: None,
+        }
+    }
+
+    /// Trusts the genesis block with id `genesis_id`.
+    pub fn with_genesis_id(mut self, genesis_id: impl Into<Bytes32>) -> Self {
+        self.genesis_id = Some(genesis_id.into());
+        self
+    }
+
+    pub fn signer(&self) -> &Address {
+        &self.signer
+    }
+
+    pub(crate) fn verify(&self, block: &ClientBlock) -> Result<()> {
+        let height = block.header.height;
+        let id = header_id(&block.header);
+        if id != block.id || id != block.header.id {
+            return Err(error!(
+                Provider,
+                "block {height}: id `{}` is not the hash of its header `{id}`", block.id
+            ));
+        }
+
+        match &block.consensus {
+            Consensus::PoAConsensus(poa) => {
+                let message = BlockId::from(id).into_message();
+                let signer = poa
+                    .signature
+                    .recover(&message)
+                    .map(|public_key| Input::owner(&public_key))
+                    .map_err(|e| error!(Provider, "block {height}: invalid signature: {e}"))?;
+
+                if signer != self.signer {
+                    return Err(error!(
+                        Provider,
+                        "block {height}: signed by `{signer}` instead of `{}`", self.signer
+                    ));
+                }
+            }
+            Consensus::Genesis(_) if self.genesis_id == Some(id) => {}
+            Consensus::Genesis(_) => {
+                return Err(error!(
+                    Provider,
+                    "block {height}: genesis block `{id}` is not trusted"
+                ))
+            }
+            Consensus::Unknown => {
+                return Err(error!(Provider, "block {height}: unknown consensus"))
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Hashes `header` the way the block producer does to get the block id.
+fn header_id(header: &ClientHeader) -> Bytes32 {
+    let application = ApplicationHeader {
+        da_height: header.da_height.into(),
+        consensus_parameters_version: header.consensus_parameters_version,
+        state_transition_bytecode_version: header.state_transition_bytecode_version,
+        generated: GeneratedApplicationFields {
+            transactions_count: header.transactions_count,
+            message_receipt_count: header.message_receipt_count,
+            transactions_root: header.transactions_root,
+            message_outbox_root: header.message_outbox_root,
+            event_inbox_root: header.event_inbox_root,
+        },
+    };
+
+    let consensus = ConsensusHeader {
+        prev_root: header.prev_root,
+        height: header.height.into(),
+        time: header.time,
+        generated: GeneratedConsensusFields {
+            application_hash: application.hash(),
+        },
+    };
+
+    consensus.hash().into()
+}
+
+/// The `transactions_root` of a block containing `transactions`, in order.
+pub(crate) fn transactions_root(transactions: &[Transaction]) -> Bytes32 {
+    merkle_root(transactions.iter().map(|tx| tx.to_bytes()))
+}
+
+/// The binary Merkle root used for the roots of block headers.
+pub(crate) fn merkle_root<T: AsRef<[u8]>>(leaves: impl IntoIterator<Item = T>) -> Bytes32 {
+    let mut calculator = MerkleRootCalculator::new();
+    for leaf in leaves {
+        calculator.push(leaf.as_ref());
+    }
+
+    calculator.root().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_core_client::client::types::block::{Genesis, PoAConsensus};
+    use fuel_crypto::{Message, SecretKey, Signature};
+    use tai64::Tai64;
+
+    use super::*;
+
+    fn signed_block(secret_key: &SecretKey, height: u32) -> ClientBlock {
+        let mut header = ClientHeader {
+            id: Default::default(),
+            da_height: 3,
+            consensus_parameters_version: 0,
+            state_transition_bytecode_version: 0,
+            transactions_count: 0,
+            message_receipt_count: 0,
+            transactions_root: merkle_root::<&[u8]>([]),
+            message_outbox_root: Default::default(),
+            event_inbox_root: Default::default(),
+            height,
+            prev_root: Bytes32::from([1; 32]),
+            time: Tai64::UNIX_EPOCH,
+            application_hash: Default::default(),
+        };
+        header.id = header_id(&header);
+
+        let signature = Signature::sign(secret_key, &Message::from_bytes(*header.id));
+
+        ClientBlock {
+            id: header.id,
+            header,
+            consensus: Consensus::PoAConsensus(PoAConsensus { signature }),
+            transactions: vec![],
+            block_producer: None,
+        }
+    }
+
+    #[test]
+    fn accepts_only_untampered_blocks_of_the_authority() -> Result<()> {
+        let authority = SecretKey::random(&mut rand::thread_rng());
+        let verifier = BlockVerifier::new(Input::owner(&authority.public_key()));
+
+        let block = signed_block(&authority, 7);
+        verifier.verify(&block)?;
+
+        let mut tampered = block.clone();
+        tampered.header.da_height += 1;
+        assert!(verifier.verify(&tampered).is_err());
+
+        let impostor = SecretKey::random(&mut rand::thread_rng());
+        assert!(verifier.verify(&signed_block(&impostor, 7)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn accepts_only_the_trusted_genesis_block() -> Result<()> {
+        let authority = SecretKey::random(&mut rand::thread_rng());
+        let mut genesis = signed_block(&authority, 0);
+        genesis.consensus = Consensus::Genesis(Genesis {
+            chain_config_hash: Default::default(),
+            coins_root: Default::default(),
+            contracts_root: Default::default(),
+            messages_root: Default::default(),
+            transactions_root: Default::default(),
+        });
+
+        let verifier = BlockVerifier::new(Input::owner(&authority.public_key()));
+        assert!(verifier.verify(&genesis).is_err());
+
+        verifier.with_genesis_id(genesis.id).verify(&genesis)?;
+
+        Ok(())
+    }
+}
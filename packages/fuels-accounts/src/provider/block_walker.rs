@@ -0,0 +1,195 @@
+use fuel_types::Bytes32;
+use fuels_core::types::{
+    block::Block,
+    errors::{error, Result},
+    transaction_response::TransactionResponse,
+};
+use futures::{stream, Stream, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::provider::{Provider, BLOCK_POLL_INTERVAL};
+
+const DEFAULT_WALK_CONCURRENCY: usize = 8;
+
+/// The last block processed by a [`BlockWalker`]. Persist it to resume the walk after a restart
+/// with [`BlockWalker::resume`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BlockCursor {
+    pub height: u32,
+    pub block_id: Bytes32,
+}
+
+/// A block returned by a [`BlockWalker`], with its transactions if the walker fetches them.
+#[derive(Debug, Clone)]
+pub struct WalkedBlock {
+    pub block: Block,
+    /// The transactions of the block, in order, with their status and receipts. Empty unless
+    /// the walker was created with [`BlockWalker::with_transactions`].
+    pub transactions: Vec<TransactionResponse>,
+    /// The cursor to persist once the block is processed.
+    pub cursor: BlockCursor,
+}
+
+/// Walks the chain block by block from a starting height or a persisted [`BlockCursor`].
+///
+/// When resumed, the block of the cursor is checked to still be part of the chain before the
+/// walk continues, so that blocks replaced while the walker was stopped are not silently
+/// skipped.
+#[derive(Debug, Clone)]
+pub struct BlockWalker {
+    provider: Provider,
+    next_height: u32,
+    cursor: Option<BlockCursor>,
+    cursor_checked: bool,
+    with_transactions: bool,
+    concurrency: usize,
+}
+
+impl BlockWalker {
+    /// Walks the blocks from `start_height` onwards.
+    pub fn new(provider: Provider, start_height: u32) -> Self {
+        Self {
+            provider,
+            next_height: start_height,
+            cursor: None,
+            cursor_checked: true,
+            with_transactions: false,
+            concurrency: DEFAULT_WALK_CONCURRENCY,
+        }
+    }
+
+    /// Walks the blocks after the one of `cursor`.
+    pub fn resume(provider: Provider, cursor: BlockCursor) -> Self {
+        Self {
+            next_height: cursor.height.saturating_add(1),
+            cursor: Some(cursor),
+            cursor_checked: false,
+            ..Self::new(provider, 0)
+        }
+    }
+
+    /// Fetches the transactions of every block, with their status and receipts.
+    pub fn with_transactions(mut self) -> Self {
+        self.with_transactions = true;
+        self
+    }
+
+    /// How many transactions are fetched at the same time. Defaults to 8.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// The last block returned, or the cursor the walk was resumed from.
+    pub fn cursor(&self) -> Option<BlockCursor> {
+        self.cursor
+    }
+
+    /// The height of the next block to be returned.
+    pub fn next_height(&self) -> u32 {
+        self.next_height
+    }
+
+    /// Returns the next block, or `None` if it is not produced yet.
+    pub async fn next_block(&mut self) -> Result<Option<WalkedBlock>> {
+        self.check_cursor().await?;
+
+        let Some(block) = self
+            .provider
+            .block_by_height(self.next_height.into())
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let transactions = if self.with_transactions {
+            self.transactions(&block).await?
+        } else {
+            vec![]
+        };
+
+        let cursor = BlockCursor {
+            height: block.header.height,
+            block_id: block.id,
+        };
+        self.cursor = Some(cursor);
+        self.next_height = cursor.height.saturating_add(1);
+
+        Ok(Some(WalkedBlock {
+            block,
+            transactions,
+            cursor,
+        }))
+    }
+
+    /// Streams the blocks in order, waiting for new blocks once the walk reaches the head of the
+    /// chain. The node is polled for them every [`BLOCK_POLL_INTERVAL`].
+    pub fn into_stream(self) -> impl Stream<Item = Result<WalkedBlock>> {
+        stream::try_unfold(self, |mut walker| async move {
+            loop {
+                if let Some(block) = walker.next_block().await? {
+                    return Ok(Some((block, walker)));
+                }
+
+                tokio::time::sleep(BLOCK_POLL_INTERVAL).await;
+            }
+        })
+    }
+
+    async fn check_cursor(&mut self) -> Result<()> {
+        let Some(cursor) = self.cursor.filter(|_| !self.cursor_checked) else {
+            return Ok(());
+        };
+
+        let block = self.provider.block_by_height(cursor.height.into()).await?;
+        if block.map(|block| block.id) != Some(cursor.block_id) {
+            return Err(error!(
+                Provider,
+                "block `{}` at height {} of the cursor is no longer part of the chain",
+                cursor.block_id,
+                cursor.height
+            ));
+        }
+
+        self.cursor_checked = true;
+        Ok(())
+    }
+
+    async fn transactions(&self, block: &Block) -> Result<Vec<TransactionResponse>> {
+        stream::iter(block.transactions.iter().copied())
+            .map(|tx_id| async move {
+                self.provider
+                    .get_transaction_by_id(&tx_id)
+                    .await?
+                    .ok_or_else(|| {
+                        error!(
+                            Provider,
+                            "transaction `{tx_id}` of block {} not found", block.header.height
+                        )
+                    })
+            })
+            .buffered(self.concurrency)
+            .try_collect()
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_survives_persistence() {
+        let cursor = BlockCursor {
+            height: 42,
+            block_id: Bytes32::new([7; 32]),
+        };
+
+        let persisted = serde_json::to_string(&cursor).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<BlockCursor>(&persisted).unwrap(),
+            cursor
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use fuel_tx::Transaction as FuelTransaction;
+use fuels_core::types::{
+    errors::{error, Result},
+    transaction::TransactionType,
+};
+use serde::Deserialize;
+
+pub(crate) const DA_COMPRESSED_BLOCK_QUERY: &str =
+    "query($height: U32!) { daCompressedBlock(height: $height) { bytes } }";
+
+/// A block in the compressed form the node posts to the DA layer, as returned by
+/// [`Provider::da_compressed_block`](crate::provider::Provider::da_compressed_block).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaCompressedBlock {
+    pub height: u32,
+    pub bytes: Vec<u8>,
+}
+
+impl DaCompressedBlock {
+    /// Decompresses the block with `decompressor`, returning its transactions in order.
+    pub async fn transactions(
+        &self,
+        decompressor: &dyn DaDecompressor,
+    ) -> Result<Vec<TransactionType>> {
+        Ok(decompressor
+            .decompress(self)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+}
+
+/// Turns a [`DaCompressedBlock`] back into its transactions.
+///
+/// Compressed blocks refer to addresses, contracts and scripts by their keys in a registry built
+/// from all the previous compressed blocks, so decompression needs that registry. The SDK does
+/// not maintain it; implement this trait with `fuel-core-compression` and the storage of the
+/// registry to decode blocks.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait DaDecompressor: Send + Sync {
+    async fn decompress(&self, block: &DaCompressedBlock) -> Result<Vec<FuelTransaction>>;
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DaCompressedBlockData {
+    da_compressed_block: Option<DaCompressedBlockBytes>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DaCompressedBlockBytes {
+    bytes: String,
+}
+
+impl DaCompressedBlockData {
+    pub(crate) fn into_block(self, height: u32) -> Result<Option<DaCompressedBlock>> {
+        self.da_compressed_block
+            .map(|block| {
+                let bytes = hex::decode(block.bytes.trim_start_matches("0x")).map_err(|err| {
+                    error!(
+                        Provider,
+                        "invalid compressed block at height {height}: {err}"
+                    )
+                })?;
+
+                Ok(DaCompressedBlock { height, bytes })
+            })
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(json: &str) -> DaCompressedBlockData {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn decodes_hex_bytes() -> Result<()> {
+        let block = data(r#"{"daCompressedBlock": {"bytes": "0x01ff"}}"#).into_block(3)?;
+
+        assert_eq!(
+            block,
+            Some(DaCompressedBlock {
+                height: 3,
+                bytes: vec![1, 255],
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_block_is_none() -> Result<()> {
+        assert_eq!(data(r#"{"daCompressedBlock": null}"#).into_block(3)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_hex() {
+        assert!(data(r#"{"daCompressedBlock": {"bytes": "0xzz"}}"#)
+            .into_block(3)
+            .is_err());
+    }
+}
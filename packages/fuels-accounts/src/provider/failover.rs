@@ -0,0 +1,283 @@
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use fuel_core_client::client::{from_strings_errors_to_std_error, FuelClient};
+use fuels_core::types::errors::{error, Result};
+use reqwest::header::HeaderMap;
+use serde::Deserialize;
+use serde_json::json;
+
+/// How long a node that failed a request is only used as a last resort.
+const UNHEALTHY_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// How a provider connected to several nodes spreads its requests over them.
+///
+/// Whatever the policy, a request that a node could not answer is retried on the next one, and
+/// nodes that failed recently are only tried once every healthy node failed. Errors a node
+/// answered with, e.g. a rejected transaction, are returned as is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FailoverPolicy {
+    /// Sends every request to the first healthy node, in the order the nodes were given.
+    #[default]
+    PrimaryBackup,
+    /// Sends each request to the next healthy node in turn.
+    RoundRobin,
+}
+
+#[derive(Debug)]
+pub(crate) struct Endpoint {
+    pub url: String,
+    pub client: FuelClient,
+    http: reqwest::Client,
+    graphql_url: reqwest::Url,
+}
+
+impl Endpoint {
+    pub fn new(url: String) -> Result<Self> {
+        let client = FuelClient::new(&url).map_err(|e| error!(Provider, "{e}"))?;
+        let graphql_url = graphql_url(&url)?;
+
+        Ok(Self {
+            url,
+            client,
+            http: Default::default(),
+            graphql_url,
+        })
+    }
+
+    /// Sends `query` to the GraphQL API of the node with `headers`, returning the `data` of the
+    /// response.
+    pub async fn raw_query(
+        &self,
+        query: &str,
+        variables: &serde_json::Value,
+        headers: HeaderMap,
+    ) -> io::Result<serde_json::Value> {
+        let response: GraphqlResponse = self
+            .http
+            .post(self.graphql_url.clone())
+            .headers(headers)
+            .json(&json!({ "query": query, "variables": variables }))
+            .send()
+            .await
+            .map_err(io::Error::other)?
+            .json()
+            .await
+            .map_err(io::Error::other)?;
+
+        match response {
+            GraphqlResponse {
+                data: Some(data), ..
+            } => Ok(data),
+            GraphqlResponse {
+                errors: Some(errors),
+                ..
+            } => Err(from_strings_errors_to_std_error(
+                errors.into_iter().map(|error| error.message).collect(),
+            )),
+            _ => Err(io::Error::other("Invalid response")),
+        }
+    }
+}
+
+/// The URL `FuelClient` sends its queries to.
+fn graphql_url(url: &str) -> Result<reqwest::Url> {
+    let url = if url.starts_with("http") {
+        url.to_string()
+    } else {
+        format!("http://{url}")
+    };
+
+    let mut url =
+        reqwest::Url::parse(&url).map_err(|e| error!(Provider, "invalid node URL `{url}`: {e}"))?;
+    url.set_path("/v1/graphql");
+
+    Ok(url)
+}
+
+#[derive(Deserialize)]
+struct GraphqlResponse {
+    data: Option<serde_json::Value>,
+    errors: Option<Vec<GraphqlError>>,
+}
+
+#[derive(Deserialize)]
+struct GraphqlError {
+    message: String,
+}
+
+/// The nodes a provider is connected to, along with their health.
+#[derive(Debug)]
+pub(crate) struct Endpoints {
+    endpoints: Vec<Endpoint>,
+    failed_at: Mutex<Vec<Option<Instant>>>,
+    policy: FailoverPolicy,
+    next: AtomicUsize,
+}
+
+impl Endpoints {
+    pub fn new(endpoints: Vec<Endpoint>, policy: FailoverPolicy) -> Self {
+        Self {
+            failed_at: Mutex::new(vec![None; endpoints.len()]),
+            endpoints,
+            policy,
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn primary(&self) -> &Endpoint {
+        &self.endpoints[0]
+    }
+
+    pub fn urls(&self) -> impl Iterator<Item = &str> {
+        self.endpoints.iter().map(|endpoint| endpoint.url.as_str())
+    }
+
+    /// The order in which the nodes should be tried for the next request: healthy nodes in the
+    /// order of the policy, followed by the ones that failed recently.
+    pub fn route(&self) -> Vec<(usize, &Endpoint)> {
+        let len = self.endpoints.len();
+        let start = match self.policy {
+            FailoverPolicy::PrimaryBackup => 0,
+            FailoverPolicy::RoundRobin => self.next.fetch_add(1, Ordering::Relaxed) % len,
+        };
+
+        let failed_at = self.failed_at.lock().expect("poisoned lock");
+        let (healthy, unhealthy): (Vec<_>, Vec<_>) = (0..len)
+            .map(|offset| (start + offset) % len)
+            .partition(|&index| {
+                failed_at[index].map_or(true, |failed_at| failed_at.elapsed() >= UNHEALTHY_COOLDOWN)
+            });
+
+        healthy
+            .into_iter()
+            .chain(unhealthy)
+            .map(|index| (index, &self.endpoints[index]))
+            .collect()
+    }
+
+    pub fn mark_healthy(&self, index: usize) {
+        self.failed_at.lock().expect("poisoned lock")[index] = None;
+    }
+
+    pub fn mark_failed(&self, index: usize) {
+        self.failed_at.lock().expect("poisoned lock")[index] = Some(Instant::now());
+    }
+
+    /// Updates the health of node `index` with the outcome of a request: it failed only if it
+    /// could not answer.
+    pub fn report<T>(&self, index: usize, result: &io::Result<T>) {
+        match result {
+            Err(e) if is_node_failure(e) => self.mark_failed(index),
+            _ => self.mark_healthy(index),
+        }
+    }
+}
+
+/// Whether `error` means that the node could not be reached or did not answer in time, as opposed
+/// to an error the node answered with, such as a GraphQL error rejecting a transaction.
+pub(crate) fn is_node_failure(error: &io::Error) -> bool {
+    use io::ErrorKind::*;
+
+    if matches!(
+        error.kind(),
+        TimedOut
+            | ConnectionRefused
+            | ConnectionReset
+            | ConnectionAborted
+            | NotConnected
+            | BrokenPipe
+            | UnexpectedEof
+    ) {
+        return true;
+    }
+
+    // Errors of the HTTP clients wrap their cause, while the errors answered by the node are
+    // plain messages.
+    error.get_ref().and_then(|inner| inner.source()).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoints(policy: FailoverPolicy) -> Endpoints {
+        let endpoints = ["http://a", "http://b", "http://c"]
+            .into_iter()
+            .map(|url| Endpoint::new(url.to_string()).unwrap())
+            .collect();
+
+        Endpoints::new(endpoints, policy)
+    }
+
+    fn route(endpoints: &Endpoints) -> Vec<usize> {
+        endpoints
+            .route()
+            .into_iter()
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    #[test]
+    fn builds_the_graphql_url() -> Result<()> {
+        assert_eq!(
+            graphql_url("127.0.0.1:4000")?.as_str(),
+            "http://127.0.0.1:4000/v1/graphql"
+        );
+        assert_eq!(
+            graphql_url("https://testnet.fuel.network/v1/graphql")?.as_str(),
+            "https://testnet.fuel.network/v1/graphql"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn primary_backup_skips_failed_nodes() {
+        let endpoints = endpoints(FailoverPolicy::PrimaryBackup);
+        assert_eq!(route(&endpoints), [0, 1, 2]);
+
+        endpoints.mark_failed(0);
+        assert_eq!(route(&endpoints), [1, 2, 0]);
+        assert_eq!(route(&endpoints), [1, 2, 0]);
+
+        endpoints.mark_healthy(0);
+        assert_eq!(route(&endpoints), [0, 1, 2]);
+    }
+
+    #[test]
+    fn rejected_requests_leave_the_route_unchanged() {
+        let endpoints = endpoints(FailoverPolicy::PrimaryBackup);
+
+        let rejected = from_strings_errors_to_std_error(vec![
+            "Transaction is not inserted. Not enough coins to fit the target".to_string(),
+        ]);
+        assert!(!is_node_failure(&rejected));
+        endpoints.report::<()>(0, &Err(rejected));
+        assert_eq!(route(&endpoints), [0, 1, 2]);
+
+        let timed_out = io::Error::new(io::ErrorKind::TimedOut, "`submit` timed out");
+        endpoints.report::<()>(0, &Err(timed_out));
+        assert_eq!(route(&endpoints), [1, 2, 0]);
+
+        endpoints.report(0, &Ok(()));
+        assert_eq!(route(&endpoints), [0, 1, 2]);
+    }
+
+    #[test]
+    fn round_robin_rotates_over_healthy_nodes() {
+        let endpoints = endpoints(FailoverPolicy::RoundRobin);
+        assert_eq!(route(&endpoints), [0, 1, 2]);
+        assert_eq!(route(&endpoints), [1, 2, 0]);
+
+        endpoints.mark_failed(0);
+        assert_eq!(route(&endpoints), [2, 1, 0]);
+        assert_eq!(route(&endpoints), [1, 2, 0]);
+    }
+}
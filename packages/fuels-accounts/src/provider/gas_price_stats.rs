@@ -0,0 +1,91 @@
+/// The gas price of the node and the tips paid by the transactions of recent blocks, to pick a
+/// tip for the inclusion of a transaction instead of hardcoding it in the `TxPolicies`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasPriceStats {
+    /// The gas price of the latest block.
+    pub latest_gas_price: u64,
+    /// The height of the latest block.
+    pub block_height: u32,
+    /// The gas price estimated by the node for a transaction to be included within `horizon`
+    /// blocks.
+    pub estimated_gas_price: u64,
+    /// The number of blocks whose transactions were considered, up to the latest block.
+    pub horizon: u32,
+    /// The tips paid by the transactions in the considered blocks, or `None` if they had no
+    /// transactions other than mints.
+    pub tips: Option<TipPercentiles>,
+}
+
+/// Percentiles of the tips paid by a set of transactions, by the nearest-rank method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TipPercentiles {
+    pub transactions_count: usize,
+    pub min: u64,
+    pub p25: u64,
+    pub p50: u64,
+    pub p75: u64,
+    pub p90: u64,
+    pub max: u64,
+}
+
+impl TipPercentiles {
+    pub(crate) fn new(mut tips: Vec<u64>) -> Option<Self> {
+        if tips.is_empty() {
+            return None;
+        }
+        tips.sort_unstable();
+
+        let percentile = |percent: usize| {
+            let rank = (percent * tips.len()).div_ceil(100).max(1);
+            tips[rank - 1]
+        };
+
+        Some(Self {
+            transactions_count: tips.len(),
+            min: tips[0],
+            p25: percentile(25),
+            p50: percentile(50),
+            p75: percentile(75),
+            p90: percentile(90),
+            max: tips[tips.len() - 1],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_tips_no_percentiles() {
+        assert_eq!(TipPercentiles::new(vec![]), None);
+    }
+
+    #[test]
+    fn percentiles_use_the_nearest_rank() {
+        let tips = TipPercentiles::new((1..=10).rev().collect()).unwrap();
+
+        assert_eq!(
+            tips,
+            TipPercentiles {
+                transactions_count: 10,
+                min: 1,
+                p25: 3,
+                p50: 5,
+                p75: 8,
+                p90: 9,
+                max: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn single_tip_is_every_percentile() {
+        let tips = TipPercentiles::new(vec![7]).unwrap();
+
+        assert_eq!(
+            [tips.min, tips.p25, tips.p50, tips.p75, tips.p90, tips.max],
+            [7; 6]
+        );
+    }
+}
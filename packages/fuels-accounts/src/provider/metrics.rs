@@ -0,0 +1,47 @@
+use std::{fmt, io, sync::Arc, time::Duration};
+
+use fuel_tx::TxId;
+use fuels_core::types::errors::Error;
+
+/// A measurement reported to the callback given to
+/// [`Provider::with_metrics`](crate::provider::Provider::with_metrics).
+#[derive(Debug)]
+pub enum MetricEvent<'a> {
+    /// An attempt at a request to a node. Retried and failed over requests report one event
+    /// per attempt.
+    NodeRequest {
+        /// The name of the `FuelClient` method serving the request, e.g. `chain_info`.
+        operation: &'static str,
+        url: &'a str,
+        latency: Duration,
+        error: Option<&'a io::Error>,
+    },
+    /// A transaction sent to a node, from submission until the node accepted it or, when
+    /// awaiting the commit, until it was included in a block.
+    TransactionSubmission {
+        tx_id: TxId,
+        /// The metered size of the transaction.
+        bytes: usize,
+        latency: Duration,
+        error: Option<&'a Error>,
+    },
+}
+
+#[derive(Clone)]
+pub(crate) struct MetricsCallback(Arc<dyn Fn(&MetricEvent<'_>) + Send + Sync>);
+
+impl MetricsCallback {
+    pub fn new(callback: impl Fn(&MetricEvent<'_>) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    pub fn record(&self, event: &MetricEvent<'_>) {
+        (self.0)(event)
+    }
+}
+
+impl fmt::Debug for MetricsCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MetricsCallback")
+    }
+}
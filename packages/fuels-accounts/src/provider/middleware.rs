@@ -0,0 +1,246 @@
+//! Layers wrapping every request the provider sends to a node.
+//!
+//! A [`Middleware`] receives the [`NodeRequest`] and decides whether, and how, to pass it on to
+//! the [`Next`] layer. This is enough to implement logging, rate limiting, chaos injection or
+//! caching without touching the client:
+//!
+//! ```
+//! use async_trait::async_trait;
+//! use fuels_accounts::provider::middleware::{Middleware, Next, NodeRequest, NodeResponse};
+//!
+//! #[derive(Debug)]
+//! struct Logger;
+//!
+//! #[async_trait]
+//! impl Middleware for Logger {
+//!     async fn handle(
+//!         &self,
+//!         request: &mut NodeRequest<'_>,
+//!         next: Next<'_>,
+//!     ) -> std::io::Result<NodeResponse> {
+//!         println!("{} -> {}", request.cache_key(), request.url);
+//!         next.run(request).await
+//!     }
+//! }
+//! ```
+
+use std::{
+    any::Any,
+    fmt::{self, Debug},
+    io,
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use fuel_core_client::client::types::TransactionStatus;
+use fuel_core_types::services::executor::TransactionExecutionStatus;
+use futures::future::BoxFuture;
+use reqwest::header::HeaderMap;
+
+/// A single request to a node.
+#[derive(Debug, Clone)]
+pub struct NodeRequest<'a> {
+    /// The name of the `FuelClient` method serving the request, e.g. `chain_info`.
+    pub operation: &'static str,
+    /// The node the request is sent to.
+    pub url: &'a str,
+    /// The arguments of the `FuelClient` method, e.g. the owner and asset id of a `balance`
+    /// request.
+    pub arguments: &'a (dyn Debug + Sync),
+    /// Headers sent along with the request.
+    ///
+    /// Only raw GraphQL queries can carry them: the other operations are sent by the
+    /// `FuelClient`, which has no way to add headers, and fail if any is set.
+    pub headers: HeaderMap,
+}
+
+impl NodeRequest<'_> {
+    /// Identifies the request by its operation and arguments, e.g. to cache its response. The
+    /// same request sent to another node has the same key.
+    pub fn cache_key(&self) -> String {
+        format!("{}{:?}", self.operation, self.arguments)
+    }
+}
+
+/// The response of a node, holding the return type of the `FuelClient` method named by
+/// [`NodeRequest::operation`].
+pub struct NodeResponse(Box<dyn Any + Send>);
+
+impl NodeResponse {
+    /// Answers a request with `response`, which must have the return type of its operation.
+    pub fn new<T: Send + 'static>(response: T) -> Self {
+        Self(Box::new(response))
+    }
+
+    /// The response, if the operation returns a `T`.
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+
+    pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.0.downcast_mut()
+    }
+
+    /// Takes the response out, or gives it back if the operation does not return a `T`.
+    pub fn into_inner<T: 'static>(self) -> Result<T, Self> {
+        self.0.downcast().map(|response| *response).map_err(Self)
+    }
+
+    /// The status returned by `submit_and_await_commit` and `transaction_status`.
+    pub fn transaction_status(&self) -> Option<&TransactionStatus> {
+        self.get()
+    }
+
+    /// The statuses returned by `dry_run` and `dry_run_opt`.
+    pub fn execution_statuses(&self) -> Option<&[TransactionExecutionStatus]> {
+        self.get::<Vec<TransactionExecutionStatus>>()
+            .map(Vec::as_slice)
+    }
+}
+
+impl Debug for NodeResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeResponse").finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait Middleware: Debug + Send + Sync {
+    /// Handles `request`, usually by passing it on to `next`. A middleware can also answer the
+    /// request itself, e.g. from a cache, fail it, or add headers before passing it on.
+    async fn handle(
+        &self,
+        request: &mut NodeRequest<'_>,
+        next: Next<'_>,
+    ) -> io::Result<NodeResponse>;
+}
+
+/// Sends a request to the node with the given headers.
+type Call<'a> = dyn Fn(HeaderMap) -> BoxFuture<'a, io::Result<NodeResponse>> + Send + Sync;
+
+/// The remaining layers, ending with the node itself.
+pub struct Next<'a> {
+    middlewares: &'a [Arc<dyn Middleware>],
+    call: &'a Call<'a>,
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(middlewares: &'a [Arc<dyn Middleware>], call: &'a Call<'a>) -> Self {
+        Self { middlewares, call }
+    }
+
+    /// Passes `request` on to the next layer. Can be called more than once, e.g. to retry.
+    pub async fn run(&self, request: &mut NodeRequest<'_>) -> io::Result<NodeResponse> {
+        match self.middlewares.split_first() {
+            Some((middleware, middlewares)) => {
+                let next = Next {
+                    middlewares,
+                    call: self.call,
+                };
+                middleware.handle(request, next).await
+            }
+            None => (self.call)(request.headers.clone()).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct Recorder {
+        name: &'static str,
+        calls: Arc<Mutex<Vec<String>>>,
+        answer: Option<u64>,
+    }
+
+    #[async_trait]
+    impl Middleware for Recorder {
+        async fn handle(
+            &self,
+            request: &mut NodeRequest<'_>,
+            next: Next<'_>,
+        ) -> io::Result<NodeResponse> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("{} {}", self.name, request.cache_key()));
+            request
+                .headers
+                .insert("x-layer", HeaderValue::from_static(self.name));
+
+            match self.answer {
+                Some(answer) => Ok(NodeResponse::new(answer)),
+                None => next.run(request).await,
+            }
+        }
+    }
+
+    /// Answers with the number of headers the node received.
+    async fn run(middlewares: &[Arc<dyn Middleware>]) -> u64 {
+        let call = |headers: HeaderMap| -> BoxFuture<'_, io::Result<NodeResponse>> {
+            Box::pin(async move { Ok(NodeResponse::new(headers.len() as u64)) })
+        };
+        let mut request = NodeRequest {
+            operation: "balance",
+            url: "http://localhost:4000",
+            arguments: &("owner", 1),
+            headers: HeaderMap::new(),
+        };
+
+        Next::new(middlewares, &call)
+            .run(&mut request)
+            .await
+            .unwrap()
+            .into_inner::<u64>()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn layers_run_in_order_and_can_answer() {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let layer = |name, answer| -> Arc<dyn Middleware> {
+            Arc::new(Recorder {
+                name,
+                calls: calls.clone(),
+                answer,
+            })
+        };
+
+        assert_eq!(run(&[layer("outer", None), layer("inner", None)]).await, 1);
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [
+                r#"outer balance("owner", 1)"#,
+                r#"inner balance("owner", 1)"#
+            ]
+        );
+
+        calls.lock().unwrap().clear();
+        assert_eq!(
+            run(&[layer("cache", Some(2)), layer("inner", None)]).await,
+            2
+        );
+        assert_eq!(*calls.lock().unwrap(), [r#"cache balance("owner", 1)"#]);
+    }
+
+    #[test]
+    fn responses_are_only_returned_as_their_type() {
+        let response = NodeResponse::new(TransactionStatus::SqueezedOut {
+            reason: "squeezed".to_string(),
+        });
+
+        assert!(response.transaction_status().is_some());
+        assert!(response.execution_statuses().is_none());
+        assert!(response.get::<u64>().is_none());
+
+        let response = response.into_inner::<u64>().unwrap_err();
+        assert!(response.into_inner::<TransactionStatus>().is_ok());
+    }
+}
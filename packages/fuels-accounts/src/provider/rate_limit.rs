@@ -0,0 +1,159 @@
+use std::time::Duration;
+
+use fuels_core::types::errors::{error, Result};
+use rand::Rng;
+use tokio::{sync::Mutex, time::Instant};
+
+/// How many requests a provider may send to nodes over an interval.
+///
+/// Requests over the limit are queued and sent in order once the limit allows it, optionally
+/// after a random jitter so that throttled clients do not all retry at the same instant. Up to
+/// `max_requests` requests can be sent in a burst after a quiet period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    max_requests: u32,
+    interval: Duration,
+    max_jitter: Duration,
+}
+
+impl RateLimit {
+    pub fn new(max_requests: u32, interval: Duration) -> Result<Self> {
+        if max_requests == 0 || interval.is_zero() {
+            return Err(error!(
+                Other,
+                "rate limit must allow at least one request over a non-zero interval"
+            ));
+        }
+
+        Ok(Self {
+            max_requests,
+            interval,
+            max_jitter: Duration::ZERO,
+        })
+    }
+
+    pub fn per_second(max_requests: u32) -> Result<Self> {
+        Self::new(max_requests, Duration::from_secs(1))
+    }
+
+    /// Delays queued requests by a random duration of up to `max_jitter`.
+    pub fn with_jitter(mut self, max_jitter: Duration) -> Self {
+        self.max_jitter = max_jitter;
+        self
+    }
+
+    fn time_per_request(&self) -> Duration {
+        self.interval / self.max_requests
+    }
+
+    fn jitter(&self) -> Duration {
+        if self.max_jitter.is_zero() {
+            return Duration::ZERO;
+        }
+
+        self.max_jitter.mul_f64(rand::thread_rng().gen())
+    }
+}
+
+/// A token bucket enforcing a [`RateLimit`]. Waiting requests are served in order.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    limit: RateLimit,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(limit.max_requests),
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a request can be sent.
+    pub async fn acquire(&self) {
+        // The lock is held while waiting so that requests are let through in order.
+        let mut bucket = self.bucket.lock().await;
+
+        loop {
+            let now = Instant::now();
+            let refilled = now.duration_since(bucket.refilled_at).as_secs_f64()
+                / self.limit.time_per_request().as_secs_f64();
+            bucket.tokens = (bucket.tokens + refilled).min(f64::from(self.limit.max_requests));
+            bucket.refilled_at = now;
+
+            // Tolerates the rounding of the durations slept.
+            if bucket.tokens >= 1.0 - 1e-6 {
+                bucket.tokens = (bucket.tokens - 1.0).max(0.0);
+                return;
+            }
+
+            let wait = self.limit.time_per_request().mul_f64(1.0 - bucket.tokens);
+            tokio::time::sleep(wait + self.limit.jitter()).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[test]
+    fn rejects_empty_limits() {
+        assert!(RateLimit::per_second(0).is_err());
+        assert!(RateLimit::new(1, Duration::ZERO).is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn lets_bursts_through_then_throttles() -> Result<()> {
+        let limiter = Arc::new(RateLimiter::new(RateLimit::per_second(2)?));
+        let start = Instant::now();
+
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_millis(500));
+
+        let queued = (0..4)
+            .map(|_| {
+                let limiter = limiter.clone();
+                tokio::spawn(async move { limiter.acquire().await })
+            })
+            .collect::<Vec<_>>();
+        for request in queued {
+            request.await.unwrap();
+        }
+        assert_eq!(start.elapsed(), Duration::from_millis(2500));
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn jitter_delays_only_throttled_requests() -> Result<()> {
+        let jitter = Duration::from_millis(100);
+        let limiter = RateLimiter::new(RateLimit::per_second(1)?.with_jitter(jitter));
+        let start = Instant::now();
+
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_secs(1));
+        assert!(start.elapsed() <= Duration::from_secs(1) + jitter);
+
+        Ok(())
+    }
+}
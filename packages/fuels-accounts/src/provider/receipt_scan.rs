@@ -0,0 +1,195 @@
+use std::ops::{Bound, RangeBounds};
+
+use fuel_tx::{Receipt, TxId};
+use fuel_types::ContractId;
+use fuels_core::types::{ReceiptKind, ReceiptMatcher};
+
+const DEFAULT_SCAN_CONCURRENCY: usize = 8;
+
+/// The position of a receipt in the chain. Scans resumed after a cursor skip every receipt up to
+/// and including it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScanCursor {
+    pub block_height: u32,
+    pub tx_index: usize,
+    pub receipt_index: usize,
+}
+
+/// A receipt found by [`Provider::scan_receipts`](crate::provider::Provider::scan_receipts).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScannedReceipt {
+    pub tx_id: TxId,
+    pub receipt: Receipt,
+    pub cursor: ScanCursor,
+}
+
+/// Which blocks [`Provider::scan_receipts`](crate::provider::Provider::scan_receipts) walks and
+/// which of their receipts it yields. Receipts must match every criterion set.
+#[derive(Debug, Clone)]
+pub struct ReceiptFilter {
+    blocks: (Bound<u32>, Bound<u32>),
+    matcher: ReceiptMatcher,
+    resume_after: Option<ScanCursor>,
+    concurrency: usize,
+}
+
+impl Default for ReceiptFilter {
+    fn default() -> Self {
+        Self::new(..)
+    }
+}
+
+impl ReceiptFilter {
+    /// Matches every receipt of the blocks with a height in `blocks`.
+    pub fn new(blocks: impl RangeBounds<u32>) -> Self {
+        Self {
+            blocks: (blocks.start_bound().cloned(), blocks.end_bound().cloned()),
+            matcher: ReceiptMatcher::default(),
+            resume_after: None,
+            concurrency: DEFAULT_SCAN_CONCURRENCY,
+        }
+    }
+
+    /// Only matches receipts emitted by, or calling into, `contract_id`.
+    pub fn with_contract_id(mut self, contract_id: impl Into<ContractId>) -> Self {
+        self.matcher = self.matcher.with_contract_id(contract_id);
+        self
+    }
+
+    /// Only matches receipts of `kind`. Can be called several times to match more kinds.
+    pub fn with_kind(mut self, kind: ReceiptKind) -> Self {
+        self.matcher = self.matcher.with_kind(kind);
+        self
+    }
+
+    /// Only matches `Log` and `LogData` receipts with the log id generated by `abigen!`.
+    pub fn with_log_id(mut self, log_id: u64) -> Self {
+        self.matcher = self.matcher.with_rb(log_id);
+        self
+    }
+
+    /// Only matches receipts matched by `matcher`, replacing the criteria set so far.
+    pub fn with_matcher(mut self, matcher: ReceiptMatcher) -> Self {
+        self.matcher = matcher;
+        self
+    }
+
+    /// Skips every receipt up to and including `cursor`, e.g. the cursor of the last receipt
+    /// processed by a previous scan.
+    pub fn resume_after(mut self, cursor: ScanCursor) -> Self {
+        self.resume_after = Some(cursor);
+        self
+    }
+
+    /// How many transactions are fetched at the same time. Defaults to 8.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub(crate) fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// The blocks to walk, starting at the block of the resume cursor if there is one.
+    pub(crate) fn blocks(&self) -> (Bound<u32>, Bound<u32>) {
+        (
+            Bound::Included(self.start_height().unwrap_or(0)),
+            self.blocks.1,
+        )
+    }
+
+    /// The height of the first block to walk, if the blocks or the resume cursor set one.
+    pub(crate) fn start_height(&self) -> Option<u32> {
+        let start = match self.blocks.0 {
+            Bound::Included(start) => Some(start),
+            Bound::Excluded(start) => Some(start.saturating_add(1)),
+            Bound::Unbounded => None,
+        };
+        let resume_height = self.resume_after.map(|cursor| cursor.block_height);
+
+        start.max(resume_height)
+    }
+
+    pub(crate) fn is_past_end(&self, height: u32) -> bool {
+        match self.blocks.1 {
+            Bound::Included(end) => height > end,
+            Bound::Excluded(end) => height >= end,
+            Bound::Unbounded => false,
+        }
+    }
+
+    pub(crate) fn is_resumed_past(&self, cursor: &ScanCursor) -> bool {
+        self.resume_after
+            .map_or(true, |resume_after| *cursor > resume_after)
+    }
+
+    pub(crate) fn matches(&self, receipt: &Receipt) -> bool {
+        self.matcher.matches(receipt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(contract_id: ContractId, log_id: u64) -> Receipt {
+        Receipt::log(contract_id, 0, log_id, 0, 0, 0, 0)
+    }
+
+    #[test]
+    fn matches_receipts_on_every_criterion() {
+        let contract_id = ContractId::from([1; 32]);
+        let other_contract_id = ContractId::from([2; 32]);
+
+        let filter = ReceiptFilter::default()
+            .with_contract_id(contract_id)
+            .with_kind(ReceiptKind::Log)
+            .with_log_id(42);
+
+        assert!(filter.matches(&log(contract_id, 42)));
+        assert!(!filter.matches(&log(contract_id, 7)));
+        assert!(!filter.matches(&log(other_contract_id, 42)));
+        assert!(!filter.matches(&Receipt::ret(contract_id, 42, 0, 0)));
+    }
+
+    #[test]
+    fn resumes_after_cursor() {
+        let cursor = ScanCursor {
+            block_height: 10,
+            tx_index: 1,
+            receipt_index: 3,
+        };
+        let filter = ReceiptFilter::new(5..20).resume_after(cursor);
+
+        assert_eq!(filter.blocks(), (Bound::Included(10), Bound::Excluded(20)));
+        assert!(!filter.is_resumed_past(&cursor));
+        assert!(filter.is_resumed_past(&ScanCursor {
+            receipt_index: 4,
+            ..cursor
+        }));
+        assert!(filter.is_resumed_past(&ScanCursor {
+            block_height: 11,
+            tx_index: 0,
+            receipt_index: 0,
+        }));
+    }
+
+    #[test]
+    fn start_height_is_only_set_by_bounds_or_cursor() {
+        let cursor = ScanCursor {
+            block_height: 10,
+            tx_index: 0,
+            receipt_index: 0,
+        };
+
+        assert_eq!(ReceiptFilter::default().start_height(), None);
+        assert_eq!(ReceiptFilter::new(3..).start_height(), Some(3));
+        assert_eq!(
+            ReceiptFilter::default().resume_after(cursor).start_height(),
+            Some(10)
+        );
+        assert!(ReceiptFilter::new(..=5).is_past_end(6));
+        assert!(!ReceiptFilter::new(..=5).is_past_end(5));
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+
+use fuel_types::Bytes32;
+use fuels_core::types::block::Header;
+
+/// Blocks seen by the provider were replaced by the node, e.g. after it switched to another
+/// branch of the chain.
+#[derive(Debug, Clone)]
+pub struct Reorg {
+    /// The height of the last seen block which is still part of the chain. State derived from
+    /// the blocks above it has to be rolled back.
+    pub common_ancestor: u32,
+    /// The headers of the seen blocks which were replaced, in order of height.
+    pub replaced: Vec<Header>,
+}
+
+/// The headers of the last blocks returned by the provider, by height.
+#[derive(Debug)]
+pub(crate) struct HeaderChain {
+    depth: usize,
+    headers: BTreeMap<u32, Header>,
+}
+
+impl HeaderChain {
+    pub fn new(depth: usize) -> Self {
+        Self {
+            depth: depth.max(1),
+            headers: BTreeMap::new(),
+        }
+    }
+
+    /// Keeps `header` unless another header was already seen at its height, which is left for
+    /// the next reorg check to find.
+    pub fn record(&mut self, header: &Header) {
+        let below_window = self.headers.len() >= self.depth
+            && self
+                .headers
+                .first_key_value()
+                .is_some_and(|(&oldest, _)| header.height < oldest);
+        if below_window || self.headers.contains_key(&header.height) {
+            return;
+        }
+
+        self.headers.insert(header.height, header.clone());
+        while self.headers.len() > self.depth {
+            self.headers.pop_first();
+        }
+    }
+
+    /// The heights and ids of the tracked blocks, from the highest.
+    pub fn ids_from_head(&self) -> Vec<(u32, Bytes32)> {
+        self.headers
+            .values()
+            .rev()
+            .map(|header| (header.height, header.id))
+            .collect()
+    }
+
+    /// Forgets the blocks in `replaced` that are still tracked, returning their headers. Blocks
+    /// already forgotten, e.g. by a concurrent check, are skipped.
+    pub fn remove(&mut self, replaced: &[(u32, Bytes32)]) -> Vec<Header> {
+        let mut removed = replaced
+            .iter()
+            .filter_map(|(height, id)| {
+                let is_tracked = self
+                    .headers
+                    .get(height)
+                    .is_some_and(|tracked| tracked.id == *id);
+                is_tracked.then(|| self.headers.remove(height)).flatten()
+            })
+            .collect::<Vec<_>>();
+        removed.sort_by_key(|header| header.height);
+
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(height: u32, branch: u8) -> Header {
+        Header {
+            id: Bytes32::from([branch; 32]),
+            da_height: 0,
+            transactions_count: 0,
+            message_receipt_count: 0,
+            transactions_root: Default::default(),
+            message_outbox_root: Default::default(),
+            event_inbox_root: Default::default(),
+            consensus_parameters_version: 0,
+            state_transition_bytecode_version: 0,
+            height,
+            prev_root: Default::default(),
+            time: None,
+            application_hash: Default::default(),
+        }
+    }
+
+    #[test]
+    fn keeps_only_the_first_header_seen_within_the_window() {
+        let mut chain = HeaderChain::new(3);
+        for height in 1..=4 {
+            chain.record(&header(height, 1));
+        }
+        chain.record(&header(0, 1));
+        chain.record(&header(4, 2));
+
+        let heights = chain
+            .ids_from_head()
+            .into_iter()
+            .map(|(height, id)| (height, id[0]))
+            .collect::<Vec<_>>();
+        assert_eq!(heights, vec![(4, 1), (3, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn removes_only_blocks_still_tracked() {
+        let mut chain = HeaderChain::new(10);
+        for height in 1..=3 {
+            chain.record(&header(height, 1));
+        }
+        let replaced = vec![(3, Bytes32::from([1; 32])), (2, Bytes32::from([1; 32]))];
+
+        let removed = chain.remove(&replaced);
+        assert_eq!(
+            removed.iter().map(|h| h.height).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+
+        assert!(chain.remove(&replaced).is_empty());
+        assert_eq!(chain.ids_from_head(), vec![(1, Bytes32::from([1; 32]))]);
+    }
+}
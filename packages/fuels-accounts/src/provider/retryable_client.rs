@@ -1,12 +1,12 @@
-use std::{future::Future, io};
+use std::{fmt::Debug, future::Future, io, sync::Arc};
 
 use fuel_core_client::client::{
     pagination::{PaginatedResult, PaginationRequest},
     types::{
         gas_price::{EstimateGasPrice, LatestGasPrice},
         primitives::{BlockId, TransactionId},
-        Balance, Block, ChainInfo, Coin, CoinType, ContractBalance, Message, MessageProof,
-        NodeInfo, TransactionResponse, TransactionStatus,
+        Balance, Block, ChainInfo, Coin, CoinType, Contract, ContractBalance, Message,
+        MessageProof, NodeInfo, TransactionResponse, TransactionStatus,
     },
     FuelClient,
 };
@@ -15,13 +15,26 @@ use fuel_tx::{Transaction, TxId, UtxoId};
 use fuel_types::{Address, AssetId, BlockHeight, ContractId, Nonce};
 use fuels_core::types::errors::{error, Error, Result};
 
-use super::supported_versions::{self, VersionCompatibility};
+use futures::FutureExt;
+use reqwest::header::HeaderMap;
+
+#[cfg(feature = "metrics")]
+use super::metrics::{MetricEvent, MetricsCallback};
+use super::{
+    failover::{is_node_failure, Endpoint, Endpoints, FailoverPolicy},
+    middleware::{Middleware, Next, NodeRequest, NodeResponse},
+    rate_limit::{RateLimit, RateLimiter},
+    supported_versions::{self, NodeCompatibility},
+    timeouts::{OperationClass, Timeouts},
+};
 use crate::provider::{retry_util, RetryConfig};
 
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum RequestError {
     #[error("io error: {0}")]
     IO(String),
+    #[error("timed out: {0}")]
+    Timeout(String),
 }
 
 type RequestResult<T> = std::result::Result<T, RequestError>;
@@ -34,126 +47,402 @@ impl From<RequestError> for Error {
 
 #[derive(Debug, Clone)]
 pub(crate) struct RetryableClient {
-    client: FuelClient,
-    url: String,
+    endpoints: Arc<Endpoints>,
     retry_config: RetryConfig,
+    compatibility: NodeCompatibility,
     prepend_warning: Option<String>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    endpoint_rate_limiters: Option<Arc<Vec<RateLimiter>>>,
+    timeouts: Timeouts,
+    #[cfg(feature = "metrics")]
+    metrics: Option<MetricsCallback>,
 }
 
 impl RetryableClient {
     pub(crate) async fn connect(url: impl AsRef<str>, retry_config: RetryConfig) -> Result<Self> {
-        let url = url.as_ref().to_string();
-        let client = FuelClient::new(&url).map_err(|e| error!(Provider, "{e}"))?;
+        Self::connect_multi([url], FailoverPolicy::default(), retry_config).await
+    }
+
+    /// Connects to every node in `urls`. Fails only if none of them can be reached, the others
+    /// are then marked as unhealthy.
+    pub(crate) async fn connect_multi(
+        urls: impl IntoIterator<Item = impl AsRef<str>>,
+        policy: FailoverPolicy,
+        retry_config: RetryConfig,
+    ) -> Result<Self> {
+        let mut endpoints = vec![];
+        let mut node_info = None::<(usize, NodeInfo)>;
+        let mut unreachable = vec![];
+        let mut errors = vec![];
+        for url in urls {
+            let endpoint = Endpoint::new(url.as_ref().to_string())?;
+
+            match endpoint.client.node_info().await {
+                Ok(info) => {
+                    node_info.get_or_insert((endpoints.len(), info));
+                }
+                Err(e) => {
+                    unreachable.push(endpoints.len());
+                    errors.push(format!("`{}`: {e}", endpoint.url));
+                }
+            }
+            endpoints.push(endpoint);
+        }
+
+        if endpoints.is_empty() {
+            return Err(error!(Provider, "no node URL given"));
+        }
+        let Some((reachable, node_info)) = node_info else {
+            return Err(error!(
+                Provider,
+                "could not reach any node: {}",
+                errors.join(", ")
+            ));
+        };
+        // Nodes that disable introspection can still be used, the schema hash is then unknown.
+        let schema_hash = endpoints[reachable]
+            .raw_query(
+                supported_versions::SCHEMA_INTROSPECTION_QUERY,
+                &Default::default(),
+                HeaderMap::new(),
+            )
+            .await
+            .ok()
+            .and_then(|data| supported_versions::schema_hash(&data));
+        let compatibility = NodeCompatibility::new(&node_info.node_version, schema_hash)?;
+        let warning = compatibility.warning();
 
-        let node_info = client.node_info().await?;
-        let warning = Self::version_compatibility_warning(&node_info)?;
+        let endpoints = Endpoints::new(endpoints, policy);
+        for index in unreachable {
+            endpoints.mark_failed(index);
+        }
 
         Ok(Self {
-            client,
+            endpoints: Arc::new(endpoints),
             retry_config,
-            url,
+            compatibility,
             prepend_warning: warning,
+            middlewares: vec![],
+            rate_limiter: None,
+            endpoint_rate_limiters: None,
+            timeouts: Timeouts::default(),
+            #[cfg(feature = "metrics")]
+            metrics: None,
         })
     }
 
-    fn version_compatibility_warning(node_info: &NodeInfo) -> Result<Option<String>> {
-        let node_version = node_info
-            .node_version
-            .parse::<semver::Version>()
-            .map_err(|e| error!(Provider, "could not parse Fuel client version: {}", e))?;
-
-        let VersionCompatibility {
-            supported_version,
-            is_major_supported,
-            is_minor_supported,
-            ..
-        } = supported_versions::compare_node_compatibility(node_version.clone());
-
-        let msg = if !is_major_supported || !is_minor_supported {
-            Some(format!(
-                "warning: the fuel node version to which this provider is connected has a semver incompatible version from the one the SDK was developed against. Connected node version: {node_version}, supported version: {supported_version}",
-            ))
-        } else {
-            None
-        };
-
-        Ok(msg)
+    pub(crate) fn compatibility(&self) -> &NodeCompatibility {
+        &self.compatibility
     }
 
     pub(crate) fn url(&self) -> &str {
-        &self.url
+        &self.endpoints.primary().url
+    }
+
+    pub(crate) fn urls(&self) -> impl Iterator<Item = &str> {
+        self.endpoints.urls()
     }
 
     pub(crate) fn set_retry_config(&mut self, retry_config: RetryConfig) {
         self.retry_config = retry_config;
     }
 
-    async fn wrap<T, Fut>(&self, action: impl Fn() -> Fut) -> RequestResult<T>
+    /// Middlewares wrap every attempt at a node, the first one added being the outermost.
+    pub(crate) fn add_middleware(&mut self, middleware: Arc<dyn Middleware>) {
+        self.middlewares.push(middleware);
+    }
+
+    /// Limits the requests sent to all nodes together.
+    pub(crate) fn set_rate_limit(&mut self, limit: RateLimit) {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(limit)));
+    }
+
+    /// Limits the requests sent to each node separately.
+    pub(crate) fn set_endpoint_rate_limit(&mut self, limit: RateLimit) {
+        let limiters = self
+            .endpoints
+            .urls()
+            .map(|_| RateLimiter::new(limit))
+            .collect();
+        self.endpoint_rate_limiters = Some(Arc::new(limiters));
+    }
+
+    pub(crate) fn set_timeouts(&mut self, timeouts: Timeouts) {
+        self.timeouts = timeouts;
+    }
+
+    pub(crate) fn timeouts(&self) -> &Timeouts {
+        &self.timeouts
+    }
+
+    /// Waits until the rate limits allow a request to the node at `index`.
+    async fn throttle(&self, index: usize) {
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire().await;
+        }
+        if let Some(limiters) = &self.endpoint_rate_limiters {
+            limiters[index].acquire().await;
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    pub(crate) fn set_metrics(&mut self, metrics: MetricsCallback) {
+        self.metrics = Some(metrics);
+    }
+
+    #[cfg(feature = "metrics")]
+    pub(crate) fn record_metric(&self, event: &MetricEvent<'_>) {
+        if let Some(metrics) = &self.metrics {
+            metrics.record(event);
+        }
+    }
+
+    /// Sends the request made by `action` through the middlewares, failing over between nodes.
+    /// `arguments` are shown to the middlewares.
+    async fn wrap<'a, T, Fut>(
+        &'a self,
+        operation: &'static str,
+        arguments: &'a (dyn Debug + Sync),
+        action: impl Fn(&'a FuelClient) -> Fut + Send + Sync,
+    ) -> RequestResult<T>
     where
-        Fut: Future<Output = io::Result<T>>,
+        T: Send + 'static,
+        Fut: Future<Output = io::Result<T>> + Send + 'a,
+    {
+        self.wrap_endpoint(operation, arguments, |endpoint, headers| {
+            let request = action(&endpoint.client);
+            async move {
+                if !headers.is_empty() {
+                    return Err(io::Error::other(format!(
+                        "`{operation}` is sent by the `FuelClient`, which cannot add headers"
+                    )));
+                }
+
+                request.await
+            }
+        })
+        .await
+    }
+
+    /// Like [`Self::wrap`], for requests that send the headers set by the middlewares.
+    async fn wrap_endpoint<'a, T, Fut>(
+        &'a self,
+        operation: &'static str,
+        arguments: &'a (dyn Debug + Sync),
+        action: impl Fn(&'a Endpoint, HeaderMap) -> Fut + Send + Sync,
+    ) -> RequestResult<T>
+    where
+        T: Send + 'static,
+        Fut: Future<Output = io::Result<T>> + Send + 'a,
     {
-        retry_util::retry(action, &self.retry_config, |result| result.is_err())
+        self.failover(|endpoint| self.call(operation, arguments, endpoint, &action))
             .await
-            .map_err(|e| {
-                let msg = if let Some(warning) = &self.prepend_warning {
-                    format!("{warning}. {e}")
-                } else {
-                    e.to_string()
-                };
-                RequestError::IO(msg)
+    }
+
+    /// A single attempt at `endpoint`, reported to tracing and the metrics callback.
+    async fn call<'a, 'b, T, Fut>(
+        &'b self,
+        operation: &'static str,
+        arguments: &'a (dyn Debug + Sync),
+        endpoint: &'a Endpoint,
+        action: &'b (impl Fn(&'a Endpoint, HeaderMap) -> Fut + Send + Sync),
+    ) -> io::Result<T>
+    where
+        T: Send + 'static,
+        Fut: Future<Output = io::Result<T>> + Send + 'a,
+    {
+        #[cfg(feature = "metrics")]
+        let started = std::time::Instant::now();
+
+        let timeout = self.timeouts.get(OperationClass::of(operation));
+        let result = async move {
+            let request = self.call_middlewares(operation, arguments, endpoint, action);
+            let Some(timeout) = timeout else {
+                return request.await;
+            };
+
+            tokio::time::timeout(timeout, request)
+                .await
+                .unwrap_or_else(|_| {
+                    Err(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        format!("`{operation}` timed out after {timeout:?}"),
+                    ))
+                })
+        };
+        #[cfg(feature = "tracing")]
+        let result = tracing::Instrument::instrument(
+            result,
+            tracing::debug_span!("node_request", operation, url = %endpoint.url),
+        );
+        let result = result.await;
+
+        #[cfg(feature = "tracing")]
+        if let Err(e) = &result {
+            tracing::debug!(operation, url = %endpoint.url, error = %e, "node request failed");
+        }
+
+        #[cfg(feature = "metrics")]
+        self.record_metric(&MetricEvent::NodeRequest {
+            operation,
+            url: &endpoint.url,
+            latency: started.elapsed(),
+            error: result.as_ref().err(),
+        });
+
+        result
+    }
+
+    async fn call_middlewares<'a, 'b, T, Fut>(
+        &'b self,
+        operation: &'static str,
+        arguments: &'a (dyn Debug + Sync),
+        endpoint: &'a Endpoint,
+        action: &'b (impl Fn(&'a Endpoint, HeaderMap) -> Fut + Send + Sync),
+    ) -> io::Result<T>
+    where
+        T: Send + 'static,
+        Fut: Future<Output = io::Result<T>> + Send + 'a,
+    {
+        if self.middlewares.is_empty() {
+            return action(endpoint, HeaderMap::new()).await;
+        }
+
+        let call = |headers| {
+            async move { Ok(NodeResponse::new(action(endpoint, headers).await?)) }.boxed()
+        };
+        let mut request = NodeRequest {
+            operation,
+            url: &endpoint.url,
+            arguments,
+            headers: HeaderMap::new(),
+        };
+
+        Next::new(&self.middlewares, &call)
+            .run(&mut request)
+            .await?
+            .into_inner()
+            .map_err(|_| {
+                io::Error::other(format!(
+                    "a middleware answered `{operation}` with a response of the wrong type"
+                ))
             })
     }
 
+    /// Runs `attempt` against the nodes in the order given by the failover policy, retrying on
+    /// each of them according to the retry config, until one answers. Errors answered by a node
+    /// are returned without trying the next ones.
+    async fn failover<'a, T, Fut>(
+        &'a self,
+        attempt: impl Fn(&'a Endpoint) -> Fut,
+    ) -> RequestResult<T>
+    where
+        Fut: Future<Output = io::Result<T>>,
+    {
+        let mut last_error = None;
+        for (index, endpoint) in self.endpoints.route() {
+            let result = retry_util::retry(
+                || async {
+                    self.throttle(index).await;
+                    attempt(endpoint).await
+                },
+                &self.retry_config,
+                |result| result.is_err(),
+            )
+            .await;
+
+            self.endpoints.report(index, &result);
+            match result {
+                Ok(value) => return Ok(value),
+                Err(e) if is_node_failure(&e) => last_error = Some(e),
+                Err(e) => {
+                    last_error = Some(e);
+                    break;
+                }
+            }
+        }
+
+        let e = last_error.expect("there is at least one endpoint");
+        let msg = if let Some(warning) = &self.prepend_warning {
+            format!("{warning}. {e}")
+        } else {
+            e.to_string()
+        };
+
+        if e.kind() == io::ErrorKind::TimedOut {
+            return Err(RequestError::Timeout(msg));
+        }
+
+        Err(RequestError::IO(msg))
+    }
+
     // DELEGATION START
     pub async fn health(&self) -> RequestResult<bool> {
-        self.wrap(|| self.client.health()).await
+        self.wrap("health", &(), |client| client.health()).await
     }
 
     pub async fn transaction(&self, id: &TxId) -> RequestResult<Option<TransactionResponse>> {
-        self.wrap(|| self.client.transaction(id)).await
+        self.wrap("transaction", id, |client| client.transaction(id))
+            .await
     }
 
     pub(crate) async fn chain_info(&self) -> RequestResult<ChainInfo> {
-        self.wrap(|| self.client.chain_info()).await
+        self.wrap("chain_info", &(), |client| client.chain_info())
+            .await
     }
 
-    pub async fn await_transaction_commit(&self, id: &TxId) -> RequestResult<TransactionStatus> {
-        self.wrap(|| self.client.await_transaction_commit(id)).await
+    pub async fn subscribe_transaction_status<'a>(
+        &'a self,
+        id: &'a TxId,
+    ) -> RequestResult<impl futures::Stream<Item = io::Result<TransactionStatus>> + 'a> {
+        self.failover(|endpoint| endpoint.client.subscribe_transaction_status(id))
+            .await
     }
 
     pub async fn submit_and_await_commit(
         &self,
         tx: &Transaction,
     ) -> RequestResult<TransactionStatus> {
-        self.wrap(|| self.client.submit_and_await_commit(tx)).await
+        self.wrap("submit_and_await_commit", tx, |client| {
+            client.submit_and_await_commit(tx)
+        })
+        .await
     }
 
     pub async fn submit(&self, tx: &Transaction) -> RequestResult<TransactionId> {
-        self.wrap(|| self.client.submit(tx)).await
+        self.wrap("submit", tx, |client| client.submit(tx)).await
     }
 
     pub async fn transaction_status(&self, id: &TxId) -> RequestResult<TransactionStatus> {
-        self.wrap(|| self.client.transaction_status(id)).await
+        self.wrap("transaction_status", id, |client| {
+            client.transaction_status(id)
+        })
+        .await
     }
 
     pub async fn node_info(&self) -> RequestResult<NodeInfo> {
-        self.wrap(|| self.client.node_info()).await
+        self.wrap("node_info", &(), |client| client.node_info())
+            .await
     }
 
     pub async fn latest_gas_price(&self) -> RequestResult<LatestGasPrice> {
-        self.wrap(|| self.client.latest_gas_price()).await
+        self.wrap("latest_gas_price", &(), |client| client.latest_gas_price())
+            .await
     }
 
     pub async fn estimate_gas_price(&self, block_horizon: u32) -> RequestResult<EstimateGasPrice> {
-        self.wrap(|| self.client.estimate_gas_price(block_horizon))
-            .await
-            .map(Into::into)
+        self.wrap("estimate_gas_price", &block_horizon, |client| {
+            client.estimate_gas_price(block_horizon)
+        })
+        .await
+        .map(Into::into)
     }
 
     pub async fn estimate_predicates(&self, tx: &Transaction) -> RequestResult<Transaction> {
-        self.wrap(|| async {
+        self.wrap("estimate_predicates", tx, |client| async move {
             let mut new_tx = tx.clone();
-            self.client.estimate_predicates(&mut new_tx).await?;
+            client.estimate_predicates(&mut new_tx).await?;
             Ok(new_tx)
         })
         .await
@@ -163,7 +452,7 @@ impl RetryableClient {
         &self,
         tx: &[Transaction],
     ) -> RequestResult<Vec<TransactionExecutionStatus>> {
-        self.wrap(|| self.client.dry_run(tx)).await
+        self.wrap("dry_run", &tx, |client| client.dry_run(tx)).await
     }
 
     pub async fn dry_run_opt(
@@ -172,8 +461,10 @@ impl RetryableClient {
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
     ) -> RequestResult<Vec<TransactionExecutionStatus>> {
-        self.wrap(|| self.client.dry_run_opt(tx, utxo_validation, gas_price))
-            .await
+        self.wrap("dry_run_opt", &(tx, utxo_validation, gas_price), |client| {
+            client.dry_run_opt(tx, utxo_validation, gas_price)
+        })
+        .await
     }
 
     pub async fn coins(
@@ -182,8 +473,10 @@ impl RetryableClient {
         asset_id: Option<&AssetId>,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<Coin, String>> {
-        self.wrap(move || self.client.coins(owner, asset_id, request.clone()))
-            .await
+        self.wrap("coins", &(owner, asset_id, &request), |client| {
+            client.coins(owner, asset_id, request.clone())
+        })
+        .await
     }
 
     pub async fn coins_to_spend(
@@ -192,15 +485,24 @@ impl RetryableClient {
         spend_query: Vec<(AssetId, u64, Option<u32>)>,
         excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
     ) -> RequestResult<Vec<Vec<CoinType>>> {
-        self.wrap(move || {
-            self.client
-                .coins_to_spend(owner, spend_query.clone(), excluded_ids.clone())
-        })
+        self.wrap(
+            "coins_to_spend",
+            &(owner, &spend_query, &excluded_ids),
+            |client| client.coins_to_spend(owner, spend_query.clone(), excluded_ids.clone()),
+        )
         .await
     }
 
     pub async fn balance(&self, owner: &Address, asset_id: Option<&AssetId>) -> RequestResult<u64> {
-        self.wrap(|| self.client.balance(owner, asset_id)).await
+        self.wrap("balance", &(owner, asset_id), |client| {
+            client.balance(owner, asset_id)
+        })
+        .await
+    }
+
+    pub async fn contract(&self, id: &ContractId) -> RequestResult<Option<Contract>> {
+        self.wrap("contract", id, |client| client.contract(id))
+            .await
     }
 
     pub async fn contract_balance(
@@ -208,7 +510,10 @@ impl RetryableClient {
         id: &ContractId,
         asset: Option<&AssetId>,
     ) -> RequestResult<u64> {
-        self.wrap(|| self.client.contract_balance(id, asset)).await
+        self.wrap("contract_balance", &(id, asset), |client| {
+            client.contract_balance(id, asset)
+        })
+        .await
     }
 
     pub async fn contract_balances(
@@ -216,8 +521,10 @@ impl RetryableClient {
         contract: &ContractId,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<ContractBalance, String>> {
-        self.wrap(|| self.client.contract_balances(contract, request.clone()))
-            .await
+        self.wrap("contract_balances", &(contract, &request), |client| {
+            client.contract_balances(contract, request.clone())
+        })
+        .await
     }
 
     pub async fn balances(
@@ -225,16 +532,20 @@ impl RetryableClient {
         owner: &Address,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<Balance, String>> {
-        self.wrap(|| self.client.balances(owner, request.clone()))
-            .await
+        self.wrap("balances", &(owner, &request), |client| {
+            client.balances(owner, request.clone())
+        })
+        .await
     }
 
     pub async fn transactions(
         &self,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<TransactionResponse, String>> {
-        self.wrap(|| self.client.transactions(request.clone()))
-            .await
+        self.wrap("transactions", &request, |client| {
+            client.transactions(request.clone())
+        })
+        .await
     }
 
     pub async fn transactions_by_owner(
@@ -242,8 +553,10 @@ impl RetryableClient {
         owner: &Address,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<TransactionResponse, String>> {
-        self.wrap(|| self.client.transactions_by_owner(owner, request.clone()))
-            .await
+        self.wrap("transactions_by_owner", &(owner, &request), |client| {
+            client.transactions_by_owner(owner, request.clone())
+        })
+        .await
     }
 
     pub async fn produce_blocks(
@@ -251,26 +564,31 @@ impl RetryableClient {
         blocks_to_produce: u32,
         start_timestamp: Option<u64>,
     ) -> RequestResult<BlockHeight> {
-        self.wrap(|| {
-            self.client
-                .produce_blocks(blocks_to_produce, start_timestamp)
-        })
+        self.wrap(
+            "produce_blocks",
+            &(blocks_to_produce, start_timestamp),
+            |client| client.produce_blocks(blocks_to_produce, start_timestamp),
+        )
         .await
     }
 
     pub async fn block(&self, id: &BlockId) -> RequestResult<Option<Block>> {
-        self.wrap(|| self.client.block(id)).await
+        self.wrap("block", id, |client| client.block(id)).await
     }
 
     pub async fn block_by_height(&self, height: BlockHeight) -> RequestResult<Option<Block>> {
-        self.wrap(|| self.client.block_by_height(height)).await
+        self.wrap("block_by_height", &height, |client| {
+            client.block_by_height(height)
+        })
+        .await
     }
 
     pub async fn blocks(
         &self,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<Block, String>> {
-        self.wrap(|| self.client.blocks(request.clone())).await
+        self.wrap("blocks", &request, |client| client.blocks(request.clone()))
+            .await
     }
 
     pub async fn messages(
@@ -278,8 +596,10 @@ impl RetryableClient {
         owner: Option<&Address>,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<Message, String>> {
-        self.wrap(|| self.client.messages(owner, request.clone()))
-            .await
+        self.wrap("messages", &(owner, &request), |client| {
+            client.messages(owner, request.clone())
+        })
+        .await
     }
 
     /// Request a merkle proof of an output message.
@@ -290,9 +610,22 @@ impl RetryableClient {
         commit_block_id: Option<&BlockId>,
         commit_block_height: Option<BlockHeight>,
     ) -> RequestResult<Option<MessageProof>> {
-        self.wrap(|| {
-            self.client
-                .message_proof(transaction_id, nonce, commit_block_id, commit_block_height)
+        self.wrap(
+            "message_proof",
+            &(transaction_id, nonce, commit_block_id, commit_block_height),
+            |client| {
+                client.message_proof(transaction_id, nonce, commit_block_id, commit_block_height)
+            },
+        )
+        .await
+    }
+    pub async fn raw_query(
+        &self,
+        query: &str,
+        variables: &serde_json::Value,
+    ) -> RequestResult<serde_json::Value> {
+        self.wrap_endpoint("raw_query", &(query, variables), |endpoint, headers| {
+            endpoint.raw_query(query, variables, headers)
         })
         .await
     }
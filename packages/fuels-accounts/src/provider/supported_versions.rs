@@ -1,7 +1,133 @@
+use fuels_core::types::errors::{error, Result};
 use semver::Version;
+use sha2::{Digest, Sha256};
 
 use crate::provider::supported_fuel_core_version::SUPPORTED_FUEL_CORE_VERSION;
 
+/// Lists the fields of every type of the node's GraphQL schema, to hash them.
+pub(crate) const SCHEMA_INTROSPECTION_QUERY: &str =
+    "query { __schema { types { name fields { name } } } }";
+
+/// How closely the node a provider is connected to matches the `fuel-core` version the SDK was
+/// developed against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompatibilityLevel {
+    Compatible,
+    /// Only the patch versions differ, which should not break anything.
+    PatchMismatch,
+    /// The major or minor versions differ: requests may fail with confusing deserialization
+    /// errors.
+    Incompatible,
+}
+
+/// The compatibility of the node a provider is connected to, checked when connecting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeCompatibility {
+    pub node_version: Version,
+    pub supported_version: Version,
+    pub level: CompatibilityLevel,
+    /// Hex encoded SHA-256 hash of the types and fields of the node's GraphQL schema, or `None`
+    /// if the node does not allow introspection.
+    pub schema_hash: Option<String>,
+}
+
+impl NodeCompatibility {
+    pub(crate) fn new(node_version: &str, schema_hash: Option<String>) -> Result<Self> {
+        let node_version = node_version
+            .parse::<Version>()
+            .map_err(|e| error!(Provider, "could not parse Fuel client version: {}", e))?;
+
+        let VersionCompatibility {
+            supported_version,
+            is_major_supported,
+            is_minor_supported,
+            is_patch_supported,
+        } = compare_node_compatibility(node_version.clone());
+
+        let level = if !is_major_supported || !is_minor_supported {
+            CompatibilityLevel::Incompatible
+        } else if !is_patch_supported {
+            CompatibilityLevel::PatchMismatch
+        } else {
+            CompatibilityLevel::Compatible
+        };
+
+        Ok(Self {
+            node_version,
+            supported_version,
+            level,
+            schema_hash,
+        })
+    }
+
+    /// The warning prepended to request errors when the node is incompatible.
+    pub(crate) fn warning(&self) -> Option<String> {
+        (self.level == CompatibilityLevel::Incompatible).then(|| format!(
+            "warning: the fuel node version to which this provider is connected has a semver incompatible version from the one the SDK was developed against. Connected node version: {}, supported version: {}",
+            self.node_version, self.supported_version
+        ))
+    }
+
+    /// Fails if the node version is semver incompatible with the supported one or, when
+    /// `expected_schema_hash` is given, if the schema of the node has another hash.
+    ///
+    /// Pinning the schema hash of a node known to work catches schema changes between nodes of
+    /// the same version, e.g. custom builds.
+    pub fn ensure_compatible(&self, expected_schema_hash: Option<&str>) -> Result<()> {
+        if self.level == CompatibilityLevel::Incompatible {
+            return Err(error!(
+                Provider,
+                "node version `{}` is incompatible with the supported version `{}`",
+                self.node_version,
+                self.supported_version
+            ));
+        }
+
+        match (expected_schema_hash, &self.schema_hash) {
+            (Some(expected), Some(actual)) if !expected.eq_ignore_ascii_case(actual) => {
+                Err(error!(
+                    Provider,
+                    "node GraphQL schema hash `{actual}` does not match the expected `{expected}`"
+                ))
+            }
+            (Some(_), None) => Err(error!(
+                Provider,
+                "node does not allow introspecting its GraphQL schema"
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Hashes the `data` of [`SCHEMA_INTROSPECTION_QUERY`], ignoring the order of types and fields
+/// as well as the introspection types themselves.
+pub(crate) fn schema_hash(introspection: &serde_json::Value) -> Option<String> {
+    let types = introspection.pointer("/__schema/types")?.as_array()?;
+
+    let mut fields = types
+        .iter()
+        .filter_map(|ty| {
+            let name = ty.get("name")?.as_str()?;
+            (!name.starts_with("__")).then_some((name, ty.get("fields")))
+        })
+        .flat_map(|(name, fields)| {
+            let fields = fields
+                .and_then(|fields| fields.as_array())
+                .map(|fields| fields.as_slice())
+                .unwrap_or_default();
+            let fields = fields
+                .iter()
+                .filter_map(|field| field.get("name")?.as_str())
+                .map(move |field| format!("{name}.{field}"));
+
+            std::iter::once(name.to_string()).chain(fields)
+        })
+        .collect::<Vec<_>>();
+    fields.sort();
+
+    Some(hex::encode(Sha256::digest(fields.join("\n"))))
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) struct VersionCompatibility {
     pub(crate) supported_version: Version,
@@ -32,8 +158,71 @@ fn check_version_compatibility(
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use super::*;
 
+    #[test]
+    fn classifies_node_versions() -> Result<()> {
+        let supported = SUPPORTED_FUEL_CORE_VERSION;
+        let version = |major, minor, patch| Version::new(major, minor, patch).to_string();
+
+        let compatible = NodeCompatibility::new(&supported.to_string(), None)?;
+        assert_eq!(compatible.level, CompatibilityLevel::Compatible);
+        assert!(compatible.warning().is_none());
+        compatible.ensure_compatible(None)?;
+
+        let patch = NodeCompatibility::new(
+            &version(supported.major, supported.minor, supported.patch + 1),
+            None,
+        )?;
+        assert_eq!(patch.level, CompatibilityLevel::PatchMismatch);
+        patch.ensure_compatible(None)?;
+
+        let minor = NodeCompatibility::new(
+            &version(supported.major, supported.minor + 1, supported.patch),
+            None,
+        )?;
+        assert_eq!(minor.level, CompatibilityLevel::Incompatible);
+        assert!(minor.warning().is_some());
+        assert!(minor.ensure_compatible(None).is_err());
+
+        assert!(NodeCompatibility::new("not a version", None).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn schema_hash_ignores_ordering_and_introspection_types() -> Result<()> {
+        let schema = |types| json!({ "__schema": { "types": types } });
+
+        let hash = schema_hash(&schema(json!([
+            { "name": "Query", "fields": [{ "name": "chain" }, { "name": "coins" }] },
+            { "name": "U64", "fields": null },
+            { "name": "__Type", "fields": [{ "name": "kind" }] },
+        ])))
+        .expect("valid introspection");
+
+        let reordered = schema_hash(&schema(json!([
+            { "name": "U64", "fields": null },
+            { "name": "Query", "fields": [{ "name": "coins" }, { "name": "chain" }] },
+        ])));
+        assert_eq!(reordered.as_ref(), Some(&hash));
+
+        let changed = schema_hash(&schema(json!([
+            { "name": "Query", "fields": [{ "name": "chain" }] },
+            { "name": "U64", "fields": null },
+        ])));
+        assert_ne!(changed.as_ref(), Some(&hash));
+
+        let compatibility =
+            NodeCompatibility::new(&SUPPORTED_FUEL_CORE_VERSION.to_string(), Some(hash.clone()))?;
+        compatibility.ensure_compatible(Some(&hash.to_uppercase()))?;
+        assert!(compatibility.ensure_compatible(Some("00")).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn should_validate_all_possible_version_mismatches() {
         let expected_version = "0.1.2".parse::<Version>().unwrap();
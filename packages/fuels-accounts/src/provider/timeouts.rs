@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+/// The kind of a request to a node, deciding which of the [`Timeouts`] applies to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperationClass {
+    /// Reads from the node, dry runs and estimations.
+    Query,
+    /// Submissions of transactions.
+    Submit,
+    /// Waiting for a transaction to be committed.
+    AwaitCommit,
+}
+
+impl OperationClass {
+    /// The class of the `FuelClient` method named `operation`, as reported to middlewares.
+    pub fn of(operation: &str) -> Self {
+        match operation {
+            "submit" => Self::Submit,
+            "submit_and_await_commit" => Self::AwaitCommit,
+            _ => Self::Query,
+        }
+    }
+}
+
+/// How long requests to a node may take, by [`OperationClass`]. Classes without a timeout wait
+/// as long as the node takes.
+///
+/// Query and submit timeouts apply to every attempt at a node, before it is retried. The await
+/// commit timeout applies to the whole wait.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timeouts {
+    query: Option<Duration>,
+    submit: Option<Duration>,
+    await_commit: Option<Duration>,
+}
+
+impl Timeouts {
+    pub fn with_query(mut self, timeout: Duration) -> Self {
+        self.query = Some(timeout);
+        self
+    }
+
+    pub fn with_submit(mut self, timeout: Duration) -> Self {
+        self.submit = Some(timeout);
+        self
+    }
+
+    pub fn with_await_commit(mut self, timeout: Duration) -> Self {
+        self.await_commit = Some(timeout);
+        self
+    }
+
+    pub fn get(&self, class: OperationClass) -> Option<Duration> {
+        match class {
+            OperationClass::Query => self.query,
+            OperationClass::Submit => self.submit,
+            OperationClass::AwaitCommit => self.await_commit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations_get_the_timeout_of_their_class() {
+        let timeouts = Timeouts::default()
+            .with_query(Duration::from_secs(1))
+            .with_submit(Duration::from_secs(2));
+
+        assert_eq!(
+            timeouts.get(OperationClass::of("balance")),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            timeouts.get(OperationClass::of("submit")),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            timeouts.get(OperationClass::of("submit_and_await_commit")),
+            None
+        );
+    }
+}
@@ -0,0 +1,349 @@
+use fuel_types::Bytes32;
+use fuels_core::types::{
+    block::Header,
+    errors::{error, Result},
+    message_proof::{MerkleProof, MessageProof},
+};
+use sha3::{Digest, Keccak256};
+use tai64::Tai64;
+
+/// The portal function relaying a Fuel message to Ethereum, as its selector is derived.
+pub const RELAY_MESSAGE_SIGNATURE: &str = "relayMessage(\
+    (bytes32,bytes32,bytes32,uint64,bytes),\
+    (bytes32,uint32,uint64,bytes32),\
+    (bytes32,uint32,uint64,uint64,uint16,uint32,bytes32,bytes32,bytes32,uint32,uint32),\
+    (uint256,bytes32[]),\
+    (uint256,bytes32[]))";
+
+/// The block which commits to a message, against which its proof is generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitBlock {
+    Id(Bytes32),
+    Height(u32),
+}
+
+/// The message to relay, as expected by the portal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayedMessage {
+    pub sender: Bytes32,
+    pub recipient: Bytes32,
+    pub nonce: Bytes32,
+    pub amount: u64,
+    pub data: Vec<u8>,
+}
+
+/// The commit block header, whose id was submitted to the portal by the state committer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayedBlockHeaderLite {
+    pub prev_root: Bytes32,
+    pub height: u32,
+    /// Tai64 timestamp.
+    pub timestamp: u64,
+    pub application_hash: Bytes32,
+}
+
+/// The header of the block containing the message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayedBlockHeader {
+    pub prev_root: Bytes32,
+    pub height: u32,
+    /// Tai64 timestamp.
+    pub timestamp: u64,
+    pub da_height: u64,
+    pub transactions_count: u16,
+    pub output_messages_count: u32,
+    pub transactions_root: Bytes32,
+    pub output_messages_root: Bytes32,
+    pub event_inbox_root: Bytes32,
+    pub consensus_parameters_version: u32,
+    pub state_transition_bytecode_version: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayedMerkleProof {
+    pub key: u64,
+    pub proof: Vec<Bytes32>,
+}
+
+/// Everything needed to relay a Fuel to Ethereum withdrawal on the L1 message portal, built
+/// from the [`MessageProof`] of the message.
+///
+/// [`calldata`](Self::calldata) encodes a call to the portal function with the signature
+/// [`RELAY_MESSAGE_SIGNATURE`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawalRelay {
+    pub message: RelayedMessage,
+    pub root_block_header: RelayedBlockHeaderLite,
+    pub block_header: RelayedBlockHeader,
+    /// Proves that the message block is part of the history of the commit block.
+    pub block_in_history_proof: RelayedMerkleProof,
+    /// Proves that the message is part of the message block.
+    pub message_in_block_proof: RelayedMerkleProof,
+}
+
+impl WithdrawalRelay {
+    pub fn new(proof: &MessageProof) -> Result<Self> {
+        let root = &proof.commit_block_header;
+        let block = &proof.message_block_header;
+
+        Ok(Self {
+            message: RelayedMessage {
+                sender: proof.sender.hash(),
+                recipient: proof.recipient.hash(),
+                nonce: Bytes32::new(*proof.nonce),
+                amount: proof.amount,
+                data: proof.data.clone(),
+            },
+            root_block_header: RelayedBlockHeaderLite {
+                prev_root: root.prev_root,
+                height: root.height,
+                timestamp: tai64_timestamp(root)?,
+                application_hash: root.application_hash,
+            },
+            block_header: RelayedBlockHeader {
+                prev_root: block.prev_root,
+                height: block.height,
+                timestamp: tai64_timestamp(block)?,
+                da_height: block.da_height,
+                transactions_count: block.transactions_count,
+                output_messages_count: block.message_receipt_count,
+                transactions_root: block.transactions_root,
+                output_messages_root: block.message_outbox_root,
+                event_inbox_root: block.event_inbox_root,
+                consensus_parameters_version: block.consensus_parameters_version,
+                state_transition_bytecode_version: block.state_transition_bytecode_version,
+            },
+            block_in_history_proof: (&proof.block_proof).into(),
+            message_in_block_proof: (&proof.message_proof).into(),
+        })
+    }
+
+    /// The first four bytes of the Keccak-256 hash of [`RELAY_MESSAGE_SIGNATURE`].
+    pub fn selector() -> [u8; 4] {
+        let hash = Keccak256::digest(RELAY_MESSAGE_SIGNATURE.as_bytes());
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
+
+    /// The ABI encoded call relaying the message on the portal.
+    pub fn calldata(&self) -> Vec<u8> {
+        let message = &self.message;
+        let root = &self.root_block_header;
+        let block = &self.block_header;
+
+        let encoded_message = encode_tuple(vec![
+            Param::Static(word(message.sender)),
+            Param::Static(word(message.recipient)),
+            Param::Static(word(message.nonce)),
+            Param::Static(uint(message.amount)),
+            Param::Dynamic(encode_bytes(&message.data)),
+        ]);
+        let encoded_root = [
+            word(root.prev_root),
+            uint(root.height.into()),
+            uint(root.timestamp),
+            word(root.application_hash),
+        ]
+        .concat();
+        let encoded_block = [
+            word(block.prev_root),
+            uint(block.height.into()),
+            uint(block.timestamp),
+            uint(block.da_height),
+            uint(block.transactions_count.into()),
+            uint(block.output_messages_count.into()),
+            word(block.transactions_root),
+            word(block.output_messages_root),
+            word(block.event_inbox_root),
+            uint(block.consensus_parameters_version.into()),
+            uint(block.state_transition_bytecode_version.into()),
+        ]
+        .concat();
+
+        let arguments = encode_tuple(vec![
+            Param::Dynamic(encoded_message),
+            Param::Static(encoded_root),
+            Param::Static(encoded_block),
+            Param::Dynamic(self.block_in_history_proof.encode()),
+            Param::Dynamic(self.message_in_block_proof.encode()),
+        ]);
+
+        [Self::selector().to_vec(), arguments].concat()
+    }
+}
+
+impl TryFrom<&MessageProof> for WithdrawalRelay {
+    type Error = fuels_core::types::errors::Error;
+
+    fn try_from(proof: &MessageProof) -> Result<Self> {
+        Self::new(proof)
+    }
+}
+
+impl From<&MerkleProof> for RelayedMerkleProof {
+    fn from(proof: &MerkleProof) -> Self {
+        Self {
+            key: proof.proof_index,
+            proof: proof.proof_set.clone(),
+        }
+    }
+}
+
+impl RelayedMerkleProof {
+    fn encode(&self) -> Vec<u8> {
+        let proof = [uint(self.proof.len() as u64)]
+            .into_iter()
+            .chain(self.proof.iter().copied().map(word))
+            .collect::<Vec<_>>()
+            .concat();
+
+        encode_tuple(vec![Param::Static(uint(self.key)), Param::Dynamic(proof)])
+    }
+}
+
+fn tai64_timestamp(header: &Header) -> Result<u64> {
+    let time = header
+        .time
+        .ok_or_else(|| error!(Other, "block `{}` has no timestamp", header.height))?;
+
+    Ok(Tai64::from_unix(time.timestamp()).0)
+}
+
+/// A component of an ABI encoded tuple, either encoded in place or after the static
+/// components, at the offset encoded in its place.
+enum Param {
+    Static(Vec<u8>),
+    Dynamic(Vec<u8>),
+}
+
+fn encode_tuple(params: Vec<Param>) -> Vec<u8> {
+    let head_len: usize = params
+        .iter()
+        .map(|param| match param {
+            Param::Static(encoded) => encoded.len(),
+            Param::Dynamic(_) => 32,
+        })
+        .sum();
+
+    let mut head = Vec::with_capacity(head_len);
+    let mut tail = vec![];
+    for param in params {
+        match param {
+            Param::Static(encoded) => head.extend(encoded),
+            Param::Dynamic(encoded) => {
+                head.extend(uint((head_len + tail.len()) as u64));
+                tail.extend(encoded);
+            }
+        }
+    }
+
+    [head, tail].concat()
+}
+
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    let padding = (32 - bytes.len() % 32) % 32;
+
+    [uint(bytes.len() as u64), bytes.to_vec(), vec![0; padding]].concat()
+}
+
+fn word(bytes: Bytes32) -> Vec<u8> {
+    bytes.to_vec()
+}
+
+fn uint(value: u64) -> Vec<u8> {
+    let mut encoded = vec![0; 24];
+    encoded.extend(value.to_be_bytes());
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn relay(data: Vec<u8>) -> WithdrawalRelay {
+        let block_header = RelayedBlockHeader {
+            prev_root: Bytes32::new([1; 32]),
+            height: 2,
+            timestamp: 3,
+            da_height: 4,
+            transactions_count: 5,
+            output_messages_count: 6,
+            transactions_root: Bytes32::new([7; 32]),
+            output_messages_root: Bytes32::new([8; 32]),
+            event_inbox_root: Bytes32::new([9; 32]),
+            consensus_parameters_version: 10,
+            state_transition_bytecode_version: 11,
+        };
+
+        WithdrawalRelay {
+            message: RelayedMessage {
+                sender: Bytes32::new([12; 32]),
+                recipient: Bytes32::new([13; 32]),
+                nonce: Bytes32::new([14; 32]),
+                amount: 15,
+                data,
+            },
+            root_block_header: RelayedBlockHeaderLite {
+                prev_root: Bytes32::new([16; 32]),
+                height: 17,
+                timestamp: 18,
+                application_hash: Bytes32::new([19; 32]),
+            },
+            block_header,
+            block_in_history_proof: RelayedMerkleProof {
+                key: 20,
+                proof: vec![Bytes32::new([21; 32])],
+            },
+            message_in_block_proof: RelayedMerkleProof {
+                key: 22,
+                proof: vec![],
+            },
+        }
+    }
+
+    fn words(calldata: &[u8]) -> Vec<&[u8]> {
+        calldata[4..].chunks(32).collect()
+    }
+
+    #[test]
+    fn calldata_places_dynamic_arguments_after_the_head() {
+        let calldata = relay(vec![0xaa; 33]).calldata();
+        let words = words(&calldata);
+
+        assert_eq!(calldata[..4], WithdrawalRelay::selector());
+        assert_eq!((calldata.len() - 4) % 32, 0);
+
+        // message offset, 4 lite header words, 11 header words and two proof offsets
+        let head_len = 18 * 32;
+        assert_eq!(words[0], uint(head_len as u64));
+        assert_eq!(words[1], [16; 32]);
+        assert_eq!(words[5], [1; 32]);
+        assert_eq!(words[15], uint(11));
+
+        // message: 5 head words, data length and two data words
+        let message_len = 8 * 32;
+        assert_eq!(words[16], uint((head_len + message_len) as u64));
+        // block proof: key, offset, length and one element
+        let block_proof_len = 4 * 32;
+        assert_eq!(
+            words[17],
+            uint((head_len + message_len + block_proof_len) as u64)
+        );
+
+        let message = &words[18..26];
+        assert_eq!(message[3], uint(15));
+        assert_eq!(message[4], uint(5 * 32));
+        assert_eq!(message[5], uint(33));
+        assert_eq!(message[6], [0xaa; 32]);
+        assert_eq!(message[7][0], 0xaa);
+        assert!(message[7][1..].iter().all(|&byte| byte == 0));
+
+        let block_proof = &words[26..30];
+        assert_eq!(block_proof[0], uint(20));
+        assert_eq!(block_proof[1], uint(64));
+        assert_eq!(block_proof[2], uint(1));
+        assert_eq!(block_proof[3], [21; 32]);
+
+        let message_proof = &words[30..];
+        assert_eq!(message_proof, [uint(22), uint(64), uint(0)]);
+    }
+}
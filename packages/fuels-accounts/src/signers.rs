@@ -0,0 +1,18 @@
+#[cfg(feature = "std")]
+mod account;
+mod callback;
+#[cfg(any(
+    feature = "signer-aws-kms",
+    feature = "signer-google-kms",
+    feature = "signer-vault"
+))]
+pub mod kms;
+pub mod remote;
+#[cfg(feature = "signer-threshold")]
+pub mod threshold;
+#[cfg(feature = "signer-web-crypto")]
+pub mod web_crypto;
+
+#[cfg(feature = "std")]
+pub use account::SignerAccount;
+pub use callback::{CallbackSigner, SignFuture};
@@ -0,0 +1,153 @@
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::Bech32Address,
+        coin_type_id::CoinTypeId,
+        errors::{error, Result},
+        input::Input,
+        transaction_builders::TransactionBuilder,
+        unsigned_transaction::OfflineSigner,
+        AssetId,
+    },
+};
+
+use crate::{accounts_utils::try_provider_error, provider::Provider, Account, ViewOnlyAccount};
+
+/// An [`Account`] whose witnesses are produced by an arbitrary [`Signer`], e.g. a hardware wallet
+/// or a key held by a remote key management service.
+///
+/// An account created with [`watch_only`](Self::watch_only) only knows its address, until a
+/// signer is attached with [`attach_signer`](Self::attach_signer). Meanwhile the transactions it
+/// builds carry empty signatures: they can be simulated with `Execution::StateReadOnly` but are
+/// rejected if sent.
+#[derive(Debug, Clone)]
+pub struct SignerAccount<S> {
+    address: Bech32Address,
+    signer: Option<S>,
+    provider: Option<Provider>,
+}
+
+impl<S: Signer> SignerAccount<S> {
+    pub fn new(signer: S, provider: Option<Provider>) -> Self {
+        Self {
+            address: signer.address().clone(),
+            signer: Some(signer),
+            provider,
+        }
+    }
+
+    pub fn watch_only(address: Bech32Address, provider: Option<Provider>) -> Self {
+        Self {
+            address,
+            signer: None,
+            provider,
+        }
+    }
+
+    /// Signs with `signer` from now on. Fails if `signer` is for another address.
+    pub fn attach_signer(&mut self, signer: S) -> Result<()> {
+        if signer.address() != &self.address {
+            return Err(error!(
+                Other,
+                "signer for `{}` cannot sign for `{}`",
+                signer.address(),
+                self.address
+            ));
+        }
+
+        self.signer = Some(signer);
+
+        Ok(())
+    }
+
+    /// Goes back to watching only, returning the signer.
+    pub fn detach_signer(&mut self) -> Option<S> {
+        self.signer.take()
+    }
+
+    pub fn signer(&self) -> Option<&S> {
+        self.signer.as_ref()
+    }
+
+    pub fn provider(&self) -> Option<&Provider> {
+        self.provider.as_ref()
+    }
+
+    pub fn set_provider(&mut self, provider: Provider) {
+        self.provider = Some(provider);
+    }
+
+    pub fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
+
+impl<S> ViewOnlyAccount for SignerAccount<S>
+where
+    S: Signer + Clone + std::fmt::Debug + Send + Sync,
+{
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.provider.as_ref().ok_or_else(try_provider_error)
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<S> Account for SignerAccount<S>
+where
+    S: Signer + Clone + std::fmt::Debug + Send + Sync,
+{
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: Option<Vec<CoinTypeId>>,
+    ) -> Result<Vec<Input>> {
+        Ok(self
+            .get_spendable_resources(asset_id, amount, excluded_coins)
+            .await?
+            .into_iter()
+            .map(Input::resource_signed)
+            .collect::<Vec<Input>>())
+    }
+
+    fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        match &self.signer {
+            Some(signer) => tb.add_signer(signer.clone())?,
+            None => tb.add_signer(OfflineSigner::new(self.address.clone()))?,
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::WalletUnlocked;
+
+    #[test]
+    fn attaches_only_matching_signers() -> Result<()> {
+        let wallet = WalletUnlocked::new_random(None);
+        let mut account = SignerAccount::watch_only(wallet.address().clone(), None);
+        assert!(account.signer().is_none());
+
+        assert!(account
+            .attach_signer(WalletUnlocked::new_random(None))
+            .is_err());
+
+        account.attach_signer(wallet.clone())?;
+        assert_eq!(
+            account.signer().map(|signer| signer.address()),
+            Some(wallet.address())
+        );
+
+        assert!(account.detach_signer().is_some());
+        assert!(account.signer().is_none());
+
+        Ok(())
+    }
+}
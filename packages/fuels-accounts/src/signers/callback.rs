@@ -0,0 +1,125 @@
+use std::{fmt, future::Future, pin::Pin, sync::Arc};
+
+use async_trait::async_trait;
+use fuel_crypto::{Message, Signature};
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::Bech32Address,
+        errors::{error, Result},
+    },
+};
+
+pub type SignFuture = Pin<Box<dyn Future<Output = Result<Signature>> + Send>>;
+
+type SignCallback = dyn Fn(Message) -> SignFuture + Send + Sync;
+
+/// A [`Signer`] that delegates to an async callback, e.g. one forwarding the message to a mobile
+/// app, a browser extension or a human operator and awaiting the approved signature.
+///
+/// Signatures returned by the callback are checked to belong to `address`.
+#[derive(Clone)]
+pub struct CallbackSigner {
+    address: Bech32Address,
+    callback: Arc<SignCallback>,
+}
+
+impl CallbackSigner {
+    pub fn new<F, Fut>(address: Bech32Address, callback: F) -> Self
+    where
+        F: Fn(Message) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Signature>> + Send + 'static,
+    {
+        Self {
+            address,
+            callback: Arc::new(move |message| Box::pin(callback(message))),
+        }
+    }
+}
+
+impl fmt::Debug for CallbackSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallbackSigner")
+            .field("address", &self.address)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for CallbackSigner {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let signature = (self.callback)(message).await?;
+
+        let signer = signature.recover(&message)?.hash();
+        if *signer != *self.address.hash() {
+            return Err(error!(
+                Other,
+                "callback returned a signature that does not belong to `{}`", self.address
+            ));
+        }
+
+        Ok(signature)
+    }
+
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use fuel_crypto::{PublicKey, SecretKey};
+    use fuels_core::types::bech32::FUEL_BECH32_HRP;
+    use tokio::sync::{mpsc, oneshot};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn signs_through_callback_and_checks_the_signer() -> Result<()> {
+        let secret_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?;
+        let address = Bech32Address::new(FUEL_BECH32_HRP, PublicKey::from(&secret_key).hash());
+
+        // Requests are approved by a separate task, e.g. a UI waiting for user confirmation.
+        let (requests, mut pending) = mpsc::unbounded_channel::<(Message, oneshot::Sender<_>)>();
+        tokio::spawn(async move {
+            while let Some((message, reply)) = pending.recv().await {
+                let _ = reply.send(Signature::sign(&secret_key, &message));
+            }
+        });
+
+        let signer = CallbackSigner::new(address.clone(), move |message| {
+            let requests = requests.clone();
+            async move {
+                let (reply, approved) = oneshot::channel();
+                requests
+                    .send((message, reply))
+                    .map_err(|_| error!(Other, "approver is gone"))?;
+
+                approved
+                    .await
+                    .map_err(|_| error!(Other, "request rejected"))
+            }
+        });
+
+        let message = Message::new("callback");
+        let signature = signer.sign(message).await?;
+        signature.verify(&PublicKey::from(&secret_key), &message)?;
+
+        let impostor = CallbackSigner::new(address, |message| async move {
+            Ok(Signature::sign(
+                &SecretKey::from_str(
+                    "0000000000000000000000000000000000000000000000000000000000000001",
+                )?,
+                &message,
+            ))
+        });
+        assert!(impostor.sign(message).await.is_err());
+
+        Ok(())
+    }
+}
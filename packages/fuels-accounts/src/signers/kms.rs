@@ -0,0 +1,268 @@
+//! Signers that delegate to a remote key management service.
+//!
+//! Such services usually only handle DER encoded secp256k1 keys and signatures, while the FuelVM
+//! expects a 64 byte uncompressed public key and a compact signature carrying the recovery id.
+//! [`KmsSigner`] bridges the two for any backend implementing [`KmsKey`].
+
+#[cfg(feature = "signer-aws-kms")]
+pub mod aws;
+#[cfg(feature = "signer-google-kms")]
+pub mod google;
+#[cfg(all(test, any(feature = "signer-google-kms", feature = "signer-vault")))]
+mod mock_service;
+#[cfg(feature = "signer-vault")]
+pub mod vault;
+
+use std::{fmt, sync::Arc};
+
+use async_trait::async_trait;
+use fuel_crypto::{Message, PublicKey, Signature};
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::{Bech32Address, FUEL_BECH32_HRP},
+        errors::{error, Result},
+    },
+};
+use k256::pkcs8::DecodePublicKey;
+
+use crate::provider::{retry_util, RetryConfig};
+
+/// A secp256k1 key stored in an external service which only exposes its public half and
+/// signing over precomputed digests.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait KmsKey: Send + Sync + 'static {
+    /// Identifies the key in error messages.
+    fn id(&self) -> &str;
+
+    async fn public_key(&self) -> Result<PublicKey>;
+
+    /// Signs the 32 byte `digest` and returns the DER encoded ECDSA signature.
+    async fn sign_digest(&self, digest: &Message) -> Result<Vec<u8>>;
+}
+
+/// A [`Signer`] backed by a [`KmsKey`]. The private key never leaves the service; only the
+/// transaction digest is sent out to be signed.
+///
+/// Use [`SignerAccount`](crate::signers::SignerAccount) to turn it into an
+/// [`Account`](crate::Account).
+pub struct KmsSigner<K> {
+    key: Arc<K>,
+    public_key: PublicKey,
+    address: Bech32Address,
+    retry_config: RetryConfig,
+}
+
+impl<K: KmsKey> KmsSigner<K> {
+    /// Fetches the public key once and derives the address from it.
+    pub async fn new(key: K) -> Result<Self> {
+        let public_key = key.public_key().await?;
+        let address = Bech32Address::new(FUEL_BECH32_HRP, public_key.hash());
+
+        Ok(Self {
+            key: Arc::new(key),
+            public_key,
+            address,
+            retry_config: Default::default(),
+        })
+    }
+
+    /// Retries failed signing requests according to `retry_config`.
+    pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+}
+
+impl<K> Clone for KmsSigner<K> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            public_key: self.public_key,
+            address: self.address.clone(),
+            retry_config: self.retry_config.clone(),
+        }
+    }
+}
+
+impl<K: KmsKey> fmt::Debug for KmsSigner<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KmsSigner")
+            .field("key", &self.key.id())
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<K: KmsKey> Signer for KmsSigner<K> {
+    /// Fails unless the signature of the service recovers the public key of the signer, e.g.
+    /// when the key was replaced in the service.
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let der = retry_util::retry(
+            || self.key.sign_digest(&message),
+            &self.retry_config,
+            |result| result.is_err(),
+        )
+        .await?;
+
+        signature_from_der(&der, &message, &self.public_key)
+    }
+
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
+
+/// Parses a DER encoded `SubjectPublicKeyInfo` holding a secp256k1 key.
+#[cfg(feature = "signer-aws-kms")]
+pub(crate) fn public_key_from_der(der: &[u8]) -> Result<PublicKey> {
+    let public_key = k256::PublicKey::from_public_key_der(der)
+        .map_err(|e| error!(Other, "invalid secp256k1 public key: {e}"))?;
+
+    Ok(public_key.into())
+}
+
+/// Parses a PEM encoded `SubjectPublicKeyInfo` holding a secp256k1 key.
+#[cfg(any(feature = "signer-google-kms", feature = "signer-vault"))]
+pub(crate) fn public_key_from_pem(pem: &str) -> Result<PublicKey> {
+    let public_key = k256::PublicKey::from_public_key_pem(pem)
+        .map_err(|e| error!(Other, "invalid secp256k1 public key: {e}"))?;
+
+    Ok(public_key.into())
+}
+
+/// Sends `request` and deserializes the JSON body of a successful response.
+#[cfg(any(feature = "signer-google-kms", feature = "signer-vault"))]
+pub(crate) async fn send_json_request<T: serde::de::DeserializeOwned>(
+    request: reqwest::RequestBuilder,
+    key_id: &str,
+) -> Result<T> {
+    let response = request
+        .send()
+        .await
+        .map_err(|e| error!(Other, "request for key `{key_id}` failed: {e}"))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(error!(
+            Other,
+            "request for key `{key_id}` failed with status `{status}`: {body}"
+        ));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| error!(Other, "invalid response for key `{key_id}`: {e}"))
+}
+
+/// Converts a DER encoded ECDSA signature of `message` into the compact form used by the FuelVM.
+///
+/// The signature is normalized to a low `s` value and the recovery id is found by checking which
+/// candidate recovers `public_key`.
+pub(crate) fn signature_from_der(
+    der: &[u8],
+    message: &Message,
+    public_key: &PublicKey,
+) -> Result<Signature> {
+    let signature = k256::ecdsa::Signature::from_der(der)
+        .map_err(|e| error!(Other, "invalid DER signature: {e}"))?;
+    let signature = signature.normalize_s().unwrap_or(signature);
+
+    let bytes = <[u8; Signature::LEN]>::from(signature.to_bytes());
+
+    [0u8, 1]
+        .into_iter()
+        .map(|recovery_id| {
+            let mut bytes = bytes;
+            bytes[32] |= recovery_id << 7;
+            Signature::from_bytes(bytes)
+        })
+        .find(|candidate| {
+            candidate
+                .recover(message)
+                .is_ok_and(|recovered| recovered == *public_key)
+        })
+        .ok_or_else(|| error!(Other, "signature does not match the expected public key"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        str::FromStr,
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    use fuel_crypto::SecretKey;
+    use k256::ecdsa::{signature::hazmat::PrehashSigner, SigningKey};
+
+    use super::*;
+    use crate::provider::Backoff;
+
+    /// Signs locally, failing the first `failures` requests.
+    struct LocalKey {
+        signing_key: SigningKey,
+        failures: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl KmsKey for LocalKey {
+        fn id(&self) -> &str {
+            "local"
+        }
+
+        async fn public_key(&self) -> Result<PublicKey> {
+            Ok(k256::PublicKey::from(self.signing_key.verifying_key()).into())
+        }
+
+        async fn sign_digest(&self, digest: &Message) -> Result<Vec<u8>> {
+            if self.failures.load(Ordering::SeqCst) > 0 {
+                self.failures.fetch_sub(1, Ordering::SeqCst);
+                return Err(error!(Other, "service unavailable"));
+            }
+
+            let signature: k256::ecdsa::Signature = self
+                .signing_key
+                .sign_prehash(digest.as_ref())
+                .expect("should sign");
+
+            Ok(signature.to_der().as_bytes().to_vec())
+        }
+    }
+
+    #[tokio::test]
+    async fn kms_signatures_match_local_signatures() -> Result<()> {
+        let secret_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?;
+        let key = LocalKey {
+            signing_key: SigningKey::from_slice(secret_key.as_ref()).expect("valid key"),
+            failures: AtomicUsize::new(2),
+        };
+
+        let signer = KmsSigner::new(key)
+            .await?
+            .with_retry_config(RetryConfig::new(3, Backoff::Fixed(Duration::ZERO))?);
+        assert_eq!(*signer.public_key(), PublicKey::from(&secret_key));
+
+        let message = Message::new("kms");
+        let signature = signer.sign(message).await?;
+
+        assert_eq!(signature, Signature::sign(&secret_key, &message));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+pub use aws_sdk_kms;
+use aws_sdk_kms::{
+    primitives::Blob,
+    types::{KeySpec, MessageType, SigningAlgorithmSpec},
+    Client,
+};
+use fuel_crypto::{Message, PublicKey};
+use fuels_core::types::errors::{error, Result};
+
+use super::{public_key_from_der, KmsKey, KmsSigner};
+
+/// A signer backed by an asymmetric `ECC_SECG_P256K1` key stored in AWS KMS.
+pub type AwsKmsSigner = KmsSigner<AwsKmsKey>;
+
+#[derive(Debug, Clone)]
+pub struct AwsKmsKey {
+    client: Client,
+    key_id: String,
+}
+
+impl AwsKmsKey {
+    pub fn new(key_id: impl Into<String>, client: &Client) -> Self {
+        Self {
+            client: client.clone(),
+            key_id: key_id.into(),
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl KmsKey for AwsKmsKey {
+    fn id(&self) -> &str {
+        &self.key_id
+    }
+
+    async fn public_key(&self) -> Result<PublicKey> {
+        let response = self
+            .client
+            .get_public_key()
+            .key_id(&self.key_id)
+            .send()
+            .await
+            .map_err(|e| {
+                error!(
+                    Other,
+                    "failed to fetch public key of `{}`: {e}", self.key_id
+                )
+            })?;
+
+        if response.key_spec() != Some(&KeySpec::EccSecgP256K1) {
+            return Err(error!(
+                Other,
+                "key `{}` is not an `ECC_SECG_P256K1` key, found: {:?}",
+                self.key_id,
+                response.key_spec()
+            ));
+        }
+
+        let der = response
+            .public_key()
+            .ok_or_else(|| error!(Other, "no public key returned for `{}`", self.key_id))?;
+
+        public_key_from_der(der.as_ref())
+    }
+
+    async fn sign_digest(&self, digest: &Message) -> Result<Vec<u8>> {
+        let response = self
+            .client
+            .sign()
+            .key_id(&self.key_id)
+            .message(Blob::new(digest.as_ref()))
+            .message_type(MessageType::Digest)
+            .signing_algorithm(SigningAlgorithmSpec::EcdsaSha256)
+            .send()
+            .await
+            .map_err(|e| error!(Other, "failed to sign with `{}`: {e}", self.key_id))?;
+
+        response
+            .signature
+            .map(Blob::into_inner)
+            .ok_or_else(|| error!(Other, "no signature returned for `{}`", self.key_id))
+    }
+}
@@ -0,0 +1,238 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use fuel_crypto::{Message, PublicKey};
+use fuels_core::types::errors::{error, Result};
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{public_key_from_pem, send_json_request, KmsKey, KmsSigner};
+
+const DEFAULT_ENDPOINT: &str = "https://cloudkms.googleapis.com";
+const SECP256K1_ALGORITHM: &str = "EC_SIGN_SECP256K1_SHA256";
+
+/// A signer backed by an `EC_SIGN_SECP256K1_SHA256` key version stored in Google Cloud KMS.
+pub type GoogleKmsSigner = KmsSigner<GoogleKmsKey>;
+
+/// Provides the OAuth2 bearer token sent along every request, e.g. from a service account or the
+/// metadata server. A plain `String` can be used for short-lived tokens.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait AccessTokenSource: Send + Sync + 'static {
+    async fn access_token(&self) -> Result<String>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl AccessTokenSource for String {
+    async fn access_token(&self) -> Result<String> {
+        Ok(self.clone())
+    }
+}
+
+#[derive(Clone)]
+pub struct GoogleKmsKey {
+    client: reqwest::Client,
+    endpoint: String,
+    key_version: String,
+    token_source: Arc<dyn AccessTokenSource>,
+}
+
+impl GoogleKmsKey {
+    /// `key_version` is the full resource name of the key version, i.e.
+    /// `projects/*/locations/*/keyRings/*/cryptoKeys/*/cryptoKeyVersions/*`.
+    pub fn new(key_version: impl Into<String>, token_source: impl AccessTokenSource) -> Self {
+        Self {
+            client: Default::default(),
+            endpoint: DEFAULT_ENDPOINT.to_string(),
+            key_version: key_version.into(),
+            token_source: Arc::new(token_source),
+        }
+    }
+
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    fn url(&self, suffix: &str) -> String {
+        format!(
+            "{}/v1/{}{suffix}",
+            self.endpoint.trim_end_matches('/'),
+            self.key_version
+        )
+    }
+}
+
+impl std::fmt::Debug for GoogleKmsKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GoogleKmsKey")
+            .field("endpoint", &self.endpoint)
+            .field("key_version", &self.key_version)
+            .finish()
+    }
+}
+
+#[derive(Deserialize)]
+struct PublicKeyResponse {
+    pem: String,
+    algorithm: String,
+}
+
+#[derive(Deserialize)]
+struct SignResponse {
+    signature: String,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl KmsKey for GoogleKmsKey {
+    fn id(&self) -> &str {
+        &self.key_version
+    }
+
+    async fn public_key(&self) -> Result<PublicKey> {
+        let token = self.token_source.access_token().await?;
+        let request = self.client.get(self.url("/publicKey")).bearer_auth(token);
+
+        let response: PublicKeyResponse = send_json_request(request, self.id()).await?;
+
+        if response.algorithm != SECP256K1_ALGORITHM {
+            return Err(error!(
+                Other,
+                "key `{}` is not an `{SECP256K1_ALGORITHM}` key, found: {}",
+                self.key_version,
+                response.algorithm
+            ));
+        }
+
+        public_key_from_pem(&response.pem)
+    }
+
+    async fn sign_digest(&self, digest: &Message) -> Result<Vec<u8>> {
+        let token = self.token_source.access_token().await?;
+        let request = self
+            .client
+            .post(self.url(":asymmetricSign"))
+            .bearer_auth(token)
+            .json(&json!({ "digest": { "sha256": STANDARD.encode(digest.as_ref()) } }));
+
+        let response: SignResponse = send_json_request(request, self.id()).await?;
+
+        STANDARD
+            .decode(response.signature)
+            .map_err(|e| error!(Other, "invalid signature encoding: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use fuel_crypto::{SecretKey, Signature};
+    use fuels_core::traits::Signer;
+    use k256::{
+        ecdsa::{signature::hazmat::PrehashSigner, SigningKey},
+        pkcs8::{EncodePublicKey, LineEnding},
+    };
+    use serde_json::Value;
+
+    use super::*;
+    use crate::signers::kms::mock_service::MockService;
+
+    const KEY_VERSION: &str =
+        "projects/fuel/locations/global/keyRings/ring/cryptoKeys/key/cryptoKeyVersions/1";
+
+    fn secret_key(seed: u8) -> SecretKey {
+        SecretKey::from_str(&format!("{seed:064x}")).expect("valid secret key")
+    }
+
+    /// A Cloud KMS exposing the public key of `public_seed` under `algorithm`, but signing with
+    /// the key of `signing_seed`.
+    async fn cloud_kms(algorithm: &'static str, public_seed: u8, signing_seed: u8) -> MockService {
+        MockService::start(move |path, body| {
+            if path.ends_with("/publicKey") {
+                let signing_key =
+                    SigningKey::from_slice(secret_key(public_seed).as_ref()).expect("valid key");
+                let pem = k256::PublicKey::from(signing_key.verifying_key())
+                    .to_public_key_pem(LineEnding::LF)
+                    .expect("valid key");
+
+                return json!({ "pem": pem, "algorithm": algorithm });
+            }
+
+            let digest = STANDARD
+                .decode(body["digest"]["sha256"].as_str().unwrap_or_default())
+                .expect("base64 digest");
+            let signature: k256::ecdsa::Signature =
+                SigningKey::from_slice(secret_key(signing_seed).as_ref())
+                    .expect("valid key")
+                    .sign_prehash(&digest)
+                    .expect("should sign");
+
+            json!({ "signature": STANDARD.encode(signature.to_der().as_bytes()) })
+        })
+        .await
+    }
+
+    fn key(service: &MockService) -> GoogleKmsKey {
+        GoogleKmsKey::new(KEY_VERSION, "token".to_string()).with_endpoint(service.url())
+    }
+
+    #[tokio::test]
+    async fn signs_through_the_kms() -> Result<()> {
+        let service = cloud_kms(SECP256K1_ALGORITHM, 1, 1).await;
+
+        let signer = GoogleKmsSigner::new(key(&service)).await?;
+        assert_eq!(*signer.public_key(), PublicKey::from(&secret_key(1)));
+
+        let message = Message::new("google");
+        assert_eq!(
+            signer.sign(message).await?,
+            Signature::sign(&secret_key(1), &message)
+        );
+
+        let (path, body) = service.requests().pop().expect("a sign request");
+        assert_eq!(path, format!("/v1/{KEY_VERSION}:asymmetricSign"));
+        assert_eq!(
+            body["digest"]["sha256"],
+            Value::from(STANDARD.encode(message.as_ref()))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_other_algorithms() {
+        let service = cloud_kms("EC_SIGN_P256_SHA256", 1, 1).await;
+
+        let err = GoogleKmsSigner::new(key(&service))
+            .await
+            .expect_err("should fail");
+
+        assert!(err.to_string().contains("EC_SIGN_P256_SHA256"));
+    }
+
+    #[tokio::test]
+    async fn rejects_signatures_of_another_key() -> Result<()> {
+        let service = cloud_kms(SECP256K1_ALGORITHM, 1, 2).await;
+
+        let signer = GoogleKmsSigner::new(key(&service)).await?;
+        let err = signer
+            .sign(Message::new("google"))
+            .await
+            .expect_err("should fail");
+
+        assert!(err
+            .to_string()
+            .contains("signature does not match the expected public key"));
+
+        Ok(())
+    }
+}
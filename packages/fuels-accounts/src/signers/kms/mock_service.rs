@@ -0,0 +1,83 @@
+//! An HTTP service standing in for the key management services in tests.
+
+use std::sync::{Arc, Mutex};
+
+use http_body_util::{BodyExt, Full};
+use hyper::{
+    body::{Bytes, Incoming},
+    header::CONTENT_TYPE,
+    server::conn::http1,
+    service::service_fn,
+    Request, Response,
+};
+use hyper_util::rt::TokioIo;
+use serde_json::Value;
+use tokio::net::TcpListener;
+
+type Respond = dyn Fn(&str, &Value) -> Value + Send + Sync;
+
+/// Answers every request with the JSON returned by `respond`, given the path and the JSON body
+/// of the request, and records the requests.
+pub(crate) struct MockService {
+    url: String,
+    requests: Arc<Mutex<Vec<(String, Value)>>>,
+}
+
+impl MockService {
+    pub async fn start(respond: impl Fn(&str, &Value) -> Value + Send + Sync + 'static) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("should bind");
+        let url = format!("http://{}", listener.local_addr().expect("bound address"));
+
+        let requests = Arc::new(Mutex::new(vec![]));
+        tokio::spawn(serve(listener, Arc::new(respond), requests.clone()));
+
+        Self { url, requests }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn requests(&self) -> Vec<(String, Value)> {
+        self.requests.lock().expect("poisoned lock").clone()
+    }
+}
+
+async fn serve(
+    listener: TcpListener,
+    respond: Arc<Respond>,
+    requests: Arc<Mutex<Vec<(String, Value)>>>,
+) {
+    loop {
+        let Ok((stream, _)) = listener.accept().await else {
+            continue;
+        };
+
+        let respond = respond.clone();
+        let requests = requests.clone();
+        tokio::spawn(async move {
+            let service = service_fn(|request| handle_request(request, &*respond, &requests));
+            let _ = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await;
+        });
+    }
+}
+
+async fn handle_request(
+    request: Request<Incoming>,
+    respond: &Respond,
+    requests: &Mutex<Vec<(String, Value)>>,
+) -> hyper::Result<Response<Full<Bytes>>> {
+    let path = request.uri().path().to_string();
+    let body = request.into_body().collect().await?.to_bytes();
+    let body = serde_json::from_slice(&body).unwrap_or(Value::Null);
+
+    let response = respond(&path, &body);
+    requests.lock().expect("poisoned lock").push((path, body));
+
+    Ok(Response::builder()
+        .header(CONTENT_TYPE, "application/json")
+        .body(Full::new(Bytes::from(response.to_string())))
+        .expect("valid response"))
+}
@@ -0,0 +1,317 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use fuel_crypto::{Message, PublicKey};
+use fuels_core::types::errors::{error, Result};
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{public_key_from_pem, send_json_request, KmsKey, KmsSigner};
+
+/// A signer backed by a key of a HashiCorp Vault transit secrets engine.
+///
+/// The key must be a secp256k1 key. The transit engine shipped with Vault only offers NIST
+/// curves and Ed25519, so this requires an engine exposing secp256k1 keys through the transit
+/// API and accepting `prehashed` input, e.g. a managed key backed by an HSM.
+///
+/// Signatures are made with the key version whose public key the signer was created with, so
+/// that rotating the key doesn't change the address of the signer.
+pub type VaultTransitSigner = KmsSigner<VaultTransitKey>;
+
+#[derive(Clone)]
+pub struct VaultTransitKey {
+    client: reqwest::Client,
+    address: String,
+    mount: String,
+    key_name: String,
+    token: String,
+    key_version: OnceLock<u64>,
+}
+
+impl VaultTransitKey {
+    /// Uses the transit engine mounted at `transit/` on the Vault server at `address`.
+    pub fn new(
+        address: impl Into<String>,
+        key_name: impl Into<String>,
+        token: impl Into<String>,
+    ) -> Self {
+        Self {
+            client: Default::default(),
+            address: address.into(),
+            mount: "transit".to_string(),
+            key_name: key_name.into(),
+            token: token.into(),
+            key_version: OnceLock::new(),
+        }
+    }
+
+    pub fn with_mount(mut self, mount: impl Into<String>) -> Self {
+        self.mount = mount.into();
+        self
+    }
+
+    /// Uses `key_version` instead of the latest version of the key when the signer is created.
+    pub fn with_key_version(self, key_version: u64) -> Self {
+        Self {
+            key_version: OnceLock::from(key_version),
+            ..self
+        }
+    }
+
+    /// The version signing digests, known once the public key was fetched.
+    pub fn key_version(&self) -> Option<u64> {
+        self.key_version.get().copied()
+    }
+
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    fn url(&self, operation: &str) -> String {
+        format!(
+            "{}/v1/{}/{operation}/{}",
+            self.address.trim_end_matches('/'),
+            self.mount.trim_matches('/'),
+            self.key_name
+        )
+    }
+}
+
+impl std::fmt::Debug for VaultTransitKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VaultTransitKey")
+            .field("address", &self.address)
+            .field("mount", &self.mount)
+            .field("key_name", &self.key_name)
+            .field("key_version", &self.key_version())
+            .finish()
+    }
+}
+
+#[derive(Deserialize)]
+struct VaultResponse<T> {
+    data: T,
+}
+
+#[derive(Deserialize)]
+struct KeyData {
+    latest_version: u64,
+    keys: HashMap<String, KeyVersion>,
+}
+
+#[derive(Deserialize)]
+struct KeyVersion {
+    public_key: String,
+}
+
+#[derive(Deserialize)]
+struct SignatureData {
+    signature: String,
+}
+
+/// Splits a transit signature into the key version in its `vault:v<version>:` prefix and the
+/// decoded DER payload.
+fn decode_transit_signature(signature: &str) -> Result<(u64, Vec<u8>)> {
+    let (version, encoded) = signature
+        .strip_prefix("vault:v")
+        .and_then(|rest| rest.split_once(':'))
+        .and_then(|(version, encoded)| Some((version.parse().ok()?, encoded)))
+        .ok_or_else(|| error!(Other, "unexpected transit signature format: `{signature}`"))?;
+
+    let der = STANDARD
+        .decode(encoded)
+        .map_err(|e| error!(Other, "invalid signature encoding: {e}"))?;
+
+    Ok((version, der))
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl KmsKey for VaultTransitKey {
+    fn id(&self) -> &str {
+        &self.key_name
+    }
+
+    async fn public_key(&self) -> Result<PublicKey> {
+        let request = self
+            .client
+            .get(self.url("keys"))
+            .header("X-Vault-Token", &self.token);
+
+        let VaultResponse { data } =
+            send_json_request::<VaultResponse<KeyData>>(request, self.id()).await?;
+
+        let version = *self.key_version.get_or_init(|| data.latest_version);
+        let key = data.keys.get(&version.to_string()).ok_or_else(|| {
+            error!(
+                Other,
+                "no public key found for version {version} of `{}`", self.key_name
+            )
+        })?;
+
+        public_key_from_pem(&key.public_key)
+    }
+
+    async fn sign_digest(&self, digest: &Message) -> Result<Vec<u8>> {
+        let version = self.key_version().ok_or_else(|| {
+            error!(
+                Other,
+                "the public key of `{}` must be fetched before signing", self.key_name
+            )
+        })?;
+
+        let request = self
+            .client
+            .post(self.url("sign"))
+            .header("X-Vault-Token", &self.token)
+            .json(&json!({
+                "input": STANDARD.encode(digest.as_ref()),
+                "prehashed": true,
+                "hash_algorithm": "sha2-256",
+                "marshaling_algorithm": "asn1",
+                "key_version": version,
+            }));
+
+        let VaultResponse { data } =
+            send_json_request::<VaultResponse<SignatureData>>(request, self.id()).await?;
+
+        let (signed_with, der) = decode_transit_signature(&data.signature)?;
+        if signed_with != version {
+            return Err(error!(
+                Other,
+                "`{}` signed with version {signed_with} instead of {version}", self.key_name
+            ));
+        }
+
+        Ok(der)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use fuel_crypto::{SecretKey, Signature};
+    use fuels_core::traits::Signer;
+    use k256::{
+        ecdsa::{signature::hazmat::PrehashSigner, SigningKey},
+        pkcs8::{EncodePublicKey, LineEnding},
+    };
+    use serde_json::Value;
+
+    use super::*;
+    use crate::signers::kms::mock_service::MockService;
+
+    fn secret_key(version: u64) -> SecretKey {
+        SecretKey::from_str(&format!("{version:064x}")).expect("valid secret key")
+    }
+
+    fn signing_key(version: u64) -> SigningKey {
+        SigningKey::from_slice(secret_key(version).as_ref()).expect("valid key")
+    }
+
+    /// A transit engine holding versions 1 to `latest_version` of a key, each signing with its own
+    /// secret key.
+    async fn transit_engine(latest_version: u64) -> MockService {
+        MockService::start(move |path, body| {
+            if path.ends_with("/keys/fuel") {
+                let keys: HashMap<_, _> = (1..=latest_version)
+                    .map(|version| {
+                        let pem = k256::PublicKey::from(signing_key(version).verifying_key())
+                            .to_public_key_pem(LineEnding::LF)
+                            .expect("valid key");
+
+                        (version.to_string(), json!({ "public_key": pem }))
+                    })
+                    .collect();
+
+                return json!({ "data": { "latest_version": latest_version, "keys": keys } });
+            }
+
+            let version = body["key_version"].as_u64().unwrap_or(latest_version);
+            let digest = STANDARD
+                .decode(body["input"].as_str().unwrap_or_default())
+                .expect("base64 input");
+            let signature: k256::ecdsa::Signature = signing_key(version)
+                .sign_prehash(&digest)
+                .expect("should sign");
+            let encoded = STANDARD.encode(signature.to_der().as_bytes());
+
+            json!({ "data": { "signature": format!("vault:v{version}:{encoded}") } })
+        })
+        .await
+    }
+
+    #[test]
+    fn transit_signatures_are_decoded() -> Result<()> {
+        assert_eq!(
+            decode_transit_signature("vault:v12:AQID")?,
+            (12, vec![1, 2, 3])
+        );
+        assert!(decode_transit_signature("AQID").is_err());
+        assert!(decode_transit_signature("vault:vlatest:AQID").is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn signs_with_the_latest_version_when_created() -> Result<()> {
+        let engine = transit_engine(2).await;
+
+        let key = VaultTransitKey::new(engine.url(), "fuel", "token");
+        let signer = VaultTransitSigner::new(key).await?;
+        assert_eq!(signer.key().key_version(), Some(2));
+        assert_eq!(*signer.public_key(), PublicKey::from(&secret_key(2)));
+
+        let message = Message::new("vault");
+        assert_eq!(
+            signer.sign(message).await?,
+            Signature::sign(&secret_key(2), &message)
+        );
+
+        let (path, body) = engine.requests().pop().expect("a sign request");
+        assert_eq!(path, "/v1/transit/sign/fuel");
+        assert_eq!(body["key_version"], Value::from(2));
+        assert_eq!(body["prehashed"], Value::from(true));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pinned_versions_survive_rotations() -> Result<()> {
+        let engine = transit_engine(3).await;
+
+        let key = VaultTransitKey::new(engine.url(), "fuel", "token").with_key_version(1);
+        let signer = VaultTransitSigner::new(key).await?;
+        assert_eq!(*signer.public_key(), PublicKey::from(&secret_key(1)));
+
+        let message = Message::new("vault");
+        assert_eq!(
+            signer.sign(message).await?,
+            Signature::sign(&secret_key(1), &message)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn signatures_of_another_version_are_rejected() -> Result<()> {
+        // Ignores the requested version, like an engine without support for `key_version`.
+        let engine =
+            MockService::start(|_, _| json!({ "data": { "signature": "vault:v2:AQID" } })).await;
+
+        let key = VaultTransitKey::new(engine.url(), "fuel", "token").with_key_version(1);
+        let err = key
+            .sign_digest(&Message::new("vault"))
+            .await
+            .expect_err("should fail");
+
+        assert!(err
+            .to_string()
+            .contains("signed with version 2 instead of 1"));
+
+        Ok(())
+    }
+}
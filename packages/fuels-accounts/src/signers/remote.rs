@@ -0,0 +1,354 @@
+use std::{fmt, sync::Arc};
+
+use async_trait::async_trait;
+use fuel_crypto::{Message, PublicKey, Signature};
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::{Bech32Address, FUEL_BECH32_HRP},
+        errors::{error, Error, Result},
+    },
+};
+
+use crate::derivation::DerivationPath;
+
+/// Requests sent by the host to the device or service holding the key of a
+/// [`RemoteDigestSigner`]. They are not the wire format of any vendor: a [`RemoteTransport`]
+/// translates them to the protocol of the device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteRequest {
+    /// Ask for the public key at `path`. If `show_display` is set, the device shows the
+    /// corresponding address and waits for the user to confirm it.
+    GetPublicKey {
+        path: Vec<u32>,
+        show_display: bool,
+    },
+    /// Ask the device to sign a 32-byte digest with the key at `path`.
+    SignDigest {
+        path: Vec<u32>,
+        digest: [u8; 32],
+    },
+    /// Acknowledge a [`RemoteResponse::ButtonRequest`].
+    ButtonAck,
+    PinMatrixAck {
+        pin: String,
+    },
+    PassphraseAck {
+        passphrase: String,
+    },
+}
+
+/// Responses the device or service may reply with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteResponse {
+    PublicKey(PublicKey),
+    Signature(Signature),
+    /// The device is waiting for the user to confirm (or reject) the operation on its screen.
+    ButtonRequest,
+    PinMatrixRequest,
+    PassphraseRequest,
+    Failure(String),
+}
+
+/// Moves requests between the host and the device, translating them to and from its protocol.
+/// Every call sends one request and returns the device's next response.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait RemoteTransport: Send + Sync + 'static {
+    async fn call(&self, request: RemoteRequest) -> Result<RemoteResponse>;
+}
+
+/// Handles the interactive parts of a device session.
+///
+/// The default implementation only supports devices that are already unlocked and have their
+/// PIN and passphrase entered on the device itself.
+pub trait RemotePrompter: Send + Sync + 'static {
+    /// Called every time the device waits for a physical confirmation.
+    fn on_button_request(&self) {}
+
+    /// Returns the PIN, scrambled according to the matrix shown on the device.
+    fn pin(&self) -> Result<String> {
+        Err(error!(
+            Other,
+            "remote device requested a PIN but no prompter was configured"
+        ))
+    }
+
+    fn passphrase(&self) -> Result<String> {
+        Err(error!(
+            Other,
+            "remote device requested a passphrase but no prompter was configured"
+        ))
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DevicePrompter;
+
+impl RemotePrompter for DevicePrompter {}
+
+/// A [`Signer`] whose key never leaves a remote device or service, e.g. a hardware wallet, that
+/// signs 32-byte digests with the key at a derivation path.
+///
+/// No transport ships with the SDK: implement [`RemoteTransport`] for the device to use.
+#[derive(Clone)]
+pub struct RemoteDigestSigner {
+    transport: Arc<dyn RemoteTransport>,
+    prompter: Arc<dyn RemotePrompter>,
+    derivation_path: DerivationPath,
+    public_key: PublicKey,
+    address: Bech32Address,
+}
+
+impl RemoteDigestSigner {
+    /// Connects to the device and loads the public key found at `derivation_path`.
+    pub async fn connect(
+        transport: impl RemoteTransport,
+        derivation_path: DerivationPath,
+    ) -> Result<Self> {
+        Self::connect_with_prompter(transport, derivation_path, DevicePrompter).await
+    }
+
+    pub async fn connect_with_prompter(
+        transport: impl RemoteTransport,
+        derivation_path: DerivationPath,
+        prompter: impl RemotePrompter,
+    ) -> Result<Self> {
+        let transport: Arc<dyn RemoteTransport> = Arc::new(transport);
+        let prompter: Arc<dyn RemotePrompter> = Arc::new(prompter);
+
+        let public_key =
+            Self::fetch_public_key(&*transport, &*prompter, &derivation_path, false).await?;
+        let address = Bech32Address::new(FUEL_BECH32_HRP, public_key.hash());
+
+        Ok(Self {
+            transport,
+            prompter,
+            derivation_path,
+            public_key,
+            address,
+        })
+    }
+
+    pub fn derivation_path(&self) -> &DerivationPath {
+        &self.derivation_path
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Shows the address on the device screen and waits for the user to confirm it. Fails if the
+    /// user rejects it or if the device reports a different key than the one loaded on connect.
+    pub async fn confirm_address(&self) -> Result<()> {
+        let public_key = Self::fetch_public_key(
+            &*self.transport,
+            &*self.prompter,
+            &self.derivation_path,
+            true,
+        )
+        .await?;
+
+        if public_key != self.public_key {
+            return Err(error!(
+                Other,
+                "remote device reported a different public key for path `{}`", self.derivation_path
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_public_key(
+        transport: &dyn RemoteTransport,
+        prompter: &dyn RemotePrompter,
+        derivation_path: &DerivationPath,
+        show_display: bool,
+    ) -> Result<PublicKey> {
+        let request = RemoteRequest::GetPublicKey {
+            path: derivation_path.indices().to_vec(),
+            show_display,
+        };
+
+        match Self::exchange(transport, prompter, request).await? {
+            RemoteResponse::PublicKey(public_key) => Ok(public_key),
+            response => Err(unexpected_response(&response)),
+        }
+    }
+
+    /// Sends `request` and drives the confirmation flow until the device answers with a final
+    /// response.
+    async fn exchange(
+        transport: &dyn RemoteTransport,
+        prompter: &dyn RemotePrompter,
+        request: RemoteRequest,
+    ) -> Result<RemoteResponse> {
+        let mut response = transport.call(request).await?;
+
+        loop {
+            let follow_up = match response {
+                RemoteResponse::ButtonRequest => {
+                    prompter.on_button_request();
+                    RemoteRequest::ButtonAck
+                }
+                RemoteResponse::PinMatrixRequest => RemoteRequest::PinMatrixAck {
+                    pin: prompter.pin()?,
+                },
+                RemoteResponse::PassphraseRequest => RemoteRequest::PassphraseAck {
+                    passphrase: prompter.passphrase()?,
+                },
+                RemoteResponse::Failure(reason) => {
+                    return Err(error!(Other, "remote device failure: {reason}"))
+                }
+                response => return Ok(response),
+            };
+
+            response = transport.call(follow_up).await?;
+        }
+    }
+}
+
+fn unexpected_response(response: &RemoteResponse) -> Error {
+    error!(
+        Other,
+        "unexpected response from remote device: {response:?}"
+    )
+}
+
+impl fmt::Debug for RemoteDigestSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteDigestSigner")
+            .field("derivation_path", &self.derivation_path)
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for RemoteDigestSigner {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let request = RemoteRequest::SignDigest {
+            path: self.derivation_path.indices().to_vec(),
+            digest: *message,
+        };
+
+        let signature = match Self::exchange(&*self.transport, &*self.prompter, request).await? {
+            RemoteResponse::Signature(signature) => signature,
+            response => return Err(unexpected_response(&response)),
+        };
+
+        signature.verify(&self.public_key, &message).map_err(|_| {
+            error!(
+                Other,
+                "remote device returned a signature that does not match its public key"
+            )
+        })?;
+
+        Ok(signature)
+    }
+
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, sync::Mutex};
+
+    use fuel_crypto::SecretKey;
+
+    use super::*;
+
+    struct MockDevice {
+        secret_key: SecretKey,
+        pending: Mutex<Option<RemoteRequest>>,
+        received: Mutex<Vec<RemoteRequest>>,
+    }
+
+    impl MockDevice {
+        fn new(secret_key: SecretKey) -> Self {
+            Self {
+                secret_key,
+                pending: Default::default(),
+                received: Default::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl RemoteTransport for Arc<MockDevice> {
+        async fn call(&self, request: RemoteRequest) -> Result<RemoteResponse> {
+            self.received.lock().unwrap().push(request.clone());
+
+            let response = match request {
+                RemoteRequest::ButtonAck => {
+                    match self
+                        .pending
+                        .lock()
+                        .unwrap()
+                        .take()
+                        .expect("pending request")
+                    {
+                        RemoteRequest::SignDigest { digest, .. } => RemoteResponse::Signature(
+                            Signature::sign(&self.secret_key, &Message::from_bytes(digest)),
+                        ),
+                        _ => RemoteResponse::PublicKey(PublicKey::from(&self.secret_key)),
+                    }
+                }
+                RemoteRequest::GetPublicKey {
+                    show_display: false,
+                    ..
+                } => RemoteResponse::PublicKey(PublicKey::from(&self.secret_key)),
+                request => {
+                    *self.pending.lock().unwrap() = Some(request);
+                    RemoteResponse::ButtonRequest
+                }
+            };
+
+            Ok(response)
+        }
+    }
+
+    #[tokio::test]
+    async fn signs_after_device_confirmation() -> Result<()> {
+        let secret_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?;
+        let device = Arc::new(MockDevice::new(secret_key));
+
+        let signer = RemoteDigestSigner::connect(device.clone(), DerivationPath::default()).await?;
+        signer.confirm_address().await?;
+
+        let message = Message::new("remote");
+        let signature = signer.sign(message).await?;
+
+        assert_eq!(signature, Signature::sign(&secret_key, &message));
+        assert_eq!(signer.address().hash(), PublicKey::from(&secret_key).hash());
+        assert_eq!(
+            device.received.lock().unwrap().last(),
+            Some(&RemoteRequest::ButtonAck)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pin_request_without_prompter_fails() {
+        struct LockedDevice;
+
+        #[async_trait]
+        impl RemoteTransport for LockedDevice {
+            async fn call(&self, _: RemoteRequest) -> Result<RemoteResponse> {
+                Ok(RemoteResponse::PinMatrixRequest)
+            }
+        }
+
+        let err = RemoteDigestSigner::connect(LockedDevice, DerivationPath::default())
+            .await
+            .expect_err("should fail");
+
+        assert!(err.to_string().contains("requested a PIN"));
+    }
+}
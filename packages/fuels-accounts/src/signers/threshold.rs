@@ -0,0 +1,244 @@
+//! Experimental threshold signing.
+//!
+//! A [`ThresholdSigner`] asks a group of co-signers for partial signatures and combines them into
+//! a single witness, so that no single party ever holds the whole key.
+//!
+//! The FuelVM verifies secp256k1 ECDSA signatures, hence the aggregated witness must be an ECDSA
+//! signature for the group's public key. The SDK does not ship an MPC protocol: the partial
+//! signature format and the way shares are combined are defined by the [`CoSigner`] and
+//! [`SignatureAggregator`] implementations (e.g. bindings to a threshold ECDSA library).
+//! Schnorr based schemes such as FROST can only be verified by predicates.
+
+use std::{fmt, sync::Arc};
+
+use async_trait::async_trait;
+use fuel_crypto::{Message, PublicKey, Signature};
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::{Bech32Address, FUEL_BECH32_HRP},
+        errors::{error, Result},
+    },
+};
+use futures::future::join_all;
+use itertools::{Either, Itertools};
+
+/// A co-signer's contribution to a threshold signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialSignature {
+    pub signer_id: String,
+    pub data: Vec<u8>,
+}
+
+/// Reaches a single co-signer, e.g. over HTTP or a message queue.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait CoSigner: Send + Sync + 'static {
+    fn id(&self) -> &str;
+
+    async fn partial_sign(&self, message: &Message) -> Result<PartialSignature>;
+}
+
+/// Combines at least `threshold` partial signatures into a signature for the group public key.
+pub trait SignatureAggregator: Send + Sync + 'static {
+    fn aggregate(&self, message: &Message, partials: &[PartialSignature]) -> Result<Signature>;
+}
+
+/// An experimental [`Signer`] producing witnesses from `threshold`-of-`n` co-signers.
+#[derive(Clone)]
+pub struct ThresholdSigner {
+    public_key: PublicKey,
+    address: Bech32Address,
+    threshold: usize,
+    co_signers: Vec<Arc<dyn CoSigner>>,
+    aggregator: Arc<dyn SignatureAggregator>,
+}
+
+impl ThresholdSigner {
+    /// `public_key` is the group's public key, which determines the signer's address.
+    pub fn new(
+        public_key: PublicKey,
+        threshold: usize,
+        aggregator: impl SignatureAggregator,
+    ) -> Result<Self> {
+        if threshold == 0 {
+            return Err(error!(Other, "`threshold` must be greater than `0`"));
+        }
+
+        Ok(Self {
+            address: Bech32Address::new(FUEL_BECH32_HRP, public_key.hash()),
+            public_key,
+            threshold,
+            co_signers: vec![],
+            aggregator: Arc::new(aggregator),
+        })
+    }
+
+    pub fn with_co_signer(mut self, co_signer: impl CoSigner) -> Self {
+        self.co_signers.push(Arc::new(co_signer));
+        self
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Requests partial signatures from all co-signers concurrently. Fails if fewer than
+    /// `threshold` of them answered.
+    async fn collect_partials(&self, message: &Message) -> Result<Vec<PartialSignature>> {
+        let responses = join_all(self.co_signers.iter().map(|co_signer| async move {
+            co_signer
+                .partial_sign(message)
+                .await
+                .map_err(|e| format!("`{}`: {e}", co_signer.id()))
+        }))
+        .await;
+
+        let (partials, failures): (Vec<_>, Vec<_>) =
+            responses
+                .into_iter()
+                .partition_map(|response| match response {
+                    Ok(partial) => Either::Left(partial),
+                    Err(failure) => Either::Right(failure),
+                });
+
+        if partials.len() < self.threshold {
+            return Err(error!(
+                Other,
+                "collected {} partial signatures but {} are required. Failures: [{}]",
+                partials.len(),
+                self.threshold,
+                failures.join(", ")
+            ));
+        }
+
+        Ok(partials)
+    }
+}
+
+impl fmt::Debug for ThresholdSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThresholdSigner")
+            .field("address", &self.address)
+            .field("threshold", &self.threshold)
+            .field(
+                "co_signers",
+                &self.co_signers.iter().map(|c| c.id()).collect_vec(),
+            )
+            .finish()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for ThresholdSigner {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let partials = self.collect_partials(&message).await?;
+        let signature = self.aggregator.aggregate(&message, &partials)?;
+
+        signature.verify(&self.public_key, &message).map_err(|_| {
+            error!(
+                Other,
+                "aggregated signature is not valid for the group public key"
+            )
+        })?;
+
+        Ok(signature)
+    }
+
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use fuel_crypto::SecretKey;
+
+    use super::*;
+
+    fn group_key() -> SecretKey {
+        SecretKey::from_str("5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1")
+            .expect("valid key")
+    }
+
+    struct MockCoSigner {
+        id: &'static str,
+        online: bool,
+    }
+
+    #[async_trait]
+    impl CoSigner for MockCoSigner {
+        fn id(&self) -> &str {
+            self.id
+        }
+
+        async fn partial_sign(&self, _: &Message) -> Result<PartialSignature> {
+            if !self.online {
+                return Err(error!(Other, "offline"));
+            }
+
+            Ok(PartialSignature {
+                signer_id: self.id.to_string(),
+                data: vec![],
+            })
+        }
+    }
+
+    /// Stands in for the MPC protocol by signing with the group key once enough shares arrived.
+    struct MockAggregator;
+
+    impl SignatureAggregator for MockAggregator {
+        fn aggregate(&self, message: &Message, _: &[PartialSignature]) -> Result<Signature> {
+            Ok(Signature::sign(&group_key(), message))
+        }
+    }
+
+    fn signer(online: [bool; 3]) -> Result<ThresholdSigner> {
+        let public_key = PublicKey::from(&group_key());
+
+        Ok(ThresholdSigner::new(public_key, 2, MockAggregator)?
+            .with_co_signer(MockCoSigner {
+                id: "a",
+                online: online[0],
+            })
+            .with_co_signer(MockCoSigner {
+                id: "b",
+                online: online[1],
+            })
+            .with_co_signer(MockCoSigner {
+                id: "c",
+                online: online[2],
+            }))
+    }
+
+    #[tokio::test]
+    async fn signs_when_threshold_is_reached() -> Result<()> {
+        let message = Message::new("threshold");
+
+        let signature = signer([true, false, true])?.sign(message).await?;
+
+        assert_eq!(signature, Signature::sign(&group_key(), &message));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_below_threshold() -> Result<()> {
+        let err = signer([true, false, false])?
+            .sign(Message::new("threshold"))
+            .await
+            .expect_err("should fail");
+
+        assert!(err.to_string().contains("2 are required"));
+        assert!(err.to_string().contains("`b`: offline"));
+
+        Ok(())
+    }
+}
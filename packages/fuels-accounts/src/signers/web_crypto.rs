@@ -0,0 +1,209 @@
+//! Signers for Rust apps running in the browser.
+//!
+//! WebCrypto has no support for secp256k1, the curve used by the FuelVM, so the signing key
+//! cannot be a WebCrypto key itself. Instead it is encrypted by a [`KeyStore`] with a key that
+//! can't be exported, e.g. a non-extractable WebCrypto AES key, and is only decrypted in wasm
+//! memory, for the duration of a signature. It is never handed to JavaScript.
+
+#[cfg(target_arch = "wasm32")]
+pub mod indexed_db;
+
+use std::fmt;
+
+use async_trait::async_trait;
+use fuel_crypto::{Message, PublicKey, SecretKey, Signature};
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::{Bech32Address, FUEL_BECH32_HRP},
+        errors::{error, Result},
+    },
+};
+use rand::rngs::OsRng;
+use zeroize::Zeroizing;
+
+/// Persists secret keys encrypted, under a name.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait KeyStore: Send + Sync + 'static {
+    /// Encrypts `secret` and stores it as `name`, replacing any secret already stored as `name`.
+    async fn store(&self, name: &str, secret: &[u8]) -> Result<()>;
+
+    /// Loads and decrypts the secret stored as `name`.
+    async fn load(&self, name: &str) -> Result<Option<Zeroizing<Vec<u8>>>>;
+}
+
+/// A [`Signer`] whose secret key is kept encrypted in a [`KeyStore`].
+///
+/// ```ignore
+/// let store = IndexedDbKeyStore::new("my-dapp");
+///
+/// let signer = match WebCryptoSigner::load(store.clone(), "main").await? {
+///     Some(signer) => signer,
+///     None => WebCryptoSigner::generate(store, "main").await?,
+/// };
+/// ```
+pub struct WebCryptoSigner<S> {
+    store: S,
+    name: String,
+    public_key: PublicKey,
+    address: Bech32Address,
+}
+
+impl<S: KeyStore> WebCryptoSigner<S> {
+    /// Generates a new secret key and stores it as `name`.
+    pub async fn generate(store: S, name: impl Into<String>) -> Result<Self> {
+        Self::import(store, name, SecretKey::random(&mut OsRng)).await
+    }
+
+    /// Stores `secret_key` as `name`, e.g. to move an existing wallet into the browser.
+    pub async fn import(store: S, name: impl Into<String>, secret_key: SecretKey) -> Result<Self> {
+        let name = name.into();
+        store.store(&name, &secret_key[..]).await?;
+
+        Ok(Self::new(store, name, &secret_key))
+    }
+
+    /// Loads the secret key stored as `name`, if any.
+    pub async fn load(store: S, name: impl Into<String>) -> Result<Option<Self>> {
+        let name = name.into();
+        let Some(secret_key) = load_secret_key(&store, &name).await? else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self::new(store, name, &secret_key)))
+    }
+
+    fn new(store: S, name: String, secret_key: &SecretKey) -> Self {
+        let public_key = PublicKey::from(secret_key);
+        let address = Bech32Address::new(FUEL_BECH32_HRP, public_key.hash());
+
+        Self {
+            store,
+            name,
+            public_key,
+            address,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+}
+
+async fn load_secret_key(store: &impl KeyStore, name: &str) -> Result<Option<SecretKey>> {
+    let Some(secret) = store.load(name).await? else {
+        return Ok(None);
+    };
+
+    let secret_key = SecretKey::try_from(secret.as_slice()).map_err(|_| {
+        error!(
+            Other,
+            "key store returned an invalid secret key for `{name}`"
+        )
+    })?;
+
+    Ok(Some(secret_key))
+}
+
+impl<S> fmt::Debug for WebCryptoSigner<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebCryptoSigner")
+            .field("name", &self.name)
+            .field("address", &self.address)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<S: KeyStore> Signer for WebCryptoSigner<S> {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let secret_key = load_secret_key(&self.store, &self.name)
+            .await?
+            .ok_or_else(|| {
+                error!(
+                    Other,
+                    "secret key `{}` was removed from the store", self.name
+                )
+            })?;
+
+        if PublicKey::from(&secret_key) != self.public_key {
+            return Err(error!(
+                Other,
+                "secret key `{}` was replaced in the store", self.name
+            ));
+        }
+
+        Ok(Signature::sign(&secret_key, &message))
+    }
+
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Mutex};
+
+    use super::*;
+
+    /// Stores the secrets XORed with a key it never exposes, standing in for WebCrypto.
+    #[derive(Default)]
+    struct MockStore {
+        secrets: Mutex<HashMap<String, Vec<u8>>>,
+    }
+
+    const MASK: u8 = 0x5a;
+
+    #[async_trait]
+    impl KeyStore for MockStore {
+        async fn store(&self, name: &str, secret: &[u8]) -> Result<()> {
+            let sealed = secret.iter().map(|byte| byte ^ MASK).collect();
+            self.secrets
+                .lock()
+                .unwrap()
+                .insert(name.to_string(), sealed);
+
+            Ok(())
+        }
+
+        async fn load(&self, name: &str) -> Result<Option<Zeroizing<Vec<u8>>>> {
+            let secrets = self.secrets.lock().unwrap();
+
+            Ok(secrets
+                .get(name)
+                .map(|sealed| Zeroizing::new(sealed.iter().map(|byte| byte ^ MASK).collect())))
+        }
+    }
+
+    #[tokio::test]
+    async fn signs_with_the_stored_key() -> Result<()> {
+        let secret_key = SecretKey::random(&mut OsRng);
+        let signer = WebCryptoSigner::import(MockStore::default(), "main", secret_key).await?;
+
+        let message = Message::new("browser");
+        assert_eq!(
+            signer.sign(message).await?,
+            Signature::sign(&secret_key, &message)
+        );
+        assert_eq!(signer.address().hash(), PublicKey::from(&secret_key).hash());
+
+        let stored = signer.store.secrets.lock().unwrap()["main"].clone();
+        assert_ne!(stored, &secret_key[..]);
+
+        let store = signer.store;
+        let reloaded = WebCryptoSigner::load(store, "main").await?.expect("stored");
+        assert_eq!(reloaded.public_key(), &PublicKey::from(&secret_key));
+        assert!(WebCryptoSigner::load(MockStore::default(), "main")
+            .await?
+            .is_none());
+
+        Ok(())
+    }
+}
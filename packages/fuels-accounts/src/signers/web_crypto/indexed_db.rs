@@ -0,0 +1,212 @@
+use async_trait::async_trait;
+use fuels_core::types::errors::{error, Error, Result};
+use js_sys::{Array, Promise, Reflect, Uint8Array};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    AesGcmParams, AesKeyGenParams, Crypto, CryptoKey, Event, IdbDatabase, IdbFactory,
+    IdbObjectStore, IdbOpenDbRequest, IdbRequest, IdbTransactionMode,
+};
+use zeroize::Zeroizing;
+
+use super::KeyStore;
+
+const SECRETS: &str = "secrets";
+const WRAPPING_KEYS: &str = "wrapping-keys";
+const WRAPPING_KEY: &str = "default";
+const AES_GCM: &str = "AES-GCM";
+const IV_LEN: usize = 12;
+
+/// A [`KeyStore`] encrypting the secrets with AES-GCM and keeping them in IndexedDB.
+///
+/// The AES key is a non-extractable WebCrypto key, stored in the same database: it can be used
+/// by the origin to encrypt and decrypt, but its bytes can't be read, even by JavaScript running
+/// on the page. Available in windows and workers.
+#[derive(Debug, Clone)]
+pub struct IndexedDbKeyStore {
+    database: String,
+}
+
+impl IndexedDbKeyStore {
+    /// Uses the IndexedDB database named `database`, creating it if needed.
+    pub fn new(database: impl Into<String>) -> Self {
+        Self {
+            database: database.into(),
+        }
+    }
+
+    async fn open(&self) -> Result<IdbDatabase> {
+        let factory: IdbFactory = global("indexedDB")?;
+        let request = factory.open_with_u32(&self.database, 1).map_err(js_error)?;
+
+        let upgrade = Closure::once_into_js(move |event: Event| {
+            let Some(database) = event
+                .target()
+                .and_then(|target| target.dyn_into::<IdbOpenDbRequest>().ok())
+                .and_then(|request| request.result().ok())
+            else {
+                return;
+            };
+            let database: IdbDatabase = database.unchecked_into();
+
+            for store in [SECRETS, WRAPPING_KEYS] {
+                let _ = database.create_object_store(store);
+            }
+        });
+        request.set_onupgradeneeded(Some(upgrade.unchecked_ref()));
+
+        Ok(completion(&request).await?.unchecked_into())
+    }
+
+    /// Loads the AES key of the database, generating it on first use.
+    async fn wrapping_key(&self, database: &IdbDatabase, crypto: &Crypto) -> Result<CryptoKey> {
+        let id = JsValue::from_str(WRAPPING_KEY);
+
+        if let Some(key) = get(database, WRAPPING_KEYS, &id).await? {
+            return Ok(key.unchecked_into());
+        }
+
+        let algorithm = AesKeyGenParams::new(AES_GCM, 256);
+        let usages = Array::of2(&"encrypt".into(), &"decrypt".into());
+        let generated = crypto
+            .subtle()
+            .generate_key_with_object(&algorithm, false, &usages)
+            .map_err(js_error)?;
+        let key = JsFuture::from(generated).await.map_err(js_error)?;
+
+        // Another tab may have stored its key in the meantime, in which case `add` fails and
+        // that key is used instead.
+        let added = object_store(database, WRAPPING_KEYS, IdbTransactionMode::Readwrite)?
+            .add_with_key(&key, &id)
+            .map_err(js_error)?;
+        if completion(&added).await.is_ok() {
+            return Ok(key.unchecked_into());
+        }
+
+        get(database, WRAPPING_KEYS, &id)
+            .await?
+            .map(|key| key.unchecked_into())
+            .ok_or_else(|| error!(Other, "could not store the wrapping key in IndexedDB"))
+    }
+}
+
+#[async_trait(?Send)]
+impl KeyStore for IndexedDbKeyStore {
+    async fn store(&self, name: &str, secret: &[u8]) -> Result<()> {
+        let crypto: Crypto = global("crypto")?;
+        let database = self.open().await?;
+        let key = self.wrapping_key(&database, &crypto).await?;
+
+        let mut iv = [0; IV_LEN];
+        crypto
+            .get_random_values_with_u8_array(&mut iv)
+            .map_err(js_error)?;
+
+        let mut plaintext = Zeroizing::new(secret.to_vec());
+        let encrypted = crypto
+            .subtle()
+            .encrypt_with_object_and_u8_array(&aes_gcm(&iv), &key, plaintext.as_mut_slice())
+            .map_err(js_error)?;
+        let ciphertext = Uint8Array::new(&JsFuture::from(encrypted).await.map_err(js_error)?);
+
+        let sealed = [&iv[..], &ciphertext.to_vec()].concat();
+        let request = object_store(&database, SECRETS, IdbTransactionMode::Readwrite)?
+            .put_with_key(
+                &Uint8Array::from(sealed.as_slice()),
+                &JsValue::from_str(name),
+            )
+            .map_err(js_error)?;
+        completion(&request).await?;
+
+        Ok(())
+    }
+
+    async fn load(&self, name: &str) -> Result<Option<Zeroizing<Vec<u8>>>> {
+        let database = self.open().await?;
+        let Some(sealed) = get(&database, SECRETS, &JsValue::from_str(name)).await? else {
+            return Ok(None);
+        };
+
+        let mut sealed = Uint8Array::new(&sealed).to_vec();
+        if sealed.len() < IV_LEN {
+            return Err(error!(Other, "secret `{name}` is corrupted in IndexedDB"));
+        }
+        let (iv, ciphertext) = sealed.split_at_mut(IV_LEN);
+
+        let crypto: Crypto = global("crypto")?;
+        let key = self.wrapping_key(&database, &crypto).await?;
+        let decrypted = crypto
+            .subtle()
+            .decrypt_with_object_and_u8_array(&aes_gcm(iv), &key, ciphertext)
+            .map_err(js_error)?;
+        let plaintext = Uint8Array::new(&JsFuture::from(decrypted).await.map_err(js_error)?);
+
+        let secret = Zeroizing::new(plaintext.to_vec());
+        // WebCrypto hands the plaintext back in a JavaScript buffer, cleared once copied.
+        plaintext.fill(0, 0, plaintext.length());
+
+        Ok(Some(secret))
+    }
+}
+
+fn aes_gcm(iv: &[u8]) -> AesGcmParams {
+    AesGcmParams::new(AES_GCM, &Uint8Array::from(iv))
+}
+
+fn object_store(
+    database: &IdbDatabase,
+    name: &str,
+    mode: IdbTransactionMode,
+) -> Result<IdbObjectStore> {
+    database
+        .transaction_with_str_and_mode(name, mode)
+        .and_then(|transaction| transaction.object_store(name))
+        .map_err(js_error)
+}
+
+async fn get(database: &IdbDatabase, store: &str, id: &JsValue) -> Result<Option<JsValue>> {
+    let request = object_store(database, store, IdbTransactionMode::Readonly)?
+        .get(id)
+        .map_err(js_error)?;
+    let value = completion(&request).await?;
+
+    Ok((!value.is_undefined()).then_some(value))
+}
+
+/// Resolves with the result of `request` once it succeeds.
+async fn completion(request: &IdbRequest) -> Result<JsValue> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        let succeeded = request.clone();
+        let on_success = Closure::once_into_js(move |_: Event| {
+            let result = succeeded.result().unwrap_or(JsValue::UNDEFINED);
+            let _ = resolve.call1(&JsValue::UNDEFINED, &result);
+        });
+
+        let failed = request.clone();
+        let on_error = Closure::once_into_js(move |_: Event| {
+            let error = failed
+                .error()
+                .ok()
+                .flatten()
+                .map_or(JsValue::UNDEFINED, JsValue::from);
+            let _ = reject.call1(&JsValue::UNDEFINED, &error);
+        });
+
+        request.set_onsuccess(Some(on_success.unchecked_ref()));
+        request.set_onerror(Some(on_error.unchecked_ref()));
+    });
+
+    JsFuture::from(promise).await.map_err(js_error)
+}
+
+/// The global object named `name`, of the window or of the worker.
+fn global<T: JsCast>(name: &str) -> Result<T> {
+    Reflect::get(&js_sys::global(), &JsValue::from_str(name))
+        .map_err(js_error)?
+        .dyn_into()
+        .map_err(|_| error!(Other, "`{name}` is not available in this environment"))
+}
+
+fn js_error(err: JsValue) -> Error {
+    error!(Other, "browser key store failed: {err:?}")
+}
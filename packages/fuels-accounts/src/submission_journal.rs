@@ -0,0 +1,140 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use fuel_tx::TxId;
+use fuels_core::types::errors::{error, Result};
+
+/// Records the transaction submitted for every idempotency key given to
+/// [`Provider::send_transaction_once`], so that a process restarting after a crash can find out
+/// whether it already submitted a transaction instead of paying twice.
+///
+/// Journals are registered with [`Provider::with_submission_journal`].
+///
+/// [`Provider::send_transaction_once`]: crate::provider::Provider::send_transaction_once
+/// [`Provider::with_submission_journal`]: crate::provider::Provider::with_submission_journal
+pub trait SubmissionJournal: Debug + Send + Sync {
+    /// The transaction last recorded for `key`.
+    fn get(&self, key: &str) -> Result<Option<TxId>>;
+
+    /// Records `tx_id` for `key`, replacing the transaction recorded before. Must be durable
+    /// once it returns, as the transaction is submitted right after.
+    fn record(&self, key: &str, tx_id: TxId) -> Result<()>;
+}
+
+/// A [`SubmissionJournal`] lost when the process exits, for tests and short-lived processes.
+#[derive(Debug, Default)]
+pub struct MemoryJournal {
+    entries: Mutex<HashMap<String, TxId>>,
+}
+
+impl SubmissionJournal for MemoryJournal {
+    fn get(&self, key: &str) -> Result<Option<TxId>> {
+        Ok(self
+            .entries
+            .lock()
+            .expect("journal lock poisoned")
+            .get(key)
+            .copied())
+    }
+
+    fn record(&self, key: &str, tx_id: TxId) -> Result<()> {
+        self.entries
+            .lock()
+            .expect("journal lock poisoned")
+            .insert(key.to_string(), tx_id);
+
+        Ok(())
+    }
+}
+
+/// A [`SubmissionJournal`] kept in a JSON file, which is rewritten on every record.
+#[derive(Debug)]
+pub struct FileJournal {
+    path: PathBuf,
+    entries: Mutex<HashMap<String, TxId>>,
+}
+
+impl FileJournal {
+    /// Opens the journal at `path`, which is created on the first record if it does not exist.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let entries = if path.exists() {
+            serde_json::from_slice(&fs::read(&path)?)?
+        } else {
+            HashMap::new()
+        };
+
+        Ok(Self {
+            path,
+            entries: Mutex::new(entries),
+        })
+    }
+
+    /// Writes the journal next to its file first, so that a crash while writing leaves the
+    /// previous journal intact.
+    fn persist(&self, entries: &HashMap<String, TxId>) -> Result<()> {
+        let tmp_path = self.path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec(entries)?)?;
+        fs::rename(&tmp_path, &self.path).map_err(|err| {
+            error!(
+                IO,
+                "could not write journal `{}`: {err}",
+                self.path.display()
+            )
+        })
+    }
+}
+
+impl SubmissionJournal for FileJournal {
+    fn get(&self, key: &str) -> Result<Option<TxId>> {
+        Ok(self
+            .entries
+            .lock()
+            .expect("journal lock poisoned")
+            .get(key)
+            .copied())
+    }
+
+    fn record(&self, key: &str, tx_id: TxId) -> Result<()> {
+        let mut entries = self.entries.lock().expect("journal lock poisoned");
+        let previous = entries.insert(key.to_string(), tx_id);
+
+        if let Err(err) = self.persist(&entries) {
+            match previous {
+                Some(previous) => entries.insert(key.to_string(), previous),
+                None => entries.remove(key),
+            };
+            return Err(err);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_journal_survives_reopening() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("journal.json");
+        let tx_id = TxId::new([1; 32]);
+
+        let journal = FileJournal::open(&path)?;
+        assert_eq!(journal.get("transfer-1")?, None);
+        journal.record("transfer-1", tx_id)?;
+        drop(journal);
+
+        let journal = FileJournal::open(&path)?;
+        assert_eq!(journal.get("transfer-1")?, Some(tx_id));
+        assert_eq!(journal.get("transfer-2")?, None);
+
+        Ok(())
+    }
+}
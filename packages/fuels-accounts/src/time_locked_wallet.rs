@@ -0,0 +1,280 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex, Weak},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use fuel_crypto::{Message, PublicKey, SecretKey, Signature};
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::Bech32Address,
+        coin_type_id::CoinTypeId,
+        errors::{error, Result},
+        input::Input,
+        transaction_builders::TransactionBuilder,
+        AssetId,
+    },
+};
+use zeroize::Zeroize;
+
+use crate::{
+    provider::Provider,
+    wallet::{Wallet, WalletUnlocked},
+    Account, ViewOnlyAccount,
+};
+
+/// A wallet whose private key is zeroized once a timeout expires or [`lock`](Self::lock) is
+/// called. Signing then fails until the key is provided again through [`unlock`](Self::unlock).
+///
+/// Created with [`WalletUnlocked::lock_after`]. When a tokio runtime is available the key is wiped
+/// as soon as the timeout expires, otherwise on the first use after it.
+#[derive(Clone)]
+pub struct TimeLockedWallet {
+    wallet: Wallet,
+    state: Arc<Mutex<KeyState>>,
+}
+
+struct KeyState {
+    private_key: Option<SecretKey>,
+    expires_at: Instant,
+    /// Incremented on every unlock so that timers of previous unlocks do not lock the wallet.
+    generation: u64,
+}
+
+impl KeyState {
+    /// Wipes the key where it is stored. `SecretKey` is `Copy`, so moving it out with `take`
+    /// would only wipe a copy.
+    fn lock(&mut self) {
+        if let Some(private_key) = self.private_key.as_mut() {
+            private_key.zeroize();
+        }
+        self.private_key = None;
+    }
+
+    fn private_key(&mut self) -> Option<&SecretKey> {
+        if Instant::now() >= self.expires_at {
+            self.lock();
+        }
+
+        self.private_key.as_ref()
+    }
+}
+
+impl Drop for KeyState {
+    fn drop(&mut self) {
+        self.lock();
+    }
+}
+
+impl TimeLockedWallet {
+    pub(crate) fn new(wallet: WalletUnlocked, timeout: Duration) -> Self {
+        let locked = Self {
+            wallet: (*wallet).clone(),
+            state: Arc::new(Mutex::new(KeyState {
+                private_key: None,
+                expires_at: Instant::now(),
+                generation: 0,
+            })),
+        };
+        locked.set_key(wallet.private_key, timeout);
+
+        locked
+    }
+
+    /// Stores `private_key` again for `timeout`. Fails if the key does not belong to this wallet.
+    pub fn unlock(&self, private_key: SecretKey, timeout: Duration) -> Result<()> {
+        let address = PublicKey::from(&private_key).hash();
+        if *address != *self.wallet.address().hash() {
+            return Err(error!(
+                Other,
+                "private key does not belong to `{}`",
+                self.wallet.address()
+            ));
+        }
+
+        self.set_key(private_key, timeout);
+
+        Ok(())
+    }
+
+    /// Zeroizes the private key immediately.
+    pub fn lock(&self) {
+        self.state.lock().expect("not poisoned").lock();
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.state
+            .lock()
+            .expect("not poisoned")
+            .private_key()
+            .is_none()
+    }
+
+    pub fn address(&self) -> &Bech32Address {
+        self.wallet.address()
+    }
+
+    pub fn provider(&self) -> Option<&Provider> {
+        self.wallet.provider()
+    }
+
+    pub fn set_provider(&mut self, provider: Provider) {
+        self.wallet.set_provider(provider);
+    }
+
+    fn set_key(&self, private_key: SecretKey, timeout: Duration) {
+        let generation = {
+            let mut state = self.state.lock().expect("not poisoned");
+            state.lock();
+            state.private_key = Some(private_key);
+            state.expires_at = Instant::now() + timeout;
+            state.generation += 1;
+
+            state.generation
+        };
+
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            runtime.spawn(lock_when_expired(
+                Arc::downgrade(&self.state),
+                timeout,
+                generation,
+            ));
+        }
+    }
+}
+
+async fn lock_when_expired(state: Weak<Mutex<KeyState>>, timeout: Duration, generation: u64) {
+    tokio::time::sleep(timeout).await;
+
+    if let Some(state) = state.upgrade() {
+        let mut state = state.lock().expect("not poisoned");
+        if state.generation == generation {
+            state.lock();
+        }
+    }
+}
+
+impl fmt::Debug for TimeLockedWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TimeLockedWallet")
+            .field("address", self.wallet.address())
+            .field("locked", &self.is_locked())
+            .finish()
+    }
+}
+
+impl ViewOnlyAccount for TimeLockedWallet {
+    fn address(&self) -> &Bech32Address {
+        self.wallet.address()
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.wallet.try_provider()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Account for TimeLockedWallet {
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: Option<Vec<CoinTypeId>>,
+    ) -> Result<Vec<Input>> {
+        Ok(self
+            .get_spendable_resources(asset_id, amount, excluded_coins)
+            .await?
+            .into_iter()
+            .map(Input::resource_signed)
+            .collect::<Vec<Input>>())
+    }
+
+    fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        tb.add_signer(self.clone())?;
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for TimeLockedWallet {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let mut state = self.state.lock().expect("not poisoned");
+        let private_key = state
+            .private_key()
+            .ok_or_else(|| error!(Other, "wallet `{}` is locked", self.wallet.address()))?;
+
+        Ok(Signature::sign(private_key, &message))
+    }
+
+    fn address(&self) -> &Bech32Address {
+        self.wallet.address()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn locks_after_timeout_and_unlocks_again() -> Result<()> {
+        let private_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?;
+        let wallet = WalletUnlocked::new_from_private_key(private_key, None)
+            .lock_after(Duration::from_millis(50));
+        let message = Message::new("locked");
+
+        assert_eq!(
+            wallet.sign(message).await?,
+            Signature::sign(&private_key, &message)
+        );
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(wallet.is_locked());
+        assert!(wallet.sign(message).await.is_err());
+
+        wallet.unlock(private_key, Duration::from_secs(60))?;
+        wallet.sign(message).await?;
+
+        wallet.lock();
+        assert!(wallet.sign(message).await.is_err());
+
+        let other_key = SecretKey::from_str(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        )?;
+        assert!(wallet.unlock(other_key, Duration::from_secs(60)).is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn locked_wallets_stay_locked_until_unlocked() -> Result<()> {
+        let private_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?;
+        let wallet = WalletUnlocked::new_from_private_key(private_key, None)
+            .lock_after(Duration::from_secs(60));
+        let message = Message::new("locked");
+
+        wallet.lock();
+
+        assert!(wallet.is_locked());
+        assert!(wallet.sign(message).await.is_err());
+        assert!(wallet.clone().sign(message).await.is_err());
+
+        wallet.unlock(private_key, Duration::from_secs(60))?;
+        assert!(!wallet.is_locked());
+        assert_eq!(
+            wallet.sign(message).await?,
+            Signature::sign(&private_key, &message)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,313 @@
+//! Signing of typed structured data, modelled after EIP-712.
+//!
+//! A value is hashed together with a description of its type and with a [`TypedDataDomain`]
+//! identifying the application, so that a signature made for one application, chain or struct can
+//! never be reinterpreted as a signature for another. The final digest is
+//!
+//! ```text
+//! sha256(0x19 ++ 0x01 ++ domain_separator ++ hash_struct(value))
+//! hash_struct(value) = sha256(sha256(encode_type(T)) ++ abi_encode(value))
+//! ```
+//!
+//! where `abi_encode` is the regular ABI encoding, which predicates and contracts can reproduce
+//! with `core::codec::encode`.
+
+use std::collections::BTreeMap;
+
+use fuel_crypto::{Hasher, Message, Signature};
+use fuel_types::{Bytes32, ContractId};
+use fuels_core::{
+    codec::ABIEncoder,
+    traits::{Parameterize, Signer, Tokenizable},
+    types::{
+        bech32::Bech32Address,
+        errors::{error, Result},
+        param_types::ParamType,
+    },
+};
+
+const DOMAIN_TYPE: &str =
+    "FuelDomain(String name,String version,u64 chain_id,b256 verifying_contract)";
+
+/// Identifies the application a typed signature is meant for.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TypedDataDomain {
+    pub name: String,
+    pub version: String,
+    pub chain_id: u64,
+    /// The contract or predicate expected to validate the signature, if any.
+    pub verifying_contract: Option<ContractId>,
+}
+
+impl TypedDataDomain {
+    pub fn new(name: impl Into<String>, version: impl Into<String>, chain_id: u64) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            chain_id,
+            verifying_contract: None,
+        }
+    }
+
+    pub fn with_verifying_contract(mut self, contract_id: ContractId) -> Self {
+        self.verifying_contract = Some(contract_id);
+        self
+    }
+
+    pub fn separator(&self) -> Bytes32 {
+        let mut hasher = Hasher::default();
+        hasher.input(Hasher::hash(DOMAIN_TYPE));
+        hasher.input(Hasher::hash(&self.name));
+        hasher.input(Hasher::hash(&self.version));
+        hasher.input(self.chain_id.to_be_bytes());
+        hasher.input(self.verifying_contract.unwrap_or_default());
+
+        hasher.digest()
+    }
+}
+
+/// Describes `param_type` as `Name(type field,...)` followed by the descriptions of every struct
+/// and enum it references, sorted by name. Enums are described as `Name{type variant,...}`.
+pub fn encode_type(param_type: &ParamType) -> Result<String> {
+    let mut definitions = BTreeMap::new();
+    let primary = type_name(param_type, &mut definitions)?;
+
+    let primary_definition = definitions.remove(&primary).ok_or_else(|| {
+        error!(
+            Other,
+            "typed data must be a struct or an enum, got `{primary}`"
+        )
+    })?;
+
+    Ok(std::iter::once(primary_definition)
+        .chain(definitions.into_values())
+        .collect())
+}
+
+pub fn hash_struct<T: Tokenizable + Parameterize>(value: T) -> Result<Bytes32> {
+    let type_hash = Hasher::hash(encode_type(&T::param_type())?);
+    let encoded = ABIEncoder::default().encode(&[value.into_token()])?;
+
+    Ok(Hasher::default().chain(type_hash).chain(encoded).digest())
+}
+
+/// The message to sign for `value` within `domain`.
+pub fn typed_data_message<T: Tokenizable + Parameterize>(
+    domain: &TypedDataDomain,
+    value: T,
+) -> Result<Message> {
+    let digest = Hasher::default()
+        .chain([0x19, 0x01])
+        .chain(domain.separator())
+        .chain(hash_struct(value)?)
+        .digest();
+
+    Ok(Message::from_bytes(*digest))
+}
+
+pub async fn sign_typed<T: Tokenizable + Parameterize>(
+    signer: &(impl Signer + ?Sized),
+    domain: &TypedDataDomain,
+    value: T,
+) -> Result<Signature> {
+    signer.sign(typed_data_message(domain, value)?).await
+}
+
+/// Checks that `signature` is a typed signature of `value` within `domain` made by `address`.
+pub fn verify_typed<T: Tokenizable + Parameterize>(
+    address: &Bech32Address,
+    domain: &TypedDataDomain,
+    value: T,
+    signature: &Signature,
+) -> Result<()> {
+    let signer = signature
+        .recover(&typed_data_message(domain, value)?)?
+        .hash();
+
+    if *signer != *address.hash() {
+        return Err(error!(Other, "typed signature was not made by `{address}`"));
+    }
+
+    Ok(())
+}
+
+fn type_name(param_type: &ParamType, definitions: &mut BTreeMap<String, String>) -> Result<String> {
+    let name = match param_type {
+        ParamType::Unit => "()".to_string(),
+        ParamType::Bool => "bool".to_string(),
+        ParamType::U8 => "u8".to_string(),
+        ParamType::U16 => "u16".to_string(),
+        ParamType::U32 => "u32".to_string(),
+        ParamType::U64 => "u64".to_string(),
+        ParamType::U128 => "u128".to_string(),
+        ParamType::U256 => "u256".to_string(),
+        ParamType::B256 => "b256".to_string(),
+        ParamType::Bytes => "Bytes".to_string(),
+        ParamType::String => "String".to_string(),
+        ParamType::RawSlice => "raw_slice".to_string(),
+        ParamType::StringSlice => "str".to_string(),
+        ParamType::StringArray(len) => format!("str[{len}]"),
+        ParamType::Array(param_type, len) => {
+            format!("[{}; {len}]", type_name(param_type, definitions)?)
+        }
+        ParamType::Vector(param_type) => format!("Vec<{}>", type_name(param_type, definitions)?),
+        ParamType::Tuple(param_types) => {
+            let elements = param_types
+                .iter()
+                .map(|param_type| type_name(param_type, definitions))
+                .collect::<Result<Vec<_>>>()?;
+
+            format!("({})", elements.join(","))
+        }
+        ParamType::Struct {
+            name,
+            fields,
+            generics,
+        } => {
+            let name = generic_name(name, generics, definitions)?;
+            let members = members(fields, definitions)?;
+            definitions.insert(name.clone(), format!("{name}({members})"));
+
+            name
+        }
+        ParamType::Enum {
+            name,
+            enum_variants,
+            generics,
+        } => {
+            let name = generic_name(name, generics, definitions)?;
+            let members = members(enum_variants.variants(), definitions)?;
+            definitions.insert(name.clone(), format!("{name}{{{members}}}"));
+
+            name
+        }
+    };
+
+    Ok(name)
+}
+
+fn generic_name(
+    name: &str,
+    generics: &[ParamType],
+    definitions: &mut BTreeMap<String, String>,
+) -> Result<String> {
+    if generics.is_empty() {
+        return Ok(name.to_string());
+    }
+
+    let generics = generics
+        .iter()
+        .map(|param_type| type_name(param_type, definitions))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(format!("{name}<{}>", generics.join(",")))
+}
+
+fn members(
+    members: &[(String, ParamType)],
+    definitions: &mut BTreeMap<String, String>,
+) -> Result<String> {
+    Ok(members
+        .iter()
+        .map(|(name, param_type)| Ok(format!("{} {name}", type_name(param_type, definitions)?)))
+        .collect::<Result<Vec<_>>>()?
+        .join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use fuel_crypto::{PublicKey, SecretKey};
+    use fuels_core::types::{bech32::FUEL_BECH32_HRP, Bits256, Token};
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Order {
+        maker: Bytes32,
+        amount: u64,
+        expiry: Option<u32>,
+    }
+
+    impl Parameterize for Order {
+        fn param_type() -> ParamType {
+            ParamType::Struct {
+                name: "Order".to_string(),
+                fields: vec![
+                    ("maker".to_string(), ParamType::B256),
+                    ("amount".to_string(), ParamType::U64),
+                    ("expiry".to_string(), Option::<u32>::param_type()),
+                ],
+                generics: vec![],
+            }
+        }
+    }
+
+    impl Tokenizable for Order {
+        fn from_token(token: Token) -> Result<Self> {
+            let Token::Struct(tokens) = token else {
+                return Err(error!(Other, "`Order` cannot be decoded from `{token}`"));
+            };
+            let [maker, amount, expiry]: [Token; 3] = tokens
+                .try_into()
+                .map_err(|_| error!(Other, "`Order` expects 3 fields"))?;
+
+            Ok(Self {
+                maker: Bytes32::new(Bits256::from_token(maker)?.0),
+                amount: u64::from_token(amount)?,
+                expiry: Option::<u32>::from_token(expiry)?,
+            })
+        }
+
+        fn into_token(self) -> Token {
+            Token::Struct(vec![
+                Token::B256(*self.maker),
+                self.amount.into_token(),
+                self.expiry.into_token(),
+            ])
+        }
+    }
+
+    #[test]
+    fn encodes_referenced_types() -> Result<()> {
+        assert_eq!(
+            encode_type(&Order::param_type())?,
+            "Order(b256 maker,u64 amount,Option<u32> expiry)Option<u32>{() None,u32 Some}"
+        );
+        assert!(encode_type(&ParamType::U64).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn typed_signatures_are_bound_to_domain_and_value() -> Result<()> {
+        let secret_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?;
+        let address = Bech32Address::new(FUEL_BECH32_HRP, PublicKey::from(&secret_key).hash());
+
+        let domain = TypedDataDomain::new("exchange", "1", 0);
+        let order = Order {
+            maker: Bytes32::from(*address.hash()),
+            amount: 100,
+            expiry: Some(10),
+        };
+
+        assert_eq!(Order::from_token(order.clone().into_token())?, order);
+
+        let signature = Signature::sign(&secret_key, &typed_data_message(&domain, order.clone())?);
+        verify_typed(&address, &domain, order.clone(), &signature)?;
+
+        let other_order = Order {
+            amount: 101,
+            ..order.clone()
+        };
+        assert!(verify_typed(&address, &domain, other_order, &signature).is_err());
+
+        let other_domain = TypedDataDomain::new("exchange", "2", 0);
+        assert!(verify_typed(&address, &other_domain, order, &signature).is_err());
+
+        Ok(())
+    }
+}
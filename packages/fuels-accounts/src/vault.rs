@@ -0,0 +1,222 @@
+use std::{collections::BTreeMap, path::Path, str::FromStr};
+
+use fuel_crypto::{PublicKey, SecretKey};
+use fuels_core::types::{
+    bech32::{Bech32Address, FUEL_BECH32_HRP},
+    errors::{error, Error, Result},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{keystore::Keystore, provider::Provider, wallet::WalletUnlocked};
+
+const VAULT_VERSION: u8 = 1;
+
+/// Many labeled keys stored in a single JSON file, all encrypted under the same password.
+///
+/// Every key is kept as its own [`Keystore`], so unlocking one account only decrypts that key.
+/// Addresses are stored in the clear so the content of a vault can be listed without the password.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Vault {
+    version: u8,
+    keys: BTreeMap<String, VaultEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct VaultEntry {
+    address: String,
+    keystore: Keystore,
+}
+
+impl Default for Vault {
+    fn default() -> Self {
+        Self {
+            version: VAULT_VERSION,
+            keys: BTreeMap::new(),
+        }
+    }
+}
+
+impl Vault {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.keys.keys().map(String::as_str)
+    }
+
+    pub fn contains(&self, label: &str) -> bool {
+        self.keys.contains_key(label)
+    }
+
+    pub fn address(&self, label: &str) -> Result<Bech32Address> {
+        Bech32Address::from_str(&self.entry(label)?.address)
+    }
+
+    /// Encrypts `private_key` under `password` and stores it as `label`.
+    ///
+    /// Fails if the label is taken or if `password` does not unlock the keys already in the vault.
+    pub fn add(
+        &mut self,
+        label: impl Into<String>,
+        private_key: &SecretKey,
+        password: impl AsRef<[u8]>,
+    ) -> Result<Bech32Address> {
+        let label = label.into();
+        if self.contains(&label) {
+            return Err(error!(
+                Other,
+                "vault already contains a key labeled `{label}`"
+            ));
+        }
+
+        if let Some(entry) = self.keys.values().next() {
+            entry.keystore.decrypt(&password)?;
+        }
+
+        let address = Bech32Address::new(FUEL_BECH32_HRP, PublicKey::from(private_key).hash());
+        let keystore = Keystore::encrypt(&mut rand::thread_rng(), private_key, password)?;
+        self.keys.insert(
+            label,
+            VaultEntry {
+                address: address.to_string(),
+                keystore,
+            },
+        );
+
+        Ok(address)
+    }
+
+    /// Removes the key labeled `label`, returning its encrypted keystore.
+    pub fn remove(&mut self, label: &str) -> Result<Keystore> {
+        self.keys
+            .remove(label)
+            .map(|entry| entry.keystore)
+            .ok_or_else(|| missing_label(label))
+    }
+
+    /// Decrypts only the key labeled `label`.
+    pub fn unlock(
+        &self,
+        label: &str,
+        password: impl AsRef<[u8]>,
+        provider: Option<Provider>,
+    ) -> Result<WalletUnlocked> {
+        let entry = self.entry(label)?;
+        let wallet =
+            WalletUnlocked::new_from_private_key(entry.keystore.decrypt(password)?, provider);
+
+        if wallet.address().to_string() != entry.address {
+            return Err(error!(
+                Other,
+                "key labeled `{label}` does not match its stored address"
+            ));
+        }
+
+        Ok(wallet)
+    }
+
+    /// Re-encrypts every key under `new_password`. The vault is left untouched if any key fails
+    /// to decrypt with `old_password`.
+    pub fn rotate_password(
+        &mut self,
+        old_password: impl AsRef<[u8]>,
+        new_password: impl AsRef<[u8]>,
+    ) -> Result<()> {
+        let mut rng = rand::thread_rng();
+        let keys = self
+            .keys
+            .iter()
+            .map(|(label, entry)| {
+                let keystore =
+                    entry
+                        .keystore
+                        .rotate_password(&mut rng, &old_password, &new_password)?;
+
+                Ok((
+                    label.clone(),
+                    VaultEntry {
+                        address: entry.address.clone(),
+                        keystore,
+                    },
+                ))
+            })
+            .collect::<Result<_>>()?;
+
+        self.keys = keys;
+
+        Ok(())
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        let vault: Self = serde_json::from_str(json)?;
+        if vault.version != VAULT_VERSION {
+            return Err(error!(
+                Other,
+                "unsupported vault version `{}`", vault.version
+            ));
+        }
+
+        Ok(vault)
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        Ok(std::fs::write(path, self.to_json()?)?)
+    }
+
+    fn entry(&self, label: &str) -> Result<&VaultEntry> {
+        self.keys.get(label).ok_or_else(|| missing_label(label))
+    }
+}
+
+fn missing_label(label: &str) -> Error {
+    error!(Other, "vault has no key labeled `{label}`")
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn stores_labeled_keys_and_rotates_password() -> Result<()> {
+        let mut rng = rand::thread_rng();
+        let treasury_key = SecretKey::random(&mut rng);
+        let hot_key = SecretKey::random(&mut rng);
+
+        let mut vault = Vault::new();
+        let treasury = vault.add("treasury", &treasury_key, "old")?;
+        vault.add("hot", &hot_key, "old")?;
+        vault.add("cold", &SecretKey::random(&mut rng), "old")?;
+
+        assert!(vault.add("hot", &hot_key, "old").is_err());
+        assert!(vault.add("other", &hot_key, "wrong").is_err());
+
+        vault.remove("cold")?;
+        assert_eq!(vault.labels().collect::<Vec<_>>(), ["hot", "treasury"]);
+
+        let dir = tempdir()?;
+        let path = dir.path().join("vault.json");
+        vault.save(&path)?;
+        let mut vault = Vault::load(&path)?;
+        assert_eq!(vault.address("treasury")?, treasury);
+
+        vault.rotate_password("old", "new")?;
+        assert!(vault.unlock("hot", "old", None).is_err());
+        assert!(vault.rotate_password("old", "newer").is_err());
+
+        let wallet = vault.unlock("treasury", "new", None)?;
+        assert_eq!(wallet.address(), &treasury);
+
+        Ok(())
+    }
+}
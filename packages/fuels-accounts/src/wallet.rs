@@ -1,10 +1,11 @@
-use std::{fmt, ops, path::Path};
+use std::{fmt, ops, path::Path, time::Duration};
 
 use async_trait::async_trait;
 use elliptic_curve::rand_core;
+use fuel_core_client::client::pagination::{PageDirection, PaginationRequest};
 use fuel_crypto::{Message, PublicKey, SecretKey, Signature};
 use fuels_core::{
-    traits::Signer,
+    traits::{Parameterize, Signer, Tokenizable},
     types::{
         bech32::{Bech32Address, FUEL_BECH32_HRP},
         coin_type_id::CoinTypeId,
@@ -17,9 +18,18 @@ use fuels_core::{
 use rand::{CryptoRng, Rng};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
-use crate::{accounts_utils::try_provider_error, provider::Provider, Account, ViewOnlyAccount};
-
-pub const DEFAULT_DERIVATION_PATH_PREFIX: &str = "m/44'/1179993420'";
+pub use crate::derivation::DEFAULT_DERIVATION_PATH_PREFIX;
+#[cfg(feature = "keystore")]
+use crate::keystore::Keystore;
+use crate::{
+    accounts_utils::try_provider_error,
+    derivation::DerivationTemplate,
+    personal_sign::personal_message,
+    provider::Provider,
+    time_locked_wallet::TimeLockedWallet,
+    typed_data::{typed_data_message, TypedDataDomain},
+    Account, ViewOnlyAccount,
+};
 
 /// A FuelVM-compatible wallet that can be used to list assets, balances and more.
 ///
@@ -95,6 +105,12 @@ impl WalletUnlocked {
         self.wallet.clone()
     }
 
+    /// Keeps the private key in memory for at most `timeout`, after which it is `zeroize`-d and
+    /// the wallet has to be unlocked again to sign.
+    pub fn lock_after(self, timeout: Duration) -> TimeLockedWallet {
+        TimeLockedWallet::new(self, timeout)
+    }
+
     // NOTE: Rather than providing a `DerefMut` implementation, we wrap the `set_provider` method
     // directly. This is because we should not allow the user a `&mut` handle to the inner `Wallet`
     // as this could lead to ending up with a `WalletUnlocked` in an inconsistent state (e.g. the
@@ -137,6 +153,68 @@ impl WalletUnlocked {
         Ok(Self::new_from_private_key(secret_key, provider))
     }
 
+    /// Creates a new wallet from a mnemonic phrase, deriving the account at `index` of `template`.
+    pub fn new_from_mnemonic_phrase_with_template(
+        phrase: &str,
+        provider: Option<Provider>,
+        template: &DerivationTemplate,
+        index: u32,
+    ) -> Result<Self> {
+        let path = template.path(index)?.to_string();
+        Self::new_from_mnemonic_phrase_with_path(phrase, provider, &path)
+    }
+
+    /// Scans the accounts `m/44'/1179993420'/{index}'/0/0` derived from `phrase` in order and
+    /// returns those that hold a balance or have a transaction history. Scanning stops once
+    /// `gap_limit` consecutive unused accounts have been found, as in BIP-44 account discovery.
+    pub async fn discover_accounts(
+        phrase: &str,
+        provider: &Provider,
+        gap_limit: usize,
+    ) -> Result<Vec<Self>> {
+        Self::discover_accounts_with_template(
+            phrase,
+            provider,
+            &DerivationTemplate::default(),
+            gap_limit,
+        )
+        .await
+    }
+
+    /// Same as [`discover_accounts`](Self::discover_accounts), scanning the accounts of
+    /// `template` instead of the default Fuel ones.
+    pub async fn discover_accounts_with_template(
+        phrase: &str,
+        provider: &Provider,
+        template: &DerivationTemplate,
+        gap_limit: usize,
+    ) -> Result<Vec<Self>> {
+        let mut used_accounts = vec![];
+        let mut unused_in_a_row = 0;
+
+        for index in 0.. {
+            if unused_in_a_row >= gap_limit {
+                break;
+            }
+
+            let wallet = Self::new_from_mnemonic_phrase_with_template(
+                phrase,
+                Some(provider.clone()),
+                template,
+                index,
+            )?;
+
+            if is_used(provider, wallet.address()).await? {
+                used_accounts.push(wallet);
+                unused_in_a_row = 0;
+            } else {
+                unused_in_a_row += 1;
+            }
+        }
+
+        Ok(used_accounts)
+    }
+
     /// Creates a new wallet and stores its encrypted version in the given path.
     pub fn new_from_keystore<P, R, S>(
         dir: P,
@@ -185,11 +263,83 @@ impl WalletUnlocked {
         Ok(Self::new_from_private_key(secret_key, provider))
     }
 
+    /// Encrypts the wallet's private key with the given password without writing it to disk.
+    #[cfg(feature = "keystore")]
+    pub fn to_keystore<S: AsRef<[u8]>>(&self, password: S) -> Result<Keystore> {
+        Keystore::encrypt(&mut rand::thread_rng(), &self.private_key, password)
+    }
+
+    /// Recreates a wallet from an in-memory encrypted JSON keystore.
+    #[cfg(feature = "keystore")]
+    pub fn from_keystore<S: AsRef<[u8]>>(
+        keystore: &Keystore,
+        password: S,
+        provider: Option<Provider>,
+    ) -> Result<Self> {
+        let secret_key = keystore.decrypt(password)?;
+
+        Ok(Self::new_from_private_key(secret_key, provider))
+    }
+
+    /// Re-encrypts the keystore stored at the given path with `new_password`, keeping its id.
+    #[cfg(feature = "keystore")]
+    pub fn rotate_keystore_password<P, S, T>(
+        keypath: P,
+        old_password: S,
+        new_password: T,
+    ) -> Result<()>
+    where
+        P: AsRef<Path>,
+        S: AsRef<[u8]>,
+        T: AsRef<[u8]>,
+    {
+        Keystore::load(&keypath)?
+            .rotate_password(&mut rand::thread_rng(), old_password, new_password)?
+            .save(keypath)
+    }
+
+    /// Signs an arbitrary off-chain message following the [`personal_sign`](crate::personal_sign)
+    /// scheme. Verify it with [`verify_personal`](crate::personal_sign::verify_personal).
+    pub fn sign_personal(&self, message: impl AsRef<[u8]>) -> Signature {
+        Signature::sign(&self.private_key, &personal_message(message))
+    }
+
+    /// Signs `value` within `domain` following the [`typed_data`](crate::typed_data) scheme.
+    /// Verify it with [`verify_typed`](crate::typed_data::verify_typed).
+    pub fn sign_typed<T: Tokenizable + Parameterize>(
+        &self,
+        domain: &TypedDataDomain,
+        value: T,
+    ) -> Result<Signature> {
+        let message = typed_data_message(domain, value)?;
+
+        Ok(Signature::sign(&self.private_key, &message))
+    }
+
     pub fn address(&self) -> &Bech32Address {
         &self.address
     }
 }
 
+async fn is_used(provider: &Provider, address: &Bech32Address) -> Result<bool> {
+    if !provider.get_balances(address).await?.is_empty() {
+        return Ok(true);
+    }
+
+    let history = provider
+        .get_transactions_by_owner(
+            address,
+            PaginationRequest {
+                cursor: None,
+                results: 1,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await?;
+
+    Ok(!history.results.is_empty())
+}
+
 impl ViewOnlyAccount for WalletUnlocked {
     fn address(&self) -> &Bech32Address {
         self.wallet.address()
@@ -358,4 +508,25 @@ mod tests {
         assert!(std::fs::remove_file(&path).is_ok());
         Ok(())
     }
+
+    #[cfg(feature = "keystore")]
+    #[tokio::test]
+    async fn rotate_keystore_password() -> Result<()> {
+        let dir = tempdir()?;
+        let wallet = WalletUnlocked::new_random(None);
+
+        let path = dir.path().join(wallet.encrypt(&dir, "old")?);
+        WalletUnlocked::rotate_keystore_password(&path, "old", "new")?;
+
+        assert!(WalletUnlocked::load_keystore(&path, "old", None).is_err());
+        let recovered_wallet = WalletUnlocked::load_keystore(&path, "new", None)?;
+        assert_eq!(wallet.address(), recovered_wallet.address());
+
+        let json = wallet.to_keystore("password")?.to_json()?;
+        let imported =
+            WalletUnlocked::from_keystore(&Keystore::from_json(&json)?, "password", None)?;
+        assert_eq!(wallet.address(), imported.address());
+
+        Ok(())
+    }
 }
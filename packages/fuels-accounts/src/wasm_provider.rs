@@ -0,0 +1,418 @@
+//! A read-only client for `wasm32-unknown-unknown`, e.g. Rust apps running in the browser.
+//!
+//! [`Provider`](crate::provider::Provider) is built on tokio and on the native client of
+//! `fuel-core`, neither of which compiles to `wasm32-unknown-unknown`, so it is not available on
+//! that target yet. Until it is, [`WasmProvider`] sends a few GraphQL queries with `reqwest`,
+//! which goes through the `fetch` API of the browser on that target: enough to read the chain and
+//! dry-run transactions, but not to submit them.
+
+use async_trait::async_trait;
+use fuel_tx::{ConsensusParameters, Receipt, TxId};
+use fuel_types::{
+    canonical::{Deserialize, Serialize},
+    Address, AssetId,
+};
+use fuels_core::types::{
+    errors::{error, Error, Result},
+    transaction::Transaction,
+    tx_status::TxStatus,
+    DryRun, DryRunner, ReceiptIteratorExt, ReceiptKind, ReceiptMatcher,
+};
+use serde_json::{json, Value};
+
+const CHAIN_ID_QUERY: &str = "query { chain { consensusParameters { chainId } } }";
+
+const LATEST_BLOCK_HEIGHT_QUERY: &str = "query { chain { latestBlock { height } } }";
+
+const ESTIMATE_GAS_PRICE_QUERY: &str = "query($blockHorizon: U32) {
+    estimateGasPrice(blockHorizon: $blockHorizon) { gasPrice }
+}";
+
+const ESTIMATE_PREDICATES_QUERY: &str = "query($tx: HexString!) {
+    estimatePredicates(tx: $tx) { rawPayload }
+}";
+
+const BALANCE_QUERY: &str = "query($owner: Address!, $assetId: AssetId!) {
+    balance(owner: $owner, assetId: $assetId) { amount }
+}";
+
+const DRY_RUN_MUTATION: &str = "mutation(
+    $txs: [HexString!]!, $utxoValidation: Boolean, $gasPrice: U64
+) {
+    dryRun(txs: $txs, utxoValidation: $utxoValidation, gasPrice: $gasPrice) {
+        status {
+            __typename
+            ... on DryRunSuccessStatus { receipts { rawPayload } }
+            ... on DryRunFailureStatus { reason receipts { rawPayload } }
+        }
+    }
+}";
+
+const TX_STATUS_QUERY: &str = "query($id: TransactionId!) {
+    transaction(id: $id) {
+        status {
+            __typename
+            ... on SuccessStatus { receipts { rawPayload } }
+            ... on FailureStatus { reason receipts { rawPayload } }
+            ... on SqueezedOutStatus { reason }
+        }
+    }
+}";
+
+/// A read-only client of the GraphQL API of a node that compiles to `wasm32-unknown-unknown`:
+///
+/// ```ignore
+/// let provider =
+///     WasmProvider::connect("https://testnet.fuel.network/v1/graphql", consensus_parameters)
+///         .await?;
+///
+/// let balance = provider.get_asset_balance(&owner, &asset_id).await?;
+/// let status = provider.dry_run(tx).await?;
+/// ```
+///
+/// It reads the block height, balances, gas prices and transaction statuses, and dry-runs
+/// transactions, which the node executes without committing them. It never changes the state of
+/// the chain: transactions are submitted by a [`Provider`](crate::provider::Provider) elsewhere,
+/// e.g. on a backend.
+///
+/// It is also a [`DryRunner`], estimating gas and predicates with the node.
+#[derive(Debug, Clone)]
+pub struct WasmProvider {
+    url: String,
+    client: reqwest::Client,
+    consensus_parameters: ConsensusParameters,
+}
+
+impl WasmProvider {
+    /// Sends the queries to the GraphQL endpoint at `url`, e.g. `http://127.0.0.1:4000/v1/graphql`.
+    ///
+    /// The GraphQL API returns the consensus parameters field by field, in a shape that changes
+    /// with the version of `fuel-core`, so they are given instead, e.g. as serialized by a
+    /// backend from [`Provider::consensus_parameters`](crate::provider::Provider::consensus_parameters).
+    /// Fails if they are the parameters of another chain than the one of the node.
+    pub async fn connect(
+        url: impl Into<String>,
+        consensus_parameters: ConsensusParameters,
+    ) -> Result<Self> {
+        let provider = Self {
+            url: url.into(),
+            client: reqwest::Client::new(),
+            consensus_parameters,
+        };
+
+        let data = provider.query(CHAIN_ID_QUERY, json!({})).await?;
+        let chain_id = parse_number(&data["chain"]["consensusParameters"]["chainId"])?;
+        let expected = u64::from(provider.consensus_parameters.chain_id());
+        if chain_id != expected {
+            return Err(error!(
+                Provider,
+                "the consensus parameters are those of chain `{expected}`, but the node is on chain `{chain_id}`"
+            ));
+        }
+
+        Ok(provider)
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn consensus_parameters(&self) -> &ConsensusParameters {
+        &self.consensus_parameters
+    }
+
+    pub async fn latest_block_height(&self) -> Result<u32> {
+        let data = self.query(LATEST_BLOCK_HEIGHT_QUERY, json!({})).await?;
+
+        let height = parse_number(&data["chain"]["latestBlock"]["height"])?;
+        u32::try_from(height).map_err(|_| error!(Provider, "invalid block height `{height}`"))
+    }
+
+    pub async fn get_asset_balance(&self, owner: &Address, asset_id: &AssetId) -> Result<u64> {
+        let data = self
+            .query(
+                BALANCE_QUERY,
+                json!({ "owner": format!("{owner:#x}"), "assetId": format!("{asset_id:#x}") }),
+            )
+            .await?;
+
+        parse_number(&data["balance"]["amount"])
+    }
+
+    /// Executes `tx` without committing it, with the validation of its UTXOs.
+    pub async fn dry_run(&self, tx: impl Transaction) -> Result<TxStatus> {
+        self.dry_run_status(&tx.into(), true, None).await
+    }
+
+    pub async fn estimate_gas_price(&self, block_horizon: u32) -> Result<u64> {
+        let data = self
+            .query(
+                ESTIMATE_GAS_PRICE_QUERY,
+                json!({ "blockHorizon": block_horizon.to_string() }),
+            )
+            .await?;
+
+        parse_number(&data["estimateGasPrice"]["gasPrice"])
+    }
+
+    async fn dry_run_status(
+        &self,
+        tx: &fuel_tx::Transaction,
+        utxo_validation: bool,
+        gas_price: Option<u64>,
+    ) -> Result<TxStatus> {
+        let variables = json!({
+            "txs": [hex_string(&tx.to_bytes())],
+            "utxoValidation": utxo_validation,
+            "gasPrice": gas_price.map(|gas_price| gas_price.to_string()),
+        });
+        let data = self.query(DRY_RUN_MUTATION, variables).await?;
+
+        let status = &data["dryRun"][0]["status"];
+        match parse_status(status)? {
+            TxStatus::Submitted | TxStatus::SqueezedOut { .. } => Err(error!(
+                Provider,
+                "unexpected dry run status `{}`", status["__typename"]
+            )),
+            status => Ok(status),
+        }
+    }
+
+    /// The status of a transaction submitted elsewhere.
+    pub async fn tx_status(&self, tx_id: &TxId) -> Result<TxStatus> {
+        let data = self
+            .query(TX_STATUS_QUERY, json!({ "id": format!("{tx_id:#x}") }))
+            .await?;
+
+        match &data["transaction"] {
+            Value::Null => Err(error!(Provider, "transaction `{tx_id:#x}` not found")),
+            transaction => parse_status(&transaction["status"]),
+        }
+    }
+
+    async fn query(&self, query: &str, variables: Value) -> Result<Value> {
+        let response: Value = self
+            .client
+            .post(&self.url)
+            .json(&json!({ "query": query, "variables": variables }))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(request_error)?
+            .json()
+            .await
+            .map_err(request_error)?;
+
+        if let Some(errors) = response.get("errors").filter(|errors| !errors.is_null()) {
+            return Err(error!(Provider, "the node returned errors: {errors}"));
+        }
+
+        Ok(response["data"].clone())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl DryRunner for WasmProvider {
+    async fn dry_run(&self, tx: fuel_tx::Transaction) -> Result<DryRun> {
+        let status = self.dry_run_status(&tx, false, Some(0)).await?;
+
+        Ok(summarize_dry_run(status))
+    }
+
+    async fn estimate_gas_price(&self, block_horizon: u32) -> Result<u64> {
+        self.estimate_gas_price(block_horizon).await
+    }
+
+    fn consensus_parameters(&self) -> &ConsensusParameters {
+        self.consensus_parameters()
+    }
+
+    /// The version of the executor of the node isn't known to this provider, so predicates are
+    /// always estimated by the node.
+    async fn maybe_estimate_predicates(
+        &self,
+        tx: &fuel_tx::Transaction,
+        _latest_chain_executor_version: Option<u32>,
+    ) -> Result<Option<fuel_tx::Transaction>> {
+        let data = self
+            .query(
+                ESTIMATE_PREDICATES_QUERY,
+                json!({ "tx": hex_string(&tx.to_bytes()) }),
+            )
+            .await?;
+
+        let bytes = parse_bytes(&data["estimatePredicates"]["rawPayload"])?;
+        let estimated = fuel_tx::Transaction::from_bytes(&bytes)
+            .map_err(|err| error!(Codec, "could not decode transaction: {err:?}"))?;
+
+        Ok(Some(estimated))
+    }
+}
+
+fn summarize_dry_run(status: TxStatus) -> DryRun {
+    let succeeded = matches!(status, TxStatus::Success { .. });
+    let receipts = status.take_receipts();
+
+    let script_gas = receipts
+        .iter()
+        .rev()
+        .find_map(|receipt| match receipt {
+            Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+            _ => None,
+        })
+        .unwrap_or(0);
+
+    let variable_outputs = receipts
+        .iter()
+        .matching(
+            &ReceiptMatcher::default()
+                .with_kind(ReceiptKind::TransferOut)
+                .with_amount(1..),
+        )
+        .count();
+
+    DryRun {
+        succeeded,
+        script_gas,
+        variable_outputs,
+    }
+}
+
+fn parse_status(status: &Value) -> Result<TxStatus> {
+    let status = match status["__typename"].as_str() {
+        Some("SubmittedStatus") => TxStatus::Submitted,
+        Some("SuccessStatus" | "DryRunSuccessStatus") => TxStatus::Success {
+            receipts: parse_receipts(&status["receipts"])?,
+        },
+        Some("FailureStatus" | "DryRunFailureStatus") => {
+            let receipts = parse_receipts(&status["receipts"])?;
+            let revert_id = receipts
+                .iter()
+                .rev()
+                .find_map(|receipt| match receipt {
+                    Receipt::Revert { ra, .. } => Some(*ra),
+                    _ => None,
+                })
+                .unwrap_or_default();
+
+            TxStatus::Revert {
+                receipts,
+                reason: status["reason"].as_str().unwrap_or_default().to_string(),
+                revert_id,
+            }
+        }
+        Some("SqueezedOutStatus") => TxStatus::SqueezedOut {
+            reason: status["reason"].as_str().unwrap_or_default().to_string(),
+        },
+        _ => return Err(error!(Provider, "unknown transaction status `{status}`")),
+    };
+
+    Ok(status)
+}
+
+fn parse_receipts(receipts: &Value) -> Result<Vec<Receipt>> {
+    receipts
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .map(|receipt| {
+            let bytes = parse_bytes(&receipt["rawPayload"])?;
+
+            Receipt::from_bytes(&bytes)
+                .map_err(|err| error!(Codec, "could not decode receipt: {err:?}"))
+        })
+        .collect()
+}
+
+/// Numbers wider than 32 bits are given as strings by the node.
+fn parse_number(value: &Value) -> Result<u64> {
+    value
+        .as_u64()
+        .or_else(|| value.as_str().and_then(|number| number.parse().ok()))
+        .ok_or_else(|| error!(Provider, "expected a number, got `{value}`"))
+}
+
+fn parse_bytes(value: &Value) -> Result<Vec<u8>> {
+    let hex_string = value
+        .as_str()
+        .ok_or_else(|| error!(Provider, "expected a hex string, got `{value}`"))?;
+
+    hex::decode(hex_string.trim_start_matches("0x"))
+        .map_err(|err| error!(Provider, "invalid hex string `{hex_string}`: {err}"))
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn request_error(err: reqwest::Error) -> Error {
+    error!(Provider, "request to the node failed: {err}")
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_types::ContractId;
+
+    use super::*;
+
+    #[test]
+    fn statuses_are_parsed_with_their_receipts() -> Result<()> {
+        let revert = Receipt::revert(ContractId::zeroed(), 42, 0, 0);
+        let status = json!({
+            "__typename": "DryRunFailureStatus",
+            "reason": "Revert(42)",
+            "receipts": [{ "rawPayload": hex_string(&revert.to_bytes()) }],
+        });
+
+        let TxStatus::Revert {
+            receipts,
+            reason,
+            revert_id,
+        } = parse_status(&status)?
+        else {
+            panic!("expected a revert");
+        };
+        assert_eq!(receipts, [revert]);
+        assert_eq!(reason, "Revert(42)");
+        assert_eq!(revert_id, 42);
+
+        assert!(matches!(
+            parse_status(&json!({ "__typename": "SubmittedStatus" }))?,
+            TxStatus::Submitted
+        ));
+        assert_eq!(parse_number(&json!("18446744073709551615"))?, u64::MAX);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dry_runs_are_summarized_from_their_receipts() {
+        let receipts = vec![
+            Receipt::transfer_out(
+                ContractId::zeroed(),
+                Address::zeroed(),
+                0,
+                AssetId::zeroed(),
+                0,
+                0,
+            ),
+            Receipt::transfer_out(
+                ContractId::zeroed(),
+                Address::zeroed(),
+                10,
+                AssetId::zeroed(),
+                0,
+                0,
+            ),
+            Receipt::script_result(fuel_tx::ScriptExecutionResult::Success, 1234),
+        ];
+
+        let dry_run = summarize_dry_run(TxStatus::Success { receipts });
+
+        assert!(dry_run.succeeded);
+        assert_eq!(dry_run.script_gas, 1234);
+        assert_eq!(dry_run.variable_outputs, 1);
+    }
+}
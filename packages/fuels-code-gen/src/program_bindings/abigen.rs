@@ -11,7 +11,8 @@ use regex::Regex;
 use crate::{
     error::Result,
     program_bindings::{
-        abigen::bindings::generate_bindings, custom_types::generate_types,
+        abigen::bindings::generate_bindings,
+        custom_types::{generate_js_conversions, generate_types},
         generated_code::GeneratedCode,
     },
     utils::ident,
@@ -68,9 +69,10 @@ impl Abigen {
     fn generate_code(no_std: bool, parsed_targets: Vec<AbigenTarget>) -> Result<GeneratedCode> {
         let custom_types = Self::filter_custom_types(&parsed_targets);
         let shared_types = Self::filter_shared_types(custom_types);
+        let wasm_bindgen = parsed_targets.iter().any(AbigenTarget::wasm_bindgen);
 
         let bindings = Self::generate_all_bindings(parsed_targets, no_std, &shared_types)?;
-        let shared_types = Self::generate_shared_types(shared_types, no_std)?;
+        let shared_types = Self::generate_shared_types(shared_types, no_std, wasm_bindgen)?;
 
         let mod_name = ident("abigen_bindings");
         Ok(shared_types.merge(bindings).wrap_in_mod(mod_name))
@@ -99,9 +101,15 @@ impl Abigen {
         let recompile_trigger =
             Self::generate_macro_recompile_trigger(target.source.path.as_ref(), no_std);
         let types = generate_types(&target.source.abi.types, shared_types, no_std)?;
+        let js_conversions = if target.wasm_bindgen {
+            generate_js_conversions(&target.source.abi.types, shared_types, no_std)?
+        } else {
+            GeneratedCode::default()
+        };
         let bindings = generate_bindings(target, no_std)?;
         Ok(recompile_trigger
             .merge(types)
+            .merge(js_conversions)
             .merge(bindings)
             .wrap_in_mod(mod_name))
     }
@@ -125,8 +133,16 @@ impl Abigen {
     fn generate_shared_types(
         shared_types: HashSet<FullTypeDeclaration>,
         no_std: bool,
+        wasm_bindgen: bool,
     ) -> Result<GeneratedCode> {
-        let types = generate_types(&shared_types, &HashSet::default(), no_std)?;
+        let mut types = generate_types(&shared_types, &HashSet::default(), no_std)?;
+        if wasm_bindgen {
+            types = types.merge(generate_js_conversions(
+                &shared_types,
+                &HashSet::default(),
+                no_std,
+            )?);
+        }
 
         if types.is_empty() {
             Ok(Default::default())
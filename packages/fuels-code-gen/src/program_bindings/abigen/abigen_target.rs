@@ -15,6 +15,7 @@ pub struct AbigenTarget {
     pub(crate) name: String,
     pub(crate) source: Abi,
     pub(crate) program_type: ProgramType,
+    pub(crate) wasm_bindgen: bool,
 }
 
 impl AbigenTarget {
@@ -23,9 +24,17 @@ impl AbigenTarget {
             name,
             source,
             program_type,
+            wasm_bindgen: false,
         }
     }
 
+    /// Also generates `JsValue` conversions for the custom types and, for predicates, a
+    /// `#[wasm_bindgen]` function encoding the predicate data.
+    pub fn with_wasm_bindgen(mut self, wasm_bindgen: bool) -> Self {
+        self.wasm_bindgen = wasm_bindgen;
+        self
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -37,6 +46,10 @@ impl AbigenTarget {
     pub fn program_type(&self) -> ProgramType {
         self.program_type
     }
+
+    pub fn wasm_bindgen(&self) -> bool {
+        self.wasm_bindgen
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -4,7 +4,9 @@ use crate::{
         abigen::{
             abigen_target::AbigenTarget,
             bindings::{
-                contract::contract_bindings, predicate::predicate_bindings, script::script_bindings,
+                contract::contract_bindings,
+                predicate::{predicate_bindings, predicate_wasm_bindings},
+                script::script_bindings,
             },
             ProgramType,
         },
@@ -27,6 +29,13 @@ pub(crate) fn generate_bindings(target: AbigenTarget, no_std: bool) -> Result<Ge
     };
 
     let name = ident(&target.name);
+    let wasm_bindings = match target.program_type {
+        ProgramType::Predicate if target.wasm_bindgen => {
+            predicate_wasm_bindings(&name, &target.source.abi, no_std)?
+        }
+        _ => GeneratedCode::default(),
+    };
+
     let abi = target.source.abi;
-    bindings_generator(&name, abi, no_std)
+    Ok(bindings_generator(&name, abi, no_std)?.merge(wasm_bindings))
 }
@@ -82,6 +82,13 @@ pub(crate) fn contract_bindings(
                 self
             }
 
+            pub fn with_log_decoder(mut self, log_decoder: ::fuels::core::codec::LogDecoder)
+            -> #name::<A> {
+                self.log_decoder = log_decoder;
+
+                self
+            }
+
             pub async fn get_balances(&self) -> ::fuels::types::errors::Result<::std::collections::HashMap<::fuels::types::AssetId, u64>> {
                 ::fuels::accounts::ViewOnlyAccount::try_provider(&self.account)?
                                   .get_contract_balances(&self.contract_id)
@@ -89,6 +96,17 @@ pub(crate) fn contract_bindings(
                                   .map_err(::std::convert::Into::into)
             }
 
+            pub fn events<T>(&self) -> ::fuels::programs::events::Events<T>
+            where
+                T: ::fuels::core::traits::Tokenizable + ::fuels::core::traits::Parameterize + 'static,
+            {
+                ::fuels::programs::events::Events::new(
+                    ::fuels::accounts::ViewOnlyAccount::try_provider(&self.account).ok().cloned(),
+                    &self.contract_id,
+                    self.log_decoder.clone(),
+                )
+            }
+
             pub fn methods(&self) -> #methods_name<A> {
                 #methods_name {
                     contract_id: self.contract_id.clone(),
@@ -1,4 +1,7 @@
+use std::collections::HashSet;
+
 use fuel_abi_types::abi::full_program::{FullABIFunction, FullProgramABI};
+use inflector::Inflector;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
@@ -10,6 +13,7 @@ use crate::{
             configurables::generate_code_for_configurable_constants,
         },
         generated_code::GeneratedCode,
+        utils::Components,
     },
     utils::{ident, TypePath},
 };
@@ -54,6 +58,47 @@ pub(crate) fn predicate_bindings(
     Ok(GeneratedCode::new(code, type_paths, no_std))
 }
 
+/// A `#[wasm_bindgen]` function encoding the predicate data from a JavaScript array holding the
+/// arguments of `main`, converted through their JSON representation.
+pub(crate) fn predicate_wasm_bindings(
+    name: &Ident,
+    abi: &FullProgramABI,
+    no_std: bool,
+) -> Result<GeneratedCode> {
+    let main_function_abi = extract_main_fn(&abi.functions)?;
+    let args = Components::new(main_function_abi.inputs(), true, TypePath::default())?;
+    let (arg_names, arg_types): (Vec<_>, Vec<_>) = args.iter().unzip();
+
+    let encoder_struct_name = ident(&format!("{name}Encoder"));
+    let fn_name = ident(&format!("encode_{}_data", name.to_string().to_snake_case()));
+    let js_name = format!("encode{}Data", name.to_string().to_pascal_case());
+
+    let code = quote! {
+        #[doc = "Encodes the predicate data from an array of the arguments of `main`."]
+        #[::wasm_bindgen::prelude::wasm_bindgen(js_name = #js_name)]
+        pub fn #fn_name(
+            args: ::wasm_bindgen::JsValue,
+        ) -> ::core::result::Result<::std::vec::Vec<u8>, ::wasm_bindgen::JsError> {
+            let to_js_error =
+                |err: ::fuels::types::errors::Error| ::wasm_bindgen::JsError::new(&err.to_string());
+
+            let json: ::std::string::String = ::js_sys::JSON::stringify(&args)
+                .map_err(|_| ::wasm_bindgen::JsError::new("the predicate arguments cannot be converted to JSON"))?
+                .into();
+            let (#(#arg_names,)*): (#(#arg_types,)*) =
+                ::fuels::core::codec::from_json_str(&json).map_err(to_js_error)?;
+
+            #encoder_struct_name::default()
+                .encode_data(#(#arg_names),*)
+                .map_err(to_js_error)
+        }
+    };
+
+    let fn_path = TypePath::new(&fn_name).expect("We know the given function name is not empty");
+
+    Ok(GeneratedCode::new(code, HashSet::from([fn_path]), no_std))
+}
+
 fn expand_fn(fn_abi: &FullABIFunction) -> Result<TokenStream> {
     let mut generator = FunctionGenerator::new(fn_abi)?;
 
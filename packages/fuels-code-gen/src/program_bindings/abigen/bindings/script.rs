@@ -89,6 +89,14 @@ pub(crate) fn script_bindings(
                 self.log_decoder.clone()
             }
 
+            pub fn with_log_decoder(mut self, log_decoder: ::fuels::core::codec::LogDecoder)
+                -> Self
+            {
+                self.log_decoder = log_decoder;
+
+                self
+            }
+
             #main_function
         }
 
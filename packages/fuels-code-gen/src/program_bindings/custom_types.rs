@@ -15,9 +15,12 @@ use crate::{
 };
 
 mod enums;
+mod js_conversions;
 mod structs;
 mod utils;
 
+pub(crate) use js_conversions::generate_js_conversions;
+
 /// Generates Rust code for each type inside `types` if:
 /// * the type is not present inside `shared_types`, and
 /// * if it should be generated (see: [`should_skip_codegen`], and
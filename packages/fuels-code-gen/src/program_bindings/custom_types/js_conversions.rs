@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+
+use fuel_abi_types::abi::full_program::FullTypeDeclaration;
+use itertools::Itertools;
+use quote::quote;
+
+use crate::{
+    error::Result,
+    program_bindings::{
+        custom_types::{should_skip_codegen, utils::extract_generic_parameters},
+        generated_code::GeneratedCode,
+    },
+};
+
+/// Generates `TryFrom` conversions between `::wasm_bindgen::JsValue` and the non-generic structs
+/// and enums among `types`, except those in `shared_types`. The values are converted through
+/// their JSON representation, see `fuels::core::codec::to_json`.
+pub(crate) fn generate_js_conversions<'a, T: IntoIterator<Item = &'a FullTypeDeclaration>>(
+    types: T,
+    shared_types: &HashSet<FullTypeDeclaration>,
+    no_std: bool,
+) -> Result<GeneratedCode> {
+    types
+        .into_iter()
+        .filter(|ttype| {
+            !should_skip_codegen(ttype)
+                && !shared_types.contains(ttype)
+                && extract_generic_parameters(ttype).is_empty()
+        })
+        .map(|ttype| js_conversions(ttype, no_std))
+        .fold_ok(GeneratedCode::default(), |acc, generated_code| {
+            acc.merge(generated_code)
+        })
+}
+
+fn js_conversions(type_decl: &FullTypeDeclaration, no_std: bool) -> Result<GeneratedCode> {
+    let type_path = type_decl.custom_type_path()?;
+    let type_ident = type_path.ident().unwrap();
+
+    let stringify_error = format!("the value cannot be converted to `{type_ident}`");
+    let parse_error = format!("`{type_ident}` cannot be converted to a JavaScript value");
+
+    let code = quote! {
+        impl ::core::convert::TryFrom<::wasm_bindgen::JsValue> for #type_ident {
+            type Error = ::wasm_bindgen::JsError;
+
+            fn try_from(value: ::wasm_bindgen::JsValue) -> ::core::result::Result<Self, Self::Error> {
+                let json: ::std::string::String = ::js_sys::JSON::stringify(&value)
+                    .map_err(|_| ::wasm_bindgen::JsError::new(#stringify_error))?
+                    .into();
+
+                ::fuels::core::codec::from_json_str(&json)
+                    .map_err(|err| ::wasm_bindgen::JsError::new(&err.to_string()))
+            }
+        }
+
+        impl ::core::convert::TryFrom<#type_ident> for ::wasm_bindgen::JsValue {
+            type Error = ::wasm_bindgen::JsError;
+
+            fn try_from(value: #type_ident) -> ::core::result::Result<Self, Self::Error> {
+                let json = ::fuels::core::codec::to_json_string(value)
+                    .map_err(|err| ::wasm_bindgen::JsError::new(&err.to_string()))?;
+
+                ::js_sys::JSON::parse(&json).map_err(|_| ::wasm_bindgen::JsError::new(#parse_error))
+            }
+        }
+    };
+
+    Ok(GeneratedCode::new(code, HashSet::default(), no_std).wrap_in_mod(type_path.parent()))
+}
@@ -0,0 +1,18 @@
+//! Writes the encoding test vectors of fuels-rs to the given file:
+//!
+//! ```sh
+//! cargo run -p fuels-core --features test-vectors --example test_vectors -- vectors.json
+//! ```
+
+use fuels_core::{codec::test_vectors::TestVectors, types::errors::Result};
+
+fn main() -> Result<()> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "test-vectors.json".to_string());
+
+    TestVectors::generate()?.save(&path)?;
+    println!("test vectors written to `{path}`");
+
+    Ok(())
+}
@@ -1,12 +1,18 @@
 mod abi_decoder;
 mod abi_encoder;
 mod function_selector;
+#[cfg(feature = "json")]
+mod json;
 mod logs;
+#[cfg(feature = "test-vectors")]
+pub mod test_vectors;
 mod utils;
 
 pub use abi_decoder::*;
 pub use abi_encoder::*;
 pub use function_selector::*;
+#[cfg(feature = "json")]
+pub use json::*;
 pub use logs::*;
 
 use crate::{
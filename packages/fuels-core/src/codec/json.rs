@@ -0,0 +1,294 @@
+use serde_json::{json, Map, Value};
+
+use crate::{
+    traits::{Parameterize, Tokenizable},
+    types::{
+        errors::{error, Result},
+        param_types::ParamType,
+        StaticStringToken, Token, U256,
+    },
+};
+
+/// Converts `value` to JSON, e.g. to hand it to JavaScript:
+///
+/// * structs are objects keyed by field name, and tuples and arrays are arrays,
+/// * enum variants are the name of the variant if it holds no data, and an object with the name
+///   of the variant as its only key otherwise,
+/// * integers wider than 64 bits are decimal strings, and bytes are `0x` prefixed hex strings.
+pub fn to_json<T: Tokenizable + Parameterize>(value: T) -> Result<Value> {
+    token_to_json(&T::param_type(), value.into_token())
+}
+
+/// The reverse of [`to_json`]. Integers are also accepted as decimal strings, so that 64-bit
+/// values beyond the safe integer range of JavaScript don't lose precision.
+pub fn from_json<T: Tokenizable + Parameterize>(value: &Value) -> Result<T> {
+    T::from_token(json_to_token(&T::param_type(), value)?)
+}
+
+/// [`to_json`], serialized to a string.
+pub fn to_json_string<T: Tokenizable + Parameterize>(value: T) -> Result<String> {
+    Ok(to_json(value)?.to_string())
+}
+
+/// [`from_json`], parsing `json` first.
+pub fn from_json_str<T: Tokenizable + Parameterize>(json: &str) -> Result<T> {
+    let value: Value =
+        serde_json::from_str(json).map_err(|err| error!(Codec, "invalid JSON: {err}"))?;
+
+    from_json(&value)
+}
+
+pub fn token_to_json(param_type: &ParamType, token: Token) -> Result<Value> {
+    let value = match (param_type, token) {
+        (_, Token::Unit) => Value::Null,
+        (_, Token::Bool(value)) => value.into(),
+        (_, Token::U8(value)) => value.into(),
+        (_, Token::U16(value)) => value.into(),
+        (_, Token::U32(value)) => value.into(),
+        (_, Token::U64(value)) => value.into(),
+        (_, Token::U128(value)) => value.to_string().into(),
+        (_, Token::U256(value)) => value.to_string().into(),
+        (_, Token::B256(bytes)) => format!("0x{}", hex::encode(bytes)).into(),
+        (_, Token::Bytes(bytes) | Token::RawSlice(bytes)) => {
+            format!("0x{}", hex::encode(bytes)).into()
+        }
+        (_, Token::String(string)) => string.into(),
+        (_, Token::StringArray(string) | Token::StringSlice(string)) => {
+            String::try_from(string)?.into()
+        }
+        (ParamType::Tuple(param_types), Token::Tuple(tokens)) => param_types
+            .iter()
+            .zip(tokens)
+            .map(|(param_type, token)| token_to_json(param_type, token))
+            .collect::<Result<_>>()?,
+        (
+            ParamType::Array(param_type, _) | ParamType::Vector(param_type),
+            Token::Array(tokens) | Token::Vector(tokens),
+        ) => tokens
+            .into_iter()
+            .map(|token| token_to_json(param_type, token))
+            .collect::<Result<_>>()?,
+        (ParamType::Struct { fields, .. }, Token::Struct(tokens)) => fields
+            .iter()
+            .zip(tokens)
+            .map(|((name, param_type), token)| {
+                Ok((name.clone(), token_to_json(param_type, token)?))
+            })
+            .collect::<Result<Map<_, _>>>()?
+            .into(),
+        (ParamType::Enum { .. }, Token::Enum(selector)) => {
+            let (discriminant, token, variants) = *selector;
+            let (name, param_type) = variants.select_variant(discriminant)?;
+
+            match param_type {
+                ParamType::Unit => name.clone().into(),
+                _ => json!({ name: token_to_json(param_type, token)? }),
+            }
+        }
+        (param_type, token) => {
+            return Err(error!(
+                Codec,
+                "token `{token}` does not match the type `{param_type:?}`"
+            ))
+        }
+    };
+
+    Ok(value)
+}
+
+pub fn json_to_token(param_type: &ParamType, value: &Value) -> Result<Token> {
+    let mismatch = || {
+        error!(
+            Codec,
+            "JSON `{value}` does not match the type `{param_type:?}`"
+        )
+    };
+
+    let token = match param_type {
+        ParamType::Unit => Token::Unit,
+        ParamType::Bool => Token::Bool(value.as_bool().ok_or_else(mismatch)?),
+        ParamType::U8 => Token::U8(json_to_int(value)?),
+        ParamType::U16 => Token::U16(json_to_int(value)?),
+        ParamType::U32 => Token::U32(json_to_int(value)?),
+        ParamType::U64 => Token::U64(json_to_int(value)?),
+        ParamType::U128 => Token::U128(json_to_int(value)?),
+        ParamType::U256 => {
+            let number = json_to_decimal(value)?;
+            Token::U256(
+                U256::from_dec_str(&number)
+                    .map_err(|err| error!(Codec, "invalid u256 `{number}`: {err:?}"))?,
+            )
+        }
+        ParamType::B256 => Token::B256(
+            json_to_bytes(value)?
+                .try_into()
+                .map_err(|_| error!(Codec, "expected 32 bytes, got `{value}`"))?,
+        ),
+        ParamType::Bytes => Token::Bytes(json_to_bytes(value)?),
+        ParamType::RawSlice => Token::RawSlice(json_to_bytes(value)?),
+        ParamType::String => Token::String(value.as_str().ok_or_else(mismatch)?.to_string()),
+        ParamType::StringArray(len) => Token::StringArray(StaticStringToken::new(
+            value.as_str().ok_or_else(mismatch)?.to_string(),
+            Some(*len),
+        )),
+        ParamType::StringSlice => Token::StringSlice(StaticStringToken::new(
+            value.as_str().ok_or_else(mismatch)?.to_string(),
+            None,
+        )),
+        ParamType::Tuple(param_types) => {
+            let values = value
+                .as_array()
+                .filter(|values| values.len() == param_types.len())
+                .ok_or_else(mismatch)?;
+
+            Token::Tuple(
+                param_types
+                    .iter()
+                    .zip(values)
+                    .map(|(param_type, value)| json_to_token(param_type, value))
+                    .collect::<Result<_>>()?,
+            )
+        }
+        ParamType::Array(param_type, len) => {
+            let values = value
+                .as_array()
+                .filter(|values| values.len() == *len)
+                .ok_or_else(mismatch)?;
+
+            Token::Array(
+                values
+                    .iter()
+                    .map(|value| json_to_token(param_type, value))
+                    .collect::<Result<_>>()?,
+            )
+        }
+        ParamType::Vector(param_type) => Token::Vector(
+            value
+                .as_array()
+                .ok_or_else(mismatch)?
+                .iter()
+                .map(|value| json_to_token(param_type, value))
+                .collect::<Result<_>>()?,
+        ),
+        ParamType::Struct { fields, .. } => {
+            let object = value.as_object().ok_or_else(mismatch)?;
+
+            Token::Struct(
+                fields
+                    .iter()
+                    .map(|(name, param_type)| {
+                        let value = object
+                            .get(name)
+                            .ok_or_else(|| error!(Codec, "missing field `{name}` in `{value}`"))?;
+
+                        json_to_token(param_type, value)
+                    })
+                    .collect::<Result<_>>()?,
+            )
+        }
+        ParamType::Enum { enum_variants, .. } => {
+            let (variant, inner) = match value {
+                Value::String(variant) => (variant.as_str(), &Value::Null),
+                Value::Object(object) if object.len() == 1 => {
+                    let (variant, inner) = object.iter().next().expect("has one entry");
+                    (variant.as_str(), inner)
+                }
+                _ => return Err(mismatch()),
+            };
+
+            let (discriminant, (_, variant_type)) = enum_variants
+                .variants()
+                .iter()
+                .enumerate()
+                .find(|(_, (name, _))| name == variant)
+                .ok_or_else(|| error!(Codec, "unknown variant `{variant}` in `{value}`"))?;
+
+            Token::Enum(Box::new((
+                discriminant as u64,
+                json_to_token(variant_type, inner)?,
+                enum_variants.clone(),
+            )))
+        }
+    };
+
+    Ok(token)
+}
+
+fn json_to_decimal(value: &Value) -> Result<String> {
+    match value {
+        Value::Number(number) if number.is_u64() => Ok(number.to_string()),
+        Value::String(number) => Ok(number.clone()),
+        _ => Err(error!(Codec, "expected an unsigned integer, got `{value}`")),
+    }
+}
+
+fn json_to_int<T: TryFrom<u128>>(value: &Value) -> Result<T> {
+    let number = json_to_decimal(value)?;
+
+    number
+        .parse::<u128>()
+        .ok()
+        .and_then(|number| T::try_from(number).ok())
+        .ok_or_else(|| error!(Codec, "integer `{number}` is invalid or out of range"))
+}
+
+fn json_to_bytes(value: &Value) -> Result<Vec<u8>> {
+    let hex_string = value
+        .as_str()
+        .ok_or_else(|| error!(Codec, "expected a hex string, got `{value}`"))?;
+
+    hex::decode(hex_string.trim_start_matches("0x"))
+        .map_err(|err| error!(Codec, "invalid hex string `{hex_string}`: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Bits256, SizedAsciiString};
+
+    #[test]
+    fn values_round_trip_through_json() -> Result<()> {
+        type Tuple = (
+            u64,
+            u128,
+            Bits256,
+            Vec<bool>,
+            Option<SizedAsciiString<3>>,
+            Option<u8>,
+        );
+        let value: Tuple = (
+            u64::MAX,
+            7,
+            Bits256([1; 32]),
+            vec![true, false],
+            Some("abc".try_into()?),
+            None,
+        );
+
+        let json = to_json(value.clone())?;
+        assert_eq!(
+            json,
+            json!([
+                u64::MAX,
+                "7",
+                format!("0x{}", "01".repeat(32)),
+                [true, false],
+                { "Some": "abc" },
+                "None",
+            ])
+        );
+        assert_eq!(from_json::<Tuple>(&json)?, value);
+
+        let precise = json!([
+            u64::MAX.to_string(),
+            7,
+            format!("0x{}", "01".repeat(32)),
+            [true, false],
+            { "Some": "abc" },
+            "None",
+        ]);
+        assert_eq!(from_json::<Tuple>(&precise)?, value);
+
+        Ok(())
+    }
+}
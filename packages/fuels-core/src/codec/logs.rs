@@ -1,43 +1,79 @@
 use std::{
-    any::TypeId,
+    any::{Any, TypeId},
     collections::{HashMap, HashSet},
     fmt::{Debug, Formatter},
     iter::FilterMap,
+    sync::Arc,
 };
 
+#[cfg(feature = "json")]
+use fuel_tx::TxId;
 use fuel_tx::{ContractId, Receipt};
+#[cfg(feature = "json")]
+use serde_json::{json, Value};
 
+#[cfg(feature = "json")]
+use crate::codec::token_to_json;
 use crate::{
     codec::{ABIDecoder, DecoderConfig},
     traits::{Parameterize, Tokenizable},
-    types::errors::{error, Error, Result},
+    types::{
+        errors::{error, Error, Result},
+        param_types::ParamType,
+        Token,
+    },
 };
 
+type FormatFn = dyn Fn(DecoderConfig, &[u8]) -> Result<String> + Send + Sync;
+
 #[derive(Clone)]
 pub struct LogFormatter {
-    formatter: fn(DecoderConfig, &[u8]) -> Result<String>,
-    type_id: TypeId,
+    formatter: Arc<FormatFn>,
+    /// `None` for the formatters of types only known at runtime.
+    type_id: Option<TypeId>,
+    param_type: ParamType,
 }
 
 impl LogFormatter {
     pub fn new<T: Tokenizable + Parameterize + Debug + 'static>() -> Self {
+        Self::new_custom(|log: T| format!("{log:?}"))
+    }
+
+    /// A formatter for the logs of type `T` printing them with `format` instead of their `Debug`
+    /// implementation.
+    pub fn new_custom<T: Tokenizable + Parameterize + 'static>(
+        format: impl Fn(T) -> String + Send + Sync + 'static,
+    ) -> Self {
         Self {
-            formatter: Self::format_log::<T>,
-            type_id: TypeId::of::<T>(),
+            formatter: Arc::new(move |decoder_config: DecoderConfig, bytes: &[u8]| {
+                let token = ABIDecoder::new(decoder_config).decode(&T::param_type(), bytes)?;
+
+                Ok(format(T::from_token(token)?))
+            }),
+            type_id: Some(TypeId::of::<T>()),
+            param_type: T::param_type(),
         }
     }
 
-    fn format_log<T: Parameterize + Tokenizable + Debug>(
-        decoder_config: DecoderConfig,
-        bytes: &[u8],
-    ) -> Result<String> {
-        let token = ABIDecoder::new(decoder_config).decode(&T::param_type(), bytes)?;
+    /// A formatter for the logs of `param_type`, e.g. of an ABI loaded at runtime, printing the
+    /// decoded tokens with `format`.
+    pub fn from_param_type(
+        param_type: ParamType,
+        format: impl Fn(Token) -> Result<String> + Send + Sync + 'static,
+    ) -> Self {
+        let decoded_type = param_type.clone();
 
-        Ok(format!("{:?}", T::from_token(token)?))
+        Self {
+            formatter: Arc::new(move |decoder_config: DecoderConfig, bytes: &[u8]| {
+                format(ABIDecoder::new(decoder_config).decode(&decoded_type, bytes)?)
+            }),
+            type_id: None,
+            param_type,
+        }
     }
 
     pub fn can_handle_type<T: Tokenizable + Parameterize + 'static>(&self) -> bool {
-        TypeId::of::<T>() == self.type_id
+        self.type_id == Some(TypeId::of::<T>())
     }
 
     pub fn format(&self, decoder_config: DecoderConfig, bytes: &[u8]) -> Result<String> {
@@ -62,6 +98,8 @@ pub struct LogId(ContractId, String);
 pub struct LogDecoder {
     /// A mapping of LogId and param-type
     log_formatters: HashMap<LogId, LogFormatter>,
+    /// Formatters overriding those of `log_formatters` for all the logs of a type.
+    type_formatters: HashMap<TypeId, LogFormatter>,
     decoder_config: DecoderConfig,
 }
 
@@ -86,14 +124,116 @@ impl LogResult {
     }
 }
 
+type DecodeAsFn = fn(Token) -> Result<Box<dyn Any + Send + Sync>>;
+
+/// A set of log types to decode together with [`LogDecoder::decode_logs_of_types`], given as a
+/// tuple, e.g. `(TransferEvent, MintEvent)`.
+pub trait LogTypes {
+    #[doc(hidden)]
+    fn decoders() -> Vec<(TypeId, DecodeAsFn)>;
+}
+
+fn decode_as<T: Tokenizable + Send + Sync + 'static>(
+    token: Token,
+) -> Result<Box<dyn Any + Send + Sync>> {
+    Ok(Box::new(T::from_token(token)?))
+}
+
+macro_rules! impl_log_types_tuples {
+    ($($ty: ident,)+) => {
+        impl<$($ty,)+> LogTypes for ($($ty,)+)
+        where
+            $(
+                $ty: Tokenizable + Parameterize + Send + Sync + 'static,
+            )+
+        {
+            fn decoders() -> Vec<(TypeId, DecodeAsFn)> {
+                vec![$((TypeId::of::<$ty>(), decode_as::<$ty> as DecodeAsFn),)+]
+            }
+        }
+    };
+}
+
+impl_log_types_tuples!(A,);
+impl_log_types_tuples!(A, B,);
+impl_log_types_tuples!(A, B, C,);
+impl_log_types_tuples!(A, B, C, D,);
+impl_log_types_tuples!(A, B, C, D, E,);
+impl_log_types_tuples!(A, B, C, D, E, F,);
+impl_log_types_tuples!(A, B, C, D, E, F, G,);
+impl_log_types_tuples!(A, B, C, D, E, F, G, H,);
+
+/// A log decoded by [`LogDecoder::decode_logs_of_types`], holding one of the requested types.
+pub struct DecodedLog {
+    /// The index of the log receipt among the decoded receipts.
+    pub receipt_index: usize,
+    /// The contract that emitted the log.
+    pub contract_id: ContractId,
+    value: Box<dyn Any + Send + Sync>,
+}
+
+impl DecodedLog {
+    pub fn is<T: 'static>(&self) -> bool {
+        self.value.is::<T>()
+    }
+
+    /// The log as a `T`, or `None` if it is of another type.
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+
+    /// Takes the log out as a `T`, or gives it back if it is of another type.
+    pub fn downcast<T: 'static>(self) -> std::result::Result<T, Self> {
+        match self.value.downcast() {
+            Ok(value) => Ok(*value),
+            Err(value) => Err(Self { value, ..self }),
+        }
+    }
+}
+
+impl Debug for DecodedLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DecodedLog")
+            .field("receipt_index", &self.receipt_index)
+            .field("contract_id", &self.contract_id)
+            .finish_non_exhaustive()
+    }
+}
+
 impl LogDecoder {
     pub fn new(log_formatters: HashMap<LogId, LogFormatter>) -> Self {
         Self {
             log_formatters,
+            type_formatters: Default::default(),
             decoder_config: Default::default(),
         }
     }
 
+    /// Registers `log_formatter` for the logs of `contract_id` with the given log id, e.g. to
+    /// decode the logs of a contract whose ABI is loaded at runtime. Replaces the formatter
+    /// registered for that log id, if any.
+    pub fn register_log_formatter(
+        &mut self,
+        contract_id: ContractId,
+        log_id: impl Into<String>,
+        log_formatter: LogFormatter,
+    ) -> &mut Self {
+        self.log_formatters
+            .insert(LogId(contract_id, log_id.into()), log_formatter);
+        self
+    }
+
+    /// Formats all the logs of type `T`, whichever contract emitted them, with `format` instead
+    /// of their `Debug` implementation, e.g. to pretty-print domain types.
+    pub fn register_type_formatter<T: Tokenizable + Parameterize + 'static>(
+        &mut self,
+        format: impl Fn(T) -> String + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.type_formatters
+            .insert(TypeId::of::<T>(), LogFormatter::new_custom(format));
+        self
+    }
+
     pub fn set_decoder_config(&mut self, decoder_config: DecoderConfig) -> &mut Self {
         self.decoder_config = decoder_config;
         self
@@ -122,6 +262,12 @@ impl LogDecoder {
                     data
                 )
             })
+            .map(|log_formatter| {
+                log_formatter
+                    .type_id
+                    .and_then(|type_id| self.type_formatters.get(&type_id))
+                    .unwrap_or(log_formatter)
+            })
             .and_then(|log_formatter| log_formatter.format(self.decoder_config, data))
     }
 
@@ -182,8 +328,100 @@ impl LogDecoder {
             .collect()
     }
 
+    /// Decodes the logs of any of the types of `L`, a tuple of log types, in a single pass over
+    /// `receipts`. Unlike calling [`decode_logs_with_type`](Self::decode_logs_with_type) once per
+    /// type, the logs keep their order and the index of their receipt:
+    ///
+    /// ```ignore
+    /// for log in log_decoder.decode_logs_of_types::<(TransferEvent, MintEvent)>(&receipts)? {
+    ///     if let Some(transfer) = log.get::<TransferEvent>() {
+    ///         // ...
+    ///     } else if let Some(mint) = log.get::<MintEvent>() {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    pub fn decode_logs_of_types<L: LogTypes>(
+        &self,
+        receipts: &[Receipt],
+    ) -> Result<Vec<DecodedLog>> {
+        let decoders = L::decoders();
+
+        receipts
+            .iter()
+            .enumerate()
+            .filter_map(|(receipt_index, receipt)| {
+                let (log_id, data) = log_id_and_data(receipt)?;
+                let log_formatter = self.log_formatters.get(&log_id)?;
+                let (_, decode_as) = decoders
+                    .iter()
+                    .find(|(type_id, _)| Some(*type_id) == log_formatter.type_id)?;
+
+                let decoded = ABIDecoder::new(self.decoder_config)
+                    .decode(&log_formatter.param_type, &data)
+                    .and_then(decode_as)
+                    .map(|value| DecodedLog {
+                        receipt_index,
+                        contract_id: log_id.0,
+                        value,
+                    });
+
+                Some(decoded)
+            })
+            .collect()
+    }
+
+    /// Decodes the logs of `receipts`, e.g. of the transaction `tx_id`, into JSON objects carrying
+    /// the Sway type of the log, the contract that emitted it and the index of its receipt, so
+    /// that they can be consumed without the Rust types:
+    ///
+    /// ```json
+    /// {
+    ///   "type": "TransferEvent",
+    ///   "contract_id": "0x…",
+    ///   "tx_id": "0x…",
+    ///   "receipt_index": 2,
+    ///   "value": { "amount": 100, "to": "0x…" }
+    /// }
+    /// ```
+    ///
+    /// Integers wider than 64 bits are given as decimal strings, and bytes as hex strings.
+    #[cfg(feature = "json")]
+    pub fn decode_logs_json(
+        &self,
+        receipts: &[Receipt],
+        tx_id: Option<TxId>,
+    ) -> Result<Vec<Value>> {
+        receipts
+            .iter()
+            .enumerate()
+            .filter_map(|(receipt_index, receipt)| {
+                log_id_and_data(receipt).map(|log| (receipt_index, log))
+            })
+            .map(|(receipt_index, (log_id, data))| {
+                let param_type = &self
+                    .log_formatters
+                    .get(&log_id)
+                    .ok_or_else(|| {
+                        error!(Codec, "missing log formatter for log_id: `{:?}`", log_id)
+                    })?
+                    .param_type;
+                let token = ABIDecoder::new(self.decoder_config).decode(param_type, &data)?;
+
+                Ok(json!({
+                    "type": sway_type_name(param_type),
+                    "contract_id": format!("0x{}", hex::encode(log_id.0)),
+                    "tx_id": tx_id.map(|tx_id| format!("0x{}", hex::encode(tx_id))),
+                    "receipt_index": receipt_index,
+                    "value": token_to_json(param_type, token)?,
+                }))
+            })
+            .collect()
+    }
+
     pub fn merge(&mut self, log_decoder: LogDecoder) {
         self.log_formatters.extend(log_decoder.log_formatters);
+        self.type_formatters.extend(log_decoder.type_formatters);
     }
 }
 
@@ -195,18 +433,53 @@ trait ExtractLogIdData {
 impl<'a, I: Iterator<Item = &'a Receipt>> ExtractLogIdData for I {
     type Output = FilterMap<Self, fn(&Receipt) -> Option<(LogId, Vec<u8>)>>;
     fn extract_log_id_and_data(self) -> Self::Output {
-        self.filter_map(|r| match r {
-            Receipt::LogData {
-                rb,
-                data: Some(data),
-                id,
-                ..
-            } => Some((LogId(*id, (*rb).to_string()), data.clone())),
-            Receipt::Log { ra, rb, id, .. } => {
-                Some((LogId(*id, (*rb).to_string()), ra.to_be_bytes().to_vec()))
-            }
-            _ => None,
-        })
+        self.filter_map(log_id_and_data)
+    }
+}
+
+fn log_id_and_data(receipt: &Receipt) -> Option<(LogId, Vec<u8>)> {
+    match receipt {
+        Receipt::LogData {
+            rb,
+            data: Some(data),
+            id,
+            ..
+        } => Some((LogId(*id, (*rb).to_string()), data.clone())),
+        Receipt::Log { ra, rb, id, .. } => {
+            Some((LogId(*id, (*rb).to_string()), ra.to_be_bytes().to_vec()))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(feature = "json")]
+fn sway_type_name(param_type: &ParamType) -> String {
+    match param_type {
+        ParamType::Unit => "()".to_string(),
+        ParamType::Bool => "bool".to_string(),
+        ParamType::U8 => "u8".to_string(),
+        ParamType::U16 => "u16".to_string(),
+        ParamType::U32 => "u32".to_string(),
+        ParamType::U64 => "u64".to_string(),
+        ParamType::U128 => "u128".to_string(),
+        ParamType::U256 => "u256".to_string(),
+        ParamType::B256 => "b256".to_string(),
+        ParamType::Bytes => "Bytes".to_string(),
+        ParamType::String => "String".to_string(),
+        ParamType::RawSlice => "raw_slice".to_string(),
+        ParamType::StringArray(len) => format!("str[{len}]"),
+        ParamType::StringSlice => "str".to_string(),
+        ParamType::Tuple(param_types) => format!(
+            "({})",
+            param_types
+                .iter()
+                .map(sway_type_name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        ParamType::Array(param_type, len) => format!("[{}; {len}]", sway_type_name(param_type)),
+        ParamType::Vector(param_type) => format!("Vec<{}>", sway_type_name(param_type)),
+        ParamType::Struct { name, .. } | ParamType::Enum { name, .. } => name.clone(),
     }
 }
 
@@ -219,3 +492,131 @@ pub fn log_formatters_lookup(
         .map(|(id, log_formatter)| (LogId(contract_id, id), log_formatter))
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn logs_are_decoded_as_json() -> Result<()> {
+        let contract_id = ContractId::from([1; 32]);
+        let log_decoder = LogDecoder::new(log_formatters_lookup(
+            vec![("1".to_string(), LogFormatter::new::<(u64, bool)>())],
+            contract_id,
+        ));
+        let data = [0, 0, 0, 0, 0, 0, 0, 5, 1];
+        let receipts = [
+            Receipt::Return {
+                id: contract_id,
+                val: 0,
+                pc: 0,
+                is: 0,
+            },
+            Receipt::LogData {
+                id: contract_id,
+                ra: 0,
+                rb: 1,
+                ptr: 0,
+                len: data.len() as u64,
+                digest: Default::default(),
+                data: Some(data.to_vec()),
+                pc: 0,
+                is: 0,
+            },
+        ];
+
+        let logs = log_decoder.decode_logs_json(&receipts, Some(TxId::from([2; 32])))?;
+
+        assert_eq!(
+            logs,
+            [json!({
+                "type": "(u64, bool)",
+                "contract_id": format!("0x{}", "01".repeat(32)),
+                "tx_id": format!("0x{}", "02".repeat(32)),
+                "receipt_index": 1,
+                "value": [5, true],
+            })]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn formatters_can_be_registered_at_runtime() {
+        let contract_id = ContractId::from([1; 32]);
+        let log = |rb| Receipt::Log {
+            id: contract_id,
+            ra: 5,
+            rb,
+            rc: 0,
+            rd: 0,
+            pc: 0,
+            is: 0,
+        };
+
+        let mut log_decoder = LogDecoder::new(log_formatters_lookup(
+            vec![("1".to_string(), LogFormatter::new::<u64>())],
+            contract_id,
+        ));
+        log_decoder.register_log_formatter(
+            contract_id,
+            "2",
+            LogFormatter::from_param_type(ParamType::U64, |token| Ok(format!("runtime {token}"))),
+        );
+        assert_eq!(
+            log_decoder
+                .decode_logs(&[log(1), log(2)])
+                .filter_succeeded(),
+            ["5", "runtime U64(5)"]
+        );
+
+        log_decoder.register_type_formatter(|amount: u64| format!("{amount} coins"));
+        assert_eq!(
+            log_decoder
+                .decode_logs(&[log(1), log(2)])
+                .filter_succeeded(),
+            ["5 coins", "runtime U64(5)"]
+        );
+    }
+
+    #[test]
+    fn logs_of_several_types_are_decoded_in_order() -> Result<()> {
+        let contract_id = ContractId::from([1; 32]);
+        let log_decoder = LogDecoder::new(log_formatters_lookup(
+            vec![
+                ("1".to_string(), LogFormatter::new::<u64>()),
+                ("2".to_string(), LogFormatter::new::<bool>()),
+                ("3".to_string(), LogFormatter::new::<u32>()),
+            ],
+            contract_id,
+        ));
+        let log = |rb, data: &[u8]| Receipt::LogData {
+            id: contract_id,
+            ra: 0,
+            rb,
+            ptr: 0,
+            len: data.len() as u64,
+            digest: Default::default(),
+            data: Some(data.to_vec()),
+            pc: 0,
+            is: 0,
+        };
+        let receipts = [
+            log(1, &5u64.to_be_bytes()),
+            log(3, &7u32.to_be_bytes()),
+            log(2, &[1]),
+            log(1, &6u64.to_be_bytes()),
+        ];
+
+        let logs = log_decoder.decode_logs_of_types::<(u64, bool)>(&receipts)?;
+
+        let indexes: Vec<_> = logs.iter().map(|log| log.receipt_index).collect();
+        assert_eq!(indexes, [0, 2, 3]);
+        assert_eq!(logs[0].get::<u64>(), Some(&5));
+        assert_eq!(logs[1].get::<bool>(), Some(&true));
+        assert!(logs[1].get::<u64>().is_none());
+
+        Ok(())
+    }
+}
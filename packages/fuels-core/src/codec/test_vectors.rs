@@ -0,0 +1,432 @@
+//! Encoding test vectors shared with other SDKs, fuels-ts in particular, to catch the SDKs
+//! drifting apart.
+//!
+//! [`TestVectors::generate`] encodes a fixed set of values, function selectors and predicate
+//! roots with fuels-rs, and [`TestVectors::validate`] checks vectors produced by any SDK against
+//! fuels-rs. The vectors are stored as JSON:
+//!
+//! * types are described with the names the ABI uses for the primitive types (`"u64"`,
+//!   `"bool"`, `"b256"`, `"raw untyped slice"`, ...) and with objects for the others:
+//!   `{ "array": T, "length": n }`, `{ "vector": T }`, `{ "tuple": [T, ...] }`, `{ "str": n }`,
+//!   `{ "struct": name, "fields": [{ "name": name, "type": T }, ...] }` and
+//!   `{ "enum": name, "variants": [{ "name": name, "type": T }, ...] }`,
+//! * values follow [`to_json`](crate::codec::to_json), integers also being accepted as decimal
+//!   strings,
+//! * bytes, selectors, bytecodes and roots are `0x` prefixed hex strings.
+
+use std::{fs, path::Path};
+
+use fuel_asm::{op, RegId};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    codec::{encode_fn_selector, json_to_token, token_to_json, ABIDecoder, ABIEncoder},
+    traits::{Parameterize, Tokenizable},
+    types::{
+        errors::{error, Result},
+        param_types::{EnumVariants, ParamType},
+        Address, AsciiString, Bits256, Bytes, Identity, RawSlice, SizedAsciiString, Token, U256,
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestVectors {
+    pub encoding: Vec<EncodingVector>,
+    pub selectors: Vec<SelectorVector>,
+    pub predicate_roots: Vec<PredicateRootVector>,
+}
+
+/// A value of type `type`, encoded as `bytes`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncodingVector {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_description: Value,
+    pub value: Value,
+    pub bytes: String,
+}
+
+/// The selector of the function `name`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SelectorVector {
+    pub name: String,
+    pub selector: String,
+}
+
+/// The root of the predicate `bytecode`, i.e. the address owning its coins.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PredicateRootVector {
+    pub name: String,
+    pub bytecode: String,
+    pub root: String,
+}
+
+impl TestVectors {
+    pub fn generate() -> Result<Self> {
+        Ok(Self {
+            encoding: encoding_vectors()?,
+            selectors: ["transfer", "get_balance", "a", "with_a_quite_long_name_123"]
+                .into_iter()
+                .map(|name| SelectorVector {
+                    name: name.to_string(),
+                    selector: hex_string(&encode_fn_selector(name)),
+                })
+                .collect(),
+            predicate_roots: predicate_root_vectors(),
+        })
+    }
+
+    /// Checks every vector against fuels-rs, reporting all the mismatches at once.
+    pub fn validate(&self) -> Result<()> {
+        let failures: Vec<String> = self
+            .encoding
+            .iter()
+            .map(|vector| (&vector.name, validate_encoding(vector)))
+            .chain(
+                self.selectors
+                    .iter()
+                    .map(|vector| (&vector.name, validate_selector(vector))),
+            )
+            .chain(
+                self.predicate_roots
+                    .iter()
+                    .map(|vector| (&vector.name, validate_predicate_root(vector))),
+            )
+            .filter_map(|(name, result)| result.err().map(|err| format!("`{name}`: {err}")))
+            .collect();
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(error!(
+                Codec,
+                "{} test vectors failed:\n{}",
+                failures.len(),
+                failures.join("\n")
+            ))
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, serde_json::to_vec_pretty(self)?)?;
+
+        Ok(())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|err| {
+            error!(
+                IO,
+                "could not read test vectors `{}`: {err}",
+                path.display()
+            )
+        })?;
+
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+}
+
+fn encoding_vectors() -> Result<Vec<EncodingVector>> {
+    let point_type = ParamType::Struct {
+        name: "Point".to_string(),
+        fields: vec![
+            ("x".to_string(), ParamType::U32),
+            ("y".to_string(), ParamType::U64),
+        ],
+        generics: vec![],
+    };
+    let point = Token::Struct(vec![Token::U32(1), Token::U64(2)]);
+
+    let shape_variants = EnumVariants::new(vec![
+        ("Empty".to_string(), ParamType::Unit),
+        ("Dot".to_string(), point_type.clone()),
+        (
+            "Line".to_string(),
+            ParamType::Array(Box::new(point_type.clone()), 2),
+        ),
+    ])?;
+    let shape_type = ParamType::Enum {
+        name: "Shape".to_string(),
+        enum_variants: shape_variants.clone(),
+        generics: vec![],
+    };
+    let shape =
+        |discriminant, token| Token::Enum(Box::new((discriminant, token, shape_variants.clone())));
+
+    Ok(vec![
+        vector("unit", ())?,
+        vector("bool", true)?,
+        vector("u8", u8::MAX)?,
+        vector("u16", 0x1234u16)?,
+        vector("u32", u32::MAX)?,
+        vector("u64", u64::MAX)?,
+        vector("u128", u128::MAX)?,
+        vector("u256", U256::MAX)?,
+        vector("b256", Bits256([0xab; 32]))?,
+        vector("bytes", Bytes(vec![1, 2, 3]))?,
+        vector("raw_slice", RawSlice(vec![4, 5, 6, 7, 8, 9, 10, 11, 12]))?,
+        vector("string", "fuel".to_string())?,
+        vector("str", AsciiString::new("abc".to_string())?)?,
+        vector(
+            "str_array",
+            SizedAsciiString::<5>::new("hello".to_string())?,
+        )?,
+        vector("array", [1u16, 2, 3])?,
+        vector("empty_vector", Vec::<u64>::new())?,
+        vector("nested_vector", vec![vec![1u8], vec![], vec![2, 3]])?,
+        vector("tuple", (1u8, true, 3u64))?,
+        vector("option_none", Option::<u32>::None)?,
+        vector("option_some", Some(42u32))?,
+        vector("result_err", std::result::Result::<u64, bool>::Err(true))?,
+        vector("address", Address::new([0x11; 32]))?,
+        vector("identity", Identity::Address(Address::new([0x22; 32])))?,
+        token_vector("struct", &point_type, point.clone())?,
+        token_vector("enum_unit_variant", &shape_type, shape(0, Token::Unit))?,
+        token_vector("enum_struct_variant", &shape_type, shape(1, point.clone()))?,
+        token_vector(
+            "enum_array_variant",
+            &shape_type,
+            shape(2, Token::Array(vec![point.clone(), point])),
+        )?,
+    ])
+}
+
+fn predicate_root_vectors() -> Vec<PredicateRootVector> {
+    let single_instruction = op::ret(RegId::ONE).to_bytes().to_vec();
+    // Longer than the 16 KiB leaves of the merkle tree of the bytecode.
+    let two_leaves = std::iter::repeat(op::noop().to_bytes())
+        .take(4097)
+        .chain([op::ret(RegId::ONE).to_bytes()])
+        .flatten()
+        .collect::<Vec<_>>();
+
+    [
+        ("single_instruction", single_instruction),
+        ("two_leaves", two_leaves),
+    ]
+    .into_iter()
+    .map(|(name, bytecode)| PredicateRootVector {
+        name: name.to_string(),
+        root: hex_string(&*fuel_tx::Input::predicate_owner(&bytecode)),
+        bytecode: hex_string(&bytecode),
+    })
+    .collect()
+}
+
+fn vector<T: Tokenizable + Parameterize>(name: &str, value: T) -> Result<EncodingVector> {
+    token_vector(name, &T::param_type(), value.into_token())
+}
+
+fn token_vector(name: &str, param_type: &ParamType, token: Token) -> Result<EncodingVector> {
+    let bytes = ABIEncoder::default().encode(&[token.clone()])?;
+
+    Ok(EncodingVector {
+        name: name.to_string(),
+        type_description: describe_type(param_type),
+        value: token_to_json(param_type, token)?,
+        bytes: hex_string(&bytes),
+    })
+}
+
+fn validate_encoding(vector: &EncodingVector) -> Result<()> {
+    let param_type = parse_type(&vector.type_description)?;
+    let bytes = parse_bytes(&vector.bytes)?;
+    let token = json_to_token(&param_type, &vector.value)?;
+
+    let encoded = ABIEncoder::default().encode(&[token.clone()])?;
+    if encoded != bytes {
+        return Err(error!(
+            Codec,
+            "encodes to `{}`, expected `{}`",
+            hex_string(&encoded),
+            vector.bytes
+        ));
+    }
+
+    // Compared as JSON, normalized by fuels-rs, as the vectors may give integers as strings.
+    let expected = token_to_json(&param_type, token)?;
+    let decoded = token_to_json(
+        &param_type,
+        ABIDecoder::default().decode(&param_type, &bytes)?,
+    )?;
+    if decoded != expected {
+        return Err(error!(
+            Codec,
+            "decodes to `{decoded}`, expected `{expected}`"
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_selector(vector: &SelectorVector) -> Result<()> {
+    let selector = encode_fn_selector(&vector.name);
+
+    if selector != parse_bytes(&vector.selector)? {
+        return Err(error!(
+            Codec,
+            "selector is `{}`, expected `{}`",
+            hex_string(&selector),
+            vector.selector
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_predicate_root(vector: &PredicateRootVector) -> Result<()> {
+    let root = fuel_tx::Input::predicate_owner(parse_bytes(&vector.bytecode)?);
+
+    if root.as_slice() != parse_bytes(&vector.root)? {
+        return Err(error!(
+            Codec,
+            "root is `{}`, expected `{}`",
+            hex_string(&*root),
+            vector.root
+        ));
+    }
+
+    Ok(())
+}
+
+fn describe_type(param_type: &ParamType) -> Value {
+    let describe_named = |named: &[(String, ParamType)]| {
+        named
+            .iter()
+            .map(|(name, param_type)| json!({ "name": name, "type": describe_type(param_type) }))
+            .collect::<Vec<_>>()
+    };
+
+    match param_type {
+        ParamType::Unit => "()".into(),
+        ParamType::Bool => "bool".into(),
+        ParamType::U8 => "u8".into(),
+        ParamType::U16 => "u16".into(),
+        ParamType::U32 => "u32".into(),
+        ParamType::U64 => "u64".into(),
+        ParamType::U128 => "u128".into(),
+        ParamType::U256 => "u256".into(),
+        ParamType::B256 => "b256".into(),
+        ParamType::Bytes => "bytes".into(),
+        ParamType::String => "String".into(),
+        ParamType::RawSlice => "raw untyped slice".into(),
+        ParamType::StringSlice => "str".into(),
+        ParamType::StringArray(len) => json!({ "str": len }),
+        ParamType::Tuple(param_types) => {
+            json!({ "tuple": param_types.iter().map(describe_type).collect::<Vec<_>>() })
+        }
+        ParamType::Array(param_type, len) => {
+            json!({ "array": describe_type(param_type), "length": len })
+        }
+        ParamType::Vector(param_type) => json!({ "vector": describe_type(param_type) }),
+        ParamType::Struct { name, fields, .. } => {
+            json!({ "struct": name, "fields": describe_named(fields) })
+        }
+        ParamType::Enum {
+            name,
+            enum_variants,
+            ..
+        } => json!({ "enum": name, "variants": describe_named(enum_variants.variants()) }),
+    }
+}
+
+fn parse_type(description: &Value) -> Result<ParamType> {
+    let invalid = || error!(Codec, "invalid type description `{description}`");
+    let parse_len = |value: &Value| value.as_u64().map(|len| len as usize).ok_or_else(invalid);
+    let parse_named = |value: &Value| {
+        value
+            .as_array()
+            .ok_or_else(invalid)?
+            .iter()
+            .map(|named| {
+                let name = named["name"].as_str().ok_or_else(invalid)?;
+                Ok((name.to_string(), parse_type(&named["type"])?))
+            })
+            .collect::<Result<Vec<_>>>()
+    };
+
+    if let Some(name) = description.as_str() {
+        let param_type = match name {
+            "()" => ParamType::Unit,
+            "bool" => ParamType::Bool,
+            "u8" => ParamType::U8,
+            "u16" => ParamType::U16,
+            "u32" => ParamType::U32,
+            "u64" => ParamType::U64,
+            "u128" => ParamType::U128,
+            "u256" => ParamType::U256,
+            "b256" => ParamType::B256,
+            "bytes" => ParamType::Bytes,
+            "String" => ParamType::String,
+            "raw untyped slice" => ParamType::RawSlice,
+            "str" => ParamType::StringSlice,
+            _ => return Err(invalid()),
+        };
+
+        return Ok(param_type);
+    }
+
+    let object = description.as_object().ok_or_else(invalid)?;
+    let param_type = if let Some(len) = object.get("str") {
+        ParamType::StringArray(parse_len(len)?)
+    } else if let Some(param_types) = object.get("tuple") {
+        ParamType::Tuple(
+            param_types
+                .as_array()
+                .ok_or_else(invalid)?
+                .iter()
+                .map(parse_type)
+                .collect::<Result<_>>()?,
+        )
+    } else if let Some(param_type) = object.get("array") {
+        ParamType::Array(
+            Box::new(parse_type(param_type)?),
+            parse_len(&description["length"])?,
+        )
+    } else if let Some(param_type) = object.get("vector") {
+        ParamType::Vector(Box::new(parse_type(param_type)?))
+    } else if let Some(name) = object.get("struct") {
+        ParamType::Struct {
+            name: name.as_str().ok_or_else(invalid)?.to_string(),
+            fields: parse_named(&description["fields"])?,
+            generics: vec![],
+        }
+    } else if let Some(name) = object.get("enum") {
+        ParamType::Enum {
+            name: name.as_str().ok_or_else(invalid)?.to_string(),
+            enum_variants: EnumVariants::new(parse_named(&description["variants"])?)?,
+            generics: vec![],
+        }
+    } else {
+        return Err(invalid());
+    };
+
+    Ok(param_type)
+}
+
+fn parse_bytes(hex_string: &str) -> Result<Vec<u8>> {
+    hex::decode(hex_string.trim_start_matches("0x"))
+        .map_err(|err| error!(Codec, "invalid hex string `{hex_string}`: {err}"))
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn type_descriptions_round_trip() -> Result<()> {
+        for vector in TestVectors::generate()?.encoding {
+            let param_type = parse_type(&vector.type_description)?;
+
+            assert_eq!(describe_type(&param_type), vector.type_description);
+        }
+
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
+mod observer;
 mod parameterize;
 mod signer;
 mod tokenizable;
 
+pub use observer::*;
 pub use parameterize::*;
 pub use signer::*;
 pub use tokenizable::*;
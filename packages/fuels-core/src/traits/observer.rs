@@ -0,0 +1,30 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+
+use crate::types::{
+    errors::{Error, Result},
+    transaction::TransactionSummary,
+};
+
+/// Hooks notified about the transactions of a builder or an account, for audit logging and for
+/// policy engines vetoing transactions.
+///
+/// Observers are attached to a builder with [`TransactionBuilder::add_observer`], which only
+/// calls [`on_sign`](Self::on_sign). Accounts wrapped in an `ObservedAccount` attach their
+/// observers to every transaction they build and also report what they send.
+///
+/// [`TransactionBuilder::add_observer`]: crate::types::transaction_builders::TransactionBuilder::add_observer
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait TransactionObserver: Debug + Send + Sync {
+    /// Called once a transaction is complete and before its signers sign it. Returning an error
+    /// aborts the build.
+    async fn on_sign(&self, _summary: &TransactionSummary) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called after a transaction was submitted to the node, with the error if the node
+    /// rejected it.
+    async fn on_submit(&self, _summary: &TransactionSummary, _error: Option<&Error>) {}
+}
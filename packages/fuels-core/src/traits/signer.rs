@@ -12,3 +12,15 @@ pub trait Signer: 'static {
     async fn sign(&self, message: Message) -> Result<Signature>;
     fn address(&self) -> &Bech32Address;
 }
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<S: Signer + Send + Sync + ?Sized> Signer for Box<S> {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        (**self).sign(message).await
+    }
+
+    fn address(&self) -> &Bech32Address {
+        (**self).address()
+    }
+}
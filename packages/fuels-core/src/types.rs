@@ -7,16 +7,22 @@ pub use fuel_types::{
 pub use crate::types::{core::*, token::*, wrappers::*};
 use crate::{error, types::errors::Result};
 
+mod asset_events;
 pub mod bech32;
 mod core;
 mod dry_runner;
 pub mod errors;
+mod offline;
 pub mod param_types;
+mod receipt_matcher;
 mod token;
 pub mod transaction_builders;
 pub mod tx_status;
 mod wrappers;
+pub use asset_events::*;
 pub use dry_runner::*;
+pub use offline::*;
+pub use receipt_matcher::*;
 
 pub type ByteArray = [u8; 8];
 pub type Selector = Vec<u8>;
@@ -0,0 +1,184 @@
+use fuel_tx::Receipt;
+use fuel_types::{AssetId, Bytes32, ContractId};
+
+use crate::types::Identity;
+
+/// An asset minted by a contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AssetMinted {
+    pub contract_id: ContractId,
+    pub sub_id: Bytes32,
+    pub asset_id: AssetId,
+    pub amount: u64,
+}
+
+/// An asset burned by a contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AssetBurned {
+    pub contract_id: ContractId,
+    pub sub_id: Bytes32,
+    pub asset_id: AssetId,
+    pub amount: u64,
+}
+
+/// An asset moved during a transaction, by a transfer or forwarded along a contract call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AssetTransferred {
+    /// The sending contract, or the zeroed contract id if sent by the script.
+    pub from: ContractId,
+    pub to: Identity,
+    pub asset_id: AssetId,
+    pub amount: u64,
+}
+
+/// A change of the balances of assets, decoded from the `Mint`, `Burn`, `Transfer`,
+/// `TransferOut` and `Call` receipts of a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetEvent {
+    Minted(AssetMinted),
+    Burned(AssetBurned),
+    Transferred(AssetTransferred),
+}
+
+impl AssetEvent {
+    /// The event of `receipt`, if it changes balances. Calls and transfers of no assets are left
+    /// out.
+    pub fn from_receipt(receipt: &Receipt) -> Option<Self> {
+        let event = match receipt {
+            Receipt::Mint {
+                sub_id,
+                contract_id,
+                val,
+                ..
+            } => Self::Minted(AssetMinted {
+                contract_id: *contract_id,
+                sub_id: *sub_id,
+                asset_id: contract_id.asset_id(sub_id),
+                amount: *val,
+            }),
+            Receipt::Burn {
+                sub_id,
+                contract_id,
+                val,
+                ..
+            } => Self::Burned(AssetBurned {
+                contract_id: *contract_id,
+                sub_id: *sub_id,
+                asset_id: contract_id.asset_id(sub_id),
+                amount: *val,
+            }),
+            Receipt::Transfer {
+                id,
+                to,
+                amount,
+                asset_id,
+                ..
+            }
+            | Receipt::Call {
+                id,
+                to,
+                amount,
+                asset_id,
+                ..
+            } if *amount > 0 => Self::Transferred(AssetTransferred {
+                from: *id,
+                to: Identity::ContractId(*to),
+                asset_id: *asset_id,
+                amount: *amount,
+            }),
+            Receipt::TransferOut {
+                id,
+                to,
+                amount,
+                asset_id,
+                ..
+            } if *amount > 0 => Self::Transferred(AssetTransferred {
+                from: *id,
+                to: Identity::Address(*to),
+                asset_id: *asset_id,
+                amount: *amount,
+            }),
+            _ => return None,
+        };
+
+        Some(event)
+    }
+
+    /// The events of `receipts`, in order.
+    pub fn from_receipts(receipts: &[Receipt]) -> Vec<Self> {
+        receipts.iter().filter_map(Self::from_receipt).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_types::Address;
+
+    use super::*;
+
+    #[test]
+    fn asset_receipts_are_decoded() {
+        let contract_id = ContractId::from([1; 32]);
+        let sub_id = Bytes32::from([2; 32]);
+        let asset_id = contract_id.asset_id(&sub_id);
+        let receipts = [
+            Receipt::Call {
+                id: ContractId::zeroed(),
+                to: contract_id,
+                amount: 0,
+                asset_id: AssetId::zeroed(),
+                gas: 0,
+                param1: 0,
+                param2: 0,
+                pc: 0,
+                is: 0,
+            },
+            Receipt::Mint {
+                sub_id,
+                contract_id,
+                val: 100,
+                pc: 0,
+                is: 0,
+            },
+            Receipt::TransferOut {
+                id: contract_id,
+                to: Address::from([3; 32]),
+                amount: 40,
+                asset_id,
+                pc: 0,
+                is: 0,
+            },
+            Receipt::Burn {
+                sub_id,
+                contract_id,
+                val: 10,
+                pc: 0,
+                is: 0,
+            },
+        ];
+
+        assert_eq!(
+            AssetEvent::from_receipts(&receipts),
+            [
+                AssetEvent::Minted(AssetMinted {
+                    contract_id,
+                    sub_id,
+                    asset_id,
+                    amount: 100,
+                }),
+                AssetEvent::Transferred(AssetTransferred {
+                    from: contract_id,
+                    to: Identity::Address(Address::from([3; 32])),
+                    asset_id,
+                    amount: 40,
+                }),
+                AssetEvent::Burned(AssetBurned {
+                    contract_id,
+                    sub_id,
+                    asset_id,
+                    amount: 10,
+                }),
+            ]
+        );
+    }
+}
@@ -6,6 +6,7 @@ use std::{
 use bech32::{FromBase32, ToBase32, Variant::Bech32m};
 use fuel_tx::{Address, Bytes32, ContractId, ContractIdExt};
 use fuel_types::AssetId;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{
     errors::{Error, Result},
@@ -19,7 +20,7 @@ pub const FUEL_BECH32_HRP: &str = "fuel";
 /// consisting of a human-readable part (hrp) and a hash (e.g. pubkey-, contract hash)
 macro_rules! bech32type {
     ($i:ident) => {
-        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub struct $i {
             pub hrp: String,
             pub hash: Bytes32,
@@ -86,6 +86,7 @@ macro_rules! impl_error_from {
 impl_error_from!(Other, &'static str);
 impl_error_from!(Other, bech32::Error);
 impl_error_from!(Other, fuel_crypto::Error);
+#[cfg(feature = "json")]
 impl_error_from!(Other, serde_json::Error);
 impl_error_from!(Other, hex::FromHexError);
 impl_error_from!(Other, std::array::TryFromSliceError);
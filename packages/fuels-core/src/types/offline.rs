@@ -0,0 +1,121 @@
+#![cfg(feature = "std")]
+
+use std::{fs, path::Path};
+
+use async_trait::async_trait;
+use fuel_tx::{ConsensusParameters, Transaction as FuelTransaction};
+
+use crate::types::{
+    errors::{error, Result},
+    DryRun, DryRunner,
+};
+
+/// Stores [`ConsensusParameters`], gas costs included, in a JSON file, so that transactions can
+/// later be built with an [`OfflineContext`] where no node is reachable.
+pub trait ConsensusParametersFile: Sized {
+    fn save(&self, path: impl AsRef<Path>) -> Result<()>;
+
+    fn load(path: impl AsRef<Path>) -> Result<Self>;
+}
+
+impl ConsensusParametersFile for ConsensusParameters {
+    fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, serde_json::to_vec_pretty(self)?)?;
+
+        Ok(())
+    }
+
+    fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|err| {
+            error!(
+                IO,
+                "could not read consensus parameters `{}`: {err}",
+                path.display()
+            )
+        })?;
+
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+}
+
+/// A [`DryRunner`] for building transactions without a node, from consensus parameters exported
+/// beforehand and a gas price chosen by the caller.
+///
+/// Fees are computed and predicates estimated locally. Scripts cannot be dry run without the
+/// state of the chain, so script transactions need an explicit script gas limit, and variable
+/// outputs cannot be estimated.
+#[derive(Debug, Clone)]
+pub struct OfflineContext {
+    consensus_parameters: ConsensusParameters,
+    gas_price: u64,
+}
+
+impl OfflineContext {
+    pub fn new(consensus_parameters: ConsensusParameters, gas_price: u64) -> Self {
+        Self {
+            consensus_parameters,
+            gas_price,
+        }
+    }
+
+    /// Loads the consensus parameters saved at `path` with [`ConsensusParametersFile::save`].
+    pub fn load(path: impl AsRef<Path>, gas_price: u64) -> Result<Self> {
+        Ok(Self::new(ConsensusParameters::load(path)?, gas_price))
+    }
+
+    pub fn gas_price(&self) -> u64 {
+        self.gas_price
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl DryRunner for OfflineContext {
+    async fn dry_run(&self, _: FuelTransaction) -> Result<DryRun> {
+        Err(error!(
+            Other,
+            "cannot dry run a transaction offline: set the script gas limit in the `TxPolicies` and the variable outputs explicitly"
+        ))
+    }
+
+    async fn estimate_gas_price(&self, _block_horizon: u32) -> Result<u64> {
+        Ok(self.gas_price)
+    }
+
+    fn consensus_parameters(&self) -> &ConsensusParameters {
+        &self.consensus_parameters
+    }
+
+    async fn maybe_estimate_predicates(
+        &self,
+        _: &FuelTransaction,
+        _: Option<u32>,
+    ) -> Result<Option<FuelTransaction>> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::ChainId;
+
+    use super::*;
+
+    #[test]
+    fn consensus_parameters_survive_saving() -> Result<()> {
+        let path = std::env::temp_dir().join("fuels_offline_consensus_parameters.json");
+        let mut consensus_parameters = ConsensusParameters::default();
+        consensus_parameters.set_chain_id(ChainId::new(42));
+
+        consensus_parameters.save(&path)?;
+        let context = OfflineContext::load(&path, 1)?;
+
+        assert_eq!(context.consensus_parameters(), &consensus_parameters);
+        assert_eq!(context.gas_price(), 1);
+
+        fs::remove_file(path)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,230 @@
+use std::ops::{Bound, RangeBounds};
+
+use fuel_tx::Receipt;
+use fuel_types::{AssetId, ContractId};
+
+/// The kind of a [`Receipt`], to filter receipts on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReceiptKind {
+    Call,
+    Return,
+    ReturnData,
+    Panic,
+    Revert,
+    Log,
+    LogData,
+    Transfer,
+    TransferOut,
+    ScriptResult,
+    MessageOut,
+    Mint,
+    Burn,
+}
+
+impl ReceiptKind {
+    pub fn of(receipt: &Receipt) -> Self {
+        match receipt {
+            Receipt::Call { .. } => Self::Call,
+            Receipt::Return { .. } => Self::Return,
+            Receipt::ReturnData { .. } => Self::ReturnData,
+            Receipt::Panic { .. } => Self::Panic,
+            Receipt::Revert { .. } => Self::Revert,
+            Receipt::Log { .. } => Self::Log,
+            Receipt::LogData { .. } => Self::LogData,
+            Receipt::Transfer { .. } => Self::Transfer,
+            Receipt::TransferOut { .. } => Self::TransferOut,
+            Receipt::ScriptResult { .. } => Self::ScriptResult,
+            Receipt::MessageOut { .. } => Self::MessageOut,
+            Receipt::Mint { .. } => Self::Mint,
+            Receipt::Burn { .. } => Self::Burn,
+        }
+    }
+}
+
+/// Criteria on receipts, to pick receipts out of a transaction without `matches!` chains:
+///
+/// ```
+/// # use fuels_core::types::{ContractId, ReceiptIteratorExt, ReceiptKind, ReceiptMatcher};
+/// # use fuel_tx::Receipt;
+/// # let receipts: Vec<Receipt> = vec![];
+/// # let contract_id = ContractId::zeroed();
+/// let large_transfers = ReceiptMatcher::default()
+///     .with_kind(ReceiptKind::Transfer)
+///     .with_kind(ReceiptKind::TransferOut)
+///     .with_contract_id(contract_id)
+///     .with_amount(1_000..);
+///
+/// for transfer in receipts.iter().matching(&large_transfers) {
+///     println!("{transfer:?}");
+/// }
+/// ```
+///
+/// Receipts must match every criterion set. The default matcher matches every receipt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiptMatcher {
+    kinds: Vec<ReceiptKind>,
+    contract_id: Option<ContractId>,
+    ra: Option<u64>,
+    rb: Option<u64>,
+    asset_id: Option<AssetId>,
+    amount: (Bound<u64>, Bound<u64>),
+}
+
+impl Default for ReceiptMatcher {
+    fn default() -> Self {
+        Self {
+            kinds: vec![],
+            contract_id: None,
+            ra: None,
+            rb: None,
+            asset_id: None,
+            amount: (Bound::Unbounded, Bound::Unbounded),
+        }
+    }
+}
+
+impl ReceiptMatcher {
+    /// Only matches receipts of `kind`. Can be called several times to match more kinds.
+    pub fn with_kind(mut self, kind: ReceiptKind) -> Self {
+        self.kinds.push(kind);
+        self
+    }
+
+    /// Only matches receipts emitted by, or calling into, `contract_id`.
+    pub fn with_contract_id(mut self, contract_id: impl Into<ContractId>) -> Self {
+        self.contract_id = Some(contract_id.into());
+        self
+    }
+
+    /// Only matches `Log`, `LogData` and `Revert` receipts with the given `ra` register.
+    pub fn with_ra(mut self, ra: u64) -> Self {
+        self.ra = Some(ra);
+        self
+    }
+
+    /// Only matches `Log` and `LogData` receipts with the given `rb` register, i.e. the log id
+    /// generated by `abigen!`.
+    pub fn with_rb(mut self, rb: u64) -> Self {
+        self.rb = Some(rb);
+        self
+    }
+
+    /// Only matches `Call`, `Transfer` and `TransferOut` receipts of `asset_id`.
+    pub fn with_asset_id(mut self, asset_id: AssetId) -> Self {
+        self.asset_id = Some(asset_id);
+        self
+    }
+
+    /// Only matches `Call`, `Transfer`, `TransferOut`, `MessageOut`, `Mint` and `Burn` receipts
+    /// with an amount in `amount`.
+    pub fn with_amount(mut self, amount: impl RangeBounds<u64>) -> Self {
+        self.amount = (amount.start_bound().cloned(), amount.end_bound().cloned());
+        self
+    }
+
+    pub fn matches(&self, receipt: &Receipt) -> bool {
+        let kind_matches = self.kinds.is_empty() || self.kinds.contains(&ReceiptKind::of(receipt));
+        let contract_matches = self.contract_id.map_or(true, |contract_id| {
+            receipt.id() == Some(&contract_id)
+                || matches!(receipt, Receipt::Call { to, .. } if *to == contract_id)
+        });
+        let ra_matches = self.ra.map_or(true, |ra| receipt.ra() == Some(ra));
+        let rb_matches = self.rb.map_or(true, |rb| receipt.rb() == Some(rb));
+        let asset_matches = self
+            .asset_id
+            .map_or(true, |asset_id| receipt.asset_id() == Some(&asset_id));
+        let amount_matches = self.amount == (Bound::Unbounded, Bound::Unbounded)
+            || amount_of(receipt).is_some_and(|amount| self.amount.contains(&amount));
+
+        kind_matches
+            && contract_matches
+            && ra_matches
+            && rb_matches
+            && asset_matches
+            && amount_matches
+    }
+}
+
+fn amount_of(receipt: &Receipt) -> Option<u64> {
+    match receipt {
+        Receipt::Call { amount, .. }
+        | Receipt::Transfer { amount, .. }
+        | Receipt::TransferOut { amount, .. }
+        | Receipt::MessageOut { amount, .. } => Some(*amount),
+        Receipt::Mint { val, .. } | Receipt::Burn { val, .. } => Some(*val),
+        _ => None,
+    }
+}
+
+/// Iterator adapters over receipts.
+pub trait ReceiptIteratorExt<'a>: Iterator<Item = &'a Receipt> + Sized {
+    /// The receipts matched by `matcher`.
+    fn matching(self, matcher: &ReceiptMatcher) -> Matching<'_, Self> {
+        Matching {
+            receipts: self,
+            matcher,
+        }
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a Receipt>> ReceiptIteratorExt<'a> for I {}
+
+/// Iterator over the receipts matched by a [`ReceiptMatcher`], created by
+/// [`ReceiptIteratorExt::matching`].
+#[derive(Debug, Clone)]
+pub struct Matching<'m, I> {
+    receipts: I,
+    matcher: &'m ReceiptMatcher,
+}
+
+impl<'a, I: Iterator<Item = &'a Receipt>> Iterator for Matching<'_, I> {
+    type Item = &'a Receipt;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receipts.find(|receipt| self.matcher.matches(receipt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transfer(id: ContractId, amount: u64, asset_id: AssetId) -> Receipt {
+        Receipt::Transfer {
+            id,
+            to: ContractId::zeroed(),
+            amount,
+            asset_id,
+            pc: 0,
+            is: 0,
+        }
+    }
+
+    #[test]
+    fn receipts_are_matched_on_every_criterion() {
+        let contract_id = ContractId::from([1; 32]);
+        let asset_id = AssetId::from([2; 32]);
+        let receipts = [
+            transfer(contract_id, 10, asset_id),
+            transfer(contract_id, 1_000, asset_id),
+            transfer(contract_id, 1_000, AssetId::zeroed()),
+            transfer(ContractId::from([3; 32]), 1_000, asset_id),
+            Receipt::log(contract_id, 1_000, 42, 0, 0, 0, 0),
+        ];
+
+        let matcher = ReceiptMatcher::default()
+            .with_kind(ReceiptKind::Transfer)
+            .with_contract_id(contract_id)
+            .with_asset_id(asset_id)
+            .with_amount(100..);
+        let matched: Vec<_> = receipts.iter().matching(&matcher).collect();
+        assert_eq!(matched, [&receipts[1]]);
+
+        let logs = ReceiptMatcher::default().with_ra(1_000).with_rb(42);
+        assert_eq!(receipts.iter().matching(&logs).count(), 1);
+        assert_eq!(
+            receipts.iter().matching(&ReceiptMatcher::default()).count(),
+            receipts.len()
+        );
+    }
+}
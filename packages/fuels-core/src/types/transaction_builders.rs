@@ -4,6 +4,7 @@ use std::{
     collections::HashMap,
     fmt::{Debug, Formatter},
     iter::repeat,
+    sync::Arc,
 };
 
 use async_trait::async_trait;
@@ -13,17 +14,19 @@ use fuel_tx::{
     field::{Outputs, Policies as PoliciesField, ScriptGasLimit, Witnesses},
     policies::{Policies, PolicyType},
     Chargeable, ConsensusParameters, Create, Input as FuelInput, Output, Script, StorageSlot,
-    Transaction as FuelTransaction, TransactionFee, TxPointer, UniqueIdentifier, Upgrade, Upload,
-    UploadBody, Witness,
+    Transaction as FuelTransaction, TransactionFee, TxPointer, Upgrade, Upload, UploadBody,
+    Witness,
 };
 pub use fuel_tx::{UpgradePurpose, UploadSubsection};
 use fuel_types::{bytes::padded_len_usize, Bytes32, Salt};
+use futures::future::try_join_all;
 use itertools::Itertools;
 use script_tx_estimator::ScriptTxEstimator;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     constants::{SIGNATURE_WITNESS_SIZE, WORD_SIZE},
-    traits::Signer,
+    traits::{Signer, TransactionObserver},
     types::{
         bech32::Bech32Address,
         coin::Coin,
@@ -32,8 +35,8 @@ use crate::{
         input::Input,
         message::Message,
         transaction::{
-            CreateTransaction, EstimablePredicates, ScriptTransaction, Transaction, TxPolicies,
-            UpgradeTransaction, UploadTransaction,
+            CreateTransaction, EstimablePredicates, ScriptTransaction, Transaction,
+            TransactionSummary, TxPolicies, UpgradeTransaction, UploadTransaction,
         },
         Address, AssetId, ContractId, DryRunner,
     },
@@ -41,6 +44,9 @@ use crate::{
 };
 
 mod script_tx_estimator;
+mod tx_layout;
+
+pub use tx_layout::TxLayout;
 
 #[derive(Debug, Clone, Default)]
 struct UnresolvedWitnessIndexes {
@@ -58,7 +64,7 @@ pub trait BuildableTransaction: sealed::Sealed {
 
 impl sealed::Sealed for ScriptTransactionBuilder {}
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub enum ScriptBuildStrategy {
     /// Transaction is estimated and signatures are automatically added.
     #[default]
@@ -74,7 +80,7 @@ pub enum ScriptBuildStrategy {
     StateReadOnly,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub enum Strategy {
     /// Transaction is estimated and signatures are automatically added.
     #[default]
@@ -157,7 +163,22 @@ pub trait TransactionBuilder: BuildableTransaction + Send + sealed::Sealed {
     type TxType: Transaction;
 
     fn add_signer(&mut self, signer: impl Signer + Send + Sync) -> Result<&mut Self>;
+    /// Adds every signer of `signers`, e.g. boxed signers of different kinds.
+    fn add_signers(
+        &mut self,
+        signers: impl IntoIterator<Item = impl Signer + Send + Sync>,
+    ) -> Result<&mut Self>;
+    /// Calls `observer` before the signers of the built transaction sign it, see
+    /// [`TransactionObserver::on_sign`].
+    fn add_observer(&mut self, observer: Arc<dyn TransactionObserver>) -> &mut Self;
+    /// The owners of the signed inputs, with the indexes of their inputs.
+    fn required_signers(&self) -> HashMap<Bech32Address, Vec<usize>>;
+    /// The owners of signed inputs for which no signer was added.
+    fn missing_signers(&self) -> Vec<Bech32Address>;
     async fn estimate_max_fee(&self, provider: impl DryRunner) -> Result<u64>;
+    /// Summarizes the transaction before any signature is made, with the max fee estimated and
+    /// room for the signatures of the added signers.
+    async fn summary(&self, provider: impl DryRunner) -> Result<TransactionSummary>;
     fn with_tx_policies(self, tx_policies: TxPolicies) -> Self;
     fn with_inputs(self, inputs: Vec<Input>) -> Self;
     fn with_outputs(self, outputs: Vec<Output>) -> Self;
@@ -199,6 +220,40 @@ macro_rules! impl_tx_trait {
                 Ok(self)
             }
 
+            fn add_signers(
+                &mut self,
+                signers: impl IntoIterator<Item = impl Signer + Send + Sync>,
+            ) -> Result<&mut Self> {
+                for signer in signers {
+                    self.add_signer(signer)?;
+                }
+
+                Ok(self)
+            }
+
+            fn add_observer(&mut self, observer: Arc<dyn TransactionObserver>) -> &mut Self {
+                self.observers.push(observer);
+
+                self
+            }
+
+            fn required_signers(&self) -> HashMap<Bech32Address, Vec<usize>> {
+                self.inputs()
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, input)| match input {
+                        Input::ResourceSigned { resource } => {
+                            Some((resource.owner().clone(), index))
+                        }
+                        _ => None,
+                    })
+                    .into_group_map()
+            }
+
+            fn missing_signers(&self) -> Vec<Bech32Address> {
+                missing_signers(self.inputs(), &self.unresolved_witness_indexes)
+            }
+
             async fn estimate_max_fee(&self, provider: impl DryRunner) -> Result<u64> {
                 let mut fee_estimation_tb = self
                     .clone_without_signers()
@@ -231,6 +286,21 @@ macro_rules! impl_tx_trait {
                 )
             }
 
+            async fn summary(&self, provider: impl DryRunner) -> Result<TransactionSummary> {
+                let mut summary_tb = self
+                    .clone_without_signers()
+                    .with_build_strategy(Self::Strategy::NoSignatures);
+
+                let witness: Witness = Signature::default().as_ref().into();
+                summary_tb
+                    .witnesses_mut()
+                    .extend(repeat(witness).take(self.unresolved_signers.len()));
+
+                let tx = BuildableTransaction::build(summary_tb, &provider).await?;
+
+                Ok(tx.summary(provider.consensus_parameters()))
+            }
+
             fn with_tx_policies(mut self, tx_policies: TxPolicies) -> Self {
                 self.tx_policies = tx_policies;
 
@@ -417,7 +487,7 @@ impl Debug for dyn Signer + Send + Sync {
 ///
 /// It is advised to avoid relying on automatic estimation of variable outputs if the script
 /// contains logic that dynamically adjusts based on the number of outputs.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum VariableOutputPolicy {
     /// Perform a dry run of the transaction estimating the minimum number of variable outputs to
     /// add.
@@ -432,7 +502,9 @@ impl Default for VariableOutputPolicy {
     }
 }
 
-#[derive(Debug, Default)]
+/// Serializing the builder keeps everything but its signers, which have to be added again once
+/// it is deserialized.
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct ScriptTransactionBuilder {
     pub script: Vec<u8>,
     pub script_data: Vec<u8>,
@@ -445,11 +517,18 @@ pub struct ScriptTransactionBuilder {
     pub gas_price_estimation_block_horizon: u32,
     pub variable_output_policy: VariableOutputPolicy,
     pub build_strategy: ScriptBuildStrategy,
+    pub layout: TxLayout,
+    #[serde(skip)]
     unresolved_witness_indexes: UnresolvedWitnessIndexes,
+    #[serde(skip)]
     unresolved_signers: Vec<Box<dyn Signer + Send + Sync>>,
+    #[serde(skip)]
+    observers: Vec<Arc<dyn TransactionObserver>>,
 }
 
-#[derive(Default)]
+/// Serializing the builder keeps everything but its signers, which have to be added again once
+/// it is deserialized.
+#[derive(Default, Serialize, Deserialize)]
 pub struct CreateTransactionBuilder {
     pub bytecode_length: u64,
     pub bytecode_witness_index: u16,
@@ -462,8 +541,12 @@ pub struct CreateTransactionBuilder {
     pub gas_price_estimation_block_horizon: u32,
     pub max_fee_estimation_tolerance: f32,
     pub build_strategy: Strategy,
+    #[serde(skip)]
     unresolved_witness_indexes: UnresolvedWitnessIndexes,
+    #[serde(skip)]
     unresolved_signers: Vec<Box<dyn Signer + Send + Sync>>,
+    #[serde(skip)]
+    observers: Vec<Arc<dyn TransactionObserver>>,
 }
 
 #[derive(Default)]
@@ -487,6 +570,7 @@ pub struct UploadTransactionBuilder {
     pub build_strategy: Strategy,
     unresolved_witness_indexes: UnresolvedWitnessIndexes,
     unresolved_signers: Vec<Box<dyn Signer + Send + Sync>>,
+    observers: Vec<Arc<dyn TransactionObserver>>,
 }
 
 pub struct UpgradeTransactionBuilder {
@@ -501,6 +585,7 @@ pub struct UpgradeTransactionBuilder {
     pub build_strategy: Strategy,
     unresolved_witness_indexes: UnresolvedWitnessIndexes,
     unresolved_signers: Vec<Box<dyn Signer + Send + Sync>>,
+    observers: Vec<Arc<dyn TransactionObserver>>,
 }
 
 impl Default for UpgradeTransactionBuilder {
@@ -516,6 +601,7 @@ impl Default for UpgradeTransactionBuilder {
             gas_price_estimation_block_horizon: Default::default(),
             unresolved_witness_indexes: Default::default(),
             unresolved_signers: Default::default(),
+            observers: Default::default(),
             max_fee_estimation_tolerance: Default::default(),
             build_strategy: Default::default(),
         }
@@ -536,6 +622,7 @@ impl ScriptTransactionBuilder {
             ScriptBuildStrategy::NoSignatures => {
                 self.set_witness_indexes();
                 self.unresolved_signers = Default::default();
+                self.observers = Default::default();
 
                 self.resolve_fuel_tx(&provider).await?
             }
@@ -551,6 +638,16 @@ impl ScriptTransactionBuilder {
     }
 
     async fn resolve_fuel_tx(self, dry_runner: impl DryRunner) -> Result<Script> {
+        if self.layout.has_canonical_order() && self.tx_policies.max_fee().is_none() {
+            return Err(error_transaction!(
+                Builder,
+                "a canonical order requires an explicit max fee, as the estimated one follows the gas price"
+            ));
+        }
+
+        let (inputs, outputs) = self
+            .layout
+            .arrange(self.inputs.clone(), self.outputs.clone())?;
         let predefined_witnesses = self.witnesses.clone();
         let mut script_tx_estimator = self.script_tx_estimator(predefined_witnesses, &dry_runner);
 
@@ -560,11 +657,11 @@ impl ScriptTransactionBuilder {
             self.script_data.clone(),
             self.generate_fuel_policies()?,
             resolve_fuel_inputs(
-                self.inputs.clone(),
+                inputs,
                 self.num_witnesses()?,
                 &self.unresolved_witness_indexes,
             )?,
-            self.outputs.clone(),
+            outputs,
             vec![],
         );
 
@@ -594,6 +691,9 @@ impl ScriptTransactionBuilder {
     }
 
     async fn resolve_fuel_tx_for_state_reading(self, dry_runner: impl DryRunner) -> Result<Script> {
+        let (inputs, outputs) = self
+            .layout
+            .arrange(self.inputs.clone(), self.outputs.clone())?;
         let predefined_witnesses = self.witnesses.clone();
         let mut script_tx_estimator = self.script_tx_estimator(predefined_witnesses, &dry_runner);
 
@@ -603,11 +703,11 @@ impl ScriptTransactionBuilder {
             self.script_data.clone(),
             self.generate_fuel_policies()?,
             resolve_fuel_inputs(
-                self.inputs.clone(),
+                inputs,
                 self.num_witnesses()?,
                 &self.unresolved_witness_indexes,
             )?,
-            self.outputs.clone(),
+            outputs,
             vec![],
         );
 
@@ -639,8 +739,10 @@ impl ScriptTransactionBuilder {
 
     async fn set_witnesses(self, tx: &mut fuel_tx::Script, provider: impl DryRunner) -> Result<()> {
         let missing_witnesses = generate_missing_witnesses(
-            tx.id(&provider.consensus_parameters().chain_id()),
+            &ScriptTransaction::from(tx.clone()),
             &self.unresolved_signers,
+            &self.observers,
+            &provider,
         )
         .await?;
         *tx.witnesses_mut() = [self.witnesses, missing_witnesses].concat();
@@ -706,6 +808,20 @@ impl ScriptTransactionBuilder {
         Ok(())
     }
 
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Places the inputs and outputs of the transaction according to `layout` when it is built.
+    pub fn with_layout(mut self, layout: TxLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
     pub fn with_variable_output_policy(mut self, variable_outputs: VariableOutputPolicy) -> Self {
         self.variable_output_policy = variable_outputs;
         self
@@ -834,10 +950,12 @@ impl ScriptTransactionBuilder {
             gas_estimation_tolerance: self.gas_estimation_tolerance,
             unresolved_witness_indexes: self.unresolved_witness_indexes.clone(),
             unresolved_signers: Default::default(),
+            observers: Default::default(),
             gas_price_estimation_block_horizon: self.gas_price_estimation_block_horizon,
             variable_output_policy: self.variable_output_policy,
             max_fee_estimation_tolerance: self.max_fee_estimation_tolerance,
             build_strategy: self.build_strategy.clone(),
+            layout: self.layout.clone(),
         }
     }
 }
@@ -862,6 +980,7 @@ impl CreateTransactionBuilder {
             Strategy::NoSignatures => {
                 self.set_witness_indexes();
                 self.unresolved_signers = Default::default();
+                self.observers = Default::default();
                 self.resolve_fuel_tx(&provider).await?
             }
         };
@@ -873,7 +992,6 @@ impl CreateTransactionBuilder {
     }
 
     async fn resolve_fuel_tx(self, provider: impl DryRunner) -> Result<Create> {
-        let chain_id = provider.consensus_parameters().chain_id();
         let num_witnesses = self.num_witnesses()?;
         let policies = self.generate_fuel_policies()?;
         let is_using_predicates = self.is_using_predicates();
@@ -901,13 +1019,26 @@ impl CreateTransactionBuilder {
             .await?;
         }
 
-        let missing_witnesses =
-            generate_missing_witnesses(tx.id(&chain_id), &self.unresolved_signers).await?;
+        let missing_witnesses = generate_missing_witnesses(
+            &CreateTransaction::from(tx.clone()),
+            &self.unresolved_signers,
+            &self.observers,
+            &provider,
+        )
+        .await?;
         tx.witnesses_mut().extend(missing_witnesses);
 
         Ok(tx)
     }
 
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
     pub fn with_bytecode_length(mut self, bytecode_length: u64) -> Self {
         self.bytecode_length = bytecode_length;
         self
@@ -969,6 +1100,7 @@ impl CreateTransactionBuilder {
             salt: self.salt,
             unresolved_witness_indexes: self.unresolved_witness_indexes.clone(),
             unresolved_signers: Default::default(),
+            observers: Default::default(),
             gas_price_estimation_block_horizon: self.gas_price_estimation_block_horizon,
             max_fee_estimation_tolerance: self.max_fee_estimation_tolerance,
             build_strategy: self.build_strategy.clone(),
@@ -985,6 +1117,7 @@ impl UploadTransactionBuilder {
             Strategy::NoSignatures => {
                 self.set_witness_indexes();
                 self.unresolved_signers = Default::default();
+                self.observers = Default::default();
                 self.resolve_fuel_tx(&provider).await?
             }
         };
@@ -996,7 +1129,6 @@ impl UploadTransactionBuilder {
     }
 
     async fn resolve_fuel_tx(self, provider: impl DryRunner) -> Result<Upload> {
-        let chain_id = provider.consensus_parameters().chain_id();
         let num_witnesses = self.num_witnesses()?;
         let policies = self.generate_fuel_policies()?;
         let is_using_predicates = self.is_using_predicates();
@@ -1028,8 +1160,13 @@ impl UploadTransactionBuilder {
             .await?;
         }
 
-        let missing_witnesses =
-            generate_missing_witnesses(tx.id(&chain_id), &self.unresolved_signers).await?;
+        let missing_witnesses = generate_missing_witnesses(
+            &UploadTransaction::from(tx.clone()),
+            &self.unresolved_signers,
+            &self.observers,
+            &provider,
+        )
+        .await?;
         tx.witnesses_mut().extend(missing_witnesses);
 
         Ok(tx)
@@ -1065,6 +1202,23 @@ impl UploadTransactionBuilder {
         self
     }
 
+    /// Splits `bytecode` into subsections of at most `subsection_size` bytes and prepares a
+    /// builder uploading each of them, in order. The root of the bytecode, to be used in an
+    /// upgrade once all subsections are uploaded, is the `root` of any of the builders.
+    pub fn prepare_bytecode_upload(
+        bytecode: &[u8],
+        subsection_size: usize,
+        tx_policies: TxPolicies,
+    ) -> Result<Vec<Self>> {
+        let subsections = UploadSubsection::split_bytecode(bytecode, subsection_size)
+            .map_err(|err| error!(Other, "cannot split bytecode into subsections: {err:?}"))?;
+
+        Ok(subsections
+            .into_iter()
+            .map(|subsection| Self::prepare_subsection_upload(subsection, tx_policies))
+            .collect())
+    }
+
     pub fn prepare_subsection_upload(
         subsection: UploadSubsection,
         tx_policies: TxPolicies,
@@ -1103,6 +1257,7 @@ impl UploadTransactionBuilder {
             tx_policies: self.tx_policies,
             unresolved_witness_indexes: self.unresolved_witness_indexes.clone(),
             unresolved_signers: Default::default(),
+            observers: Default::default(),
             gas_price_estimation_block_horizon: self.gas_price_estimation_block_horizon,
             proof_set: vec![],
             max_fee_estimation_tolerance: self.max_fee_estimation_tolerance,
@@ -1119,6 +1274,7 @@ impl UpgradeTransactionBuilder {
             Strategy::NoSignatures => {
                 self.set_witness_indexes();
                 self.unresolved_signers = Default::default();
+                self.observers = Default::default();
                 self.resolve_fuel_tx(&provider).await?
             }
         };
@@ -1129,7 +1285,6 @@ impl UpgradeTransactionBuilder {
     }
 
     async fn resolve_fuel_tx(self, provider: impl DryRunner) -> Result<Upgrade> {
-        let chain_id = provider.consensus_parameters().chain_id();
         let num_witnesses = self.num_witnesses()?;
         let policies = self.generate_fuel_policies()?;
         let is_using_predicates = self.is_using_predicates();
@@ -1155,8 +1310,13 @@ impl UpgradeTransactionBuilder {
             .await?;
         }
 
-        let missing_witnesses =
-            generate_missing_witnesses(tx.id(&chain_id), &self.unresolved_signers).await?;
+        let missing_witnesses = generate_missing_witnesses(
+            &UpgradeTransaction::from(tx.clone()),
+            &self.unresolved_signers,
+            &self.observers,
+            &provider,
+        )
+        .await?;
         tx.witnesses_mut().extend(missing_witnesses);
 
         Ok(tx)
@@ -1208,6 +1368,7 @@ impl UpgradeTransactionBuilder {
             tx_policies: self.tx_policies,
             unresolved_witness_indexes: self.unresolved_witness_indexes.clone(),
             unresolved_signers: Default::default(),
+            observers: Default::default(),
             gas_price_estimation_block_horizon: self.gas_price_estimation_block_horizon,
             max_fee_estimation_tolerance: self.max_fee_estimation_tolerance,
             build_strategy: self.build_strategy.clone(),
@@ -1215,6 +1376,26 @@ impl UpgradeTransactionBuilder {
     }
 }
 
+fn missing_signers(
+    inputs: &[Input],
+    unresolved_witness_indexes: &UnresolvedWitnessIndexes,
+) -> Vec<Bech32Address> {
+    inputs
+        .iter()
+        .filter_map(|input| match input {
+            Input::ResourceSigned { resource } => Some(resource.owner()),
+            _ => None,
+        })
+        .filter(|owner| {
+            !unresolved_witness_indexes
+                .owner_to_idx_offset
+                .contains_key(*owner)
+        })
+        .unique()
+        .cloned()
+        .collect()
+}
+
 /// Resolve SDK Inputs to fuel_tx Inputs. This function will calculate the right
 /// data offsets for predicates and set witness indexes for signed coins.
 fn resolve_fuel_inputs(
@@ -1222,6 +1403,18 @@ fn resolve_fuel_inputs(
     num_witnesses: u16,
     unresolved_witness_indexes: &UnresolvedWitnessIndexes,
 ) -> Result<Vec<FuelInput>> {
+    let missing_signers = missing_signers(&inputs, unresolved_witness_indexes);
+    if !missing_signers.is_empty() {
+        return Err(error_transaction!(
+            Builder,
+            "signers missing for the inputs owned by: {}",
+            missing_signers
+                .iter()
+                .map(|owner| format!("`{owner}`"))
+                .join(", ")
+        ));
+    }
+
     inputs
         .into_iter()
         .map(|input| match input {
@@ -1374,18 +1567,32 @@ pub fn create_coin_message_predicate(
 }
 
 async fn generate_missing_witnesses(
-    id: Bytes32,
+    tx: &impl Transaction,
     unresolved_signatures: &[Box<dyn Signer + Send + Sync>],
+    observers: &[Arc<dyn TransactionObserver>],
+    provider: &impl DryRunner,
 ) -> Result<Vec<Witness>> {
-    let mut witnesses = Vec::with_capacity(unresolved_signatures.len());
-    for signer in unresolved_signatures {
-        let message = CryptoMessage::from_bytes(*id);
-        let signature = signer.sign(message).await?;
+    if unresolved_signatures.is_empty() {
+        return Ok(vec![]);
+    }
 
-        witnesses.push(signature.as_ref().into());
+    let summary = TransactionSummary::new(tx, provider.consensus_parameters());
+    for observer in observers {
+        observer.on_sign(&summary).await?;
     }
 
-    Ok(witnesses)
+    let message = CryptoMessage::from_bytes(*summary.id);
+    let signatures = try_join_all(
+        unresolved_signatures
+            .iter()
+            .map(|signer| signer.sign(message)),
+    )
+    .await?;
+
+    Ok(signatures
+        .into_iter()
+        .map(|signature| signature.as_ref().into())
+        .collect())
 }
 
 #[cfg(test)]
@@ -1460,6 +1667,49 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn message_inputs_keep_their_data() -> Result<()> {
+        let message = given_a_message(vec![42]);
+        let mut tb = ScriptTransactionBuilder::default()
+            .with_inputs(vec![Input::message_signed(message.clone())]);
+        tb.add_signer(MockSigner::default())?;
+
+        let tx = tb.build(&MockDryRunner::default()).await?;
+
+        assert!(message.is_retryable());
+        assert!(matches!(tx.inputs()[..], [FuelInput::MessageDataSigned(_)]));
+        assert_eq!(tx.inputs()[0].input_data(), Some(&message.data[..]));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn builders_and_transactions_survive_serialization() -> Result<()> {
+        let tb = ScriptTransactionBuilder::default()
+            .with_script(vec![1, 2, 3])
+            .with_inputs(given_inputs(2))
+            .with_tx_policies(TxPolicies::default().with_tip(1));
+
+        let restored = ScriptTransactionBuilder::from_json(&tb.to_json()?)?;
+
+        assert_eq!(restored.script, tb.script);
+        assert_eq!(restored.inputs, tb.inputs);
+        assert_eq!(restored.tx_policies.tip(), Some(1));
+
+        let tx = restored
+            .with_build_strategy(ScriptBuildStrategy::NoSignatures)
+            .build(&MockDryRunner::default())
+            .await?;
+        let chain_id = fuel_types::ChainId::default();
+
+        let from_bytes = ScriptTransaction::from_bytes(&tx.to_bytes())?;
+        let from_json = ScriptTransaction::from_json(&tx.to_json()?)?;
+        assert_eq!(from_bytes.id(chain_id), tx.id(chain_id));
+        assert_eq!(from_json.id(chain_id), tx.id(chain_id));
+
+        Ok(())
+    }
+
     fn given_inputs(num_inputs: u8) -> Vec<Input> {
         (0..num_inputs)
             .map(|i| {
@@ -1518,6 +1768,17 @@ mod tests {
         }
     }
 
+    #[derive(Debug)]
+    struct RejectingObserver;
+
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl TransactionObserver for RejectingObserver {
+        async fn on_sign(&self, _summary: &TransactionSummary) -> Result<()> {
+            Err(error!(Other, "signing rejected"))
+        }
+    }
+
     #[tokio::test]
     async fn create_tx_builder_witness_indexes_set_correctly() -> Result<()> {
         // given
@@ -1615,4 +1876,50 @@ mod tests {
         tb.add_signer(signer.clone()).unwrap();
         tb.add_signer(signer.clone()).unwrap();
     }
+
+    #[tokio::test]
+    async fn every_missing_signer_is_reported() -> Result<()> {
+        // The inputs are owned by different addresses.
+        let owner = |index: u8| Bech32Address::new("fuel", [index; 32]);
+        let signers: Vec<Box<dyn Signer + Send + Sync>> =
+            vec![Box::new(MockSigner { address: owner(1) })];
+
+        let mut tb = ScriptTransactionBuilder::default().with_inputs(given_inputs(3));
+        tb.add_signers(signers)?;
+
+        assert_eq!(tb.required_signers()[&owner(2)], vec![2]);
+        assert_eq!(tb.missing_signers(), vec![owner(0), owner(2)]);
+
+        let err = tb
+            .build(&MockDryRunner::default())
+            .await
+            .expect_err("signers are missing");
+        let message = err.to_string();
+        assert!(message.contains(&owner(0).to_string()));
+        assert!(message.contains(&owner(2).to_string()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn observers_can_reject_signing() -> Result<()> {
+        let builder = || -> Result<_> {
+            let mut tb = CreateTransactionBuilder::default();
+            tb.add_signer(MockSigner::default())?;
+            Ok(tb)
+        };
+
+        let mut rejected = builder()?;
+        rejected.add_observer(Arc::new(RejectingObserver));
+        let err = rejected
+            .build(&MockDryRunner::default())
+            .await
+            .expect_err("should be rejected");
+        assert!(err.to_string().contains("signing rejected"));
+
+        let tx = builder()?.build(&MockDryRunner::default()).await?;
+        assert_eq!(tx.witnesses().len(), 1);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,282 @@
+use fuel_tx::Output;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{
+    coin_type::CoinType,
+    errors::{error_transaction, Result},
+    input::Input,
+};
+
+/// Indexes at which inputs and outputs are placed in a transaction built by a
+/// [`ScriptTransactionBuilder`](super::ScriptTransactionBuilder), for predicates and contracts
+/// that expect a specific layout.
+///
+/// The layout is applied once every input and output has been added, e.g. by
+/// `adjust_for_fee`. Pinned inputs and outputs are moved to their index and the others fill the
+/// remaining indexes in their order. Variable outputs are always added last.
+///
+/// With [`with_canonical_order`](Self::with_canonical_order), the inputs and outputs are sorted
+/// before the pins are applied, so that building the same transfer twice, from the same coins,
+/// gives the same transaction id whatever order the coins were selected in.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TxLayout {
+    inputs: Vec<(usize, Input)>,
+    outputs: Vec<(usize, Output)>,
+    canonical_order: bool,
+}
+
+impl TxLayout {
+    pub fn with_pinned_input(mut self, index: usize, input: Input) -> Self {
+        self.inputs.push((index, input));
+        self
+    }
+
+    pub fn with_pinned_output(mut self, index: usize, output: Output) -> Self {
+        self.outputs.push((index, output));
+        self
+    }
+
+    /// Sorts the inputs by the coin, message or contract they spend, and the outputs by kind:
+    /// contract outputs first, then coin outputs by recipient, asset and amount, then change
+    /// outputs by asset. The transaction then only depends on the set of its inputs and outputs.
+    ///
+    /// The max fee of a transaction follows the gas price, so builders with a canonical order
+    /// require it to be set explicitly through the `TxPolicies`.
+    pub fn with_canonical_order(mut self) -> Self {
+        self.canonical_order = true;
+        self
+    }
+
+    pub fn has_canonical_order(&self) -> bool {
+        self.canonical_order
+    }
+
+    /// Places `inputs` and `outputs` according to the layout. The contract outputs are updated
+    /// to keep pointing to their contract input, and must point to one.
+    pub(crate) fn arrange(
+        &self,
+        inputs: Vec<Input>,
+        outputs: Vec<Output>,
+    ) -> Result<(Vec<Input>, Vec<Output>)> {
+        let input_order = self.order(&inputs, input_sort_key, &self.inputs, "input")?;
+        let output_order = self.order(&outputs, output_sort_key, &self.outputs, "output")?;
+
+        let inputs = reorder(inputs, &input_order);
+        let outputs = reorder(outputs, &output_order)
+            .into_iter()
+            .map(|output| match output {
+                Output::Contract(mut contract) => {
+                    let old_index = contract.input_index as usize;
+                    let new_index = input_order
+                        .iter()
+                        .position(|&index| index == old_index)
+                        .filter(|&index| matches!(inputs[index], Input::Contract { .. }))
+                        .ok_or_else(|| {
+                            error_transaction!(
+                                Builder,
+                                "contract output points to input {old_index}, which is not a contract input"
+                            )
+                        })?;
+                    contract.input_index = new_index as u16;
+
+                    Ok(Output::Contract(contract))
+                }
+                output => Ok(output),
+            })
+            .collect::<Result<_>>()?;
+
+        Ok((inputs, outputs))
+    }
+
+    /// Returns, for every index of the arranged `items`, the index the item had in `items`.
+    fn order<T: PartialEq, K: Ord>(
+        &self,
+        items: &[T],
+        sort_key: impl Fn(&T) -> K,
+        pins: &[(usize, T)],
+        kind: &str,
+    ) -> Result<Vec<usize>> {
+        let mut sorted: Vec<usize> = (0..items.len()).collect();
+        if self.canonical_order {
+            sorted.sort_by_key(|&index| sort_key(&items[index]));
+        }
+
+        let sorted_items: Vec<&T> = sorted.iter().map(|&index| &items[index]).collect();
+
+        Ok(pinned_order(&sorted_items, pins, kind)?
+            .into_iter()
+            .map(|index| sorted[index])
+            .collect())
+    }
+}
+
+fn input_sort_key(input: &Input) -> (u8, [u8; 32], u16) {
+    match input {
+        Input::Contract { utxo_id, .. } => (0, **utxo_id.tx_id(), utxo_id.output_index()),
+        Input::ResourceSigned { resource } | Input::ResourcePredicate { resource, .. } => {
+            match resource {
+                CoinType::Coin(coin) => (1, **coin.utxo_id.tx_id(), coin.utxo_id.output_index()),
+                CoinType::Message(message) => (2, *message.nonce, 0),
+            }
+        }
+    }
+}
+
+fn output_sort_key(output: &Output) -> (u8, [u8; 32], [u8; 32], u64) {
+    match output {
+        // Contract outputs keep their order, which follows the calls.
+        Output::Contract(_) => (0, [0; 32], [0; 32], 0),
+        Output::Coin {
+            to,
+            amount,
+            asset_id,
+        } => (1, **to, **asset_id, *amount),
+        Output::Change { asset_id, .. } => (2, **asset_id, [0; 32], 0),
+        _ => (3, [0; 32], [0; 32], 0),
+    }
+}
+
+/// Returns, for every index of the arranged `items`, the index the item had in `items`.
+fn pinned_order<T: PartialEq>(items: &[&T], pins: &[(usize, T)], kind: &str) -> Result<Vec<usize>> {
+    let mut slots = vec![None; items.len()];
+    let mut pinned = vec![false; items.len()];
+
+    for (index, item) in pins {
+        let slot = slots.get_mut(*index).ok_or_else(|| {
+            error_transaction!(
+                Builder,
+                "cannot pin {kind} at index {index}: the transaction has {} {kind}s",
+                items.len()
+            )
+        })?;
+        if slot.is_some() {
+            return Err(error_transaction!(
+                Builder,
+                "more than one {kind} pinned at index {index}"
+            ));
+        }
+
+        let old_index = (0..items.len())
+            .find(|&old_index| !pinned[old_index] && items[old_index] == item)
+            .ok_or_else(|| {
+                error_transaction!(
+                    Builder,
+                    "pinned {kind} at index {index} is not in the transaction"
+                )
+            })?;
+        pinned[old_index] = true;
+        *slot = Some(old_index);
+    }
+
+    let mut unpinned = (0..items.len()).filter(|&old_index| !pinned[old_index]);
+
+    Ok(slots
+        .into_iter()
+        .map(|slot| {
+            slot.or_else(|| unpinned.next())
+                .expect("as many slots as items")
+        })
+        .collect())
+}
+
+fn reorder<T>(items: Vec<T>, order: &[usize]) -> Vec<T> {
+    let mut items: Vec<_> = items.into_iter().map(Some).collect();
+
+    order
+        .iter()
+        .map(|&index| items[index].take().expect("every index appears once"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{TxPointer, UtxoId};
+    use fuel_types::{Bytes32, ContractId};
+
+    use super::*;
+    use crate::types::{coin::Coin, coin_type::CoinType, AssetId};
+
+    fn coin_input(amount: u64) -> Input {
+        Input::resource_signed(CoinType::Coin(Coin {
+            amount,
+            ..Default::default()
+        }))
+    }
+
+    fn contract_input() -> Input {
+        Input::contract(
+            UtxoId::default(),
+            Bytes32::zeroed(),
+            Bytes32::zeroed(),
+            TxPointer::default(),
+            ContractId::zeroed(),
+        )
+    }
+
+    #[test]
+    fn pinned_items_are_moved_and_contract_outputs_follow() -> Result<()> {
+        let inputs = vec![coin_input(1), contract_input(), coin_input(2)];
+        let change = Output::change(Default::default(), 0, AssetId::zeroed());
+        let outputs = vec![
+            change,
+            Output::contract(1, Bytes32::zeroed(), Bytes32::zeroed()),
+        ];
+
+        let layout = TxLayout::default()
+            .with_pinned_input(0, coin_input(2))
+            .with_pinned_output(1, change);
+        let (inputs, outputs) = layout.arrange(inputs, outputs)?;
+
+        assert_eq!(inputs, vec![coin_input(2), coin_input(1), contract_input()]);
+        assert_eq!(
+            outputs,
+            vec![
+                Output::contract(2, Bytes32::zeroed(), Bytes32::zeroed()),
+                change
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn canonical_order_does_not_depend_on_the_order_of_insertion() -> Result<()> {
+        let coin = |tx_id: u8| {
+            Input::resource_signed(CoinType::Coin(Coin {
+                utxo_id: UtxoId::new([tx_id; 32].into(), 0),
+                ..Default::default()
+            }))
+        };
+        let change = |asset: u8| Output::change(Default::default(), 0, AssetId::from([asset; 32]));
+        let layout = TxLayout::default().with_canonical_order();
+
+        let arranged = layout.arrange(vec![coin(2), coin(1)], vec![change(2), change(1)])?;
+        let arranged_reversed =
+            layout.arrange(vec![coin(1), coin(2)], vec![change(1), change(2)])?;
+
+        assert_eq!(arranged, arranged_reversed);
+        assert_eq!(arranged.0, vec![coin(1), coin(2)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_pins_are_rejected() {
+        let inputs = vec![coin_input(1), coin_input(2)];
+
+        for layout in [
+            TxLayout::default().with_pinned_input(2, coin_input(1)),
+            TxLayout::default().with_pinned_input(0, coin_input(3)),
+            TxLayout::default()
+                .with_pinned_input(0, coin_input(1))
+                .with_pinned_input(0, coin_input(2)),
+        ] {
+            assert!(layout.arrange(inputs.clone(), vec![]).is_err());
+        }
+
+        let dangling_contract_output = Output::contract(0, Bytes32::zeroed(), Bytes32::zeroed());
+        assert!(TxLayout::default()
+            .arrange(inputs, vec![dangling_contract_output])
+            .is_err());
+    }
+}
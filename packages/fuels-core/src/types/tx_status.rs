@@ -12,7 +12,10 @@ use fuel_vm::state::ProgramState;
 
 use crate::{
     codec::LogDecoder,
-    types::errors::{transaction::Reason, Error, Result},
+    types::{
+        errors::{transaction::Reason, Error, Result},
+        AssetEvent,
+    },
 };
 
 #[derive(Debug, Clone)]
@@ -90,6 +93,15 @@ impl TxStatus {
             _ => vec![],
         }
     }
+
+    /// The mints, burns and transfers of the transaction. Empty unless it succeeded, as the
+    /// balances are left untouched otherwise.
+    pub fn asset_events(&self) -> Vec<AssetEvent> {
+        match self {
+            TxStatus::Success { receipts } => AssetEvent::from_receipts(receipts),
+            _ => vec![],
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -5,7 +5,7 @@ use fuel_tx::ConsensusParameters;
 
 use crate::types::block::Block;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ChainInfo {
     pub da_height: u64,
     pub name: String,
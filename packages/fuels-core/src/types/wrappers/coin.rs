@@ -5,17 +5,18 @@ use fuel_core_client::client::types::{
     coins::Coin as ClientCoin,
     primitives::{AssetId, UtxoId},
 };
+use serde::{Deserialize, Serialize};
 
 use crate::types::bech32::Bech32Address;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum CoinStatus {
     #[default]
     Unspent,
     Spent,
 }
 
-#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Coin {
     pub amount: u64,
     pub block_created: u32,
@@ -1,6 +1,7 @@
 #![cfg(feature = "std")]
 
 use fuel_core_client::client::types::CoinType as ClientCoinType;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{
     bech32::Bech32Address,
@@ -11,7 +12,7 @@ use crate::types::{
     AssetId,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CoinType {
     Coin(Coin),
     Message(Message),
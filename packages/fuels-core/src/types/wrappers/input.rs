@@ -4,10 +4,11 @@ use std::hash::Hash;
 
 use fuel_tx::{TxPointer, UtxoId};
 use fuel_types::{Bytes32, ContractId};
+use serde::{Deserialize, Serialize};
 
-use crate::types::coin_type::CoinType;
+use crate::types::{coin_type::CoinType, message::Message};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Input {
     ResourceSigned {
         resource: CoinType,
@@ -39,6 +40,32 @@ impl Input {
         }
     }
 
+    /// Spends `message` with the signature of its recipient. Messages carrying data become
+    /// `MessageDataSigned` inputs, the others `MessageCoinSigned` ones.
+    pub fn message_signed(message: Message) -> Self {
+        Self::resource_signed(CoinType::Message(message))
+    }
+
+    /// Spends `message` through the predicate `code`, its recipient. Messages carrying data
+    /// become `MessageDataPredicate` inputs, the others `MessageCoinPredicate` ones.
+    pub fn message_predicate(message: Message, code: Vec<u8>, data: Vec<u8>) -> Self {
+        Self::resource_predicate(CoinType::Message(message), code, data)
+    }
+
+    /// The message spent by the input, if any.
+    pub fn message(&self) -> Option<&Message> {
+        match self {
+            Self::ResourceSigned {
+                resource: CoinType::Message(message),
+            }
+            | Self::ResourcePredicate {
+                resource: CoinType::Message(message),
+                ..
+            } => Some(message),
+            _ => None,
+        }
+    }
+
     pub fn amount(&self) -> Option<u64> {
         match self {
             Self::ResourceSigned { resource, .. } | Self::ResourcePredicate { resource, .. } => {
@@ -6,17 +6,18 @@ use fuel_core_client::client::types::{
 };
 use fuel_tx::{Input, MessageId};
 use fuel_types::Nonce;
+use serde::{Deserialize, Serialize};
 
 use crate::types::bech32::Bech32Address;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum MessageStatus {
     #[default]
     Unspent,
     Spent,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Message {
     pub amount: u64,
     pub sender: Bech32Address,
@@ -28,6 +29,14 @@ pub struct Message {
 }
 
 impl Message {
+    /// Whether the message carries data. Such messages are spent by the script or predicate they
+    /// are meant for, and are retryable: their amount is only spent if the script succeeds and,
+    /// if it reverts, the message stays unspent so the transaction can be retried. Their amount
+    /// therefore cannot pay the fee of the transaction spending them.
+    pub fn is_retryable(&self) -> bool {
+        !self.data.is_empty()
+    }
+
     pub fn message_id(&self) -> MessageId {
         Input::compute_message_id(
             &(&self.sender).into(),
@@ -16,13 +16,14 @@ use fuel_tx::{
     policies::PolicyType,
     Bytes32, Cacheable, Chargeable, ConsensusParameters, Create, FormatValidityChecks, Input, Mint,
     Output, Salt as FuelSalt, Script, StorageSlot, Transaction as FuelTransaction, TransactionFee,
-    UniqueIdentifier, Upgrade, Upload, Witness,
+    UniqueIdentifier, Upgrade, Upload, ValidityError, Witness,
 };
-use fuel_types::{bytes::padded_len_usize, AssetId, ChainId};
+use fuel_types::{bytes::padded_len_usize, canonical, AssetId, ChainId};
 use fuel_vm::checked_transaction::{
     CheckPredicateParams, CheckPredicates, EstimatePredicates, IntoChecked,
 };
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     traits::Signer,
@@ -55,6 +56,14 @@ impl Transactions {
     }
 }
 
+impl<T: Into<FuelTransaction>> FromIterator<T> for Transactions {
+    fn from_iter<I: IntoIterator<Item = T>>(txs: I) -> Self {
+        Self {
+            fuel_transactions: txs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct MintTransaction {
     tx: Box<Mint>,
@@ -104,7 +113,7 @@ impl MintTransaction {
     }
 }
 
-#[derive(Default, Debug, Copy, Clone)]
+#[derive(Default, Debug, Copy, Clone, Serialize, Deserialize)]
 //ANCHOR: tx_policies_struct
 pub struct TxPolicies {
     tip: Option<u64>,
@@ -183,6 +192,78 @@ use fuel_vm::prelude::MemoryInstance;
 
 use crate::types::coin_type_id::CoinTypeId;
 
+/// What a transaction spends and pays out, as reported to [`TransactionObserver`]s, e.g. for
+/// display before the transaction is approved.
+///
+/// [`TransactionObserver`]: crate::traits::TransactionObserver
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionSummary {
+    pub id: Bytes32,
+    pub inputs: Vec<Input>,
+    pub outputs: Vec<Output>,
+    /// The most the transaction pays in fees.
+    pub max_fee: Option<u64>,
+    /// The size of the transaction in bytes, as charged for.
+    pub size: usize,
+    /// The amounts of the inputs, per asset.
+    pub inputs_by_asset: HashMap<AssetId, u64>,
+    /// The amounts of the coin outputs, per asset. Change and variable outputs are left out, as
+    /// their amounts are only known once the transaction is executed.
+    pub outputs_by_asset: HashMap<AssetId, u64>,
+    /// The owners of the signed inputs, whose signatures the transaction requires.
+    pub signers: Vec<Bech32Address>,
+}
+
+impl TransactionSummary {
+    pub fn new(tx: &impl Transaction, consensus_parameters: &ConsensusParameters) -> Self {
+        let base_asset_id = consensus_parameters.base_asset_id();
+
+        let mut inputs_by_asset = HashMap::new();
+        for input in tx.inputs() {
+            if let (Some(asset_id), Some(amount)) = (input.asset_id(base_asset_id), input.amount())
+            {
+                *inputs_by_asset.entry(*asset_id).or_default() += amount;
+            }
+        }
+
+        let mut outputs_by_asset = HashMap::new();
+        for output in tx.outputs() {
+            if let Output::Coin {
+                amount, asset_id, ..
+            } = output
+            {
+                *outputs_by_asset.entry(*asset_id).or_default() += amount;
+            }
+        }
+
+        let signers = tx
+            .inputs()
+            .iter()
+            .filter(|input| {
+                matches!(
+                    input,
+                    Input::CoinSigned(_)
+                        | Input::MessageCoinSigned(_)
+                        | Input::MessageDataSigned(_)
+                )
+            })
+            .filter_map(extract_owner_or_recipient)
+            .unique()
+            .collect();
+
+        Self {
+            id: tx.id(consensus_parameters.chain_id()),
+            inputs: tx.inputs().clone(),
+            outputs: tx.outputs().clone(),
+            max_fee: tx.max_fee(),
+            size: tx.metered_bytes_size(),
+            inputs_by_asset,
+            outputs_by_asset,
+            signers,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum TransactionType {
     Script(ScriptTransaction),
@@ -192,6 +273,33 @@ pub enum TransactionType {
     Upgrade(UpgradeTransaction),
 }
 
+impl From<FuelTransaction> for TransactionType {
+    fn from(tx: FuelTransaction) -> Self {
+        match tx {
+            FuelTransaction::Script(tx) => Self::Script(tx.into()),
+            FuelTransaction::Create(tx) => Self::Create(tx.into()),
+            FuelTransaction::Mint(tx) => Self::Mint(tx.into()),
+            FuelTransaction::Upgrade(tx) => Self::Upgrade(tx.into()),
+            FuelTransaction::Upload(tx) => Self::Upload(tx.into()),
+        }
+    }
+}
+
+impl TransactionType {
+    /// Encodes the transaction into the bytes sent to the node.
+    pub fn encode(&self) -> Vec<u8> {
+        canonical::Serialize::to_bytes(&FuelTransaction::from(self.clone()))
+    }
+
+    /// Decodes a transaction from the bytes sent to the node, as returned by
+    /// [`encode`](Self::encode).
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        <FuelTransaction as canonical::Deserialize>::from_bytes(bytes)
+            .map(Into::into)
+            .map_err(|err| error!(Codec, "could not decode transaction: {err:?}"))
+    }
+}
+
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait EstimablePredicates: sealed::Sealed {
@@ -244,8 +352,27 @@ pub trait Transaction:
     /// <https://github.com/FuelLabs/fuel-specs/blob/master/src/tx-format/transaction.md>
     fn check(&self, block_height: u32, consensus_parameters: &ConsensusParameters) -> Result<()>;
 
+    /// Validates the transaction locally against the rules of `consensus_parameters`, as the
+    /// node would on submission: size and input, output and witness counts, the max fee at
+    /// `gas_price`, the signatures of the signed inputs and the remaining stateless checks.
+    /// Unlike [`check`](Transaction::check), every violation found is returned, so an empty
+    /// list means the transaction is valid.
+    fn validate(
+        &self,
+        consensus_parameters: &ConsensusParameters,
+        gas_price: u64,
+    ) -> Vec<ValidityError>;
+
     fn id(&self, chain_id: ChainId) -> Bytes32;
 
+    /// Summarizes what the transaction spends and pays out, and who has to sign it.
+    fn summary(&self, consensus_parameters: &ConsensusParameters) -> TransactionSummary
+    where
+        Self: Sized,
+    {
+        TransactionSummary::new(self, consensus_parameters)
+    }
+
     fn maturity(&self) -> u32;
 
     fn with_maturity(self, maturity: u32) -> Self;
@@ -264,6 +391,11 @@ pub trait Transaction:
 
     fn tip(&self) -> Option<u64>;
 
+    /// Sets the tip to `tip`, raising the max fee by as much as the tip grows so that the fee
+    /// still covers the gas. The signatures of the transaction, and its precomputed id, are no
+    /// longer valid.
+    fn with_tip(self, tip: u64) -> Self;
+
     fn is_using_predicates(&self) -> bool;
 
     /// Precompute transaction metadata. The metadata is required for
@@ -307,6 +439,34 @@ fn extract_coin_type_id(input: &Input) -> Option<CoinTypeId> {
     None
 }
 
+/// Indexes of the signed inputs whose witness is not a signature of `message` by their owner.
+fn invalid_signature_indexes<'a>(
+    inputs: &'a [Input],
+    witnesses: &'a [Witness],
+    message: &'a Message,
+) -> impl Iterator<Item = usize> + 'a {
+    inputs
+        .iter()
+        .enumerate()
+        .filter(|(_, input)| {
+            matches!(
+                input,
+                Input::CoinSigned(_) | Input::MessageCoinSigned(_) | Input::MessageDataSigned(_)
+            )
+        })
+        .filter(move |(_, input)| {
+            let signer = input
+                .witness_index()
+                .and_then(|index| witnesses.get(index as usize))
+                .and_then(|witness| Signature::try_from(witness.as_vec().as_slice()).ok())
+                .and_then(|signature| signature.recover(message).ok())
+                .map(|public_key| Bech32Address::from(Input::owner(&public_key)));
+
+            signer.is_none() || signer != extract_owner_or_recipient(input)
+        })
+        .map(|(index, _)| index)
+}
+
 pub fn extract_owner_or_recipient(input: &Input) -> Option<Bech32Address> {
     let addr = match input {
         Input::CoinSigned(CoinSigned { owner, .. })
@@ -323,12 +483,37 @@ pub fn extract_owner_or_recipient(input: &Input) -> Option<Bech32Address> {
 
 macro_rules! impl_tx_wrapper {
     ($wrapper: ident, $wrapped: ident) => {
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, Serialize, Deserialize)]
         pub struct $wrapper {
             pub(crate) tx: $wrapped,
             pub(crate) is_using_predicates: bool,
         }
 
+        impl $wrapper {
+            #[cfg(feature = "json")]
+            pub fn to_json(&self) -> Result<String> {
+                Ok(serde_json::to_string(self)?)
+            }
+
+            #[cfg(feature = "json")]
+            pub fn from_json(json: &str) -> Result<Self> {
+                Ok(serde_json::from_str(json)?)
+            }
+
+            /// Encodes the transaction into the bytes sent to the node.
+            pub fn to_bytes(&self) -> Vec<u8> {
+                canonical::Serialize::to_bytes(&FuelTransaction::from(self.clone()))
+            }
+
+            /// Decodes a transaction from the bytes sent to the node, as returned by
+            /// [`to_bytes`](Self::to_bytes).
+            pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+                <FuelTransaction as canonical::Deserialize>::from_bytes(bytes)
+                    .map_err(|err| error!(Codec, "could not decode transaction: {err:?}"))?
+                    .try_into()
+            }
+        }
+
         impl From<$wrapper> for $wrapped {
             fn from(tx: $wrapper) -> Self {
                 tx.tx
@@ -422,6 +607,55 @@ macro_rules! impl_tx_wrapper {
                 Ok(self.tx.check(block_height.into(), consensus_parameters)?)
             }
 
+            fn validate(
+                &self,
+                consensus_parameters: &ConsensusParameters,
+                gas_price: u64,
+            ) -> Vec<ValidityError> {
+                let tx_params = consensus_parameters.tx_params();
+                let mut violations = vec![];
+
+                if canonical::Serialize::size(&self.tx) as u64 > tx_params.max_size() {
+                    violations.push(ValidityError::TransactionSizeLimitExceeded);
+                }
+                if self.inputs().len() > tx_params.max_inputs() as usize {
+                    violations.push(ValidityError::TransactionInputsMax);
+                }
+                if self.outputs().len() > tx_params.max_outputs() as usize {
+                    violations.push(ValidityError::TransactionOutputsMax);
+                }
+                if self.witnesses().len() > tx_params.max_witnesses() as usize {
+                    violations.push(ValidityError::TransactionWitnessesMax);
+                }
+
+                let provided = self.max_fee().unwrap_or_default();
+                if let Some(fee) = self.fee_checked_from_tx(consensus_parameters, gas_price) {
+                    if fee.max_fee() > provided {
+                        violations.push(ValidityError::InsufficientFeeAmount {
+                            expected: fee.max_fee(),
+                            provided,
+                        });
+                    }
+                }
+
+                let message = Message::from_bytes(*self.id(consensus_parameters.chain_id()));
+                violations.extend(
+                    invalid_signature_indexes(self.inputs(), self.witnesses(), &message)
+                        .map(|index| ValidityError::InputInvalidSignature { index }),
+                );
+
+                if let Err(err) = self
+                    .tx
+                    .check_without_signatures(u32::MAX.into(), consensus_parameters)
+                {
+                    if !violations.contains(&err) {
+                        violations.push(err);
+                    }
+                }
+
+                violations
+            }
+
             fn id(&self, chain_id: ChainId) -> Bytes32 {
                 self.tx.id(&chain_id)
             }
@@ -471,6 +705,20 @@ macro_rules! impl_tx_wrapper {
                 self.tx.policies().get(PolicyType::Tip)
             }
 
+            fn with_tip(mut self, tip: u64) -> Self {
+                let tip_increase = tip.saturating_sub(self.tip().unwrap_or_default());
+                let max_fee = self
+                    .max_fee()
+                    .unwrap_or_default()
+                    .saturating_add(tip_increase);
+
+                self.tx.policies_mut().set(PolicyType::Tip, Some(tip));
+                self.tx
+                    .policies_mut()
+                    .set(PolicyType::MaxFee, Some(max_fee));
+                self
+            }
+
             fn append_witness(&mut self, witness: Witness) -> Result<usize> {
                 let witness_size = calculate_witnesses_size(
                     self.tx.witnesses().iter().chain(std::iter::once(&witness)),
@@ -733,4 +981,27 @@ mod test {
 
         assert_eq!(&err.to_string(), expected_err_str);
     }
+
+    #[test]
+    fn transactions_survive_encoding() -> Result<()> {
+        let tx = FuelTransaction::script(
+            100,
+            vec![1, 2, 3],
+            vec![4, 5],
+            Policies::default(),
+            vec![],
+            vec![],
+            vec![],
+        );
+
+        let TransactionType::Script(decoded) =
+            TransactionType::decode(&TransactionType::from(tx.clone()).encode())?
+        else {
+            panic!("should decode a script");
+        };
+
+        assert_eq!(FuelTransaction::from(decoded), tx);
+
+        Ok(())
+    }
 }
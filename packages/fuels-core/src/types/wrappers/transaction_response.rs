@@ -4,13 +4,9 @@ use chrono::{DateTime, Utc};
 use fuel_core_client::client::types::{
     TransactionResponse as ClientTransactionResponse, TransactionStatus as ClientTransactionStatus,
 };
-use fuel_tx::Transaction;
 use fuel_types::BlockHeight;
 
-use crate::types::{
-    transaction::{CreateTransaction, ScriptTransaction, TransactionType},
-    tx_status::TxStatus,
-};
+use crate::types::{transaction::TransactionType, tx_status::TxStatus};
 
 #[derive(Debug, Clone)]
 pub struct TransactionResponse {
@@ -38,16 +34,8 @@ impl From<ClientTransactionResponse> for TransactionResponse {
             }
         };
 
-        let transaction = match client_response.transaction {
-            Transaction::Script(tx) => TransactionType::Script(ScriptTransaction::from(tx)),
-            Transaction::Create(tx) => TransactionType::Create(CreateTransaction::from(tx)),
-            Transaction::Mint(tx) => TransactionType::Mint(tx.into()),
-            Transaction::Upgrade(tx) => TransactionType::Upgrade(tx.into()),
-            Transaction::Upload(tx) => TransactionType::Upload(tx.into()),
-        };
-
         Self {
-            transaction,
+            transaction: client_response.transaction.into(),
             status: client_response.status.into(),
             block_height,
             time,
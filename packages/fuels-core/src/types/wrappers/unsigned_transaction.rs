@@ -0,0 +1,291 @@
+//! Building and signing transactions on different machines.
+//!
+//! 1. On a machine with a provider, add an [`OfflineSigner`] for every address that has to sign,
+//!    build the transaction and wrap it in an [`UnsignedTransaction`].
+//! 2. Move its [`to_json`](UnsignedTransaction::to_json) or
+//!    [`to_bytes`](UnsignedTransaction::to_bytes) output to the air-gapped machine, which only needs
+//!    `fuels-core`, and [`sign`](UnsignedTransaction::sign) it there.
+//! 3. Move it back and turn it into a transaction with
+//!    [`into_transaction`](UnsignedTransaction::into_transaction) to submit it.
+
+use async_trait::async_trait;
+use fuel_crypto::{Message, Signature};
+use fuel_tx::{
+    field::Witnesses, Address, Bytes32, Transaction as FuelTransaction, UniqueIdentifier, Witness,
+};
+use fuel_types::ChainId;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    traits::Signer,
+    types::{
+        bech32::{Bech32Address, FUEL_BECH32_HRP},
+        errors::{error, Result},
+        transaction::Transaction,
+    },
+};
+
+/// Reserves a witness for `address` in a transaction builder without signing anything.
+///
+/// The transaction is built, and its fee estimated, with an empty signature in that witness, to
+/// be replaced through [`UnsignedTransaction::sign`]. As is, the transaction can only be
+/// simulated without validating its witnesses, e.g. by accounts impersonating an address.
+#[derive(Debug, Clone)]
+pub struct OfflineSigner {
+    address: Bech32Address,
+}
+
+impl OfflineSigner {
+    pub fn new(address: Bech32Address) -> Self {
+        Self { address }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for OfflineSigner {
+    async fn sign(&self, _message: Message) -> Result<Signature> {
+        Ok(Signature::default())
+    }
+
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
+
+/// A built transaction together with the signatures it still needs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnsignedTransaction {
+    transaction: FuelTransaction,
+    chain_id: u64,
+    signatures: Vec<SignatureSlot>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct SignatureSlot {
+    owner: Address,
+    witness_index: u16,
+    signature: Option<Signature>,
+}
+
+impl UnsignedTransaction {
+    /// Collects the witnesses required by the signed inputs of `tx`. Every one of them must
+    /// already be present, e.g. reserved by an [`OfflineSigner`].
+    pub fn new(tx: impl Transaction, chain_id: ChainId) -> Result<Self> {
+        let num_witnesses = tx.witnesses().len();
+
+        let mut signatures: Vec<SignatureSlot> = vec![];
+        for input in tx.inputs() {
+            let (Some(owner), Some(witness_index)) = (
+                input.input_owner().or_else(|| input.recipient()),
+                input.witness_index(),
+            ) else {
+                continue;
+            };
+
+            if usize::from(witness_index) >= num_witnesses {
+                return Err(error!(
+                    Other,
+                    "no witness reserved at index {witness_index} for `{}`",
+                    Bech32Address::new(FUEL_BECH32_HRP, *owner)
+                ));
+            }
+
+            if !signatures
+                .iter()
+                .any(|slot| slot.witness_index == witness_index)
+            {
+                signatures.push(SignatureSlot {
+                    owner: *owner,
+                    witness_index,
+                    signature: None,
+                });
+            }
+        }
+
+        Ok(Self {
+            transaction: tx.into(),
+            chain_id: *chain_id,
+            signatures,
+        })
+    }
+
+    /// The transaction ID, which is what every signer signs.
+    pub fn digest(&self) -> Bytes32 {
+        self.transaction.id(&self.chain_id.into())
+    }
+
+    /// Addresses whose signature is still missing.
+    pub fn missing_signers(&self) -> Vec<Bech32Address> {
+        self.signatures
+            .iter()
+            .filter(|slot| slot.signature.is_none())
+            .map(|slot| Bech32Address::new(FUEL_BECH32_HRP, slot.owner))
+            .collect()
+    }
+
+    pub async fn sign(&mut self, signer: &(impl Signer + ?Sized)) -> Result<()> {
+        let signature = signer.sign(Message::from_bytes(*self.digest())).await?;
+
+        self.add_signature(signature)
+    }
+
+    /// Adds a signature of [`digest`](Self::digest) produced elsewhere. The signer is recovered
+    /// from the signature.
+    pub fn add_signature(&mut self, signature: Signature) -> Result<()> {
+        let owner = Address::from(
+            *signature
+                .recover(&Message::from_bytes(*self.digest()))?
+                .hash(),
+        );
+
+        let slot = self
+            .signatures
+            .iter_mut()
+            .find(|slot| slot.owner == owner)
+            .ok_or_else(|| {
+                error!(
+                    Other,
+                    "`{}` does not have to sign this transaction",
+                    Bech32Address::new(FUEL_BECH32_HRP, owner)
+                )
+            })?;
+        slot.signature = Some(signature);
+
+        Ok(())
+    }
+
+    /// Fills the reserved witnesses with the collected signatures. Fails if any is missing.
+    pub fn into_transaction<T: Transaction>(self) -> Result<T> {
+        let missing_signers = self.missing_signers();
+        if !missing_signers.is_empty() {
+            let missing_signers = missing_signers
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            return Err(error!(Other, "missing signatures of: {missing_signers}"));
+        }
+
+        let mut transaction = self.transaction;
+        let witnesses = match &mut transaction {
+            FuelTransaction::Script(tx) => tx.witnesses_mut(),
+            FuelTransaction::Create(tx) => tx.witnesses_mut(),
+            FuelTransaction::Upload(tx) => tx.witnesses_mut(),
+            FuelTransaction::Upgrade(tx) => tx.witnesses_mut(),
+            FuelTransaction::Mint(_) => {
+                return Err(error!(Other, "mint transactions cannot be signed"))
+            }
+        };
+
+        for slot in self.signatures {
+            let signature = slot.signature.expect("checked above");
+            witnesses[usize::from(slot.witness_index)] = Witness::from(signature.as_ref());
+        }
+
+        T::try_from(transaction)
+    }
+
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        postcard::to_allocvec(self).map_err(|e| error!(Codec, "{e}"))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        postcard::from_bytes(bytes).map_err(|e| error!(Codec, "{e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use fuel_crypto::{PublicKey, SecretKey};
+    use fuel_tx::{Input, TxPointer, UtxoId};
+    use fuel_types::AssetId;
+
+    use super::*;
+    use crate::types::transaction::ScriptTransaction;
+
+    #[derive(Debug)]
+    struct KeySigner {
+        secret_key: SecretKey,
+        address: Bech32Address,
+    }
+
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl Signer for KeySigner {
+        async fn sign(&self, message: Message) -> Result<Signature> {
+            Ok(Signature::sign(&self.secret_key, &message))
+        }
+
+        fn address(&self) -> &Bech32Address {
+            &self.address
+        }
+    }
+
+    #[tokio::test]
+    async fn signs_exported_transaction() -> Result<()> {
+        let secret_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?;
+        let owner = Input::owner(&PublicKey::from(&secret_key));
+        let signer = KeySigner {
+            secret_key,
+            address: Bech32Address::new(FUEL_BECH32_HRP, owner),
+        };
+
+        let coin = Input::coin_signed(
+            UtxoId::default(),
+            owner,
+            100,
+            AssetId::zeroed(),
+            TxPointer::default(),
+            0,
+        );
+        let tx = ScriptTransaction::from(FuelTransaction::script(
+            0,
+            vec![],
+            vec![],
+            Default::default(),
+            vec![coin.clone(), coin],
+            vec![],
+            vec![Witness::from(Signature::default().as_ref())],
+        ));
+
+        let chain_id = ChainId::default();
+        let unsigned = UnsignedTransaction::new(tx, chain_id)?;
+        assert_eq!(
+            unsigned.missing_signers(),
+            std::slice::from_ref(&signer.address)
+        );
+        assert!(unsigned
+            .clone()
+            .into_transaction::<ScriptTransaction>()
+            .is_err());
+
+        // Signed on another machine.
+        let mut offline = UnsignedTransaction::from_json(&unsigned.to_json()?)?;
+        offline.sign(&signer).await?;
+
+        let signed = UnsignedTransaction::from_bytes(&offline.to_bytes()?)?
+            .into_transaction::<ScriptTransaction>()?;
+        let expected = Signature::sign(&secret_key, &Message::from_bytes(*signed.id(chain_id)));
+        assert_eq!(signed.witnesses(), &[Witness::from(expected.as_ref())]);
+
+        Ok(())
+    }
+}
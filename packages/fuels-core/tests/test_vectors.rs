@@ -0,0 +1,57 @@
+#![cfg(feature = "test-vectors")]
+
+use std::path::{Path, PathBuf};
+
+use fuels_core::{codec::test_vectors::TestVectors, types::errors::Result};
+
+/// Vectors produced by fuels-ts, see `fuels_ts_vectors_match_fuels_rs`.
+const FUELS_TS_VECTORS_VAR: &str = "FUELS_TS_TEST_VECTORS";
+
+/// The vectors of fuels-rs, regenerated with the `test_vectors` example whenever the encoding
+/// changes on purpose.
+fn golden_vectors_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/test_vectors.json")
+}
+
+#[test]
+fn generated_vectors_match_the_golden_file() -> Result<()> {
+    let golden = TestVectors::load(golden_vectors_path())?;
+
+    assert_eq!(TestVectors::generate()?, golden);
+    golden.validate()
+}
+
+#[test]
+fn vectors_survive_json() -> Result<()> {
+    let vectors = TestVectors::generate()?;
+
+    let file = tempfile::NamedTempFile::new()?;
+    vectors.save(file.path())?;
+
+    assert_eq!(TestVectors::load(file.path())?, vectors);
+
+    Ok(())
+}
+
+/// Validates the vectors at the path in `FUELS_TS_TEST_VECTORS` against fuels-rs.
+#[test]
+#[ignore = "needs the vectors of fuels-ts, at the path in `FUELS_TS_TEST_VECTORS`"]
+fn fuels_ts_vectors_match_fuels_rs() -> Result<()> {
+    let path = std::env::var(FUELS_TS_VECTORS_VAR)
+        .unwrap_or_else(|_| panic!("`{FUELS_TS_VECTORS_VAR}` must be set"));
+
+    TestVectors::load(path)?.validate()
+}
+
+#[test]
+fn mismatches_are_reported() -> Result<()> {
+    let mut vectors = TestVectors::generate()?;
+    vectors.encoding[0].bytes = "0x01".to_string();
+    vectors.selectors[0].selector = "0x00".to_string();
+
+    let err = vectors.validate().expect_err("should fail").to_string();
+
+    assert!(err.contains("2 test vectors failed"));
+
+    Ok(())
+}
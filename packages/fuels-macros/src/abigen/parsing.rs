@@ -19,6 +19,7 @@ impl From<MacroAbigenTarget> for AbigenTarget {
             macro_target.source,
             macro_target.program_type,
         )
+        .with_wasm_bindgen(macro_target.wasm_bindgen)
     }
 }
 
@@ -29,6 +30,7 @@ pub(crate) struct MacroAbigenTarget {
     pub(crate) name: String,
     pub(crate) source: Abi,
     pub program_type: ProgramType,
+    pub(crate) wasm_bindgen: bool,
 }
 
 pub(crate) struct MacroAbigenTargets {
@@ -51,16 +53,20 @@ impl MacroAbigenTarget {
         let program_type = command.name.try_into()?;
 
         let name_values = UniqueNameValues::new(command.contents)?;
-        name_values.validate_has_no_other_names(&["name", "abi"])?;
+        name_values.validate_has_no_other_names(&["name", "abi", "wasm_bindgen"])?;
 
         let name = name_values.get_as_lit_str("name")?.value();
         let abi_lit_str = name_values.get_as_lit_str("abi")?;
         let source = Self::parse_inline_or_load_abi(abi_lit_str)?;
+        let wasm_bindgen = name_values
+            .try_get_as_bool("wasm_bindgen")?
+            .unwrap_or_default();
 
         Ok(Self {
             name,
             source,
             program_type,
+            wasm_bindgen,
         })
     }
 
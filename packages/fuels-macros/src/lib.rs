@@ -1,6 +1,6 @@
 use fuels_code_gen::Abigen;
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, DeriveInput, ItemFn};
 
 use crate::{
     abigen::MacroAbigenTargets,
@@ -9,12 +9,14 @@ use crate::{
         try_from::generate_try_from_impl,
     },
     setup_program_test::{generate_setup_program_test_code, TestProgramCommands},
+    test_attribute::generate_test_code,
 };
 
 mod abigen;
 mod derive;
 mod parse_utils;
 mod setup_program_test;
+mod test_attribute;
 
 /// Used to generate bindings for Contracts, Scripts and Predicates. Accepts
 /// input in the form of `ProgramType(name="MyBindings", abi=ABI_SOURCE)...`
@@ -62,6 +64,30 @@ pub fn setup_program_test(input: TokenStream) -> TokenStream {
         .into()
 }
 
+/// Turns an `async` function into a `tokio` test whose arguments are wallets, contract instances
+/// and script instances, set up by the same commands as `setup_program_test!`:
+///
+///```text
+/// #[fuels::test(
+///     Abigen(Contract(name = "TestContract", project = "e2e/sway/contracts/contract_test")),
+///     Deploy(name = "contract_instance", contract = "TestContract", wallet = "wallet"),
+/// )]
+/// async fn my_test(wallet: WalletUnlocked, contract_instance: TestContract<WalletUnlocked>) {}
+///```
+///
+/// Without a `Wallets` command, every argument that is not a contract or script instance is a
+/// funded wallet. Without any command, every argument is.
+///
+/// More details can be found in the [`Fuel Rust SDK Book`](https://fuellabs.github.io/fuels-rs/latest)
+#[proc_macro_attribute]
+pub fn test(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let test_fn = parse_macro_input!(item as ItemFn);
+
+    generate_test_code(attr.into(), test_fn)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
 #[proc_macro_derive(Parameterize, attributes(FuelsTypesPath, FuelsCorePath, NoStd, Ignore))]
 pub fn parameterize(stream: TokenStream) -> TokenStream {
     let input = parse_macro_input!(stream as DeriveInput);
@@ -4,7 +4,7 @@ use fuels_code_gen::utils::ident;
 use itertools::Itertools;
 use proc_macro2::{Ident, Span, TokenStream};
 use syn::{
-    parse::Parser, punctuated::Punctuated, spanned::Spanned, Error, Expr, Lit, LitStr,
+    parse::Parser, punctuated::Punctuated, spanned::Spanned, Error, Expr, Lit, LitBool, LitStr,
     MetaNameValue,
 };
 
@@ -70,6 +70,18 @@ impl UniqueNameValues {
         }
     }
 
+    /// The boolean value of `name`, or `None` if `name` was not given.
+    pub fn try_get_as_bool(&self, name: &str) -> syn::Result<Option<bool>> {
+        match self.try_get(name) {
+            None => Ok(None),
+            Some(Lit::Bool(LitBool { value, .. })) => Ok(Some(*value)),
+            Some(value) => Err(Error::new_spanned(
+                value.clone(),
+                format!("expected the attribute '{name}' to have a boolean value"),
+            )),
+        }
+    }
+
     fn extract_name_values<T: Iterator<Item = MetaNameValue>>(
         name_value_metas: T,
     ) -> syn::Result<Vec<(Ident, Lit)>> {
@@ -101,7 +113,6 @@ impl UniqueNameValues {
 mod tests {
     use proc_macro2::TokenStream;
     use quote::quote;
-    use syn::LitBool;
 
     use super::*;
     use crate::parse_utils::command::Command;
@@ -1,5 +1,5 @@
-pub(crate) use code_gen::generate_setup_program_test_code;
-pub(crate) use parsing::TestProgramCommands;
+pub(crate) use code_gen::{generate_setup_program_test_code, wallet_initialization_code};
+pub(crate) use parsing::{InitializeWalletCommand, TestProgramCommands};
 
 mod code_gen;
 mod parsing;
@@ -79,7 +79,9 @@ fn parse_abigen_targets(
         .collect()
 }
 
-fn wallet_initialization_code(maybe_command: Option<InitializeWalletCommand>) -> TokenStream {
+pub(crate) fn wallet_initialization_code(
+    maybe_command: Option<InitializeWalletCommand>,
+) -> TokenStream {
     let command = if let Some(command) = maybe_command {
         command
     } else {
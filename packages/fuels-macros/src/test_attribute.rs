@@ -0,0 +1,164 @@
+use std::collections::HashSet;
+
+use fuels_code_gen::utils::ident;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Error, FnArg, ItemFn, LitStr, Pat, Result};
+
+use crate::setup_program_test::{
+    generate_setup_program_test_code, wallet_initialization_code, InitializeWalletCommand,
+    TestProgramCommands,
+};
+
+/// Turns `test_fn` into a `tokio` test whose arguments are set up by the `setup_program_test!`
+/// commands in `attr`. Without a `Wallets` command, every argument that isn't a contract or a
+/// script instance is a wallet.
+pub(crate) fn generate_test_code(attr: TokenStream, test_fn: ItemFn) -> Result<TokenStream> {
+    if test_fn.sig.asyncness.is_none() {
+        return Err(Error::new_spanned(
+            test_fn.sig.fn_token,
+            "`#[fuels::test]` functions must be `async`",
+        ));
+    }
+
+    let arguments = test_arguments(&test_fn)?;
+
+    let setup_code = if attr.is_empty() {
+        wallets_only_setup_code(&arguments)
+    } else {
+        let mut commands: TestProgramCommands = syn::parse2(attr)?;
+        add_missing_wallets(&mut commands, &arguments)?;
+
+        generate_setup_program_test_code(commands)?
+    };
+
+    let bindings = test_fn
+        .sig
+        .inputs
+        .iter()
+        .zip(&arguments)
+        .map(|(input, name)| {
+            let FnArg::Typed(arg) = input else {
+                unreachable!("receivers are rejected by `test_arguments`")
+            };
+            let pat = &arg.pat;
+            let ty = &arg.ty;
+            let name = ident(&name.value());
+
+            quote! { let #pat: #ty = #name; }
+        })
+        .collect::<Vec<_>>();
+
+    let ItemFn {
+        attrs,
+        vis,
+        mut sig,
+        block,
+    } = test_fn;
+    sig.inputs.clear();
+
+    Ok(quote! {
+        #[::fuels::tokio::test(crate = "::fuels::tokio")]
+        #(#attrs)*
+        #vis #sig {
+            #setup_code
+            #(#bindings)*
+            #block
+        }
+    })
+}
+
+/// The names of the arguments of `test_fn`, in order.
+fn test_arguments(test_fn: &ItemFn) -> Result<Vec<LitStr>> {
+    test_fn
+        .sig
+        .inputs
+        .iter()
+        .map(|input| match input {
+            FnArg::Typed(arg) => match arg.pat.as_ref() {
+                Pat::Ident(pat) => Ok(LitStr::new(&pat.ident.to_string(), pat.ident.span())),
+                pat => Err(Error::new_spanned(
+                    pat,
+                    "`#[fuels::test]` arguments must be identifiers",
+                )),
+            },
+            FnArg::Receiver(receiver) => Err(Error::new_spanned(
+                receiver,
+                "`#[fuels::test]` functions cannot take `self`",
+            )),
+        })
+        .collect()
+}
+
+fn wallets_only_setup_code(arguments: &[LitStr]) -> TokenStream {
+    wallet_initialization_code(Some(InitializeWalletCommand {
+        span: Span::call_site(),
+        names: arguments.to_vec(),
+    }))
+}
+
+/// Without a `Wallets` command, sets up a wallet for every argument that isn't a contract or a
+/// script instance, and for every wallet the commands deploy or load with. Otherwise, checks that
+/// every argument is set up by the commands.
+fn add_missing_wallets(commands: &mut TestProgramCommands, arguments: &[LitStr]) -> Result<()> {
+    let instances: HashSet<String> = commands
+        .deploy_contract
+        .iter()
+        .map(|command| command.name.clone())
+        .chain(
+            commands
+                .load_scripts
+                .iter()
+                .map(|command| command.name.clone()),
+        )
+        .collect();
+
+    if let Some(wallets) = &commands.initialize_wallets {
+        let known: HashSet<String> = wallets
+            .names
+            .iter()
+            .map(LitStr::value)
+            .chain(instances)
+            .collect();
+
+        return arguments
+            .iter()
+            .find(|name| !known.contains(&name.value()))
+            .map_or(Ok(()), |name| {
+                Err(Error::new(
+                    name.span(),
+                    "argument is neither a wallet, nor a contract or script instance of the setup",
+                ))
+            });
+    }
+
+    let mut names: Vec<String> = commands
+        .deploy_contract
+        .iter()
+        .map(|command| command.wallet.clone())
+        .chain(
+            commands
+                .load_scripts
+                .iter()
+                .map(|command| command.wallet.clone()),
+        )
+        .chain(
+            arguments
+                .iter()
+                .map(LitStr::value)
+                .filter(|name| !instances.contains(name)),
+        )
+        .collect();
+    let mut seen = HashSet::new();
+    names.retain(|name| seen.insert(name.clone()));
+
+    commands.initialize_wallets = Some(InitializeWalletCommand {
+        span: Span::call_site(),
+        names: names
+            .iter()
+            .map(|name| LitStr::new(name, Span::call_site()))
+            .collect(),
+    });
+
+    Ok(())
+}
@@ -5,6 +5,7 @@ mod tests {
         let t = trybuild::TestCases::new();
         t.compile_fail("tests/ui/abigen/*.rs");
         t.compile_fail("tests/ui/setup_program_test/*.rs");
+        t.compile_fail("tests/ui/test_attribute/*.rs");
         t.compile_fail("tests/ui/derive/*/*.rs");
     }
 }
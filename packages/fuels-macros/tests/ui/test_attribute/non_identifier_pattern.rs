@@ -0,0 +1,4 @@
+#[fuels_macros::test]
+async fn destructures((first, second): (u8, u8)) {}
+
+fn main() {}
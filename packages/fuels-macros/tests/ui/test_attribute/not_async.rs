@@ -0,0 +1,4 @@
+#[fuels_macros::test]
+fn not_async() {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+struct Suite;
+
+impl Suite {
+    #[fuels_macros::test]
+    async fn takes_self(&self) {}
+}
+
+fn main() {}
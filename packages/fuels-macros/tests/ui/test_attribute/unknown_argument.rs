@@ -0,0 +1,7 @@
+#[fuels_macros::test(
+    Wallets("wallet"),
+    Abigen(Contract(name = "MyContract", project = "some_project"))
+)]
+async fn unknown_argument(wallet: Wallet, stranger: Wallet) {}
+
+fn main() {}
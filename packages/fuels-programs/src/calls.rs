@@ -1,13 +1,17 @@
 mod call_handler;
+mod call_tree;
 mod contract_call;
 pub mod receipt_parser;
 mod script_call;
 pub mod traits;
+mod transaction_decoder;
 pub mod utils;
 
 pub use call_handler::*;
+pub use call_tree::*;
 pub use contract_call::*;
 pub use script_call::*;
+pub use transaction_decoder::*;
 
 /// Used to control simulations/dry-runs
 #[derive(Debug, Clone, Default)]
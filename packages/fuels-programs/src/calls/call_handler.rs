@@ -162,24 +162,28 @@ where
     }
 
     /// Call a contract's method on the node, in a state-modifying manner.
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "call", skip_all, err))]
     pub async fn call(mut self) -> Result<CallResponse<T>> {
         let tx = self.build_tx().await?;
         let provider = self.account.try_provider()?;
 
         self.cached_tx_id = Some(tx.id(provider.chain_id()));
 
-        let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+        let tx_status = self.account.send_transaction_and_await_commit(tx).await?;
 
         let receipts = tx_status.take_receipts_checked(Some(&self.log_decoder))?;
 
         self.get_response(receipts)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "submit", skip_all, err)
+    )]
     pub async fn submit(mut self) -> Result<SubmitResponse<A, C, T>> {
         let tx = self.build_tx().await?;
-        let provider = self.account.try_provider()?;
 
-        let tx_id = provider.send_transaction(tx.clone()).await?;
+        let tx_id = self.account.send_transaction(tx.clone()).await?;
         self.cached_tx_id = Some(tx_id);
 
         Ok(SubmitResponse::<A, C, T>::new(tx_id, self))
@@ -187,6 +191,7 @@ where
 
     /// Call a contract's method on the node, in a simulated manner, meaning the state of the
     /// blockchain is *not* modified but simulated.
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "simulate", skip_all, fields(?execution), err))]
     pub async fn simulate(&mut self, execution: Execution) -> Result<CallResponse<T>> {
         let provider = self.account.try_provider()?;
 
@@ -420,6 +425,10 @@ where
     }
 
     /// Call contract methods on the node, in a state-modifying manner.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "multi_call", skip_all, err)
+    )]
     pub async fn call<T: Tokenizable + Debug>(mut self) -> Result<CallResponse<T>> {
         let tx = self.build_tx().await?;
 
@@ -427,17 +436,20 @@ where
 
         self.cached_tx_id = Some(tx.id(provider.chain_id()));
 
-        let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+        let tx_status = self.account.send_transaction_and_await_commit(tx).await?;
 
         let receipts = tx_status.take_receipts_checked(Some(&self.log_decoder))?;
         self.get_response(receipts)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "multi_call_submit", skip_all, err)
+    )]
     pub async fn submit(mut self) -> Result<SubmitResponse<A, Vec<ContractCall>, ()>> {
         let tx = self.build_tx().await?;
-        let provider = self.account.try_provider()?;
 
-        let tx_id = provider.send_transaction(tx).await?;
+        let tx_id = self.account.send_transaction(tx).await?;
         self.cached_tx_id = Some(tx_id);
 
         Ok(SubmitResponse::<A, Vec<ContractCall>, ()>::new(tx_id, self))
@@ -448,6 +460,7 @@ where
     /// It is the same as the [call] method because the API is more user-friendly this way.
     ///
     /// [call]: Self::call
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "multi_call_simulate", skip_all, fields(?execution), err))]
     pub async fn simulate<T: Tokenizable + Debug>(
         &mut self,
         execution: Execution,
@@ -0,0 +1,314 @@
+use std::fmt;
+
+use fuel_tx::{AssetId, ContractId, PanicReason, Receipt, ScriptExecutionResult};
+
+/// How a [`CallFrame`] ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallOutcome {
+    /// The call returned a single word.
+    Return(u64),
+    /// The call returned data. The data is only present if the node included it in the receipt.
+    ReturnData(Option<Vec<u8>>),
+    /// The call reverted with the given code.
+    Revert(u64),
+    Panic(PanicReason),
+    /// The call did not return, either because a nested call reverted or panicked, which aborts
+    /// the whole transaction, or because the receipts are incomplete.
+    Unfinished,
+}
+
+/// A call to a contract, with the calls it made in turn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallFrame {
+    pub contract_id: ContractId,
+    /// The calling contract, or the zeroed contract id if called by the script.
+    pub caller: ContractId,
+    /// The amount of `asset_id` forwarded to the contract.
+    pub amount: u64,
+    pub asset_id: AssetId,
+    /// The gas forwarded to the call. Receipts don't record the gas left when a call returns, so
+    /// the gas used by each call is not known, only the total of the transaction.
+    pub gas_forwarded: u64,
+    /// The first parameter of the call, i.e. the function selector for calls made by the SDK.
+    pub param1: u64,
+    pub param2: u64,
+    pub outcome: CallOutcome,
+    /// The `Log` and `LogData` receipts emitted by the contract itself during the call, excluding
+    /// those of the nested calls.
+    pub logs: Vec<Receipt>,
+    pub calls: Vec<CallFrame>,
+}
+
+impl CallFrame {
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let indent = "  ".repeat(depth);
+        write!(
+            f,
+            "{indent}{} (gas: {}",
+            self.contract_id, self.gas_forwarded
+        )?;
+        if self.amount > 0 {
+            write!(f, ", forwarded: {} of {}", self.amount, self.asset_id)?;
+        }
+        writeln!(f, ") -> {:?}", self.outcome)?;
+
+        for log in &self.logs {
+            writeln!(f, "{indent}  log: {log:?}")?;
+        }
+        for call in &self.calls {
+            call.fmt_indented(f, depth + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The nested contract calls of a script transaction, reconstructed from its receipts.
+///
+/// ```ignore
+/// let response = contract_instance.methods().swap(amount).call().await?;
+/// let tree = CallTree::from_receipts(&response.receipts);
+///
+/// println!("{tree}");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CallTree {
+    /// The calls made by the script, in order.
+    pub calls: Vec<CallFrame>,
+    /// The `Log` and `LogData` receipts emitted by the script itself.
+    pub logs: Vec<Receipt>,
+    /// The gas used by the whole transaction, if the receipts include the script result.
+    pub gas_used: Option<u64>,
+    pub result: Option<ScriptExecutionResult>,
+}
+
+impl CallTree {
+    pub fn from_receipts(receipts: &[Receipt]) -> Self {
+        let mut tree = Self::default();
+        let mut stack: Vec<CallFrame> = vec![];
+
+        for receipt in receipts {
+            match receipt {
+                Receipt::Call {
+                    id,
+                    to,
+                    amount,
+                    asset_id,
+                    gas,
+                    param1,
+                    param2,
+                    ..
+                } => stack.push(CallFrame {
+                    contract_id: *to,
+                    caller: *id,
+                    amount: *amount,
+                    asset_id: *asset_id,
+                    gas_forwarded: *gas,
+                    param1: *param1,
+                    param2: *param2,
+                    outcome: CallOutcome::Unfinished,
+                    logs: vec![],
+                    calls: vec![],
+                }),
+                Receipt::Log { .. } | Receipt::LogData { .. } => match stack.last_mut() {
+                    Some(frame) => frame.logs.push(receipt.clone()),
+                    None => tree.logs.push(receipt.clone()),
+                },
+                Receipt::Return { id, val, .. } => {
+                    tree.end_call(&mut stack, id, CallOutcome::Return(*val));
+                }
+                Receipt::ReturnData { id, data, .. } => {
+                    tree.end_call(&mut stack, id, CallOutcome::ReturnData(data.clone()));
+                }
+                Receipt::Revert { id, ra, .. } => {
+                    tree.end_call(&mut stack, id, CallOutcome::Revert(*ra));
+                    tree.unwind(&mut stack);
+                }
+                Receipt::Panic { id, reason, .. } => {
+                    tree.end_call(&mut stack, id, CallOutcome::Panic(*reason.reason()));
+                    tree.unwind(&mut stack);
+                }
+                Receipt::ScriptResult { result, gas_used } => {
+                    tree.gas_used = Some(*gas_used);
+                    tree.result = Some(result.clone());
+                }
+                _ => {}
+            }
+        }
+
+        tree.unwind(&mut stack);
+
+        tree
+    }
+
+    /// Every call of the tree, depth first, with its depth, 0 being the calls of the script.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &CallFrame)> {
+        let mut pending: Vec<_> = self.calls.iter().rev().map(|call| (0, call)).collect();
+
+        std::iter::from_fn(move || {
+            let (depth, frame) = pending.pop()?;
+            pending.extend(frame.calls.iter().rev().map(|call| (depth + 1, call)));
+
+            Some((depth, frame))
+        })
+    }
+
+    /// Ends the innermost call if it was made to `contract_id`. Returns of the script itself
+    /// don't end any call.
+    fn end_call(
+        &mut self,
+        stack: &mut Vec<CallFrame>,
+        contract_id: &ContractId,
+        outcome: CallOutcome,
+    ) {
+        if stack.last().map(|frame| &frame.contract_id) != Some(contract_id) {
+            return;
+        }
+
+        let mut frame = stack.pop().expect("checked above");
+        frame.outcome = outcome;
+        self.attach(stack, frame);
+    }
+
+    /// Ends the calls left open, e.g. by a revert aborting the transaction.
+    fn unwind(&mut self, stack: &mut Vec<CallFrame>) {
+        while let Some(frame) = stack.pop() {
+            self.attach(stack, frame);
+        }
+    }
+
+    fn attach(&mut self, stack: &mut [CallFrame], frame: CallFrame) {
+        match stack.last_mut() {
+            Some(parent) => parent.calls.push(frame),
+            None => self.calls.push(frame),
+        }
+    }
+}
+
+impl fmt::Display for CallTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for log in &self.logs {
+            writeln!(f, "log: {log:?}")?;
+        }
+        for call in &self.calls {
+            call.fmt_indented(f, 0)?;
+        }
+        if let (Some(gas_used), Some(result)) = (self.gas_used, &self.result) {
+            writeln!(f, "{result:?}, gas used: {gas_used}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_asm::PanicInstruction;
+
+    use super::*;
+
+    fn call(id: ContractId, to: ContractId, amount: u64, gas: u64) -> Receipt {
+        Receipt::Call {
+            id,
+            to,
+            amount,
+            asset_id: AssetId::zeroed(),
+            gas,
+            param1: 0,
+            param2: 0,
+            pc: 0,
+            is: 0,
+        }
+    }
+
+    fn log(id: ContractId, ra: u64) -> Receipt {
+        Receipt::Log {
+            id,
+            ra,
+            rb: 0,
+            rc: 0,
+            rd: 0,
+            pc: 0,
+            is: 0,
+        }
+    }
+
+    fn ret(id: ContractId, val: u64) -> Receipt {
+        Receipt::Return {
+            id,
+            val,
+            pc: 0,
+            is: 0,
+        }
+    }
+
+    #[test]
+    fn nested_calls_are_reconstructed() {
+        let script = ContractId::zeroed();
+        let router = ContractId::from([1; 32]);
+        let pool = ContractId::from([2; 32]);
+        let token = ContractId::from([3; 32]);
+
+        let tree = CallTree::from_receipts(&[
+            call(script, router, 10, 1000),
+            log(router, 1),
+            call(router, pool, 10, 500),
+            call(pool, token, 0, 100),
+            ret(token, 7),
+            log(pool, 2),
+            ret(pool, 8),
+            ret(router, 9),
+            ret(script, 0),
+            Receipt::ScriptResult {
+                result: ScriptExecutionResult::Success,
+                gas_used: 42,
+            },
+        ]);
+
+        assert_eq!(tree.calls.len(), 1);
+        let router_frame = &tree.calls[0];
+        assert_eq!(router_frame.contract_id, router);
+        assert_eq!(router_frame.amount, 10);
+        assert_eq!(router_frame.gas_forwarded, 1000);
+        assert_eq!(router_frame.outcome, CallOutcome::Return(9));
+        assert_eq!(router_frame.logs, vec![log(router, 1)]);
+
+        let pool_frame = &router_frame.calls[0];
+        assert_eq!(pool_frame.caller, router);
+        assert_eq!(pool_frame.logs, vec![log(pool, 2)]);
+        assert_eq!(pool_frame.calls[0].outcome, CallOutcome::Return(7));
+
+        let depths: Vec<_> = tree
+            .iter()
+            .map(|(depth, frame)| (depth, frame.contract_id))
+            .collect();
+        assert_eq!(depths, vec![(0, router), (1, pool), (2, token)]);
+        assert_eq!(tree.gas_used, Some(42));
+    }
+
+    #[test]
+    fn reverts_leave_the_outer_calls_unfinished() {
+        let script = ContractId::zeroed();
+        let outer = ContractId::from([1; 32]);
+        let inner = ContractId::from([2; 32]);
+
+        let tree = CallTree::from_receipts(&[
+            call(script, outer, 0, 1000),
+            call(outer, inner, 0, 500),
+            Receipt::Panic {
+                id: inner,
+                reason: PanicInstruction::error(PanicReason::NotEnoughBalance, 0),
+                pc: 0,
+                is: 0,
+                contract_id: None,
+            },
+        ]);
+
+        let outer_frame = &tree.calls[0];
+        assert_eq!(outer_frame.outcome, CallOutcome::Unfinished);
+        assert_eq!(
+            outer_frame.calls[0].outcome,
+            CallOutcome::Panic(PanicReason::NotEnoughBalance)
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use std::collections::HashMap;
+
+use fuel_abi_types::abi::unified_program::UnifiedProgramABI;
+use fuel_tx::{AssetId, ContractId};
+use fuel_types::Word;
+use fuels_core::{
+    codec::{ABIDecoder, ABIEncoder, DecoderConfig},
+    constants::WORD_SIZE,
+    error,
+    types::{
+        bech32::Bech32ContractId, errors::Result, param_types::ParamType,
+        transaction::TransactionType, Token,
+    },
+};
+
+/// Length of the part of a call segment preceding the encoded function selector. See
+/// [`build_script_data_from_contract_calls`](crate::calls::utils::build_script_data_from_contract_calls).
+const CALL_HEADER_LEN: usize = WORD_SIZE + AssetId::LEN + ContractId::LEN + 2 * WORD_SIZE;
+
+/// A contract call found in the script data of a transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedCall {
+    pub contract_id: Bech32ContractId,
+    pub amount: u64,
+    pub asset_id: AssetId,
+    pub function_name: String,
+    /// The encoded arguments of the call. When the function is not registered, the gas forwarded
+    /// to the call, if any, is included as the arguments cannot be told apart from it.
+    pub encoded_args: Vec<u8>,
+    /// The decoded arguments, if the function is registered.
+    pub args: Option<Vec<Token>>,
+    /// The gas forwarded to the call. Only known if the function is registered.
+    pub gas_forwarded: Option<u64>,
+}
+
+/// A transaction decoded by a [`TransactionDecoder`].
+#[derive(Debug, Clone)]
+pub struct DecodedTransaction {
+    pub transaction: TransactionType,
+    /// The contract calls of the script, in order. Empty unless the transaction is a script
+    /// built for contract calls by the SDK.
+    pub calls: Vec<DecodedCall>,
+}
+
+/// Decodes transactions the SDK did not build, e.g. to inspect them before signing or while
+/// monitoring the chain. The arguments of the contract calls are decoded for the functions whose
+/// ABI is registered.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionDecoder {
+    functions: HashMap<(Bech32ContractId, String), Vec<ParamType>>,
+    decoder_config: DecoderConfig,
+}
+
+struct CallHeader {
+    contract_id: ContractId,
+    amount: u64,
+    asset_id: AssetId,
+    function_name: String,
+    args_start: usize,
+}
+
+impl TransactionDecoder {
+    /// Registers the functions of the JSON ABI `json_abi` of the contract `contract_id`.
+    pub fn with_abi(mut self, contract_id: Bech32ContractId, json_abi: &str) -> Result<Self> {
+        let abi = UnifiedProgramABI::from_json_abi(json_abi)
+            .map_err(|err| error!(Codec, "invalid JSON ABI: {err}"))?;
+        let type_lookup = abi
+            .types
+            .into_iter()
+            .map(|declaration| (declaration.type_id, declaration))
+            .collect::<HashMap<_, _>>();
+
+        for function in abi.functions {
+            let param_types = function
+                .inputs
+                .iter()
+                .map(|input| ParamType::try_from_type_application(input, &type_lookup))
+                .collect::<Result<Vec<_>>>()?;
+
+            self = self.with_function(contract_id.clone(), function.name, param_types);
+        }
+
+        Ok(self)
+    }
+
+    /// Registers the function `name` of the contract `contract_id`, taking arguments of
+    /// `param_types`.
+    pub fn with_function(
+        mut self,
+        contract_id: Bech32ContractId,
+        name: impl Into<String>,
+        param_types: Vec<ParamType>,
+    ) -> Self {
+        self.functions
+            .insert((contract_id, name.into()), param_types);
+        self
+    }
+
+    pub fn with_decoder_config(mut self, decoder_config: DecoderConfig) -> Self {
+        self.decoder_config = decoder_config;
+        self
+    }
+
+    /// Decodes a transaction from the bytes sent to the node.
+    pub fn decode(&self, bytes: &[u8]) -> Result<DecodedTransaction> {
+        let transaction = TransactionType::decode(bytes)?;
+        let calls = match &transaction {
+            TransactionType::Script(tx) => self.decode_calls(tx.script_data()),
+            _ => vec![],
+        };
+
+        Ok(DecodedTransaction { transaction, calls })
+    }
+
+    /// Decodes the contract calls of `script_data`, or returns none if it is not laid out for
+    /// contract calls.
+    pub fn decode_calls(&self, script_data: &[u8]) -> Vec<DecodedCall> {
+        let Some(data_offset) = read_word(script_data, CALL_HEADER_LEN - 2 * WORD_SIZE)
+            .and_then(|selector_offset| (selector_offset as usize).checked_sub(CALL_HEADER_LEN))
+        else {
+            return vec![];
+        };
+
+        let mut calls = vec![];
+        let mut at = 0;
+        while at < script_data.len() {
+            let Some(header) = read_header(script_data, at, data_offset) else {
+                return vec![];
+            };
+            let contract_id = Bech32ContractId::from(header.contract_id);
+            let key = (contract_id.clone(), header.function_name);
+
+            let decoded = self.functions.get(&key).and_then(|param_types| {
+                self.decode_args(param_types, script_data, header.args_start, data_offset)
+            });
+            let (end, args, gas_forwarded) = match decoded {
+                Some((args_end, args, gas_forwarded)) => (args_end, Some(args), gas_forwarded),
+                None => {
+                    let next = (header.args_start..script_data.len())
+                        .find(|&at| read_header(script_data, at, data_offset).is_some())
+                        .unwrap_or(script_data.len());
+                    (next, None, None)
+                }
+            };
+
+            calls.push(DecodedCall {
+                contract_id,
+                amount: header.amount,
+                asset_id: header.asset_id,
+                function_name: key.1,
+                encoded_args: script_data[header.args_start..end].to_vec(),
+                args,
+                gas_forwarded,
+            });
+            at = end + gas_forwarded.map_or(0, |_| WORD_SIZE);
+        }
+
+        calls
+    }
+
+    /// Decodes the arguments of a call and returns where they end, along with the gas forwarded
+    /// to the call. The forwarded gas is recognized by the next call, or the end of the script
+    /// data, following it.
+    fn decode_args(
+        &self,
+        param_types: &[ParamType],
+        script_data: &[u8],
+        args_start: usize,
+        data_offset: usize,
+    ) -> Option<(usize, Vec<Token>, Option<u64>)> {
+        let encoded = &script_data[args_start..];
+        let args = ABIDecoder::new(self.decoder_config)
+            .decode_multiple(param_types, encoded)
+            .ok()?;
+        let args_end = args_start + ABIEncoder::default().encode(&args).ok()?.len();
+
+        let is_segment_end = |at: usize| {
+            at == script_data.len() || read_header(script_data, at, data_offset).is_some()
+        };
+        let gas_forwarded = if is_segment_end(args_end) {
+            None
+        } else if is_segment_end(args_end + WORD_SIZE) {
+            Some(read_word(script_data, args_end)?)
+        } else {
+            return None;
+        };
+
+        Some((args_end, args, gas_forwarded))
+    }
+}
+
+fn read_word(data: &[u8], at: usize) -> Option<Word> {
+    let bytes = data.get(at..at.checked_add(WORD_SIZE)?)?;
+
+    Some(Word::from_be_bytes(bytes.try_into().expect("is a word")))
+}
+
+/// Reads the call segment at `at`, checking that its offsets point into itself.
+fn read_header(data: &[u8], at: usize, data_offset: usize) -> Option<CallHeader> {
+    let amount = read_word(data, at)?;
+    let asset_id_start = at + WORD_SIZE;
+    let asset_id =
+        AssetId::try_from(data.get(asset_id_start..asset_id_start + AssetId::LEN)?).ok()?;
+    let contract_id_start = asset_id_start + AssetId::LEN;
+    let contract_id =
+        ContractId::try_from(data.get(contract_id_start..contract_id_start + ContractId::LEN)?)
+            .ok()?;
+
+    let selector_start = at + CALL_HEADER_LEN;
+    let selector_offset = read_word(data, contract_id_start + ContractId::LEN)?;
+    let args_offset = read_word(data, contract_id_start + ContractId::LEN + WORD_SIZE)?;
+    if selector_offset != (data_offset + selector_start) as Word {
+        return None;
+    }
+
+    let name_len = usize::try_from(read_word(data, selector_start)?).ok()?;
+    let name_start = selector_start + WORD_SIZE;
+    let args_start = name_start.checked_add(name_len)?;
+    if args_offset != (data_offset + args_start) as Word {
+        return None;
+    }
+    let function_name = String::from_utf8(data.get(name_start..args_start)?.to_vec()).ok()?;
+
+    Some(CallHeader {
+        contract_id,
+        amount,
+        asset_id,
+        function_name,
+        args_start,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::codec::encode_fn_selector;
+
+    use super::*;
+    use crate::calls::{
+        utils::build_script_data_from_contract_calls, CallParameters, ContractCall,
+    };
+
+    fn contract_call(
+        contract_id: &Bech32ContractId,
+        name: &str,
+        args: &[Token],
+        call_parameters: CallParameters,
+    ) -> ContractCall {
+        ContractCall {
+            contract_id: contract_id.clone(),
+            encoded_args: ABIEncoder::default().encode(args),
+            encoded_selector: encode_fn_selector(name),
+            call_parameters,
+            external_contracts: Default::default(),
+            output_param: ParamType::Unit,
+            is_payable: false,
+            custom_assets: Default::default(),
+        }
+    }
+
+    #[test]
+    fn decodes_the_calls_of_script_data() -> Result<()> {
+        let contract_id = Bech32ContractId::new("fuel", [1; 32]);
+        let other_contract_id = Bech32ContractId::new("fuel", [2; 32]);
+        let calls = [
+            contract_call(
+                &contract_id,
+                "transfer",
+                &[Token::U64(42), Token::Bool(true)],
+                CallParameters::default()
+                    .with_amount(10)
+                    .with_gas_forwarded(1000),
+            ),
+            contract_call(
+                &other_contract_id,
+                "unknown",
+                &[Token::U8(7)],
+                CallParameters::default(),
+            ),
+        ];
+        let (script_data, _) =
+            build_script_data_from_contract_calls(&calls, 200, AssetId::zeroed())?;
+
+        let decoder = TransactionDecoder::default().with_function(
+            contract_id.clone(),
+            "transfer",
+            vec![ParamType::U64, ParamType::Bool],
+        );
+        let decoded = decoder.decode_calls(&script_data);
+
+        assert_eq!(
+            decoded,
+            vec![
+                DecodedCall {
+                    contract_id,
+                    amount: 10,
+                    asset_id: AssetId::zeroed(),
+                    function_name: "transfer".to_string(),
+                    encoded_args: ABIEncoder::default()
+                        .encode(&[Token::U64(42), Token::Bool(true)])?,
+                    args: Some(vec![Token::U64(42), Token::Bool(true)]),
+                    gas_forwarded: Some(1000),
+                },
+                DecodedCall {
+                    contract_id: other_contract_id,
+                    amount: 0,
+                    asset_id: AssetId::zeroed(),
+                    function_name: "unknown".to_string(),
+                    encoded_args: vec![7],
+                    args: None,
+                    gas_forwarded: None,
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn other_script_data_has_no_calls() {
+        assert!(TransactionDecoder::default()
+            .decode_calls(&[0; 120])
+            .is_empty());
+    }
+}
@@ -19,6 +19,7 @@ use fuels_core::{
             BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder,
             VariableOutputPolicy,
         },
+        ReceiptIteratorExt, ReceiptKind, ReceiptMatcher,
     },
 };
 use itertools::{chain, Itertools};
@@ -425,9 +426,11 @@ fn extract_unique_contract_ids(calls: &[ContractCall]) -> HashSet<ContractId> {
 }
 
 pub fn is_missing_output_variables(receipts: &[Receipt]) -> bool {
-    receipts.iter().any(
-        |r| matches!(r, Receipt::Revert { ra, .. } if *ra == FAILED_TRANSFER_TO_ADDRESS_SIGNAL),
-    )
+    let failed_transfer = ReceiptMatcher::default()
+        .with_kind(ReceiptKind::Revert)
+        .with_ra(FAILED_TRANSFER_TO_ADDRESS_SIGNAL);
+
+    receipts.iter().matching(&failed_transfer).next().is_some()
 }
 
 pub fn find_id_of_missing_contract(receipts: &[Receipt]) -> Option<Bech32ContractId> {
@@ -67,6 +67,18 @@ impl Contract {
         self.contract_id
     }
 
+    pub fn binary(&self) -> &[u8] {
+        &self.binary
+    }
+
+    pub fn salt(&self) -> Salt {
+        self.salt
+    }
+
+    pub fn storage_slots(&self) -> &[StorageSlot] {
+        &self.storage_slots
+    }
+
     pub fn state_root(&self) -> Bytes32 {
         self.state_root
     }
@@ -100,7 +112,7 @@ impl Contract {
 
         let tx = tb.build(provider).await?;
 
-        provider
+        account
             .send_transaction_and_await_commit(tx)
             .await?
             .check(None)?;
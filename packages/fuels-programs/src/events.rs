@@ -0,0 +1,119 @@
+use std::marker::PhantomData;
+
+use fuel_tx::{ContractId, TxId};
+use fuels_accounts::provider::{BlockWalker, Provider};
+use fuels_core::{
+    codec::LogDecoder,
+    traits::{Parameterize, Tokenizable},
+    types::{
+        bech32::Bech32ContractId,
+        errors::{error, Result},
+        tx_status::TxStatus,
+    },
+};
+use futures::{stream, Stream, TryStreamExt};
+
+/// Where an event was emitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventMeta {
+    /// The height of the block of the transaction.
+    pub block: u32,
+    pub tx_id: TxId,
+    /// The index of the log receipt among the receipts of the transaction.
+    pub log_index: usize,
+}
+
+/// The events of type `T` logged by a contract, as returned by the `events` method of the
+/// contract instances generated by `abigen!`.
+#[derive(Debug, Clone)]
+pub struct Events<T> {
+    provider: Option<Provider>,
+    contract_id: ContractId,
+    log_decoder: LogDecoder,
+    from_block: Option<u32>,
+    _event: PhantomData<fn() -> T>,
+}
+
+impl<T: Tokenizable + Parameterize + 'static> Events<T> {
+    pub fn new(
+        provider: Option<Provider>,
+        contract_id: &Bech32ContractId,
+        log_decoder: LogDecoder,
+    ) -> Self {
+        Self {
+            provider,
+            contract_id: contract_id.into(),
+            log_decoder,
+            from_block: None,
+            _event: PhantomData,
+        }
+    }
+
+    /// Starts from the block at `height` instead of the blocks produced after
+    /// [`stream`](Self::stream) is called, e.g. to catch up from the last processed event.
+    pub fn from_block(mut self, height: u32) -> Self {
+        self.from_block = Some(height);
+        self
+    }
+
+    /// Streams the events, in chain order, as new blocks are produced.
+    ///
+    /// The node has no log subscription, so it is polled for the next block every
+    /// [`BLOCK_POLL_INTERVAL`](fuels_accounts::provider::BLOCK_POLL_INTERVAL).
+    pub async fn stream(self) -> Result<impl Stream<Item = Result<(T, EventMeta)>>> {
+        let provider = self
+            .provider
+            .ok_or_else(|| error!(Other, "no provider available to stream events"))?;
+        let start_height = match self.from_block {
+            Some(height) => height,
+            None => provider.latest_block_height().await? + 1,
+        };
+
+        let contract_id = self.contract_id;
+        let log_decoder = self.log_decoder;
+
+        Ok(BlockWalker::new(provider, start_height)
+            .with_transactions()
+            .into_stream()
+            .map_ok(move |walked| {
+                let block = walked.block.header.height;
+                let events: Vec<_> = walked
+                    .block
+                    .transactions
+                    .into_iter()
+                    .zip(walked.transactions)
+                    .flat_map(|(tx_id, response)| {
+                        let receipts = match response.status {
+                            TxStatus::Success { receipts } | TxStatus::Revert { receipts, .. } => {
+                                receipts
+                            }
+                            _ => vec![],
+                        };
+
+                        receipts
+                            .into_iter()
+                            .enumerate()
+                            .filter(|(_, receipt)| receipt.id() == Some(&contract_id))
+                            .flat_map(|(log_index, receipt)| {
+                                let meta = EventMeta {
+                                    block,
+                                    tx_id,
+                                    log_index,
+                                };
+
+                                match log_decoder.decode_logs_with_type::<T>(&[receipt]) {
+                                    Ok(events) => {
+                                        events.into_iter().map(|event| Ok((event, meta))).collect()
+                                    }
+                                    Err(err) => vec![Err(err)],
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect();
+
+                stream::iter(events)
+            })
+            .try_flatten())
+    }
+}
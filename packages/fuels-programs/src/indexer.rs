@@ -0,0 +1,489 @@
+use std::{
+    collections::HashSet,
+    ops::{Bound, RangeBounds},
+    path::Path,
+};
+
+use fuel_tx::{ContractId, TxId};
+use fuels_accounts::provider::{
+    BlockCursor, BlockWalker, Provider, WalkedBlock, BLOCK_POLL_INTERVAL,
+};
+use fuels_core::{
+    codec::LogDecoder,
+    types::{
+        errors::{error, Error, Result},
+        tx_status::TxStatus,
+        Bytes32, ReceiptKind, ReceiptMatcher,
+    },
+};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde_json::Value;
+
+use crate::calls::ContractDependency;
+
+const SCHEMA: &str = "
+    PRAGMA journal_mode = WAL;
+
+    CREATE TABLE IF NOT EXISTS events (
+        block INTEGER NOT NULL,
+        tx_index INTEGER NOT NULL,
+        tx_id BLOB NOT NULL,
+        receipt_index INTEGER NOT NULL,
+        contract_id BLOB NOT NULL,
+        event_type TEXT NOT NULL,
+        value TEXT NOT NULL,
+        PRIMARY KEY (tx_id, receipt_index)
+    );
+    CREATE INDEX IF NOT EXISTS events_by_contract ON events (contract_id, event_type, block);
+
+    CREATE TABLE IF NOT EXISTS cursor (
+        id INTEGER PRIMARY KEY CHECK (id = 0),
+        height INTEGER NOT NULL,
+        block_id BLOB NOT NULL
+    );
+";
+
+/// An event stored by an [`EventIndexer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedEvent {
+    pub block: u32,
+    pub tx_id: TxId,
+    /// The index of the log receipt among the receipts of the transaction.
+    pub receipt_index: usize,
+    pub contract_id: ContractId,
+    /// The Sway type of the event, e.g. `TransferEvent`.
+    pub event_type: String,
+    /// The event as given by [`LogDecoder::decode_logs_json`], to be deserialized with e.g.
+    /// `serde_json::from_value`.
+    pub value: Value,
+}
+
+/// Which events [`EventIndexer::query`] returns. Events must match every criterion set.
+#[derive(Debug, Clone)]
+pub struct EventQuery {
+    contract_id: Option<ContractId>,
+    event_type: Option<String>,
+    blocks: (Bound<u32>, Bound<u32>),
+    limit: Option<usize>,
+}
+
+impl Default for EventQuery {
+    fn default() -> Self {
+        Self {
+            contract_id: None,
+            event_type: None,
+            blocks: (Bound::Unbounded, Bound::Unbounded),
+            limit: None,
+        }
+    }
+}
+
+impl EventQuery {
+    pub fn with_contract_id(mut self, contract_id: impl Into<ContractId>) -> Self {
+        self.contract_id = Some(contract_id.into());
+        self
+    }
+
+    /// Only returns the events of the given Sway type, e.g. `TransferEvent`.
+    pub fn with_event_type(mut self, event_type: impl Into<String>) -> Self {
+        self.event_type = Some(event_type.into());
+        self
+    }
+
+    /// Only returns the events of the blocks with a height in `blocks`.
+    pub fn with_blocks(mut self, blocks: impl RangeBounds<u32>) -> Self {
+        self.blocks = (blocks.start_bound().cloned(), blocks.end_bound().cloned());
+        self
+    }
+
+    /// Returns the first `limit` events at most.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// The first and last heights of the blocks, inclusive.
+    fn block_range(&self) -> (i64, i64) {
+        let first = match self.blocks.0 {
+            Bound::Included(start) => i64::from(start),
+            Bound::Excluded(start) => i64::from(start) + 1,
+            Bound::Unbounded => 0,
+        };
+        let last = match self.blocks.1 {
+            Bound::Included(end) => i64::from(end),
+            Bound::Excluded(end) => i64::from(end) - 1,
+            Bound::Unbounded => i64::from(u32::MAX),
+        };
+
+        (first, last)
+    }
+}
+
+/// Indexes the events logged by a set of contracts into a local SQLite database, for small
+/// projects that need to look up past events without running a full indexer:
+///
+/// ```ignore
+/// let mut indexer = EventIndexer::open("events.db", provider)?
+///     .watch(&contract_instance)
+///     .starting_at(deployment_height);
+/// indexer.sync().await?;
+///
+/// let transfers = indexer.query(
+///     &EventQuery::default()
+///         .with_contract_id(contract_instance.contract_id())
+///         .with_event_type("TransferEvent"),
+/// )?;
+/// ```
+///
+/// The last indexed block is stored along with its events, so that syncing resumes where it
+/// stopped, and fails if that block was replaced by a reorg in the meantime. Only the logs of
+/// successful transactions are indexed.
+///
+/// The database is accessed synchronously. The writes are one transaction per block, short
+/// enough not to stall the runtime, but large queries are better run on a blocking task.
+#[derive(Debug)]
+pub struct EventIndexer {
+    store: EventStore,
+    provider: Provider,
+    contracts: HashSet<ContractId>,
+    log_decoder: LogDecoder,
+    start_height: u32,
+}
+
+impl EventIndexer {
+    /// Opens the database at `path`, creating it if needed.
+    pub fn open(path: impl AsRef<Path>, provider: Provider) -> Result<Self> {
+        Ok(Self::new(
+            EventStore::new(Connection::open(path).map_err(store_error)?)?,
+            provider,
+        ))
+    }
+
+    /// Keeps the events in memory, e.g. for tests.
+    pub fn in_memory(provider: Provider) -> Result<Self> {
+        Ok(Self::new(
+            EventStore::new(Connection::open_in_memory().map_err(store_error)?)?,
+            provider,
+        ))
+    }
+
+    fn new(store: EventStore, provider: Provider) -> Self {
+        Self {
+            store,
+            provider,
+            contracts: HashSet::new(),
+            log_decoder: LogDecoder::default(),
+            start_height: 0,
+        }
+    }
+
+    /// Indexes the events of `contract`, e.g. a contract instance generated by `abigen!`.
+    pub fn watch(mut self, contract: &impl ContractDependency) -> Self {
+        self.contracts.insert((&contract.id()).into());
+        self.log_decoder.merge(contract.log_decoder());
+        self
+    }
+
+    /// The height of the first block to index when nothing was indexed yet. Defaults to 0, so
+    /// set it to e.g. the height of the deployment of the contracts to skip the blocks before.
+    pub fn starting_at(mut self, height: u32) -> Self {
+        self.start_height = height;
+        self
+    }
+
+    /// The last indexed block.
+    pub fn cursor(&self) -> Result<Option<BlockCursor>> {
+        self.store.cursor()
+    }
+
+    /// Indexes the blocks up to the head of the chain and returns the number of events indexed.
+    pub async fn sync(&mut self) -> Result<usize> {
+        let mut walker = match self.store.cursor()? {
+            Some(cursor) => BlockWalker::resume(self.provider.clone(), cursor),
+            None => BlockWalker::new(self.provider.clone(), self.start_height),
+        }
+        .with_transactions();
+
+        let mut indexed = 0;
+        while let Some(block) = walker.next_block().await? {
+            let events = self.events_of(&block)?;
+            indexed += events.len();
+            self.store.insert_block(&block.cursor, &events)?;
+        }
+
+        Ok(indexed)
+    }
+
+    /// Keeps indexing the new blocks, polling the node every
+    /// [`BLOCK_POLL_INTERVAL`](fuels_accounts::provider::BLOCK_POLL_INTERVAL). Only returns on
+    /// errors.
+    ///
+    /// Open the database a second time to query it while indexing.
+    pub async fn run(&mut self) -> Result<()> {
+        loop {
+            self.sync().await?;
+            tokio::time::sleep(BLOCK_POLL_INTERVAL).await;
+        }
+    }
+
+    /// The indexed events matching `query`, in chain order.
+    pub fn query(&self, query: &EventQuery) -> Result<Vec<IndexedEvent>> {
+        self.store.query(query)
+    }
+
+    fn events_of(&self, block: &WalkedBlock) -> Result<Vec<(usize, IndexedEvent)>> {
+        let logs = ReceiptMatcher::default()
+            .with_kind(ReceiptKind::Log)
+            .with_kind(ReceiptKind::LogData);
+        let mut events = vec![];
+
+        for (tx_index, (tx_id, response)) in block
+            .block
+            .transactions
+            .iter()
+            .zip(&block.transactions)
+            .enumerate()
+        {
+            let TxStatus::Success { receipts } = &response.status else {
+                continue;
+            };
+
+            for (receipt_index, receipt) in receipts.iter().enumerate() {
+                let Some(contract_id) = receipt
+                    .id()
+                    .filter(|id| self.contracts.contains(*id))
+                    .filter(|_| logs.matches(receipt))
+                else {
+                    continue;
+                };
+
+                for log in self
+                    .log_decoder
+                    .decode_logs_json(std::slice::from_ref(receipt), Some(*tx_id))?
+                {
+                    events.push((
+                        tx_index,
+                        IndexedEvent {
+                            block: block.cursor.height,
+                            tx_id: *tx_id,
+                            receipt_index,
+                            contract_id: *contract_id,
+                            event_type: log["type"].as_str().unwrap_or_default().to_string(),
+                            value: log["value"].clone(),
+                        },
+                    ));
+                }
+            }
+        }
+
+        Ok(events)
+    }
+}
+
+#[derive(Debug)]
+struct EventStore {
+    connection: Connection,
+}
+
+impl EventStore {
+    fn new(connection: Connection) -> Result<Self> {
+        connection.execute_batch(SCHEMA).map_err(store_error)?;
+
+        Ok(Self { connection })
+    }
+
+    fn cursor(&self) -> Result<Option<BlockCursor>> {
+        let cursor = self
+            .connection
+            .query_row(
+                "SELECT height, block_id FROM cursor WHERE id = 0",
+                [],
+                |row| Ok((row.get::<_, u32>(0)?, row.get::<_, Vec<u8>>(1)?)),
+            )
+            .optional()
+            .map_err(store_error)?;
+
+        cursor
+            .map(|(height, block_id)| {
+                Ok(BlockCursor {
+                    height,
+                    block_id: bytes32(&block_id)?,
+                })
+            })
+            .transpose()
+    }
+
+    /// Stores the events of a block along with its cursor, so that either both or none are
+    /// stored.
+    fn insert_block(
+        &mut self,
+        cursor: &BlockCursor,
+        events: &[(usize, IndexedEvent)],
+    ) -> Result<()> {
+        let transaction = self.connection.transaction().map_err(store_error)?;
+
+        for (tx_index, event) in events {
+            transaction
+                .execute(
+                    "INSERT OR REPLACE INTO events
+                        (block, tx_index, tx_id, receipt_index, contract_id, event_type, value)
+                        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                    params![
+                        event.block,
+                        *tx_index as i64,
+                        event.tx_id.as_slice(),
+                        event.receipt_index as i64,
+                        event.contract_id.as_slice(),
+                        event.event_type,
+                        event.value.to_string(),
+                    ],
+                )
+                .map_err(store_error)?;
+        }
+
+        transaction
+            .execute(
+                "INSERT OR REPLACE INTO cursor (id, height, block_id) VALUES (0, ?1, ?2)",
+                params![cursor.height, cursor.block_id.as_slice()],
+            )
+            .map_err(store_error)?;
+
+        transaction.commit().map_err(store_error)
+    }
+
+    fn query(&self, query: &EventQuery) -> Result<Vec<IndexedEvent>> {
+        let (first_block, last_block) = query.block_range();
+        let limit = query.limit.map_or(-1, |limit| limit as i64);
+
+        let mut statement = self
+            .connection
+            .prepare_cached(
+                "SELECT block, tx_id, receipt_index, contract_id, event_type, value FROM events
+                    WHERE (?1 IS NULL OR contract_id = ?1)
+                        AND (?2 IS NULL OR event_type = ?2)
+                        AND block BETWEEN ?3 AND ?4
+                    ORDER BY block, tx_index, receipt_index
+                    LIMIT ?5",
+            )
+            .map_err(store_error)?;
+
+        let rows = statement
+            .query_map(
+                params![
+                    query.contract_id.as_ref().map(|id| id.as_slice()),
+                    query.event_type,
+                    first_block,
+                    last_block,
+                    limit,
+                ],
+                |row| {
+                    Ok((
+                        row.get::<_, u32>(0)?,
+                        row.get::<_, Vec<u8>>(1)?,
+                        row.get::<_, i64>(2)?,
+                        row.get::<_, Vec<u8>>(3)?,
+                        row.get::<_, String>(4)?,
+                        row.get::<_, String>(5)?,
+                    ))
+                },
+            )
+            .and_then(|rows| rows.collect::<rusqlite::Result<Vec<_>>>())
+            .map_err(store_error)?;
+
+        rows.into_iter()
+            .map(
+                |(block, tx_id, receipt_index, contract_id, event_type, value)| {
+                    Ok(IndexedEvent {
+                        block,
+                        tx_id: bytes32(&tx_id)?,
+                        receipt_index: receipt_index as usize,
+                        contract_id: ContractId::from(*bytes32(&contract_id)?),
+                        event_type,
+                        value: serde_json::from_str(&value).map_err(|err| {
+                            error!(Other, "invalid event in the indexer store: {err}")
+                        })?,
+                    })
+                },
+            )
+            .collect()
+    }
+}
+
+fn bytes32(bytes: &[u8]) -> Result<Bytes32> {
+    Bytes32::try_from(bytes).map_err(|_| {
+        error!(
+            Other,
+            "invalid id of {} bytes in the indexer store",
+            bytes.len()
+        )
+    })
+}
+
+fn store_error(err: rusqlite::Error) -> Error {
+    error!(Other, "indexer store: {err}")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn event(
+        block: u32,
+        receipt_index: usize,
+        contract_id: ContractId,
+        event_type: &str,
+    ) -> IndexedEvent {
+        IndexedEvent {
+            block,
+            tx_id: TxId::from([block as u8; 32]),
+            receipt_index,
+            contract_id,
+            event_type: event_type.to_string(),
+            value: json!({ "amount": block }),
+        }
+    }
+
+    #[test]
+    fn events_are_stored_with_the_cursor_and_queried() -> Result<()> {
+        let mut store = EventStore::new(Connection::open_in_memory().map_err(store_error)?)?;
+        let contract_id = ContractId::from([1; 32]);
+        let other_contract_id = ContractId::from([2; 32]);
+
+        assert_eq!(store.cursor()?, None);
+
+        for height in 1..=3 {
+            let cursor = BlockCursor {
+                height,
+                block_id: Bytes32::from([height as u8; 32]),
+            };
+            let events = [
+                (0, event(height, 1, contract_id, "TransferEvent")),
+                (0, event(height, 2, other_contract_id, "MintEvent")),
+            ];
+            store.insert_block(&cursor, &events)?;
+        }
+
+        assert_eq!(store.cursor()?.map(|cursor| cursor.height), Some(3));
+
+        let transfers = store.query(
+            &EventQuery::default()
+                .with_contract_id(contract_id)
+                .with_event_type("TransferEvent")
+                .with_blocks(2..),
+        )?;
+        assert_eq!(
+            transfers,
+            [
+                event(2, 1, contract_id, "TransferEvent"),
+                event(3, 1, contract_id, "TransferEvent")
+            ]
+        );
+
+        let first = store.query(&EventQuery::default().with_limit(1))?;
+        assert_eq!(first, [event(1, 1, contract_id, "TransferEvent")]);
+
+        Ok(())
+    }
+}
@@ -1,3 +1,6 @@
 pub mod calls;
 pub mod contract;
+pub mod events;
+#[cfg(feature = "indexer")]
+pub mod indexer;
 pub mod responses;
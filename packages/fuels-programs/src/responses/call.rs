@@ -2,9 +2,9 @@ use std::fmt::Debug;
 
 use fuel_tx::{Bytes32, Receipt};
 use fuels_core::{
-    codec::{LogDecoder, LogResult},
+    codec::{DecodedLog, LogDecoder, LogResult, LogTypes},
     traits::{Parameterize, Tokenizable},
-    types::errors::Result,
+    types::{errors::Result, AssetEvent},
 };
 
 /// [`CallResponse`] is a struct that is returned by a call to the contract or script. Its value
@@ -54,4 +54,13 @@ impl<D> CallResponse<D> {
     pub fn decode_logs_with_type<T: Tokenizable + Parameterize + 'static>(&self) -> Result<Vec<T>> {
         self.log_decoder.decode_logs_with_type::<T>(&self.receipts)
     }
+
+    pub fn decode_logs_of_types<L: LogTypes>(&self) -> Result<Vec<DecodedLog>> {
+        self.log_decoder.decode_logs_of_types::<L>(&self.receipts)
+    }
+
+    /// The mints, burns and transfers of assets made by the call.
+    pub fn asset_events(&self) -> Vec<AssetEvent> {
+        AssetEvent::from_receipts(&self.receipts)
+    }
 }
@@ -1,12 +1,13 @@
 use std::mem::size_of;
 
 use fuel_crypto::SecretKey;
-use fuels_accounts::wallet::WalletUnlocked;
+use fuels_accounts::{provider::Provider, wallet::WalletUnlocked};
 use fuels_core::types::errors::Result;
 
 use crate::{
     node_types::{ChainConfig, NodeConfig},
-    setup_custom_assets_coins, setup_test_provider,
+    seed::with_test_rng,
+    setup_test_provider_with_funds,
     wallets_config::*,
 };
 
@@ -71,12 +72,13 @@ pub async fn launch_custom_provider_and_get_wallets(
         })
         .collect();
 
-    let all_coins = wallets
+    let funds: Vec<_> = wallets
         .iter()
-        .flat_map(|wallet| setup_custom_assets_coins(wallet.address(), wallet_config.assets()))
-        .collect::<Vec<_>>();
+        .map(|wallet| wallet.address().clone())
+        .zip(wallet_config.wallet_configs())
+        .collect();
 
-    let provider = setup_test_provider(all_coins, vec![], node_config, chain_config).await?;
+    let provider = setup_test_provider_with_funds(&funds, node_config, chain_config).await?;
 
     for wallet in &mut wallets {
         wallet.set_provider(provider.clone());
@@ -85,6 +87,50 @@ pub async fn launch_custom_provider_and_get_wallets(
     Ok(wallets)
 }
 
+/// Creates a wallet with a random key, drawn from the seeded randomness of the test helpers so
+/// that it is the same on every run with the same [`test_seed`](crate::test_seed).
+pub fn generate_random_wallet(provider: Option<Provider>) -> WalletUnlocked {
+    let secret_key = with_test_rng(SecretKey::random);
+
+    WalletUnlocked::new_from_private_key(secret_key, provider)
+}
+
+/// Connects to the public node at `node_url` and returns a new random wallet funded by `faucet`.
+///
+/// Fails if the funds do not arrive within `timeout`.
+///
+/// # Examples
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use fuels_accounts::faucet::Faucet;
+/// use fuels_test_helpers::connect_and_get_funded_wallet;
+///
+/// async fn testnet_wallet() -> Result<(), Box<dyn std::error::Error>> {
+///   let wallet = connect_and_get_funded_wallet(
+///       "testnet.fuel.network",
+///       &Faucet::default(),
+///       Duration::from_secs(60),
+///   )
+///   .await?;
+///   dbg!(wallet.address());
+///   Ok(())
+/// }
+/// ```
+#[cfg(feature = "faucet")]
+pub async fn connect_and_get_funded_wallet(
+    node_url: impl AsRef<str>,
+    faucet: &fuels_accounts::faucet::Faucet,
+    timeout: std::time::Duration,
+) -> Result<WalletUnlocked> {
+    let provider = Provider::connect(node_url).await?;
+    let wallet = WalletUnlocked::new_random(Some(provider.clone()));
+
+    faucet.fund(&provider, wallet.address(), timeout).await?;
+
+    Ok(wallet)
+}
+
 #[cfg(test)]
 mod tests {
     use fuel_core_chain_config::ChainConfig;
@@ -94,7 +140,11 @@ mod tests {
     use fuels_core::types::{coin_type::CoinType, errors::Result};
     use rand::Fill;
 
-    use crate::{launch_custom_provider_and_get_wallets, AssetConfig, WalletsConfig};
+    use crate::{
+        generate_random_salt, generate_random_wallet, launch_custom_provider_and_get_wallets,
+        set_test_seed, setup_single_asset_coins, AssetConfig, BridgedMessageConfig, WalletConfig,
+        WalletsConfig,
+    };
 
     #[tokio::test]
     async fn test_wallet_config() -> Result<()> {
@@ -175,6 +225,80 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_wallet_config_per_wallet() -> Result<()> {
+        let asset_id = AssetId::from([1; 32]);
+        let base_asset = |num_coins, coin_amount| AssetConfig {
+            id: AssetId::zeroed(),
+            num_coins,
+            coin_amount,
+        };
+        let config = WalletsConfig::new_per_wallet(vec![
+            WalletConfig {
+                assets: vec![
+                    base_asset(1, 100),
+                    AssetConfig {
+                        id: asset_id,
+                        num_coins: 2,
+                        coin_amount: 5,
+                    },
+                ],
+                messages: vec![],
+            },
+            WalletConfig {
+                assets: vec![base_asset(3, 7)],
+                messages: vec![
+                    BridgedMessageConfig {
+                        amount: 50,
+                        data: vec![],
+                    },
+                    BridgedMessageConfig {
+                        amount: 60,
+                        data: vec![1, 2, 3],
+                    },
+                ],
+            },
+        ]);
+
+        let wallets = launch_custom_provider_and_get_wallets(config, None, None).await?;
+
+        assert_eq!(wallets.len(), 2);
+        assert_eq!(wallets[0].get_asset_balance(&AssetId::zeroed()).await?, 100);
+        assert_eq!(wallets[0].get_asset_balance(&asset_id).await?, 10);
+        assert!(wallets[0].get_messages().await?.is_empty());
+
+        // Messages with data are not spendable, so only the first one counts in the balance.
+        assert_eq!(wallets[1].get_asset_balance(&AssetId::zeroed()).await?, 71);
+        assert_eq!(wallets[1].get_asset_balance(&asset_id).await?, 0);
+        let mut message_amounts: Vec<_> = wallets[1]
+            .get_messages()
+            .await?
+            .into_iter()
+            .map(|message| message.amount)
+            .collect();
+        message_amounts.sort();
+        assert_eq!(message_amounts, vec![50, 60]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn seeded_helpers_are_reproducible() {
+        let generate = || {
+            let wallet = generate_random_wallet(None);
+            let coins = setup_single_asset_coins(wallet.address(), AssetId::zeroed(), 2, 1);
+
+            (wallet.address().clone(), coins, generate_random_salt())
+        };
+
+        set_test_seed(7);
+        let first = generate();
+        set_test_seed(7);
+        let second = generate();
+
+        assert_eq!(first, second);
+    }
+
     #[tokio::test]
     async fn generated_wallets_are_deterministic() -> Result<()> {
         let num_wallets = 32;
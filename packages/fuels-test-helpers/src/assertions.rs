@@ -0,0 +1,165 @@
+use std::fmt::Debug;
+
+use fuel_tx::Receipt;
+use fuels_core::{
+    codec::ABIDecoder,
+    traits::{Parameterize, Tokenizable},
+    types::errors::{error, transaction::Reason, Error, Result},
+};
+
+/// Decodes, as a `T`, the last value logged before the revert behind `error`, e.g. the error
+/// given to `require`.
+pub fn decode_revert_log<T: Tokenizable + Parameterize>(error: &Error) -> Result<T> {
+    let Error::Transaction(Reason::Reverted { receipts, .. }) = error else {
+        return Err(error!(Other, "expected a revert, got: {error}"));
+    };
+
+    let data = receipts
+        .iter()
+        .rev()
+        .find_map(|receipt| match receipt {
+            Receipt::LogData {
+                data: Some(data), ..
+            } => Some(data.clone()),
+            Receipt::Log { ra, .. } => Some(ra.to_be_bytes().to_vec()),
+            _ => None,
+        })
+        .ok_or_else(|| error!(Other, "the revert was not preceded by a log"))?;
+
+    let token = ABIDecoder::default().decode(&T::param_type(), &data)?;
+
+    T::from_token(token)
+}
+
+/// Panics unless `result` is a revert whose last log is `expected`. Used by
+/// [`assert_reverted_with!`](crate::assert_reverted_with).
+#[track_caller]
+pub fn assert_revert_log<T, U>(result: &Result<U>, expected: &T)
+where
+    T: Tokenizable + Parameterize + PartialEq + Debug,
+{
+    let error = match result {
+        Ok(_) => panic!("expected a revert with `{expected:?}`, but the call succeeded"),
+        Err(error) => error,
+    };
+
+    match decode_revert_log::<T>(error) {
+        Ok(logged) => assert_eq!(
+            &logged, expected,
+            "the call reverted with `{logged:?}` instead of `{expected:?}`"
+        ),
+        Err(decoding_error) => {
+            panic!("expected a revert with `{expected:?}`: {decoding_error}, error: {error}")
+        }
+    }
+}
+
+/// Asserts that `result` failed with a revert whose last log, e.g. the error given to `require`,
+/// equals `expected`.
+///
+/// ```ignore
+/// let result = contract_instance.methods().withdraw(100).call().await;
+/// assert_reverted_with!(result, MyError::Unauthorized);
+/// ```
+#[macro_export]
+macro_rules! assert_reverted_with {
+    ($result:expr, $expected:expr $(,)?) => {
+        $crate::assert_revert_log(&$result, &$expected)
+    };
+}
+
+/// Asserts that the call of `response` logged a value matching the struct pattern, e.g.
+/// `TransferEvent { amount: 5, .. }`. Enum patterns are given along with the logged type:
+/// `assert_emitted!(response, MyEvent, MyEvent::Transfer { .. })`.
+#[macro_export]
+macro_rules! assert_emitted {
+    ($response:expr, $ty:ident { $($fields:tt)* } $(,)?) => {
+        $crate::assert_emitted!($response, $ty, $ty { $($fields)* })
+    };
+    ($response:expr, $ty:path, $pattern:pat $(,)?) => {{
+        let logs = $response
+            .decode_logs_with_type::<$ty>()
+            .expect("failed to decode the logs");
+
+        assert!(
+            logs.iter().any(|log| matches!(log, $pattern)),
+            "no log matching `{}` in {logs:?}",
+            stringify!($pattern)
+        );
+    }};
+}
+
+/// Asserts that one of `receipts` matches the pattern, e.g.
+/// `Receipt::Transfer { amount: 5, .. }`.
+#[macro_export]
+macro_rules! assert_receipt {
+    ($receipts:expr, $pattern:pat $(,)?) => {{
+        let receipts = &$receipts;
+
+        assert!(
+            receipts.iter().any(|receipt| matches!(receipt, $pattern)),
+            "no receipt matching `{}` in {receipts:?}",
+            stringify!($pattern)
+        );
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::codec::ABIEncoder;
+
+    use super::*;
+
+    fn revert_logging(value: u64) -> Error {
+        let data = ABIEncoder::default()
+            .encode(&[value.into_token()])
+            .expect("encodes a u64");
+        let log = Receipt::LogData {
+            id: Default::default(),
+            ra: Default::default(),
+            rb: Default::default(),
+            ptr: Default::default(),
+            len: data.len() as u64,
+            digest: Default::default(),
+            data: Some(data),
+            pc: Default::default(),
+            is: Default::default(),
+        };
+
+        Error::Transaction(Reason::Reverted {
+            reason: "revert".to_string(),
+            revert_id: 0,
+            receipts: vec![log],
+        })
+    }
+
+    #[test]
+    fn revert_logs_are_decoded() -> Result<()> {
+        assert_eq!(decode_revert_log::<u64>(&revert_logging(5))?, 5);
+
+        let result: Result<()> = Err(revert_logging(5));
+        assert_reverted_with!(result, 5u64);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "instead of `6`")]
+    fn other_revert_logs_fail_the_assertion() {
+        let result: Result<()> = Err(revert_logging(5));
+
+        assert_reverted_with!(result, 6u64);
+    }
+
+    #[test]
+    fn receipts_are_matched() {
+        let receipts = vec![Receipt::Return {
+            id: Default::default(),
+            val: 3,
+            pc: Default::default(),
+            is: Default::default(),
+        }];
+
+        assert_receipt!(receipts, Receipt::Return { val: 3, .. });
+    }
+}
@@ -0,0 +1,150 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use fuel_core_client::client::types::{Block, TransactionStatus};
+use fuels_accounts::provider::middleware::{Middleware, Next, NodeRequest, NodeResponse};
+use rand::Rng;
+
+use crate::seed::with_test_rng;
+
+const SUBMISSIONS: [&str; 2] = ["submit", "submit_and_await_commit"];
+const BLOCK_QUERIES: [&str; 2] = ["block", "block_by_height"];
+
+/// The probabilities, between 0 and 1, of the faults injected by a [`ChaosMiddleware`].
+#[derive(Debug, Clone, Default)]
+pub struct ChaosConfig {
+    /// Any request fails before reaching the node.
+    pub failed_requests: f64,
+    /// A submission is dropped before reaching the node, and fails.
+    pub dropped_submissions: f64,
+    /// A submission reaches the node, but its response is lost and it fails.
+    pub lost_submission_responses: f64,
+    /// A `submit_and_await_commit` is answered with a squeezed out status, without reaching the
+    /// node.
+    pub squeezed_submissions: f64,
+    /// A block returned by the node is replaced by a block of another branch at the same height,
+    /// as the provider would see it after a reorg.
+    pub reorged_blocks: f64,
+    /// Every request is delayed by a random duration up to this one.
+    pub max_delay: Duration,
+}
+
+/// A fault injected by a [`ChaosMiddleware`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    FailedRequest { operation: &'static str },
+    DroppedSubmission,
+    LostSubmissionResponse,
+    SqueezedSubmission,
+    ReorgedBlock { height: u32 },
+}
+
+/// A provider middleware injecting network misbehavior, to check that services built on the SDK
+/// recover from it:
+///
+/// ```ignore
+/// let chaos = ChaosMiddleware::new(ChaosConfig {
+///     dropped_submissions: 0.5,
+///     ..Default::default()
+/// });
+/// let provider = provider.with_middleware(chaos.clone());
+/// ```
+///
+/// The faults are drawn from the seeded randomness of the test helpers, so a run can be
+/// reproduced from its [`test_seed`](crate::test_seed). Clones share the record of the injected
+/// faults.
+#[derive(Debug, Clone, Default)]
+pub struct ChaosMiddleware {
+    config: ChaosConfig,
+    faults: Arc<Mutex<Vec<Fault>>>,
+}
+
+impl ChaosMiddleware {
+    pub fn new(config: ChaosConfig) -> Self {
+        Self {
+            config,
+            faults: Default::default(),
+        }
+    }
+
+    /// The faults injected so far, in order.
+    pub fn faults(&self) -> Vec<Fault> {
+        self.faults.lock().expect("not poisoned").clone()
+    }
+
+    fn inject(&self, probability: f64, fault: Fault) -> bool {
+        let injected = probability > 0.0 && with_test_rng(|rng| rng.gen_bool(probability.min(1.0)));
+        if injected {
+            self.faults.lock().expect("not poisoned").push(fault);
+        }
+
+        injected
+    }
+}
+
+#[async_trait]
+impl Middleware for ChaosMiddleware {
+    async fn handle(
+        &self,
+        request: &mut NodeRequest<'_>,
+        next: Next<'_>,
+    ) -> io::Result<NodeResponse> {
+        let operation = request.operation;
+
+        if !self.config.max_delay.is_zero() {
+            let delay = with_test_rng(|rng| rng.gen_range(Duration::ZERO..=self.config.max_delay));
+            tokio::time::sleep(delay).await;
+        }
+
+        if self.inject(
+            self.config.failed_requests,
+            Fault::FailedRequest { operation },
+        ) {
+            return Err(io::Error::other(format!("chaos: `{operation}` failed")));
+        }
+
+        if SUBMISSIONS.contains(&operation) {
+            if self.inject(self.config.dropped_submissions, Fault::DroppedSubmission) {
+                return Err(io::Error::other("chaos: submission dropped"));
+            }
+
+            if operation == "submit_and_await_commit"
+                && self.inject(self.config.squeezed_submissions, Fault::SqueezedSubmission)
+            {
+                return Ok(NodeResponse::new(TransactionStatus::SqueezedOut {
+                    reason: "chaos: transaction squeezed out".to_string(),
+                }));
+            }
+
+            let response = next.run(request).await?;
+            if self.inject(
+                self.config.lost_submission_responses,
+                Fault::LostSubmissionResponse,
+            ) {
+                return Err(io::Error::other("chaos: submission response lost"));
+            }
+
+            return Ok(response);
+        }
+
+        let mut response = next.run(request).await?;
+        if !BLOCK_QUERIES.contains(&operation) {
+            return Ok(response);
+        }
+
+        if let Some(Some(block)) = response.get_mut::<Option<Block>>() {
+            let height = block.header.height;
+            if self.inject(self.config.reorged_blocks, Fault::ReorgedBlock { height }) {
+                let other_branch = with_test_rng(|rng| rng.gen::<[u8; 32]>());
+                block.id = other_branch.into();
+                block.header.id = other_branch.into();
+            }
+        }
+
+        Ok(response)
+    }
+}
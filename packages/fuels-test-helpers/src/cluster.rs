@@ -0,0 +1,214 @@
+use std::time::Duration;
+
+use fuel_crypto::{PublicKey, SecretKey};
+use fuels_accounts::provider::Provider;
+use fuels_core::types::{
+    coin::Coin,
+    errors::{error, Result},
+    message::Message,
+};
+use portpicker::pick_unused_port;
+use tokio::time::{sleep, Instant};
+
+use crate::{
+    node_types::{ChainConfig, NodeConfig, P2pConfig, StateConfig, Trigger},
+    seed::with_test_rng,
+    testnet_chain_config,
+    utils::{into_coin_configs, into_message_configs},
+    FuelService,
+};
+
+const CLUSTER_NETWORK: &str = "fuels-test-cluster";
+const SYNC_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Local nodes sharing a genesis and connected over p2p: a producer, which produces the blocks,
+/// and validators, which never produce and sync the blocks of the producer.
+pub struct Cluster {
+    services: Vec<FuelService>,
+    providers: Vec<Provider>,
+}
+
+impl Cluster {
+    pub fn producer(&self) -> &Provider {
+        &self.providers[0]
+    }
+
+    pub fn validators(&self) -> &[Provider] {
+        &self.providers[1..]
+    }
+
+    /// The provider of the producer, followed by those of the validators.
+    pub fn providers(&self) -> &[Provider] {
+        &self.providers
+    }
+
+    /// Stops the node behind `self.providers()[index]`, e.g. to check how the others behave
+    /// while it is gone.
+    pub async fn stop_node(&self, index: usize) -> Result<()> {
+        let service = self
+            .services
+            .get(index)
+            .ok_or_else(|| error!(Other, "the cluster has no node at index {index}"))?;
+        service.stop().await?;
+
+        Ok(())
+    }
+
+    /// Waits until every node of the cluster is at `height` or above.
+    pub async fn await_height(&self, height: u32, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        for provider in &self.providers {
+            while provider.latest_block_height().await? < height {
+                if Instant::now() >= deadline {
+                    return Err(error!(
+                        Other,
+                        "node at `{}` did not reach height {height} within {timeout:?}",
+                        provider.url()
+                    ));
+                }
+                sleep(SYNC_POLL_INTERVAL).await;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Launches a producer and `num_validators` validators, without a relayer, all starting from the
+/// same chain and state, and connected to each other. The producer is started with the block
+/// production of `node_config`, the validators never produce blocks.
+///
+/// The nodes are connected over p2p, which requires the `fuel-core` binary: this is not supported
+/// with the `fuel-core-lib` feature.
+pub async fn launch_cluster(
+    num_validators: usize,
+    coins: Vec<Coin>,
+    messages: Vec<Message>,
+    node_config: Option<NodeConfig>,
+    chain_config: Option<ChainConfig>,
+) -> Result<Cluster> {
+    let node_config = node_config.unwrap_or_default();
+    let chain_config = chain_config.unwrap_or_else(testnet_chain_config);
+    let state_config = StateConfig {
+        coins: into_coin_configs(coins),
+        messages: into_message_configs(messages),
+        ..StateConfig::local_testnet()
+    };
+
+    let peers = (0..=num_validators)
+        .map(|_| {
+            let keypair = with_test_rng(SecretKey::random);
+            let peering_port =
+                pick_unused_port().ok_or_else(|| error!(Other, "could not pick a free port"))?;
+
+            Ok((keypair, peering_port))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut services = Vec::with_capacity(peers.len());
+    let mut providers = Vec::with_capacity(peers.len());
+    for (index, (keypair, peering_port)) in peers.iter().enumerate() {
+        let reserved_nodes = peers
+            .iter()
+            .enumerate()
+            .filter(|(other, _)| *other != index)
+            .map(|(_, (keypair, port))| peer_multiaddr(keypair, *port))
+            .collect();
+
+        let block_production = if index == 0 {
+            node_config.block_production.clone()
+        } else {
+            Trigger::Never
+        };
+
+        let config = NodeConfig {
+            block_production,
+            p2p: Some(P2pConfig {
+                keypair: *keypair,
+                peering_port: *peering_port,
+                reserved_nodes,
+                network: CLUSTER_NETWORK.to_string(),
+            }),
+            ..node_config.clone()
+        };
+
+        let service =
+            FuelService::start(config, chain_config.clone(), state_config.clone()).await?;
+        providers.push(Provider::from(service.bound_address()).await?);
+        services.push(service);
+    }
+
+    Ok(Cluster {
+        services,
+        providers,
+    })
+}
+
+fn peer_multiaddr(keypair: &SecretKey, peering_port: u16) -> String {
+    format!("/ip4/127.0.0.1/tcp/{peering_port}/p2p/{}", peer_id(keypair))
+}
+
+/// The libp2p peer id of a node using `keypair`: the base58 encoding of the identity multihash
+/// of its protobuf encoded, compressed, secp256k1 public key.
+fn peer_id(keypair: &SecretKey) -> String {
+    const SECP256K1_KEY_TYPE: u8 = 2;
+    const IDENTITY_MULTIHASH: u8 = 0;
+
+    let public_key = PublicKey::from(keypair);
+    let (x, y) = public_key.as_ref().split_at(32);
+    let prefix = if y[31] % 2 == 0 { 0x02 } else { 0x03 };
+
+    let mut encoded_key = vec![0x08, SECP256K1_KEY_TYPE, 0x12, 33, prefix];
+    encoded_key.extend_from_slice(x);
+
+    let mut multihash = vec![IDENTITY_MULTIHASH, encoded_key.len() as u8];
+    multihash.extend(encoded_key);
+
+    base58(&multihash)
+}
+
+fn base58(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    let mut digits: Vec<u8> = vec![];
+    for &byte in bytes {
+        let mut carry = byte as usize;
+        for digit in digits.iter_mut() {
+            carry += (*digit as usize) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let leading_zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+
+    std::iter::repeat(ALPHABET[0])
+        .take(leading_zeros)
+        .chain(digits.iter().rev().map(|&digit| ALPHABET[digit as usize]))
+        .map(char::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base58_matches_known_encodings() {
+        assert_eq!(base58(b""), "");
+        assert_eq!(base58(&[0, 0, 1]), "112");
+        assert_eq!(base58(b"hello world"), "StV1DL6CwTryKyV");
+    }
+
+    #[test]
+    fn peer_ids_are_secp256k1_identity_multihashes() {
+        let keypair = SecretKey::random(&mut rand::thread_rng());
+
+        // Every secp256k1 peer id has the same 37 bytes multihash prefix.
+        assert!(peer_id(&keypair).starts_with("16Uiu2HA"));
+    }
+}
@@ -0,0 +1,218 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::Mutex,
+};
+
+use fuel_crypto::Hasher;
+use fuels_core::types::errors::{error, Result};
+
+/// Environment variable setting the directory in which [`build_sway_project`] caches artifacts.
+pub const SWAY_CACHE_DIR_ENV_VAR: &str = "FUELS_SWAY_CACHE_DIR";
+
+const FORC_MANIFEST: &str = "Forc.toml";
+
+/// The paths of the artifacts of a Sway project, to be given to e.g. `Contract::load_from`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwayArtifacts {
+    pub bin: PathBuf,
+    pub abi: PathBuf,
+    pub storage_slots: PathBuf,
+}
+
+/// Compiles the Sway project at `project` with `forc build --release`, unless it was already
+/// compiled from the same sources, and returns the paths of its artifacts.
+///
+/// The artifacts are cached by the hash of the sources of the project, or of its whole workspace
+/// if it is a workspace member, so that they are rebuilt as soon as a dependency changes. The
+/// cache is kept under [`SWAY_CACHE_DIR_ENV_VAR`] or the temporary directory of the system.
+pub fn build_sway_project(project: impl AsRef<Path>) -> Result<SwayArtifacts> {
+    // Tests building the same project in parallel would only compile it twice.
+    static BUILDING: Mutex<()> = Mutex::new(());
+
+    let project = project.as_ref();
+    let name = project_name(project)?;
+    let sources = workspace_root(project).unwrap_or_else(|| project.to_path_buf());
+
+    let cache_dir = std::env::var_os(SWAY_CACHE_DIR_ENV_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| std::env::temp_dir().join("fuels-sway-artifacts"));
+    let output_dir = cache_dir.join(format!("{name}-{}", hash_sources(&sources)?));
+
+    let artifacts = SwayArtifacts {
+        bin: output_dir.join(format!("{name}.bin")),
+        abi: output_dir.join(format!("{name}-abi.json")),
+        storage_slots: output_dir.join(format!("{name}-storage_slots.json")),
+    };
+
+    let _guard = BUILDING
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if artifacts.bin.exists() {
+        return Ok(artifacts);
+    }
+
+    let forc = which::which("forc").map_err(|_| error!(Other, "no `forc` in PATH"))?;
+    let build_dir = tempfile::Builder::new()
+        .prefix(&format!("{name}-"))
+        .tempdir_in(create_dir(&cache_dir)?)?;
+
+    let output = Command::new(forc)
+        .arg("build")
+        .arg("--release")
+        .arg("--path")
+        .arg(project)
+        .arg("--output-directory")
+        .arg(build_dir.path())
+        .output()?;
+
+    if !output.status.success() {
+        return Err(error!(
+            Other,
+            "`forc build` failed for `{}`: {}",
+            project.display(),
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    // Moved in place once complete, so that a build interrupted midway isn't taken for a cached
+    // one.
+    fs::rename(build_dir.into_path(), &output_dir)?;
+
+    Ok(artifacts)
+}
+
+fn create_dir(dir: &Path) -> Result<&Path> {
+    fs::create_dir_all(dir)?;
+
+    Ok(dir)
+}
+
+fn project_name(project: &Path) -> Result<String> {
+    let manifest = fs::read_to_string(project.join(FORC_MANIFEST))?;
+
+    let mut in_project_table = false;
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_project_table = line == "[project]";
+        } else if let Some(value) = line
+            .strip_prefix("name")
+            .map(str::trim_start)
+            .and_then(|rest| rest.strip_prefix('='))
+            .filter(|_| in_project_table)
+        {
+            return Ok(value.trim().trim_matches('"').to_string());
+        }
+    }
+
+    Err(error!(
+        Other,
+        "no project name in the manifest of `{}`",
+        project.display()
+    ))
+}
+
+/// The closest ancestor of `project` with a workspace manifest.
+fn workspace_root(project: &Path) -> Option<PathBuf> {
+    let project = project.canonicalize().ok()?;
+
+    project.ancestors().skip(1).find_map(|dir| {
+        let manifest = fs::read_to_string(dir.join(FORC_MANIFEST)).ok()?;
+
+        manifest
+            .lines()
+            .any(|line| line.trim() == "[workspace]")
+            .then(|| dir.to_path_buf())
+    })
+}
+
+/// Hashes the paths and contents of the Sway sources and manifests under `dir`, leaving out the
+/// build outputs.
+fn hash_sources(dir: &Path) -> Result<String> {
+    let mut files = vec![];
+    collect_sources(dir, &mut files)?;
+    files.sort();
+
+    let mut hasher = Hasher::default();
+    for file in files {
+        let relative = file.strip_prefix(dir).unwrap_or(&file);
+        hasher.input(relative.to_string_lossy().as_bytes());
+        hasher.input(fs::read(&file)?);
+    }
+
+    let hash = format!("{:x}", hasher.finalize());
+
+    Ok(hash[..16].to_string())
+}
+
+fn collect_sources(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+
+        if path.is_dir() {
+            if file_name != "out" && !file_name.starts_with('.') {
+                collect_sources(&path, files)?;
+            }
+        } else if path.extension().is_some_and(|extension| extension == "sw")
+            || file_name == FORC_MANIFEST
+            || file_name == "Forc.lock"
+        {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_project(dir: &Path, main: &str) -> Result<()> {
+        fs::create_dir_all(dir.join("src"))?;
+        fs::write(
+            dir.join(FORC_MANIFEST),
+            "[project]\nentry = \"main.sw\"\nname = \"my_contract\"\n",
+        )?;
+        fs::write(dir.join("src/main.sw"), main)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn sources_are_hashed_without_the_build_outputs() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        write_project(dir.path(), "contract;")?;
+        let hash = hash_sources(dir.path())?;
+
+        fs::create_dir_all(dir.path().join("out/release"))?;
+        fs::write(dir.path().join("out/release/my_contract.bin"), [1, 2, 3])?;
+        assert_eq!(hash_sources(dir.path())?, hash);
+
+        write_project(dir.path(), "contract; // changed")?;
+        assert_ne!(hash_sources(dir.path())?, hash);
+
+        Ok(())
+    }
+
+    #[test]
+    fn members_are_hashed_with_their_workspace() -> Result<()> {
+        let workspace = tempfile::tempdir()?;
+        fs::write(
+            workspace.path().join(FORC_MANIFEST),
+            "[workspace]\nmembers = [\"my_contract\"]\n",
+        )?;
+        let project = workspace.path().join("my_contract");
+        write_project(&project, "contract;")?;
+
+        assert_eq!(project_name(&project)?, "my_contract");
+        assert_eq!(
+            workspace_root(&project),
+            Some(workspace.path().canonicalize()?)
+        );
+
+        Ok(())
+    }
+}
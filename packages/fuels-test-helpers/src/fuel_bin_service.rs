@@ -89,6 +89,18 @@ impl ExtendedConfig {
             self.node_config.static_gas_price
         ));
 
+        if let Some(p2p) = &self.node_config.p2p {
+            args.extend([
+                "--enable-p2p".to_string(),
+                format!("--keypair={}", p2p.keypair),
+                format!("--peering-port={}", p2p.peering_port),
+                format!("--network={}", p2p.network),
+            ]);
+            if !p2p.reserved_nodes.is_empty() {
+                args.push(format!("--reserved-nodes={}", p2p.reserved_nodes.join(",")));
+            }
+        }
+
         Ok(args)
     }
 
@@ -0,0 +1,109 @@
+use std::{fmt::Debug, fs, path::Path};
+
+/// Environment variable which, when set, makes [`assert_state_snapshot`] overwrite the snapshots
+/// instead of comparing against them.
+pub const UPDATE_SNAPSHOTS_ENV_VAR: &str = "FUELS_UPDATE_SNAPSHOTS";
+
+/// Asserts that `state`, e.g. the storage of a contract decoded through its getters, matches the
+/// snapshot at `path`, and panics with the lines that differ otherwise.
+///
+/// The snapshot is written on the first run, or on every run with [`UPDATE_SNAPSHOTS_ENV_VAR`]
+/// set, and is meant to be committed so that unintended state changes show up across refactors.
+///
+/// ```ignore
+/// let state = (
+///     contract.methods().owner().simulate(Execution::StateReadOnly).await?.value,
+///     contract.methods().total_supply().simulate(Execution::StateReadOnly).await?.value,
+/// );
+/// assert_state_snapshot("tests/snapshots/token.snap", &state);
+/// ```
+#[track_caller]
+pub fn assert_state_snapshot(path: impl AsRef<Path>, state: &impl Debug) {
+    let path = path.as_ref();
+    let actual = format!("{state:#?}\n");
+
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) if std::env::var_os(UPDATE_SNAPSHOTS_ENV_VAR).is_none() => expected,
+        _ => {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).expect("could not create the snapshot directory");
+            }
+            fs::write(path, &actual).expect("could not write the snapshot");
+
+            return;
+        }
+    };
+
+    if actual != expected {
+        panic!(
+            "the state differs from the snapshot `{}`, rerun with `{UPDATE_SNAPSHOTS_ENV_VAR}=1` if the change is intended:\n{}",
+            path.display(),
+            line_diff(&expected, &actual)
+        );
+    }
+}
+
+/// The lines of `expected` missing from `actual`, prefixed with `-`, and the lines added to it,
+/// prefixed with `+`, between the common lines.
+fn line_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<_> = expected.lines().collect();
+    let actual: Vec<_> = actual.lines().collect();
+
+    // Lengths of the longest common subsequences of the suffixes of both sides.
+    let mut common = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i][j] = if expected[i] == actual[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        let line = if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            i += 1;
+            j += 1;
+            format!("  {}", expected[i - 1])
+        } else if i < expected.len() && (j == actual.len() || common[i + 1][j] >= common[i][j + 1])
+        {
+            i += 1;
+            format!("- {}", expected[i - 1])
+        } else {
+            j += 1;
+            format!("+ {}", actual[j - 1])
+        };
+
+        diff.push_str(&line);
+        diff.push('\n');
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshots_are_written_then_compared() {
+        let dir = tempfile::tempdir().expect("creates a temporary directory");
+        let path = dir.path().join("snapshots/state.snap");
+
+        assert_state_snapshot(&path, &(1, "owner"));
+        assert_state_snapshot(&path, &(1, "owner"));
+
+        let result = std::panic::catch_unwind(|| assert_state_snapshot(&path, &(2, "owner")));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn diffs_show_the_changed_lines() {
+        let diff = line_diff("a\nb\nc\n", "a\nd\nc\n");
+
+        assert_eq!(diff, "  a\n- b\n+ d\n  c\n");
+    }
+}
@@ -3,7 +3,11 @@ extern crate core;
 
 #[cfg(feature = "fuels-accounts")]
 pub use accounts::*;
-use fuel_tx::{Bytes32, ConsensusParameters, ContractParameters, TxParameters, UtxoId};
+use fuel_core_chain_config::{ContractConfig, ContractStateConfig};
+use fuel_tx::{
+    Bytes32, ConsensusParameters, Contract, ContractParameters, Salt, StorageSlot, TxParameters,
+    UtxoId,
+};
 use fuel_types::{AssetId, Nonce};
 use fuels_accounts::provider::Provider;
 use fuels_core::types::{
@@ -12,8 +16,12 @@ use fuels_core::types::{
     errors::Result,
     message::{Message, MessageStatus},
 };
+#[cfg(feature = "mock-provider")]
+pub use mock_provider::MockProvider;
 pub use node_types::*;
-use rand::{rngs::StdRng, Fill, Rng, SeedableRng};
+use rand::{Fill, Rng};
+use seed::with_test_rng;
+pub use seed::{set_test_seed, test_seed, TEST_SEED_ENV_VAR};
 use utils::{into_coin_configs, into_message_configs};
 pub use wallets_config::*;
 mod node_types;
@@ -24,6 +32,45 @@ pub(crate) mod fuel_bin_service;
 #[cfg(feature = "fuels-accounts")]
 mod accounts;
 
+pub use assertions::*;
+mod assertions;
+
+#[cfg(feature = "fuels-accounts")]
+pub use chaos::*;
+#[cfg(feature = "fuels-accounts")]
+mod chaos;
+
+#[cfg(not(feature = "fuel-core-lib"))]
+pub use cluster::*;
+#[cfg(not(feature = "fuel-core-lib"))]
+mod cluster;
+
+#[cfg(feature = "receipt-coverage")]
+pub use receipt_coverage::*;
+#[cfg(feature = "receipt-coverage")]
+mod receipt_coverage;
+
+pub use forc::*;
+mod forc;
+
+pub use golden::*;
+mod golden;
+
+#[cfg(feature = "mock-provider")]
+mod mock_provider;
+
+#[cfg(feature = "fuels-accounts")]
+pub use node_pool::*;
+#[cfg(feature = "fuels-accounts")]
+mod node_pool;
+
+#[cfg(feature = "fuels-accounts")]
+pub use refill::*;
+#[cfg(feature = "fuels-accounts")]
+mod refill;
+
+mod seed;
+
 pub use service::*;
 mod service;
 
@@ -41,18 +88,19 @@ pub fn setup_multiple_assets_coins(
     coins_per_asset: u64,
     amount_per_coin: u64,
 ) -> (Vec<Coin>, Vec<AssetId>) {
-    let mut rng = rand::thread_rng();
     // Create `num_asset-1` asset ids so there is `num_asset` in total with the base asset
-    let asset_ids = (0..(num_asset - 1))
-        .map(|_| {
-            let mut random_asset_id = AssetId::zeroed();
-            random_asset_id
-                .try_fill(&mut rng)
-                .expect("failed to fill with random data");
-            random_asset_id
-        })
-        .chain([AssetId::zeroed()])
-        .collect::<Vec<AssetId>>();
+    let asset_ids = with_test_rng(|rng| {
+        (0..(num_asset - 1))
+            .map(|_| {
+                let mut random_asset_id = AssetId::zeroed();
+                random_asset_id
+                    .try_fill(rng)
+                    .expect("failed to fill with random data");
+                random_asset_id
+            })
+            .chain([AssetId::zeroed()])
+            .collect::<Vec<AssetId>>()
+    });
 
     let coins = asset_ids
         .iter()
@@ -82,23 +130,25 @@ pub fn setup_single_asset_coins(
     num_coins: u64,
     amount_per_coin: u64,
 ) -> Vec<Coin> {
-    let mut rng = rand::thread_rng();
-
-    let coins: Vec<Coin> = (1..=num_coins)
-        .map(|_i| {
-            let mut r = Bytes32::zeroed();
-            r.try_fill(&mut rng)
-                .expect("failed to fill with random data");
-            let utxo_id = UtxoId::new(r, 0);
-
-            Coin {
-                owner: owner.clone(),
-                utxo_id,
-                amount: amount_per_coin,
-                asset_id,
-                status: CoinStatus::Unspent,
-                block_created: Default::default(),
-            }
+    let utxo_ids = with_test_rng(|rng| {
+        (1..=num_coins)
+            .map(|_| {
+                let mut r = Bytes32::zeroed();
+                r.try_fill(rng).expect("failed to fill with random data");
+                UtxoId::new(r, 0)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let coins: Vec<Coin> = utxo_ids
+        .into_iter()
+        .map(|utxo_id| Coin {
+            owner: owner.clone(),
+            utxo_id,
+            amount: amount_per_coin,
+            asset_id,
+            status: CoinStatus::Unspent,
+            block_created: Default::default(),
         })
         .collect();
 
@@ -123,15 +173,64 @@ pub fn setup_single_message(
     }
 }
 
+/// Creates the coins and messages funding each address as configured. The addresses can be of
+/// anything, e.g. a predicate computed in the test, so that it is funded from genesis instead of
+/// by a transfer from a wallet.
+pub fn setup_funded_addresses(
+    funds: &[(Bech32Address, WalletConfig)],
+) -> (Vec<Coin>, Vec<Message>) {
+    let mut coins = vec![];
+    let mut messages = vec![];
+    for (address, config) in funds {
+        coins.extend(setup_custom_assets_coins(address, &config.assets));
+        messages.extend(config.messages.iter().map(|message| {
+            setup_single_message(
+                &Bech32Address::default(),
+                address,
+                message.amount,
+                Nonce::from(generate_random_salt()),
+                message.data.clone(),
+            )
+        }));
+    }
+
+    (coins, messages)
+}
+
+/// Creates the genesis config of a contract, e.g. from the binary, salt and storage slots of a
+/// `Contract` loaded from a forc project. Added to the
+/// `contracts` of a [`StateConfig`], the contract exists from the start at the id it would be
+/// deployed at, without a deployment transaction.
+pub fn setup_genesis_contract(
+    binary: Vec<u8>,
+    salt: Salt,
+    storage_slots: &[StorageSlot],
+) -> ContractConfig {
+    let contract = Contract::from(binary.as_slice());
+    let code_root = contract.root();
+    let state_root = Contract::initial_state_root(storage_slots.iter());
+
+    ContractConfig {
+        contract_id: contract.id(&salt, &code_root, &state_root),
+        code: binary,
+        salt,
+        states: storage_slots
+            .iter()
+            .map(|slot| ContractStateConfig {
+                key: *slot.key(),
+                value: slot.value().to_vec(),
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
 pub async fn setup_test_provider(
     coins: Vec<Coin>,
     messages: Vec<Message>,
     node_config: Option<NodeConfig>,
     chain_config: Option<ChainConfig>,
 ) -> Result<Provider> {
-    let node_config = node_config.unwrap_or_default();
-    let chain_config = chain_config.unwrap_or_else(testnet_chain_config);
-
     let coin_configs = into_coin_configs(coins);
     let message_configs = into_message_configs(messages);
 
@@ -141,6 +240,31 @@ pub async fn setup_test_provider(
         ..StateConfig::local_testnet()
     };
 
+    setup_test_provider_from_state(state_config, node_config, chain_config).await
+}
+
+/// Like [`setup_test_provider`], with the genesis coins and messages funding each address as
+/// configured. See [`setup_funded_addresses`].
+pub async fn setup_test_provider_with_funds(
+    funds: &[(Bech32Address, WalletConfig)],
+    node_config: Option<NodeConfig>,
+    chain_config: Option<ChainConfig>,
+) -> Result<Provider> {
+    let (coins, messages) = setup_funded_addresses(funds);
+
+    setup_test_provider(coins, messages, node_config, chain_config).await
+}
+
+/// Boots a node from `state_config`, e.g. a snapshot of another network exported with
+/// [`Provider::state_snapshot`], and connects a provider to it.
+pub async fn setup_test_provider_from_state(
+    state_config: StateConfig,
+    node_config: Option<NodeConfig>,
+    chain_config: Option<ChainConfig>,
+) -> Result<Provider> {
+    let node_config = node_config.unwrap_or_default();
+    let chain_config = chain_config.unwrap_or_else(testnet_chain_config);
+
     let srv = FuelService::start(node_config, chain_config, state_config).await?;
 
     let address = srv.bound_address();
@@ -154,7 +278,7 @@ pub async fn setup_test_provider(
 }
 
 // Testnet ChainConfig with increased tx size and contract size limits
-fn testnet_chain_config() -> ChainConfig {
+pub(crate) fn testnet_chain_config() -> ChainConfig {
     let mut consensus_parameters = ConsensusParameters::default();
     let tx_params = TxParameters::default().with_max_size(10_000_000);
     let contract_params = ContractParameters::default().with_contract_max_size(1_000_000);
@@ -168,7 +292,7 @@ fn testnet_chain_config() -> ChainConfig {
 }
 
 pub fn generate_random_salt() -> [u8; 32] {
-    StdRng::from_entropy().gen()
+    with_test_rng(|rng| rng.gen())
 }
 
 #[cfg(test)]
@@ -347,6 +471,32 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn consensus_parameters_are_overridden_by_the_node_config() -> Result<()> {
+        let node_config = NodeConfig::default()
+            .with_max_gas_per_tx(1_000)
+            .with_block_gas_limit(5_000)
+            .with_max_inputs(3)
+            .with_max_outputs(4)
+            .with_contract_max_size(100);
+
+        let provider = setup_test_provider(vec![], vec![], Some(node_config), None).await?;
+        let consensus_parameters = provider.consensus_parameters();
+
+        assert_eq!(consensus_parameters.tx_params().max_gas_per_tx(), 1_000);
+        assert_eq!(consensus_parameters.block_gas_limit(), 5_000);
+        assert_eq!(consensus_parameters.tx_params().max_inputs(), 3);
+        assert_eq!(consensus_parameters.tx_params().max_outputs(), 4);
+        assert_eq!(
+            consensus_parameters.contract_params().contract_max_size(),
+            100
+        );
+        // The other parameters are those of the chain config.
+        assert_eq!(consensus_parameters.tx_params().max_size(), 10_000_000);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_chain_config_and_consensus_parameters() -> Result<()> {
         let max_inputs = 123;
@@ -0,0 +1,428 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use fuels_accounts::provider::Provider;
+use fuels_core::types::errors::{error, Result};
+use http_body_util::{BodyExt, Full};
+use hyper::{
+    body::{Bytes, Incoming},
+    header::CONTENT_TYPE,
+    server::conn::http1,
+    service::service_fn,
+    Request, Response,
+};
+use hyper_util::rt::TokioIo;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::{net::TcpListener, task::JoinHandle};
+
+const SUBSCRIPTION_PATH: &str = "/v1/graphql-sub";
+
+/// A fake node answering the GraphQL requests of a [`Provider`] without running `fuel-core`.
+///
+/// Responses come from a fixture recorded against a live node with [`record`](Self::record),
+/// replayed with [`replay`](Self::replay), and can be overridden per GraphQL field with
+/// [`set_response`](Self::set_response) and [`set_error`](Self::set_error).
+///
+/// Identical requests are answered with the responses recorded for them, in order, the last one
+/// being repeated once the others were used.
+pub struct MockProvider {
+    url: String,
+    state: Arc<State>,
+    server: JoinHandle<()>,
+}
+
+impl MockProvider {
+    /// A mock answering only the responses set with [`set_response`](Self::set_response).
+    pub async fn new() -> Result<Self> {
+        Self::start(Mode::Replay, vec![]).await
+    }
+
+    /// Replays the requests recorded in `fixture`.
+    pub async fn replay(fixture: impl AsRef<Path>) -> Result<Self> {
+        let fixture = fixture.as_ref();
+        let contents = fs::read_to_string(fixture)
+            .map_err(|e| error!(Other, "could not read fixture `{}`: {e}", fixture.display()))?;
+        let exchanges = serde_json::from_str(&contents)?;
+
+        Self::start(Mode::Replay, exchanges).await
+    }
+
+    /// Forwards every request to the node at `node_url`, writing the requests and their
+    /// responses to `fixture` as they happen.
+    pub async fn record(node_url: impl AsRef<str>, fixture: impl Into<PathBuf>) -> Result<Self> {
+        let node_url = node_url.as_ref().trim_end_matches('/');
+        let node_url = if node_url.starts_with("http") {
+            node_url.to_string()
+        } else {
+            format!("http://{node_url}")
+        };
+        let node_url = node_url
+            .strip_suffix("/v1/graphql")
+            .unwrap_or(&node_url)
+            .to_string();
+
+        let mode = Mode::Record {
+            node_url,
+            client: Default::default(),
+            fixture: fixture.into(),
+        };
+
+        Self::start(mode, vec![]).await
+    }
+
+    async fn start(mode: Mode, exchanges: Vec<Exchange>) -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?);
+
+        let state = Arc::new(State {
+            mode,
+            exchanges: Mutex::new(exchanges),
+            replayed: Default::default(),
+            responses: Default::default(),
+        });
+        let server = tokio::spawn(serve(listener, state.clone()));
+
+        Ok(Self { url, state, server })
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// A provider connected to the mock.
+    pub async fn provider(&self) -> Result<Provider> {
+        Provider::connect(&self.url).await
+    }
+
+    /// Answers every request for the GraphQL `field`, e.g. `nodeInfo` or `balance`, with
+    /// `value`, recorded responses included.
+    pub fn set_response(&self, field: &str, value: Value) {
+        let response = json!({ "data": { field: value } });
+        self.state.set_response(field, response);
+    }
+
+    /// Fails every request for the GraphQL `field` with `message`.
+    pub fn set_error(&self, field: &str, message: &str) {
+        self.state.set_response(field, graphql_error(message));
+    }
+
+    /// Goes back to the recorded responses for the GraphQL `field`.
+    pub fn clear_response(&self, field: &str) {
+        self.state
+            .responses
+            .lock()
+            .expect("poisoned lock")
+            .remove(field);
+    }
+}
+
+impl Drop for MockProvider {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+impl std::fmt::Debug for MockProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockProvider")
+            .field("url", &self.url)
+            .field("mode", &self.state.mode)
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+enum Mode {
+    Replay,
+    Record {
+        node_url: String,
+        client: reqwest::Client,
+        fixture: PathBuf,
+    },
+}
+
+/// A request and the response of the node, as stored in fixtures.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Exchange {
+    query: String,
+    variables: Value,
+    #[serde(flatten)]
+    response: Recorded,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Recorded {
+    /// The body of the response to a query or mutation.
+    Response(Value),
+    /// The events sent in response to a subscription.
+    Events(Vec<Value>),
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphqlRequest {
+    query: String,
+    #[serde(default)]
+    variables: Value,
+}
+
+impl GraphqlRequest {
+    /// The first field selected by the request. `FuelClient` queries select a single field.
+    fn field(&self) -> Option<&str> {
+        let selection = self.query.split_once('{')?.1.trim_start();
+        let end = selection
+            .find(|c: char| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(selection.len());
+
+        (end > 0).then(|| &selection[..end])
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    mode: Mode,
+    exchanges: Mutex<Vec<Exchange>>,
+    replayed: Mutex<HashMap<(String, String), usize>>,
+    responses: Mutex<HashMap<String, Value>>,
+}
+
+impl State {
+    fn set_response(&self, field: &str, response: Value) {
+        self.responses
+            .lock()
+            .expect("poisoned lock")
+            .insert(field.to_string(), response);
+    }
+
+    async fn respond(&self, path: &str, request: GraphqlRequest) -> Recorded {
+        let is_subscription = path == SUBSCRIPTION_PATH;
+
+        let programmed = request.field().and_then(|field| {
+            self.responses
+                .lock()
+                .expect("poisoned lock")
+                .get(field)
+                .cloned()
+        });
+        if let Some(response) = programmed {
+            return if is_subscription {
+                Recorded::Events(vec![response])
+            } else {
+                Recorded::Response(response)
+            };
+        }
+
+        match &self.mode {
+            Mode::Replay => self.replay(&request),
+            Mode::Record {
+                node_url,
+                client,
+                fixture,
+            } => {
+                let response = forward(
+                    client,
+                    &format!("{node_url}{path}"),
+                    &request,
+                    is_subscription,
+                )
+                .await
+                .unwrap_or_else(|e| Recorded::Response(graphql_error(&e.to_string())));
+
+                let mut exchanges = self.exchanges.lock().expect("poisoned lock");
+                exchanges.push(Exchange {
+                    query: request.query,
+                    variables: request.variables,
+                    response: response.clone(),
+                });
+                // Saved after every request so that the fixture is complete whenever the test
+                // stops.
+                if let Ok(contents) = serde_json::to_string_pretty(&*exchanges) {
+                    let _ = fs::write(fixture, contents);
+                }
+
+                response
+            }
+        }
+    }
+
+    fn replay(&self, request: &GraphqlRequest) -> Recorded {
+        let exchanges = self.exchanges.lock().expect("poisoned lock");
+        let matching = exchanges
+            .iter()
+            .filter(|exchange| {
+                exchange.query == request.query && exchange.variables == request.variables
+            })
+            .collect::<Vec<_>>();
+
+        let Some(last) = matching.len().checked_sub(1) else {
+            let field = request.field().unwrap_or(&request.query);
+            return Recorded::Response(graphql_error(&format!(
+                "no recorded response for `{field}`"
+            )));
+        };
+
+        let key = (request.query.clone(), request.variables.to_string());
+        let mut replayed = self.replayed.lock().expect("poisoned lock");
+        let count = replayed.entry(key).or_default();
+        let exchange = matching[(*count).min(last)];
+        *count += 1;
+
+        exchange.response.clone()
+    }
+}
+
+fn graphql_error(message: &str) -> Value {
+    json!({ "errors": [{ "message": message }] })
+}
+
+async fn forward(
+    client: &reqwest::Client,
+    url: &str,
+    request: &GraphqlRequest,
+    is_subscription: bool,
+) -> reqwest::Result<Recorded> {
+    let response = client
+        .post(url)
+        .json(&json!({ "query": request.query, "variables": request.variables }))
+        .send()
+        .await?;
+
+    if !is_subscription {
+        return Ok(Recorded::Response(response.json().await?));
+    }
+
+    let events = response
+        .text()
+        .await?
+        .lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .filter_map(|data| serde_json::from_str(data.trim()).ok())
+        .collect();
+
+    Ok(Recorded::Events(events))
+}
+
+async fn serve(listener: TcpListener, state: Arc<State>) {
+    loop {
+        let Ok((stream, _)) = listener.accept().await else {
+            continue;
+        };
+
+        let state = state.clone();
+        tokio::spawn(async move {
+            let service = service_fn(|request| handle_request(request, &state));
+            let _ = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await;
+        });
+    }
+}
+
+async fn handle_request(
+    request: Request<Incoming>,
+    state: &State,
+) -> hyper::Result<Response<Full<Bytes>>> {
+    let path = request.uri().path().to_string();
+    let body = request.into_body().collect().await?.to_bytes();
+
+    let response = match serde_json::from_slice::<GraphqlRequest>(&body) {
+        Ok(request) => state.respond(&path, request).await,
+        Err(e) => Recorded::Response(graphql_error(&format!("invalid request: {e}"))),
+    };
+
+    let (content_type, body) = match response {
+        Recorded::Response(body) => ("application/json", body.to_string()),
+        Recorded::Events(events) => (
+            "text/event-stream",
+            events
+                .iter()
+                .map(|event| format!("data: {event}\n\n"))
+                .collect(),
+        ),
+    };
+
+    Ok(Response::builder()
+        .header(CONTENT_TYPE, content_type)
+        .body(Full::new(Bytes::from(body)))
+        .expect("valid response"))
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_core_client::client::FuelClient;
+
+    use super::*;
+
+    fn node_info(version: &str) -> Value {
+        json!({
+            "utxoValidation": true,
+            "vmBacktrace": false,
+            "maxTx": "4064",
+            "maxDepth": "10",
+            "nodeVersion": version,
+        })
+    }
+
+    #[tokio::test]
+    async fn answers_programmed_responses() -> Result<()> {
+        let mock = MockProvider::new().await?;
+        let client = FuelClient::new(mock.url()).map_err(|e| error!(Other, "{e}"))?;
+
+        assert!(client.node_info().await.is_err());
+
+        mock.set_response("nodeInfo", node_info("0.31.0"));
+        assert_eq!(client.node_info().await?.node_version, "0.31.0");
+
+        mock.set_error("nodeInfo", "node is down");
+        let error = client.node_info().await.expect_err("should fail");
+        assert!(error.to_string().contains("node is down"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replays_recorded_responses_in_order() -> Result<()> {
+        let query = "query {\n  nodeInfo {\n    nodeVersion\n  }\n}\n";
+        let exchange = |version| Exchange {
+            query: query.to_string(),
+            variables: Value::Null,
+            response: Recorded::Response(json!({ "data": { "nodeInfo": node_info(version) } })),
+        };
+
+        let fixture = tempfile::NamedTempFile::new()?;
+        fs::write(
+            fixture.path(),
+            serde_json::to_string(&[exchange("0.31.0"), exchange("0.31.1")])?,
+        )?;
+
+        let mock = MockProvider::replay(fixture.path()).await?;
+        let request = || GraphqlRequest {
+            query: query.to_string(),
+            variables: Value::Null,
+        };
+        let version = |recorded| match recorded {
+            Recorded::Response(body) => body["data"]["nodeInfo"]["nodeVersion"].clone(),
+            Recorded::Events(_) => panic!("expected a response"),
+        };
+
+        assert_eq!(
+            version(mock.state.respond("/v1/graphql", request()).await),
+            "0.31.0"
+        );
+        assert_eq!(
+            version(mock.state.respond("/v1/graphql", request()).await),
+            "0.31.1"
+        );
+        assert_eq!(
+            version(mock.state.respond("/v1/graphql", request()).await),
+            "0.31.1"
+        );
+
+        Ok(())
+    }
+}
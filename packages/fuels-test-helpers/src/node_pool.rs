@@ -0,0 +1,380 @@
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex as StdMutex, OnceLock,
+    },
+    thread,
+};
+
+use fuel_core_chain_config::StateConfig;
+use fuel_crypto::{Hasher, SecretKey};
+use fuel_tx::Salt;
+use fuel_types::Nonce;
+use fuels_accounts::{provider::Provider, wallet::WalletUnlocked};
+use fuels_core::types::{
+    bech32::Bech32Address,
+    errors::{error, Result},
+};
+use tokio::sync::{oneshot, Mutex};
+
+use crate::{
+    generate_random_salt,
+    node_types::{ChainConfig, NodeConfig},
+    setup_custom_assets_coins, setup_single_message, testnet_chain_config,
+    utils::{into_coin_configs, into_message_configs},
+    wallets_config::WalletsConfig,
+    FuelService,
+};
+
+const DEFAULT_NAMESPACES_PER_NODE: usize = 64;
+
+static SHARED_POOL: OnceLock<NodePool> = OnceLock::new();
+
+/// Configures the nodes of a [`NodePool`].
+#[derive(Debug, Clone)]
+pub struct NodePoolConfig {
+    /// The wallets of every namespace.
+    pub wallets: WalletsConfig,
+    /// The namespaces funded in the genesis of a node. Once they are all checked out, the next
+    /// checkout boots another node.
+    pub namespaces_per_node: usize,
+    pub node_config: NodeConfig,
+    pub chain_config: ChainConfig,
+}
+
+impl Default for NodePoolConfig {
+    fn default() -> Self {
+        Self {
+            wallets: WalletsConfig::default(),
+            namespaces_per_node: DEFAULT_NAMESPACES_PER_NODE,
+            node_config: NodeConfig::default(),
+            chain_config: testnet_chain_config(),
+        }
+    }
+}
+
+/// Hands out isolated [`Namespace`]s of a single long-lived node to the tests running in
+/// parallel, instead of booting a node per test:
+///
+/// ```ignore
+/// #[tokio::test]
+/// async fn my_test() -> Result<()> {
+///     let namespace = NodePool::shared().checkout().await?;
+///     let wallet = &namespace.wallets()[0];
+///     // ...
+/// }
+/// ```
+///
+/// The node runs on a thread of its own, so that it outlives the runtime of the test that
+/// booted it. The pool keeps it running for as long as the pool lives, so that tests checking
+/// out namespaces one after the other share it, and a node replaced by a new one is stopped once
+/// its last namespace is dropped.
+///
+/// The pool of [`NodePool::shared`] is never dropped: call [`NodePool::shutdown`] once its tests
+/// are done to stop its node. Otherwise, the node keeps running until the process exits.
+pub struct NodePool {
+    config: NodePoolConfig,
+    node: Mutex<Option<Arc<PooledNode>>>,
+}
+
+impl NodePool {
+    pub fn new(config: NodePoolConfig) -> Self {
+        Self {
+            config,
+            node: Mutex::new(None),
+        }
+    }
+
+    /// The pool of the process, with the default [`NodePoolConfig`].
+    pub fn shared() -> &'static NodePool {
+        SHARED_POOL.get_or_init(|| NodePool::new(NodePoolConfig::default()))
+    }
+
+    /// Stops the current node of the pool and waits for it to be stopped, even if some of its
+    /// namespaces are still checked out. The next checkout boots a new node.
+    pub async fn shutdown(&self) -> Result<()> {
+        let node = self.node.lock().await.take();
+
+        match node {
+            Some(node) => node.stop().await,
+            None => Ok(()),
+        }
+    }
+
+    /// Checks out a namespace of the current node, booting a node if none is running or all of
+    /// its namespaces are taken.
+    pub async fn checkout(&self) -> Result<Namespace> {
+        let (node, index) = {
+            let mut current = self.node.lock().await;
+
+            let node = match current.as_ref() {
+                Some(node)
+                    if node.checked_out.load(Ordering::Relaxed)
+                        < self.config.namespaces_per_node =>
+                {
+                    node.clone()
+                }
+                _ => {
+                    let node = Arc::new(PooledNode::start(self.config.clone()).await?);
+                    *current = Some(node.clone());
+
+                    node
+                }
+            };
+            let index = node.checked_out.fetch_add(1, Ordering::Relaxed);
+
+            (node, index)
+        };
+
+        let provider = Provider::from(node.address).await?;
+        let wallets = (0..self.config.wallets.num_wallets())
+            .map(|wallet| {
+                WalletUnlocked::new_from_private_key(
+                    namespace_key(index, wallet),
+                    Some(provider.clone()),
+                )
+            })
+            .collect();
+
+        Ok(Namespace {
+            _node: node,
+            index,
+            provider,
+            wallets,
+            salts: AtomicU64::new(0),
+        })
+    }
+}
+
+/// Funded wallets and salts that no other namespace of the node uses. Keeps the node running
+/// while it is alive, even once the pool moved on to another node.
+pub struct Namespace {
+    _node: Arc<PooledNode>,
+    index: usize,
+    provider: Provider,
+    wallets: Vec<WalletUnlocked>,
+    salts: AtomicU64,
+}
+
+impl Namespace {
+    pub fn provider(&self) -> &Provider {
+        &self.provider
+    }
+
+    /// The wallets of the namespace, funded as configured by [`NodePoolConfig::wallets`].
+    pub fn wallets(&self) -> &[WalletUnlocked] {
+        &self.wallets
+    }
+
+    /// A salt that differs on every call and from the salts of the other namespaces, so that the
+    /// same contract can be deployed by tests sharing the node.
+    pub fn next_salt(&self) -> Salt {
+        let mut salt = [0u8; 32];
+        salt[..8].copy_from_slice(&(self.index as u64).to_be_bytes());
+        salt[8..16].copy_from_slice(&self.salts.fetch_add(1, Ordering::Relaxed).to_be_bytes());
+
+        salt.into()
+    }
+}
+
+/// Receives the outcome of stopping a node.
+type StopReply = oneshot::Sender<Result<()>>;
+
+struct PooledNode {
+    address: SocketAddr,
+    checked_out: AtomicUsize,
+    shutdown: StdMutex<Option<oneshot::Sender<StopReply>>>,
+}
+
+impl PooledNode {
+    async fn start(config: NodePoolConfig) -> Result<Self> {
+        let (started, on_start) = oneshot::channel();
+        let (shutdown, on_shutdown) = oneshot::channel::<StopReply>();
+
+        thread::spawn(move || {
+            let runtime = match tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+            {
+                Ok(runtime) => runtime,
+                Err(err) => {
+                    let _ = started.send(Err(error!(Other, "could not start a runtime: {err}")));
+                    return;
+                }
+            };
+
+            runtime.block_on(async move {
+                let state_config = genesis_state(&config);
+                let service =
+                    FuelService::start(config.node_config, config.chain_config, state_config).await;
+
+                let service = match service {
+                    Ok(service) => service,
+                    Err(err) => {
+                        let _ = started.send(Err(err));
+                        return;
+                    }
+                };
+                let _ = started.send(Ok(service.bound_address()));
+
+                // The pool may also be dropped without waiting for the node to stop.
+                let stopped = on_shutdown.await.ok();
+                let result = service.stop().await.map(|_| ());
+                if let Some(stopped) = stopped {
+                    let _ = stopped.send(result);
+                }
+            });
+        });
+
+        let address = on_start
+            .await
+            .map_err(|_| error!(Other, "the node of the pool stopped while starting"))??;
+
+        Ok(Self {
+            address,
+            checked_out: AtomicUsize::new(0),
+            shutdown: StdMutex::new(Some(shutdown)),
+        })
+    }
+
+    fn take_shutdown(&self) -> Option<oneshot::Sender<StopReply>> {
+        self.shutdown.lock().expect("poisoned lock").take()
+    }
+
+    /// Stops the node and waits for it to be stopped.
+    async fn stop(&self) -> Result<()> {
+        let Some(shutdown) = self.take_shutdown() else {
+            return Ok(());
+        };
+
+        let (stopped, on_stop) = oneshot::channel();
+        if shutdown.send(stopped).is_err() {
+            return Ok(());
+        }
+
+        on_stop
+            .await
+            .map_err(|_| error!(Other, "the node of the pool stopped unexpectedly"))?
+    }
+}
+
+impl Drop for PooledNode {
+    fn drop(&mut self) {
+        // Waiting for the node to stop would block the runtime of the test dropping the last
+        // namespace.
+        if let Some(shutdown) = self.take_shutdown() {
+            let (stopped, _) = oneshot::channel();
+            let _ = shutdown.send(stopped);
+        }
+    }
+}
+
+fn namespace_key(namespace: usize, wallet: u64) -> SecretKey {
+    let hash = Hasher::default()
+        .chain(b"fuels-node-pool")
+        .chain((namespace as u64).to_be_bytes())
+        .chain(wallet.to_be_bytes())
+        .finalize();
+
+    SecretKey::try_from(hash.as_slice()).expect("a hash is a valid secret key")
+}
+
+fn genesis_state(config: &NodePoolConfig) -> StateConfig {
+    let mut coins = vec![];
+    let mut messages = vec![];
+    for namespace in 0..config.namespaces_per_node {
+        for (wallet, wallet_config) in (0..).zip(config.wallets.wallet_configs()) {
+            let owner =
+                WalletUnlocked::new_from_private_key(namespace_key(namespace, wallet), None)
+                    .address()
+                    .clone();
+
+            coins.extend(setup_custom_assets_coins(&owner, &wallet_config.assets));
+            messages.extend(wallet_config.messages.into_iter().map(|message| {
+                setup_single_message(
+                    &Bech32Address::default(),
+                    &owner,
+                    message.amount,
+                    Nonce::from(generate_random_salt()),
+                    message.data,
+                )
+            }));
+        }
+    }
+
+    StateConfig {
+        coins: into_coin_configs(coins),
+        messages: into_message_configs(messages),
+        ..StateConfig::local_testnet()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_accounts::ViewOnlyAccount;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn namespaces_share_a_node() -> Result<()> {
+        let pool = NodePool::new(NodePoolConfig {
+            wallets: WalletsConfig::new(Some(2), Some(1), Some(100)),
+            namespaces_per_node: 2,
+            ..Default::default()
+        });
+
+        let first = pool.checkout().await?;
+        let second = pool.checkout().await?;
+        let third = pool.checkout().await?;
+
+        assert_eq!(first.provider().url(), second.provider().url());
+        assert_ne!(first.provider().url(), third.provider().url());
+
+        assert_ne!(first.wallets()[0].address(), second.wallets()[0].address());
+        assert_ne!(first.next_salt(), second.next_salt());
+        assert_ne!(first.next_salt(), first.next_salt());
+
+        for wallet in first.wallets().iter().chain(second.wallets()) {
+            let balance = wallet
+                .get_asset_balance(first.provider().base_asset_id())
+                .await?;
+            assert_eq!(balance, 100);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sequential_checkouts_reuse_the_node() -> Result<()> {
+        let pool = NodePool::new(NodePoolConfig {
+            wallets: WalletsConfig::new(Some(1), Some(1), Some(100)),
+            namespaces_per_node: 2,
+            ..Default::default()
+        });
+
+        let url = pool.checkout().await?.provider().url().to_string();
+        let second = pool.checkout().await?;
+
+        assert_eq!(second.provider().url(), url);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn shutdown_stops_the_node() -> Result<()> {
+        let pool = NodePool::new(NodePoolConfig {
+            wallets: WalletsConfig::new(Some(1), Some(1), Some(100)),
+            ..Default::default()
+        });
+
+        let first = pool.checkout().await?;
+        pool.shutdown().await?;
+
+        assert!(first.provider().latest_block_height().await.is_err());
+        let second = pool.checkout().await?;
+        second.provider().latest_block_height().await?;
+
+        Ok(())
+    }
+}
@@ -4,15 +4,20 @@ use std::{
     time::Duration,
 };
 
-pub use fuel_core_chain_config::{ChainConfig, StateConfig};
+pub use fuel_core_chain_config::{ChainConfig, ConsensusConfig, StateConfig};
+use fuel_crypto::SecretKey;
+use fuel_tx::ConsensusParameters;
 
 pub(crate) const MAX_DATABASE_CACHE_SIZE: usize = 10 * 1024 * 1024;
 
 #[derive(Clone, Debug)]
 pub enum Trigger {
     Instant,
+    /// Blocks are only produced on request, e.g. with `Provider::produce_blocks`.
     Never,
-    Interval { block_time: Duration },
+    Interval {
+        block_time: Duration,
+    },
 }
 
 #[cfg(feature = "fuel-core-lib")]
@@ -42,6 +47,56 @@ impl From<DbType> for fuel_core::service::DbType {
     }
 }
 
+/// Peer-to-peer networking of a node, used to connect the nodes of a cluster.
+#[derive(Clone, Debug)]
+pub struct P2pConfig {
+    /// Secret key from which the peer id of the node is derived.
+    pub keypair: SecretKey,
+    pub peering_port: u16,
+    /// Multiaddresses of the peers the node stays connected to.
+    pub reserved_nodes: Vec<String>,
+    pub network: String,
+}
+
+/// Consensus parameters overriding those of the chain a node is started with, to reproduce
+/// limit-related edge cases without building a whole [`ChainConfig`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConsensusParametersOverrides {
+    pub max_gas_per_tx: Option<u64>,
+    /// The max gas of all the transactions of a block.
+    pub block_gas_limit: Option<u64>,
+    pub max_inputs: Option<u16>,
+    pub max_outputs: Option<u16>,
+    pub contract_max_size: Option<u64>,
+}
+
+impl ConsensusParametersOverrides {
+    pub(crate) fn apply(&self, consensus_parameters: &mut ConsensusParameters) {
+        let mut tx_params = *consensus_parameters.tx_params();
+        if let Some(max_gas_per_tx) = self.max_gas_per_tx {
+            tx_params = tx_params.with_max_gas_per_tx(max_gas_per_tx);
+        }
+        if let Some(max_inputs) = self.max_inputs {
+            tx_params = tx_params.with_max_inputs(max_inputs);
+        }
+        if let Some(max_outputs) = self.max_outputs {
+            tx_params = tx_params.with_max_outputs(max_outputs);
+        }
+        consensus_parameters.set_tx_params(tx_params);
+
+        if let Some(contract_max_size) = self.contract_max_size {
+            let contract_params = consensus_parameters
+                .contract_params()
+                .with_contract_max_size(contract_max_size);
+            consensus_parameters.set_contract_params(contract_params);
+        }
+
+        if let Some(block_gas_limit) = self.block_gas_limit {
+            consensus_parameters.set_block_gas_limit(block_gas_limit);
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct NodeConfig {
     pub addr: SocketAddr,
@@ -53,6 +108,37 @@ pub struct NodeConfig {
     pub vm_backtrace: bool,
     pub silent: bool,
     pub static_gas_price: u64,
+    /// Only supported by the `fuel-core` binary, which is built with p2p.
+    pub p2p: Option<P2pConfig>,
+    /// Applied on top of the consensus parameters of the chain config.
+    pub consensus_parameters_overrides: ConsensusParametersOverrides,
+}
+
+impl NodeConfig {
+    pub fn with_max_gas_per_tx(mut self, max_gas_per_tx: u64) -> Self {
+        self.consensus_parameters_overrides.max_gas_per_tx = Some(max_gas_per_tx);
+        self
+    }
+
+    pub fn with_block_gas_limit(mut self, block_gas_limit: u64) -> Self {
+        self.consensus_parameters_overrides.block_gas_limit = Some(block_gas_limit);
+        self
+    }
+
+    pub fn with_max_inputs(mut self, max_inputs: u16) -> Self {
+        self.consensus_parameters_overrides.max_inputs = Some(max_inputs);
+        self
+    }
+
+    pub fn with_max_outputs(mut self, max_outputs: u16) -> Self {
+        self.consensus_parameters_overrides.max_outputs = Some(max_outputs);
+        self
+    }
+
+    pub fn with_contract_max_size(mut self, contract_max_size: u64) -> Self {
+        self.consensus_parameters_overrides.contract_max_size = Some(contract_max_size);
+        self
+    }
 }
 
 impl Default for NodeConfig {
@@ -67,6 +153,8 @@ impl Default for NodeConfig {
             vm_backtrace: false,
             silent: true,
             static_gas_price: 1,
+            p2p: None,
+            consensus_parameters_overrides: ConsensusParametersOverrides::default(),
         }
     }
 }
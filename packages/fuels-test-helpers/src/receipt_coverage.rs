@@ -0,0 +1,272 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use fuel_core_client::client::types::TransactionStatus;
+use fuel_core_types::services::executor::TransactionExecutionResult;
+use fuel_tx::{ContractId, Receipt};
+use fuels_accounts::provider::middleware::{Middleware, Next, NodeRequest, NodeResponse};
+use fuels_core::types::errors::{error, Result};
+use serde::Deserialize;
+
+const INSTRUCTION_SIZE: u64 = 4;
+
+/// The receipt coverage of a single contract, or of the scripts for the zeroed contract id.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContractReceiptCoverage {
+    /// Number of calls to the contract.
+    pub calls: u64,
+    /// Indexes of the instructions of the contract that emitted a receipt.
+    pub reached_instructions: BTreeSet<u64>,
+}
+
+/// The instructions of each contract that emitted a receipt in the recorded transactions.
+///
+/// The node does not return instruction traces, so only the instructions that produced a
+/// receipt, i.e. calls, logs, returns, reverts, transfers and so on, are known to be reached.
+/// This is a lower bound of the instruction coverage, coarse but enough to see which functions
+/// and branches the tests never get to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReceiptCoverage {
+    contracts: BTreeMap<ContractId, ContractReceiptCoverage>,
+}
+
+impl ReceiptCoverage {
+    pub fn record(&mut self, receipts: &[Receipt]) {
+        for receipt in receipts {
+            if let Receipt::Call { to, .. } = receipt {
+                self.contracts.entry(*to).or_default().calls += 1;
+            }
+
+            let (Some(id), Some(pc), Some(is)) = (receipt.id(), receipt.pc(), receipt.is()) else {
+                continue;
+            };
+            self.contracts
+                .entry(*id)
+                .or_default()
+                .reached_instructions
+                .insert(pc.saturating_sub(is) / INSTRUCTION_SIZE);
+        }
+    }
+
+    pub fn contracts(&self) -> &BTreeMap<ContractId, ContractReceiptCoverage> {
+        &self.contracts
+    }
+
+    /// A report of the receipt coverage of every contract, with the reached lines of those that
+    /// have a source map, i.e. the debug info written by `forc build --output-debug`.
+    pub fn report(&self, source_maps: &HashMap<ContractId, SourceMap>) -> String {
+        let mut report = String::from(
+            "receipt coverage, a lower bound: only the instructions that emitted a receipt are \
+             counted as reached\n",
+        );
+
+        for (id, coverage) in &self.contracts {
+            let name = if *id == ContractId::zeroed() {
+                "scripts".to_string()
+            } else {
+                format!("contract {id}")
+            };
+            let _ = writeln!(
+                report,
+                "{name}: {} calls, at least {} instructions reached",
+                coverage.calls,
+                coverage.reached_instructions.len()
+            );
+
+            let Some(source_map) = source_maps.get(id) else {
+                continue;
+            };
+            for (path, lines) in source_map.lines() {
+                let reached: BTreeSet<_> = coverage
+                    .reached_instructions
+                    .iter()
+                    .filter_map(|instruction| source_map.line_of(*instruction))
+                    .filter(|(reached_path, _)| *reached_path == path)
+                    .map(|(_, line)| line)
+                    .collect();
+
+                let _ = writeln!(
+                    report,
+                    "  {}: at least {}/{} lines reached {:?}",
+                    path.display(),
+                    reached.len(),
+                    lines.len(),
+                    reached
+                );
+            }
+        }
+
+        report
+    }
+}
+
+/// Maps the instructions of a program to the lines of its Sway sources.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SourceMap {
+    paths: Vec<PathBuf>,
+    map: HashMap<u64, SourceMapSpan>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct SourceMapSpan {
+    path: usize,
+    range: SourceMapRange,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct SourceMapRange {
+    start: SourceMapPosition,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct SourceMapPosition {
+    line: usize,
+}
+
+impl SourceMap {
+    /// Loads the debug info written by `forc build --output-debug <path>`.
+    pub fn load_from(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)?;
+
+        serde_json::from_str(&json)
+            .map_err(|err| error!(Other, "invalid source map `{}`: {err}", path.display()))
+    }
+
+    fn line_of(&self, instruction: u64) -> Option<(&Path, usize)> {
+        let span = self.map.get(&instruction)?;
+        let path = self.paths.get(span.path)?;
+
+        Some((path, span.range.start.line))
+    }
+
+    /// The lines with instructions, per source file.
+    fn lines(&self) -> BTreeMap<&Path, BTreeSet<usize>> {
+        let mut lines: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+        for instruction in self.map.keys() {
+            if let Some((path, line)) = self.line_of(*instruction) {
+                lines.entry(path).or_default().insert(line);
+            }
+        }
+
+        lines
+    }
+}
+
+/// A provider middleware recording the [`ReceiptCoverage`] of the transactions submitted and
+/// dry-run through the provider:
+///
+/// ```ignore
+/// let coverage = ReceiptCoverageMiddleware::default();
+/// let provider = provider.with_middleware(coverage.clone());
+///
+/// // ... run the tests ...
+///
+/// println!("{}", coverage.receipt_coverage().report(&source_maps));
+/// ```
+///
+/// Clones share the recorded coverage.
+#[derive(Debug, Clone, Default)]
+pub struct ReceiptCoverageMiddleware {
+    coverage: Arc<Mutex<ReceiptCoverage>>,
+}
+
+impl ReceiptCoverageMiddleware {
+    pub fn receipt_coverage(&self) -> ReceiptCoverage {
+        self.coverage.lock().expect("not poisoned").clone()
+    }
+
+    fn record(&self, receipts: &[Receipt]) {
+        self.coverage.lock().expect("not poisoned").record(receipts);
+    }
+}
+
+#[async_trait]
+impl Middleware for ReceiptCoverageMiddleware {
+    async fn handle(
+        &self,
+        request: &mut NodeRequest<'_>,
+        next: Next<'_>,
+    ) -> io::Result<NodeResponse> {
+        let response = next.run(request).await?;
+
+        // Statuses polled with `transaction_status` would record a transaction once per poll.
+        if request.operation == "submit_and_await_commit" {
+            if let Some(
+                TransactionStatus::Success { receipts, .. }
+                | TransactionStatus::Failure { receipts, .. },
+            ) = response.transaction_status()
+            {
+                self.record(receipts);
+            }
+        }
+        for status in response.execution_statuses().unwrap_or_default() {
+            let (TransactionExecutionResult::Success { receipts, .. }
+            | TransactionExecutionResult::Failed { receipts, .. }) = &status.result;
+            self.record(receipts);
+        }
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reached_instructions_are_mapped_to_lines() -> Result<()> {
+        let contract_id = ContractId::from([1; 32]);
+        let mut coverage = ReceiptCoverage::default();
+        coverage.record(&[
+            Receipt::Call {
+                id: ContractId::zeroed(),
+                to: contract_id,
+                amount: 0,
+                asset_id: Default::default(),
+                gas: 0,
+                param1: 0,
+                param2: 0,
+                pc: 10_000,
+                is: 10_000,
+            },
+            Receipt::Return {
+                id: contract_id,
+                val: 0,
+                pc: 20_008,
+                is: 20_000,
+            },
+        ]);
+
+        let source_map: SourceMap = serde_json::from_str(
+            r#"{
+                "paths": ["src/main.sw"],
+                "map": {
+                    "1": { "path": 0, "range": { "start": { "line": 3, "col": 1 }, "end": { "line": 3, "col": 9 } } },
+                    "2": { "path": 0, "range": { "start": { "line": 5, "col": 1 }, "end": { "line": 5, "col": 9 } } }
+                }
+            }"#,
+        )
+        .expect("is a valid source map");
+
+        assert_eq!(
+            coverage.contracts()[&contract_id],
+            ContractReceiptCoverage {
+                calls: 1,
+                reached_instructions: [2].into(),
+            }
+        );
+
+        let report = coverage.report(&[(contract_id, source_map)].into());
+        assert!(report.starts_with("receipt coverage, a lower bound"));
+        assert!(report.contains("src/main.sw: at least 1/2 lines reached {5}"));
+
+        Ok(())
+    }
+}
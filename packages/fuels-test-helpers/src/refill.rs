@@ -0,0 +1,230 @@
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use async_trait::async_trait;
+use fuel_tx::TxId;
+use fuel_types::AssetId;
+use fuels_accounts::{
+    provider::middleware::{Middleware, Next, NodeRequest, NodeResponse},
+    wallet::WalletUnlocked,
+    Account, ViewOnlyAccount,
+};
+use fuels_core::types::{
+    bech32::Bech32Address,
+    errors::Result,
+    transaction::{ScriptTransaction, TxPolicies},
+};
+use tokio::sync::Mutex;
+
+/// When and how much a [`RefillMiddleware`] tops up the watched wallets.
+#[derive(Debug, Clone)]
+pub struct RefillConfig {
+    /// A wallet is topped up once its balance drops below this amount.
+    pub threshold: u64,
+    pub amount: u64,
+    /// The asset the wallets spend, e.g. the base asset of the chain.
+    pub asset_id: AssetId,
+}
+
+/// A provider middleware topping up the watched wallets from a faucet wallet whenever a submitted
+/// transaction leaves one of them below the threshold, so that long runs don't stop halfway on
+/// drained wallets:
+///
+/// ```ignore
+/// let refill = RefillMiddleware::new(
+///     faucet,
+///     [wallet.address().clone()],
+///     RefillConfig {
+///         threshold: 100_000,
+///         amount: 1_000_000_000,
+///         asset_id: *provider.base_asset_id(),
+///     },
+/// );
+/// let provider = provider.with_middleware(refill.clone());
+/// wallet.set_provider(provider);
+/// ```
+///
+/// The faucet keeps the provider it had before the middleware was added, so that its transfers
+/// are not watched themselves.
+///
+/// A `submit` is only answered once its transaction is committed, so that the balances include
+/// its spending. A failed refill fails the submission that triggered it, even though its
+/// transaction went through.
+#[derive(Debug, Clone)]
+pub struct RefillMiddleware {
+    faucet: WalletUnlocked,
+    wallets: Arc<Vec<Bech32Address>>,
+    config: RefillConfig,
+    refilling: Arc<Mutex<()>>,
+    refills: Arc<AtomicU64>,
+}
+
+impl RefillMiddleware {
+    pub fn new(
+        faucet: WalletUnlocked,
+        wallets: impl IntoIterator<Item = Bech32Address>,
+        config: RefillConfig,
+    ) -> Self {
+        Self {
+            faucet,
+            wallets: Arc::new(wallets.into_iter().collect()),
+            config,
+            refilling: Default::default(),
+            refills: Default::default(),
+        }
+    }
+
+    /// The number of top-ups so far.
+    pub fn refills(&self) -> u64 {
+        self.refills.load(Ordering::Relaxed)
+    }
+
+    async fn refill(&self) -> Result<()> {
+        // Parallel commits would otherwise top up the same wallet twice.
+        let _guard = self.refilling.lock().await;
+        let provider = self.faucet.try_provider()?;
+
+        for wallet in self.wallets.iter() {
+            let balance = provider
+                .get_asset_balance(wallet, self.config.asset_id)
+                .await?;
+            if balance >= self.config.threshold {
+                continue;
+            }
+
+            self.faucet
+                .transfer(
+                    wallet,
+                    self.config.amount,
+                    self.config.asset_id,
+                    TxPolicies::default(),
+                )
+                .await?;
+            self.refills.fetch_add(1, Ordering::Relaxed);
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Middleware for RefillMiddleware {
+    async fn handle(
+        &self,
+        request: &mut NodeRequest<'_>,
+        next: Next<'_>,
+    ) -> io::Result<NodeResponse> {
+        let response = next.run(request).await?;
+
+        match request.operation {
+            "submit_and_await_commit" => {}
+            "submit" => {
+                if let Some(id) = response.get::<TxId>() {
+                    self.faucet
+                        .try_provider()
+                        .map_err(io::Error::other)?
+                        .await_transaction_commit::<ScriptTransaction>(*id)
+                        .await
+                        .map_err(io::Error::other)?;
+                }
+            }
+            _ => return Ok(response),
+        }
+
+        self.refill().await.map_err(|err| {
+            io::Error::other(format!(
+                "the transaction was submitted, but the test wallets could not be refilled: {err}"
+            ))
+        })?;
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::types::transaction_builders::{BuildableTransaction, ScriptTransactionBuilder};
+
+    use super::*;
+    use crate::{launch_custom_provider_and_get_wallets, WalletsConfig};
+
+    /// A wallet with 1_000_000 coins, topped up by 300_000 once below 600_000.
+    async fn watched_wallet() -> Result<(WalletUnlocked, RefillMiddleware)> {
+        let mut wallets = launch_custom_provider_and_get_wallets(
+            WalletsConfig::new(Some(2), Some(1), Some(1_000_000)),
+            None,
+            None,
+        )
+        .await?;
+        let mut wallet = wallets.pop().expect("has two wallets");
+        let faucet = wallets.pop().expect("has two wallets");
+
+        let refill = RefillMiddleware::new(
+            faucet,
+            [wallet.address().clone()],
+            RefillConfig {
+                threshold: 600_000,
+                amount: 300_000,
+                asset_id: AssetId::zeroed(),
+            },
+        );
+        let provider = wallet
+            .try_provider()?
+            .clone()
+            .with_middleware(refill.clone());
+        wallet.set_provider(provider);
+
+        Ok((wallet, refill))
+    }
+
+    #[tokio::test]
+    async fn drained_wallets_are_refilled() -> Result<()> {
+        let (wallet, refill) = watched_wallet().await?;
+
+        wallet
+            .transfer(
+                &Bech32Address::default(),
+                500_000,
+                AssetId::zeroed(),
+                TxPolicies::default(),
+            )
+            .await?;
+
+        assert_eq!(refill.refills(), 1);
+        assert!(wallet.get_asset_balance(&AssetId::zeroed()).await? > 700_000);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wallets_are_refilled_after_plain_submissions() -> Result<()> {
+        let (wallet, refill) = watched_wallet().await?;
+
+        let amount = 500_000;
+        let inputs = wallet
+            .get_asset_inputs_for_amount(AssetId::zeroed(), amount, None)
+            .await?;
+        let outputs = wallet.get_asset_outputs_for_amount(
+            &Bech32Address::default(),
+            AssetId::zeroed(),
+            amount,
+        );
+        let mut tb =
+            ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+        wallet.add_witnesses(&mut tb)?;
+        wallet.adjust_for_fee(&mut tb, amount).await?;
+        let tx = tb.build(wallet.try_provider()?).await?;
+
+        wallet.send_transaction(tx).await?;
+
+        assert_eq!(refill.refills(), 1);
+        assert!(wallet.get_asset_balance(&AssetId::zeroed()).await? > 700_000);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use std::cell::RefCell;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Environment variable from which the seed of the test helpers is read, to reproduce a run.
+pub const TEST_SEED_ENV_VAR: &str = "FUELS_TEST_SEED";
+
+thread_local! {
+    static TEST_RNG: RefCell<Option<(u64, StdRng)>> = const { RefCell::new(None) };
+}
+
+/// Seeds the randomness of the test helpers on the current thread: the coins, asset ids, salts
+/// and keys they generate. Tests run on a thread of their own, so seeding at the start of a test
+/// makes its setup reproducible.
+pub fn set_test_seed(seed: u64) {
+    TEST_RNG.with(|rng| *rng.borrow_mut() = Some((seed, StdRng::seed_from_u64(seed))));
+}
+
+/// The seed of the test helpers on the current thread. Unless set with [`set_test_seed`], it is
+/// read from [`TEST_SEED_ENV_VAR`] or picked at random, and printed to stderr so that a failing
+/// test shows the seed to rerun it with.
+pub fn test_seed() -> u64 {
+    seeded(|seed, _| seed)
+}
+
+/// Runs `f` with the seeded random number generator of the current thread.
+pub(crate) fn with_test_rng<T>(f: impl FnOnce(&mut StdRng) -> T) -> T {
+    seeded(|_, rng| f(rng))
+}
+
+fn seeded<T>(f: impl FnOnce(u64, &mut StdRng) -> T) -> T {
+    TEST_RNG.with(|rng| {
+        let mut rng = rng.borrow_mut();
+        let (seed, rng) = rng.get_or_insert_with(|| {
+            let seed = std::env::var(TEST_SEED_ENV_VAR)
+                .ok()
+                .and_then(|seed| seed.parse().ok())
+                .unwrap_or_else(|| rand::thread_rng().gen());
+            eprintln!("fuels test helpers seeded with {TEST_SEED_ENV_VAR}={seed}");
+
+            (seed, StdRng::seed_from_u64(seed))
+        });
+
+        f(*seed, rng)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_same_seed_gives_the_same_values() {
+        set_test_seed(42);
+        let first = with_test_rng(|rng| rng.gen::<[u8; 32]>());
+
+        set_test_seed(42);
+        let second = with_test_rng(|rng| rng.gen::<[u8; 32]>());
+
+        assert_eq!(test_seed(), 42);
+        assert_eq!(first, second);
+    }
+}
@@ -23,11 +23,22 @@ pub struct FuelService {
 impl FuelService {
     pub async fn start(
         node_config: NodeConfig,
-        chain_config: ChainConfig,
+        mut chain_config: ChainConfig,
         state_config: StateConfig,
     ) -> Result<Self> {
+        node_config
+            .consensus_parameters_overrides
+            .apply(&mut chain_config.consensus_parameters);
+
         #[cfg(feature = "fuel-core-lib")]
         let service = {
+            if node_config.p2p.is_some() {
+                return Err(error!(
+                    Other,
+                    "p2p is only supported by the `fuel-core` binary, disable the `fuel-core-lib` feature"
+                ));
+            }
+
             let config = Self::service_config(node_config, chain_config, state_config);
             CoreFuelService::new_node(config)
                 .await
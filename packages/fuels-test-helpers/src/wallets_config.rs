@@ -13,10 +13,25 @@ pub struct AssetConfig {
     pub coin_amount: u64,
 }
 
-#[derive(Debug)]
+/// A message bridged to a wallet, which it can spend or, with data, relay.
+#[derive(Debug, Clone, Default)]
+pub struct BridgedMessageConfig {
+    pub amount: u64,
+    pub data: Vec<u8>,
+}
+
+/// The coins and messages of a single wallet.
+#[derive(Debug, Clone, Default)]
+pub struct WalletConfig {
+    pub assets: Vec<AssetConfig>,
+    pub messages: Vec<BridgedMessageConfig>,
+}
+
+#[derive(Debug, Clone)]
 pub struct WalletsConfig {
     num_wallets: u64,
     assets: Vec<AssetConfig>,
+    wallets: Vec<WalletConfig>,
 }
 
 impl WalletsConfig {
@@ -28,6 +43,7 @@ impl WalletsConfig {
                 num_coins: num_coins.unwrap_or(DEFAULT_NUM_COINS),
                 coin_amount: coin_amount.unwrap_or(DEFAULT_COIN_AMOUNT),
             }],
+            wallets: vec![],
         }
     }
 
@@ -35,6 +51,16 @@ impl WalletsConfig {
         Self {
             num_wallets,
             assets,
+            wallets: vec![],
+        }
+    }
+
+    /// One wallet per config, each with its own coins and messages.
+    pub fn new_per_wallet(wallets: Vec<WalletConfig>) -> Self {
+        Self {
+            num_wallets: wallets.len() as u64,
+            assets: vec![],
+            wallets,
         }
     }
 
@@ -42,9 +68,24 @@ impl WalletsConfig {
         self.num_wallets
     }
 
+    /// The assets of every wallet, empty when configured per wallet.
     pub fn assets(&self) -> &[AssetConfig] {
         &self.assets[..]
     }
+
+    /// The config of each wallet, in order.
+    pub fn wallet_configs(&self) -> Vec<WalletConfig> {
+        if !self.wallets.is_empty() {
+            return self.wallets.clone();
+        }
+
+        let uniform = WalletConfig {
+            assets: self.assets.clone(),
+            messages: vec![],
+        };
+
+        vec![uniform; self.num_wallets as usize]
+    }
 }
 
 impl Default for WalletsConfig {
@@ -56,6 +97,7 @@ impl Default for WalletsConfig {
                 num_coins: DEFAULT_NUM_COINS,
                 coin_amount: DEFAULT_COIN_AMOUNT,
             }],
+            wallets: vec![],
         }
     }
 }
@@ -33,6 +33,13 @@ pub mod macros {
     pub use fuels_macros::*;
 }
 
+#[cfg(feature = "std")]
+pub use fuels_macros::test;
+/// The runtime of the tests generated by [`test`], so that they don't need a `tokio` dependency.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use tokio;
+
 #[cfg(feature = "std")]
 pub mod programs {
     pub use fuels_programs::*;
@@ -89,7 +96,8 @@ pub mod prelude {
             bech32::{Bech32Address, Bech32ContractId},
             errors::{Error, Result},
             transaction::*,
-            Address, AssetId, Bytes, ContractId, RawSlice, Salt,
+            Address, AssetId, Bytes, ContractId, RawSlice, ReceiptIteratorExt, ReceiptMatcher,
+            Salt,
         },
     };
 }
@@ -5,11 +5,14 @@ mod tests {
     use std::{default::Default, str::FromStr};
 
     use fuels::{
-        accounts::predicate::Predicate,
+        accounts::{predicate::Predicate, wasm_provider::WasmProvider},
         core::{codec::ABIEncoder, traits::Tokenizable},
         macros::wasm_abigen,
-        types::{bech32::Bech32Address, errors::Result},
+        tx::ConsensusParameters,
+        types::{bech32::Bech32Address, errors::Result, DryRunner},
     };
+    use js_sys::JSON;
+    use wasm_bindgen::JsValue;
     use wasm_bindgen_test::wasm_bindgen_test;
 
     #[wasm_bindgen_test]
@@ -212,4 +215,146 @@ mod tests {
 
         Ok(())
     }
+
+    #[wasm_bindgen_test]
+    fn bindgen_converts_structs_to_js_values() {
+        wasm_abigen!(Contract(
+            name = "BindgenContract",
+            wasm_bindgen = true,
+            abi = r#"
+            {
+              "programType": "contract",
+              "specVersion": "1",
+              "encodingVersion": "1",
+              "concreteTypes": [
+                {
+                  "type": "()",
+                  "concreteTypeId": "2e38e77b22c314a449e91fafed92a43826ac6aa403ae6a8acb6cf58239fbaf5d"
+                },
+                {
+                  "type": "struct SomeStruct",
+                  "concreteTypeId": "c672b07b5808bcc04715d73ca6d42eaabd332266144c1017c20833ef05a4a484",
+                  "metadataTypeId": 1
+                }
+              ],
+              "metadataTypes": [
+                {
+                  "type": "bool",
+                  "metadataTypeId": 0
+                },
+                {
+                  "type": "struct SomeStruct",
+                  "metadataTypeId": 1,
+                  "components": [
+                    {
+                      "name": "a",
+                      "typeId": 2
+                    },
+                    {
+                      "name": "b",
+                      "typeId": 0
+                    }
+                  ]
+                },
+                {
+                  "type": "u32",
+                  "metadataTypeId": 2
+                }
+              ],
+              "functions": [
+                {
+                  "inputs": [
+                    {
+                      "name": "_arg",
+                      "concreteTypeId": "c672b07b5808bcc04715d73ca6d42eaabd332266144c1017c20833ef05a4a484"
+                    }
+                  ],
+                  "name": "test_function",
+                  "output": "2e38e77b22c314a449e91fafed92a43826ac6aa403ae6a8acb6cf58239fbaf5d"
+                }
+              ],
+              "loggedTypes": [],
+              "messagesTypes": [],
+              "configurables": []
+            }
+            "#
+        ));
+
+        let some_struct = SomeStruct { a: 123, b: false };
+        let js_value = JsValue::try_from(some_struct.clone()).unwrap();
+
+        assert_eq!(
+            JSON::stringify(&js_value).unwrap(),
+            r#"{"a":123,"b":false}"#
+        );
+        assert_eq!(SomeStruct::try_from(js_value).unwrap(), some_struct);
+    }
+
+    #[wasm_bindgen_test]
+    fn bindgen_encodes_predicate_data() -> Result<()> {
+        wasm_abigen!(Predicate(
+            name = "BindgenPredicate",
+            wasm_bindgen = true,
+            abi = r#"
+            {
+              "programType": "predicate",
+              "specVersion": "1",
+              "encodingVersion": "1",
+              "concreteTypes": [
+                {
+                  "type": "bool",
+                  "concreteTypeId": "b760f44fa5965c2474a3b471467a22c43185152129295af588b022ae50b50903"
+                },
+                {
+                  "type": "u64",
+                  "concreteTypeId": "1506e6f44c1d6291cdf46395a8e573276a4fa79e8ace3fc891e092ef32d1b0a0"
+                }
+              ],
+              "metadataTypes": [],
+              "functions": [
+                {
+                  "inputs": [
+                    {
+                      "name": "val",
+                      "concreteTypeId": "1506e6f44c1d6291cdf46395a8e573276a4fa79e8ace3fc891e092ef32d1b0a0"
+                    }
+                  ],
+                  "name": "main",
+                  "output": "b760f44fa5965c2474a3b471467a22c43185152129295af588b022ae50b50903",
+                  "attributes": null
+                }
+              ],
+              "loggedTypes": [],
+              "messagesTypes": [],
+              "configurables": []
+            }
+            "#
+        ));
+
+        assert_eq!(
+            encode_bindgen_predicate_data(JSON::parse("[129]").unwrap()).unwrap(),
+            BindgenPredicateEncoder::default().encode_data(129)?
+        );
+
+        Ok(())
+    }
+
+    fn assert_dry_runner<T: DryRunner>() {}
+
+    #[wasm_bindgen_test]
+    fn wasm_provider_is_a_dry_runner() {
+        assert_dry_runner::<WasmProvider>();
+    }
+
+    #[wasm_bindgen_test]
+    async fn wasm_provider_reports_unreachable_nodes() {
+        let err = WasmProvider::connect(
+            "http://127.0.0.1:1/v1/graphql",
+            ConsensusParameters::default(),
+        )
+        .await
+        .expect_err("no node listens on port 1");
+
+        assert!(err.to_string().contains("request to the node failed"));
+    }
 }
@@ -0,0 +1,55 @@
+//! IndexedDB is only available in browsers, so these tests run with
+//! `wasm-pack test --headless --firefox -- --test indexed_db_key_store`.
+
+use fuels::{
+    accounts::signers::web_crypto::{indexed_db::IndexedDbKeyStore, KeyStore, WebCryptoSigner},
+    core::traits::Signer,
+    crypto::{Message, PublicKey, SecretKey, Signature},
+    types::errors::Result,
+};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn stores_and_loads_secrets() -> Result<()> {
+    let store = IndexedDbKeyStore::new("stores-and-loads-secrets");
+
+    assert!(store.load("main").await?.is_none());
+
+    store.store("main", &[1, 2, 3]).await?;
+    assert_eq!(store.load("main").await?.as_deref(), Some(&vec![1, 2, 3]));
+
+    store.store("main", &[4, 5]).await?;
+    store.store("other", &[6]).await?;
+    assert_eq!(store.load("main").await?.as_deref(), Some(&vec![4, 5]));
+    assert_eq!(store.load("other").await?.as_deref(), Some(&vec![6]));
+
+    // A new handle to the same database reuses its wrapping key.
+    let reopened = IndexedDbKeyStore::new("stores-and-loads-secrets");
+    assert_eq!(reopened.load("main").await?.as_deref(), Some(&vec![4, 5]));
+
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn signer_survives_a_reload() -> Result<()> {
+    let store = IndexedDbKeyStore::new("signer-survives-a-reload");
+    let secret_key = SecretKey::try_from([7u8; 32].as_slice()).expect("valid secret key");
+
+    let signer = WebCryptoSigner::import(store.clone(), "main", secret_key).await?;
+    let reloaded = WebCryptoSigner::load(store, "main")
+        .await?
+        .expect("should be stored");
+
+    assert_eq!(reloaded.public_key(), &PublicKey::from(&secret_key));
+    assert_eq!(reloaded.address(), signer.address());
+
+    let message = Message::new("browser");
+    assert_eq!(
+        reloaded.sign(message).await?,
+        Signature::sign(&secret_key, &message)
+    );
+
+    Ok(())
+}